@@ -0,0 +1,120 @@
+//! The benchmarks of the app's hot paths: converting currencies, generating interest schedules, and looking up
+//! accounts in a large bank.
+
+use std::{collections::HashMap, fs, hint::black_box, path::PathBuf};
+
+use banking_and_currency_app::{
+    account::{AccountType, Contact},
+    bank::Bank,
+    crypto,
+    currency::{self, BASE_CURRENCY, RoundingPolicy},
+    interest::{self, Compounding, Contribution, DayCount, InterestMode, InterestTerms},
+    journal::{Entry, EntryKind, Journal},
+};
+use chrono::NaiveDate;
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use rust_decimal_macros::dec;
+
+/// The numbers of days that the interest schedules are generated for, up to a century.
+const DAY_CNTS: [u32; 3] = [365, 3_650, 36_500];
+
+/// The numbers of accounts in the banks that accounts are looked up in.
+const ACCOUNT_CNTS: [usize; 2] = [1_000, 10_000];
+
+/// Benchmarks converting an amount into, out of, and across Philippine Pesos.
+fn convert_currency(c: &mut Criterion) {
+    let rates = HashMap::from([(String::from("USD"), dec!(56.25)), (String::from("JPY"), dec!(0.3775))]);
+    let mut group = c.benchmark_group("convert_currency");
+
+    for (src, dest) in [("USD", BASE_CURRENCY), (BASE_CURRENCY, "JPY"), ("USD", "JPY")] {
+        group.bench_function(format!("{src} to {dest}"), |b| {
+            b.iter(|| currency::convert_currency(black_box(dec!(1234.56)), src, dest, &rates))
+        });
+    }
+
+    group.finish();
+}
+
+/// Benchmarks generating daily interest schedules with planned deposits over more and more days.
+fn interest_schedule(c: &mut Criterion) {
+    let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    let contribution = Contribution {
+        amount: dec!(1000),
+        interval_days: 30,
+    };
+    let mut group = c.benchmark_group("interest_schedule");
+
+    for mode in [InterestMode::Simple, InterestMode::Compound] {
+        let terms = InterestTerms {
+            annual_rate: dec!(0.05),
+            tiers: &[],
+            mode,
+            compounding: Compounding::Daily,
+            day_count: DayCount::ActualActual,
+            withholding_rate: dec!(0.2),
+            rounding: RoundingPolicy::HalfUp,
+        };
+
+        for day_cnt in DAY_CNTS {
+            group.bench_with_input(BenchmarkId::new(mode.to_string(), day_cnt), &day_cnt, |b, day_cnt| {
+                b.iter(|| interest::schedule(black_box(dec!(50000)), &terms, start, *day_cnt, Some(contribution)))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+/// Creates a bank with a number of accounts by replaying a journal of their registrations, since registering each
+/// would hash its PIN.
+fn bank_with_accounts(account_cnt: usize) -> Bank {
+    let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(format!("bench-{account_cnt}.jsonl"));
+    let pin_hash = crypto::hash_secret("1234");
+
+    let _ = fs::remove_file(&path);
+
+    let mut journal = Journal::open(&path).expect("Failed to open the journal...");
+
+    for i in 0..account_cnt {
+        let account = format!("Account {i}");
+
+        journal
+            .append(&Entry::new(
+                EntryKind::Register {
+                    owners: vec![account.clone(), format!("Owner {i}")],
+                    account,
+                    account_type: AccountType::Savings,
+                    interest_rate: AccountType::Savings.interest_rate(),
+                    contact: Contact::default(),
+                    pin_hash: pin_hash.clone(),
+                },
+                0,
+            ))
+            .expect("Failed to append to the journal...");
+    }
+
+    Bank::replay(journal).expect("Failed to replay the journal...")
+}
+
+/// Benchmarks looking up the last registered account by its name, number, and joint owner in larger and larger banks.
+fn account_lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("account_lookup");
+
+    for account_cnt in ACCOUNT_CNTS {
+        let bank = bank_with_accounts(account_cnt);
+        let last = &bank.accounts()[account_cnt - 1];
+        let (name, number) = (last.name.clone(), last.number.clone());
+        let owner = format!("Owner {}", account_cnt - 1);
+
+        for (query, key) in [("name", &name), ("number", &number), ("owner", &owner)] {
+            group.bench_with_input(BenchmarkId::new(query, account_cnt), key, |b, key| {
+                b.iter(|| bank.find_account(black_box(key)))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, convert_currency, interest_schedule, account_lookup);
+criterion_main!(benches);
@@ -0,0 +1,23 @@
+//! Generates the gRPC service from its protocol buffers definition, with a compiler written in Rust so that `protoc`
+//! isn't needed, and the C header of the FFI from its functions.
+
+fn main() {
+    #[cfg(feature = "grpc")]
+    {
+        let descriptors = protox::compile(["proto/mcos.proto"], ["proto"]).expect("Failed to compile the protos...");
+
+        tonic_prost_build::configure()
+            .build_client(false)
+            .compile_fds(descriptors)
+            .expect("Failed to generate the gRPC service...");
+    }
+
+    #[cfg(feature = "ffi")]
+    cbindgen::generate(env!("CARGO_MANIFEST_DIR"))
+        .expect("Failed to generate the C header...")
+        .write_to_file("include/mcos.h");
+
+    println!("cargo::rerun-if-changed=proto");
+    println!("cargo::rerun-if-changed=src/ffi.rs");
+    println!("cargo::rerun-if-changed=cbindgen.toml");
+}
@@ -0,0 +1,74 @@
+//! Throws arbitrary responses at the parsing of the amounts, currency codes, and choices that the prompts read, and at
+//! the transactions they are passed to, which must reject malformed input rather than panic.
+//!
+//! The input is split into lines like the responses to consecutive prompts: an amount, a currency, and a choice.
+
+#![no_main]
+
+use banking_and_currency_app::{
+    account::{AccountOrder, AccountType, Contact},
+    bank::Bank,
+    batch,
+    currency::{self, BASE_CURRENCY, RoundingPolicy},
+    interest::{Compounding, DayCount, InterestMode},
+    iso4217,
+    locale::Locale,
+};
+use libfuzzer_sys::fuzz_target;
+use rust_decimal::Decimal;
+
+fuzz_target!(|input: &str| {
+    let mut responses = input.lines().map(str::trim);
+    let amount = responses.next().unwrap_or_default();
+    let code = responses.next().unwrap_or_default();
+    let choice = responses.next().unwrap_or_default();
+
+    let iso = iso4217::find(code);
+    let code = iso.map_or_else(|| code.to_uppercase(), |c| c.code.to_string());
+
+    let _ = currency::is_valid_code(&code);
+    let _ = currency::find_code(&code);
+    let _ = currency::symbol(&code);
+    let _ = currency::minor_units(&code);
+
+    let _ = choice.parse::<usize>();
+    let _ = choice.parse::<AccountType>();
+    let _ = choice.parse::<AccountOrder>();
+    let _ = choice.parse::<Compounding>();
+    let _ = choice.parse::<DayCount>();
+    let _ = choice.parse::<InterestMode>();
+    let _ = choice.parse::<RoundingPolicy>();
+    let _ = choice.parse::<Locale>();
+
+    let _ = batch::parse_deposits(input);
+
+    let Ok(amount) = amount.parse::<Decimal>() else {
+        return;
+    };
+
+    let _ = currency::is_valid_amount(amount, None);
+
+    let mut bank = Bank::new();
+
+    bank.register(
+        String::from("Ana"),
+        Vec::new(),
+        AccountType::Savings,
+        None,
+        Contact::default(),
+        "1234",
+    )
+    .expect("Failed to register the account...");
+
+    let _ = bank.set_exchange_rate("USD", Decimal::from(56));
+    let _ = bank.deposit("Ana", &code, amount, None);
+    let _ = bank.withdraw("Ana", &code, amount, None);
+    let _ = bank.quote_exchange(BASE_CURRENCY, &code, amount);
+    let _ = bank.exchange(&code, "USD", amount);
+    let _ = bank.set_exchange_rate(&code, amount);
+    let _ = bank.convert("USD", &code, amount);
+    let _ = bank.set_spread(&code, amount);
+    let _ = bank.set_overdraft_limit("Ana", amount);
+    let _ = bank.set_interest_rate("Ana", amount);
+    let _ = bank.post_interest("Ana", None);
+});
@@ -0,0 +1,123 @@
+/* The C API of the banking and currency exchange app's Rust core, generated by cbindgen. */
+
+#ifndef MCOS_H
+#define MCOS_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The statuses returned by the API's fallible functions.
+typedef enum McosStatus {
+  // The function succeeded.
+  MCOS_STATUS_OK = 0,
+  // An argument was null, not UTF-8, or not a decimal string.
+  MCOS_STATUS_INVALID_ARGUMENT = 1,
+  // The bank rejected the transaction.
+  MCOS_STATUS_REJECTED = 2,
+  // The bank's journal couldn't be read or written.
+  MCOS_STATUS_IO = 3,
+} McosStatus;
+
+// A bank created or opened through the API, which is opaque to its callers.
+typedef struct McosBank McosBank;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Creates an empty bank that isn't saved anywhere, which must be freed with [`mcos_bank_free`].
+struct McosBank *mcos_bank_new(void);
+
+// Opens the bank recorded in a journal, like a profile's `journal.jsonl`, which records the transactions performed on
+// it and must be freed with [`mcos_bank_free`].
+//
+// Null is returned if the journal can't be read, with the error's message readable with [`mcos_last_error`].
+//
+// # Safety
+//
+// The path must be null or point to a null-terminated string.
+struct McosBank *mcos_bank_open(const char *journal_path);
+
+// Frees a bank, doing nothing if it is null.
+//
+// # Safety
+//
+// The bank must be null or returned by [`mcos_bank_new`] or [`mcos_bank_open`], and not freed already.
+void mcos_bank_free(struct McosBank *bank);
+
+// Registers a new savings account, protected by a PIN.
+//
+// # Safety
+//
+// The bank must be null or a live bank that isn't used elsewhere during the call, and the strings must be null or
+// null-terminated.
+enum McosStatus mcos_register(struct McosBank *bank,
+                              const char *name,
+                              const char *pin);
+
+// Deposits an amount in a currency to an account, writing its updated balance to `balance_out` unless it is null.
+//
+// # Safety
+//
+// The bank must be null or a live bank that isn't used elsewhere during the call, the strings must be null or
+// null-terminated, and `balance_out` must be null or valid for writes.
+enum McosStatus mcos_deposit(struct McosBank *bank,
+                             const char *account,
+                             const char *amount,
+                             const char *currency,
+                             char **balance_out);
+
+// Withdraws an amount in a currency from an account, writing its updated balance to `balance_out` unless it is null.
+//
+// # Safety
+//
+// The bank must be null or a live bank that isn't used elsewhere during the call, the strings must be null or
+// null-terminated, and `balance_out` must be null or valid for writes.
+enum McosStatus mcos_withdraw(struct McosBank *bank,
+                              const char *account,
+                              const char *amount,
+                              const char *currency,
+                              char **balance_out);
+
+// Sets the value of one unit of a foreign currency in Philippine Pesos.
+//
+// # Safety
+//
+// The bank must be null or a live bank that isn't used elsewhere during the call, and the strings must be null or
+// null-terminated.
+enum McosStatus mcos_set_exchange_rate(struct McosBank *bank,
+                                       const char *currency,
+                                       const char *rate);
+
+// Converts an amount from one currency to another at the bank's rates, after its fees and spread, without exchanging
+// it, writing the converted amount to `amount_out` unless it is null.
+//
+// # Safety
+//
+// The bank must be null or a live bank that isn't changed elsewhere during the call, the strings must be null or
+// null-terminated, and `amount_out` must be null or valid for writes.
+enum McosStatus mcos_convert(const struct McosBank *bank,
+                             const char *amount,
+                             const char *from,
+                             const char *to,
+                             char **amount_out);
+
+// Returns the message of the last error on the calling thread, or null if there was none.
+//
+// The message stays valid until the next error on the thread, and mustn't be freed.
+const char *mcos_last_error(void);
+
+// Frees a string returned by the API, doing nothing if it is null.
+//
+// # Safety
+//
+// The string must be null or written by the API, and not freed already.
+void mcos_string_free(char *string);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* MCOS_H */
@@ -0,0 +1,431 @@
+//! The user bank accounts.
+
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    clock, crypto,
+    currency::{self, BASE_CURRENCY, DEFAULT_MINOR_UNITS, MinorAmount, RoundingPolicy},
+    history::{Record, RecordKind, Stamp},
+    interest::{Compounding, SAVINGS_INTEREST_RATE, TIME_DEPOSIT_INTEREST_RATE},
+};
+
+/// The sequence number of the first account, which the following accounts' numbers count up from.
+const FIRST_SEQUENCE: u64 = 100_000_000;
+
+/// The number of days a time deposit must be kept before it can be withdrawn from without a penalty.
+pub const TIME_DEPOSIT_TERM_DAYS: u64 = 180;
+/// The percentage of an early withdrawal from a time deposit charged as a penalty.
+pub const EARLY_WITHDRAWAL_PENALTY_RATE: Decimal = dec!(0.02);
+
+/// The types of accounts, each with its own interest rate and withdrawal rules.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AccountType {
+    /// An account earning interest, which can be withdrawn from anytime.
+    #[default]
+    Savings,
+    /// An account earning no interest, which can be withdrawn from anytime.
+    Checking,
+    /// An account earning a higher interest, which is penalized when withdrawn from before its term ends.
+    TimeDeposit,
+}
+impl AccountType {
+    /// Every type of account, in the order they are listed.
+    pub const ALL: [AccountType; 3] = [AccountType::Savings, AccountType::Checking, AccountType::TimeDeposit];
+    /// The tags of the types of accounts.
+    pub const TAGS: [&str; 3] = ["savings", "checking", "time-deposit"];
+
+    /// Returns the annual interest rate that accounts of the type earn by default, as a fraction.
+    pub fn interest_rate(self) -> Decimal {
+        match self {
+            AccountType::Savings => SAVINGS_INTEREST_RATE,
+            AccountType::Checking => Decimal::ZERO,
+            AccountType::TimeDeposit => TIME_DEPOSIT_INTEREST_RATE,
+        }
+    }
+}
+impl fmt::Display for AccountType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", AccountType::TAGS[*self as usize])
+    }
+}
+impl FromStr for AccountType {
+    type Err = String;
+
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        match tag.replace('_', "-").to_lowercase().as_str() {
+            "savings" => Ok(AccountType::Savings),
+            "checking" => Ok(AccountType::Checking),
+            "time-deposit" => Ok(AccountType::TimeDeposit),
+            _ => Err(format!(
+                "unknown account type, expected one of: {}",
+                AccountType::TAGS.join(", ")
+            )),
+        }
+    }
+}
+
+/// The orders that accounts can be listed in.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AccountOrder {
+    /// Alphabetically by name.
+    #[default]
+    Name,
+    /// From the highest balance to the lowest.
+    Balance,
+}
+impl AccountOrder {
+    /// The tags of the orders.
+    pub const TAGS: [&str; 2] = ["name", "balance"];
+
+    /// Compares two accounts by the order.
+    pub fn compare(self, a: &Account, b: &Account) -> Ordering {
+        match self {
+            AccountOrder::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            AccountOrder::Balance => b.balance.amount().cmp(&a.balance.amount()),
+        }
+    }
+}
+impl fmt::Display for AccountOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", AccountOrder::TAGS[*self as usize])
+    }
+}
+impl FromStr for AccountOrder {
+    type Err = String;
+
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        match tag.to_lowercase().as_str() {
+            "name" => Ok(AccountOrder::Name),
+            "balance" => Ok(AccountOrder::Balance),
+            _ => Err(format!(
+                "unknown order, expected one of: {}",
+                AccountOrder::TAGS.join(", ")
+            )),
+        }
+    }
+}
+
+/// The criteria that listed accounts must meet.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AccountFilter {
+    /// The lowest balance an account can have.
+    pub min_balance: Option<Decimal>,
+    /// The type an account must be.
+    pub account_type: Option<AccountType>,
+}
+impl AccountFilter {
+    /// Checks whether an account meets every criterion.
+    pub fn matches(&self, account: &Account) -> bool {
+        self.min_balance.is_none_or(|min| account.balance.amount() >= min)
+            && self.account_type.is_none_or(|t| account.account_type == t)
+    }
+}
+
+/// The balance that a type of account must maintain and the fees charged for falling below it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MinimumBalance {
+    /// The lowest balance the accounts must maintain, or zero for no minimum.
+    pub amount: Decimal,
+    /// The fee charged for a withdrawal leaving an account below the minimum, or none to reject such withdrawals.
+    pub breach_fee: Option<Decimal>,
+    /// The fee charged to every account below the minimum at the end of each period.
+    pub monthly_fee: Decimal,
+}
+impl MinimumBalance {
+    /// Checks whether a balance falls below the minimum, which is never the case without one.
+    pub fn is_breached_by(&self, balance: Decimal) -> bool {
+        self.amount > Decimal::ZERO && balance < self.amount
+    }
+
+    /// Checks whether neither the minimum nor its fees are negative.
+    pub fn is_valid(&self) -> bool {
+        [self.amount, self.breach_fee.unwrap_or_default(), self.monthly_fee]
+            .iter()
+            .all(|n| *n >= Decimal::ZERO)
+    }
+}
+
+/// The statuses of an account, deciding which transactions it allows.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AccountStatus {
+    /// The account allows every transaction.
+    Open,
+    /// The account rejects deposits and withdrawals until it is unfrozen.
+    Frozen,
+    /// The account rejects every transaction, but keeps its record.
+    Closed,
+}
+
+/// The optional contact details of an account's owners.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Contact {
+    /// The email address.
+    pub email: Option<String>,
+    /// The phone number.
+    pub phone: Option<String>,
+}
+impl Contact {
+    /// Checks whether the email address, if any, has a name and a dotted domain around its `@`.
+    pub fn is_valid_email(&self) -> bool {
+        self.email.as_deref().is_none_or(|email| {
+            email.split_once('@').is_some_and(|(name, domain)| {
+                !name.is_empty()
+                    && !domain.starts_with('.')
+                    && !domain.ends_with('.')
+                    && domain.contains('.')
+                    && !email.contains(char::is_whitespace)
+            })
+        })
+    }
+
+    /// Checks whether the phone number, if any, has 7 to 15 digits, optionally separated by spaces or dashes and led by
+    /// a `+`.
+    pub fn is_valid_phone(&self) -> bool {
+        self.phone.as_deref().is_none_or(|phone| {
+            let digits = phone.strip_prefix('+').unwrap_or(phone);
+
+            digits.chars().all(|c| c.is_ascii_digit() || c == ' ' || c == '-')
+                && (7..=15).contains(&digits.chars().filter(char::is_ascii_digit).count())
+        })
+    }
+}
+
+/// The total amount withdrawn from an account on a day.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DailyTotal {
+    /// The day of the withdrawals, in days since the Unix epoch.
+    pub day: u64,
+    /// The total withdrawn amount, in the account's currency.
+    pub amount: Decimal,
+}
+
+/// A simple user bank account.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Account {
+    /// The unique 10-digit number of the account, ending with a check digit.
+    pub number: String,
+    /// The name of the account.
+    pub name: String,
+    /// The names of the account's owners, any of whom can transact with it.
+    pub owners: Vec<String>,
+    /// The current balance of the account, kept in the minor units of its currency.
+    pub balance: MinorAmount,
+    /// The currency that the account's balance is based on.
+    pub currency: String,
+    /// The type of the account.
+    pub account_type: AccountType,
+    /// The time the account was registered, in seconds since the Unix epoch.
+    pub opened_at: u64,
+    /// The contact details of the account's owners.
+    pub contact: Contact,
+    /// The Argon2 hash of the account's PIN, or an empty string if it has none.
+    pub pin_hash: String,
+    /// Whether the account was closed, which keeps its record but rejects further transactions.
+    pub is_closed: bool,
+    /// Whether the account was frozen, which rejects deposits and withdrawals until it is unfrozen.
+    pub is_frozen: bool,
+    /// How far below zero withdrawals can push the account's balance.
+    pub overdraft_limit: Decimal,
+    /// The most that can be withdrawn from the account each day, or none to use the bank's default.
+    pub daily_withdrawal_limit: Option<Decimal>,
+    /// The annual interest rate earned by the account, as a fraction.
+    pub interest_rate: Decimal,
+    /// How often the interest earned by the account is added to its balance.
+    pub compounding: Compounding,
+    /// When the interest earned by the account was last posted to its balance, in seconds since the Unix epoch, which
+    /// is when it was opened if interest was never posted.
+    pub interest_posted_at: u64,
+    /// The amount withdrawn from the account on the day of its latest withdrawal.
+    pub daily_withdrawals: DailyTotal,
+    /// The balance that the account's history starts from, which is only nonzero for accounts kept since before their
+    /// histories were.
+    pub opening_balance: Decimal,
+    /// The transactions against the account, from oldest to newest.
+    pub history: Vec<Record>,
+}
+impl Account {
+    /// Creates a new account of a type with the default values, earning the type's interest rate.
+    pub fn new(
+        name: String,
+        owners: Vec<String>,
+        number: String,
+        account_type: AccountType,
+        opened_at: u64,
+        contact: Contact,
+        pin_hash: String,
+    ) -> Account {
+        Account {
+            number,
+            name,
+            owners,
+            balance: MinorAmount::zero(currency::minor_units(BASE_CURRENCY).unwrap_or(DEFAULT_MINOR_UNITS)),
+            currency: String::from(BASE_CURRENCY),
+            account_type,
+            opened_at,
+            contact,
+            pin_hash,
+            is_closed: false,
+            is_frozen: false,
+            overdraft_limit: Decimal::ZERO,
+            daily_withdrawal_limit: None,
+            interest_rate: account_type.interest_rate(),
+            compounding: Compounding::default(),
+            interest_posted_at: opened_at,
+            daily_withdrawals: DailyTotal::default(),
+            opening_balance: Decimal::ZERO,
+            history: Vec::new(),
+        }
+    }
+
+    /// Returns the status of the account, with closure taking precedence over freezing.
+    pub fn status(&self) -> AccountStatus {
+        if self.is_closed {
+            AccountStatus::Closed
+        } else if self.is_frozen {
+            AccountStatus::Frozen
+        } else {
+            AccountStatus::Open
+        }
+    }
+
+    /// Returns the balance that can be withdrawn from the account, including its overdraft.
+    pub fn available_balance(&self) -> Decimal {
+        self.balance.amount() + self.overdraft_limit
+    }
+
+    /// Returns the amount withdrawn from the account on the day of a time, in seconds since the Unix epoch.
+    pub fn withdrawn_on(&self, timestamp: u64) -> Decimal {
+        if self.daily_withdrawals.day == clock::day(timestamp) {
+            self.daily_withdrawals.amount
+        } else {
+            Decimal::ZERO
+        }
+    }
+
+    /// Counts an amount withdrawn at a time, in seconds since the Unix epoch, toward the account's total for that day.
+    ///
+    /// A negative amount takes back an earlier withdrawal, which only changes the total if it was on the same day.
+    pub fn count_withdrawal(&mut self, amount: Decimal, timestamp: u64) {
+        let day = clock::day(timestamp);
+
+        if self.daily_withdrawals.day != day {
+            self.daily_withdrawals = DailyTotal {
+                day,
+                amount: Decimal::ZERO,
+            };
+        }
+
+        self.daily_withdrawals.amount = (self.daily_withdrawals.amount + amount).max(Decimal::ZERO);
+    }
+
+    /// Records a stamped transaction of an amount in a currency against the account, with the account's current balance
+    /// as its result.
+    pub fn record(&mut self, kind: RecordKind, amount: Decimal, currency: &str, stamp: &Stamp) {
+        self.history.push(Record {
+            timestamp: stamp.timestamp,
+            kind,
+            amount,
+            currency: currency.to_string(),
+            balance: self.balance.amount(),
+            reference: stamp.reference.clone(),
+            memo: stamp.memo.clone(),
+            reversal_of: None,
+            reversed_by: None,
+        });
+    }
+
+    /// Checks whether the account is jointly owned by more than one owner.
+    pub fn is_joint(&self) -> bool {
+        self.owners.len() > 1
+    }
+
+    /// Checks whether the account is protected by a PIN.
+    ///
+    /// Accounts registered before PINs were required have none.
+    pub fn has_pin(&self) -> bool {
+        !self.pin_hash.is_empty()
+    }
+
+    /// Checks whether a PIN is the account's, which it always is if the account has none.
+    pub fn verify_pin(&self, pin: &str) -> bool {
+        !self.has_pin() || crypto::verify_secret(pin, &self.pin_hash)
+    }
+
+    /// Returns the penalty for withdrawing an amount at a time, in seconds since the Unix epoch, rounded to the cent by
+    /// a rounding policy.
+    ///
+    /// Only time deposits withdrawn from before their term ends are penalized.
+    pub fn withdrawal_penalty(&self, amount: Decimal, now: u64, rounding: RoundingPolicy) -> Decimal {
+        let matures_at = self.opened_at + TIME_DEPOSIT_TERM_DAYS * clock::SECONDS_PER_DAY;
+
+        if self.account_type == AccountType::TimeDeposit && now < matures_at {
+            rounding.round(amount * EARLY_WITHDRAWAL_PENALTY_RATE, 2)
+        } else {
+            Decimal::ZERO
+        }
+    }
+}
+
+/// Generates the number of the account registered in a sequence, starting from one.
+///
+/// The number is the sequence's 9 digits followed by a [Luhn](https://en.wikipedia.org/wiki/Luhn_algorithm) check
+/// digit, catching mistyped digits.
+pub fn generate_number(seq: u64) -> String {
+    let payload = (FIRST_SEQUENCE + seq - 1).to_string();
+
+    format!("{payload}{}", check_digit(&payload))
+}
+
+/// Returns the sequence an account number was generated in, if it is valid.
+pub fn number_sequence(number: &str) -> Option<u64> {
+    if number.len() != 10 || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let (payload, check) = number.split_at(9);
+
+    if check_digit(payload).to_string() != check {
+        return None;
+    }
+
+    payload
+        .parse::<u64>()
+        .ok()?
+        .checked_sub(FIRST_SEQUENCE)
+        .map(|seq| seq + 1)
+}
+
+/// Checks whether a PIN is made of 4 to 6 digits.
+pub fn is_valid_pin(pin: &str) -> bool {
+    (4..=6).contains(&pin.len()) && pin.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Calculates the Luhn check digit of a number's digits.
+fn check_digit(payload: &str) -> u32 {
+    let sum: u32 = payload
+        .chars()
+        .rev()
+        .filter_map(|c| c.to_digit(10))
+        .enumerate()
+        .map(|(i, digit)| {
+            if i % 2 == 0 {
+                let doubled = digit * 2;
+
+                if doubled > 9 { doubled - 9 } else { doubled }
+            } else {
+                digit
+            }
+        })
+        .sum();
+
+    (10 - sum % 10) % 10
+}
@@ -0,0 +1,68 @@
+//! The alerts notifying users when an exchange rate reaches a threshold.
+
+use std::fmt;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// The sides of a threshold that an exchange rate can reach.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AlertCondition {
+    /// The rate is at or above the threshold.
+    AtLeast,
+    /// The rate is at or below the threshold.
+    AtMost,
+}
+impl fmt::Display for AlertCondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlertCondition::AtLeast => write!(f, "≥"),
+            AlertCondition::AtMost => write!(f, "≤"),
+        }
+    }
+}
+
+/// An alert on the exchange rate of a foreign currency reaching a threshold.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RateAlert {
+    /// The ID of the alert, numbered in the order the alerts were added, starting from one.
+    pub id: u64,
+    /// The code of the currency whose rate is watched.
+    pub currency: String,
+    /// The side of the threshold the rate must reach.
+    pub condition: AlertCondition,
+    /// The threshold, as a value of one unit of the currency in Philippine Pesos.
+    pub threshold: Decimal,
+    /// Whether the alert was removed, which keeps its record but stops its notifications.
+    pub is_removed: bool,
+}
+impl RateAlert {
+    /// Checks whether an exchange rate is on the alert's side of its threshold.
+    pub fn is_met_by(&self, rate: Decimal) -> bool {
+        match self.condition {
+            AlertCondition::AtLeast => rate >= self.threshold,
+            AlertCondition::AtMost => rate <= self.threshold,
+        }
+    }
+
+    /// Checks whether a change in the exchange rate of the alert's currency crosses to the alert's side of its
+    /// threshold, which notifies its user.
+    pub fn is_triggered_by(&self, previous_rate: Decimal, rate: Decimal) -> bool {
+        !self.is_removed && self.is_met_by(rate) && !self.is_met_by(previous_rate)
+    }
+}
+
+/// A notification that an exchange rate reached an alert's threshold.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RateNotification {
+    /// The triggered alert.
+    pub alert: RateAlert,
+    /// The exchange rate that reached the threshold.
+    pub rate: Decimal,
+    /// When the rate changed, in seconds since the Unix epoch.
+    pub triggered_at: u64,
+}
@@ -0,0 +1,85 @@
+//! The large transactions held for approval before they move any balance.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// The transactions that can be held for approval.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HeldTransaction {
+    /// Withdraws an amount in any currency from an account.
+    Withdrawal {
+        /// The name of the account withdrawn from.
+        account: String,
+        /// The withdrawn amount, in the withdrawn currency.
+        amount: Decimal,
+        /// The currency of the withdrawn amount.
+        currency: String,
+    },
+    /// Transfers an amount in the sending account's currency to another account.
+    Transfer {
+        /// The name of the account transferred from.
+        account: String,
+        /// The name of the account transferred to.
+        recipient: String,
+        /// The transferred amount, in the currency of the account transferred from.
+        amount: Decimal,
+    },
+}
+impl HeldTransaction {
+    /// Returns the name of the account the transaction is against, which a transfer is from.
+    pub fn account(&self) -> &str {
+        match self {
+            HeldTransaction::Withdrawal { account, .. } | HeldTransaction::Transfer { account, .. } => account,
+        }
+    }
+
+    /// Follows an account involved in the transaction to its new name.
+    pub fn rename_account(&mut self, name: &str, new_name: &str) {
+        let accounts = match self {
+            HeldTransaction::Withdrawal { account, .. } => vec![account],
+            HeldTransaction::Transfer { account, recipient, .. } => vec![account, recipient],
+        };
+
+        for account in accounts.into_iter().filter(|a| *a == name) {
+            *account = new_name.to_string();
+        }
+    }
+}
+
+/// The stages of a held transaction's approval.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalStatus {
+    /// Awaiting approval, without moving any balance yet.
+    #[default]
+    Pending,
+    /// Approved, which committed the transaction.
+    Approved,
+    /// Rejected, which dropped the transaction.
+    Rejected,
+}
+
+/// A transaction held for approval.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PendingTransaction {
+    /// The ID of the held transaction, numbered in the order the transactions were held, starting from one.
+    pub id: u64,
+    /// The held transaction.
+    pub transaction: HeldTransaction,
+    /// The note attached to the transaction, if any.
+    pub memo: Option<String>,
+    /// When the transaction was held, in seconds since the Unix epoch.
+    pub submitted_at: u64,
+    /// The stage of the transaction's approval.
+    pub status: ApprovalStatus,
+}
+impl PendingTransaction {
+    /// Checks whether the transaction still awaits approval.
+    pub fn is_pending(&self) -> bool {
+        self.status == ApprovalStatus::Pending
+    }
+}
@@ -0,0 +1,103 @@
+//! The timestamped backups of the bank's state.
+
+use std::{
+    fmt, fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use chrono::{Local, NaiveDateTime};
+
+use serde_json::Value;
+
+use crate::{bank::Snapshot, crypto::Cipher, schema};
+
+/// The default directory where backups are stored.
+pub const BACKUP_DIR: &str = "backups";
+
+/// The prefix of every backup file's name.
+const FILE_PREFIX: &str = "backup-";
+/// The format of the timestamp in every backup file's name.
+const TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S%3f";
+
+/// A backup file of the bank's state.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Backup {
+    /// The path of the backup file.
+    pub path: PathBuf,
+    /// When the backup was created.
+    pub created_at: NaiveDateTime,
+}
+impl Backup {
+    /// Writes a snapshot to a new backup file timestamped with the current time.
+    ///
+    /// The snapshot is encrypted if a cipher is given.
+    pub fn create<P: AsRef<Path>>(dir: P, snapshot: &Snapshot, cipher: Option<&Cipher>) -> io::Result<Backup> {
+        fs::create_dir_all(&dir)?;
+
+        let created_at = Local::now().naive_local();
+        let path = dir
+            .as_ref()
+            .join(format!("{FILE_PREFIX}{}.json", created_at.format(TIMESTAMP_FORMAT)));
+        let mut contents = serde_json::to_string_pretty(snapshot)?;
+
+        if let Some(cipher) = cipher {
+            contents = cipher.encrypt(&contents)?;
+        }
+
+        fs::write(&path, contents)?;
+
+        Ok(Backup { path, created_at })
+    }
+
+    /// Lists the backup files in a directory, from oldest to newest.
+    pub fn list<P: AsRef<Path>>(dir: P) -> io::Result<Vec<Backup>> {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+        let mut backups = Vec::new();
+
+        for entry in entries {
+            let path = entry?.path();
+            let created_at = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .and_then(|s| s.strip_prefix(FILE_PREFIX))
+                .and_then(|s| NaiveDateTime::parse_from_str(s, TIMESTAMP_FORMAT).ok());
+
+            if let Some(created_at) = created_at {
+                backups.push(Backup { path, created_at });
+            }
+        }
+
+        backups.sort_by_key(|b| b.created_at);
+
+        Ok(backups)
+    }
+
+    /// Reads the snapshot stored in the backup file, migrating it if it was saved with an older format.
+    ///
+    /// Encrypted backups can only be read with the cipher they were created with.
+    pub fn load(&self, cipher: Option<&Cipher>) -> io::Result<Snapshot> {
+        let mut contents = fs::read_to_string(&self.path)?;
+
+        if let Some(cipher) = cipher
+            && serde_json::from_str::<Value>(&contents).is_err()
+        {
+            contents = cipher.decrypt(&contents)?;
+        }
+
+        let mut value = serde_json::from_str(&contents)?;
+
+        schema::migrate_snapshot(&mut value)?;
+
+        Ok(serde_json::from_value(value)?)
+    }
+}
+impl fmt::Display for Backup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.created_at.format("%Y-%m-%d %H:%M:%S"))
+    }
+}
@@ -0,0 +1,2959 @@
+//! The bank holding the user accounts and exchange rates.
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt, io, mem,
+    path::Path,
+};
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, error, info};
+
+use crate::{
+    account::{self, Account, AccountFilter, AccountOrder, AccountType, Contact, MinimumBalance},
+    alert::{AlertCondition, RateAlert, RateNotification},
+    approval::{ApprovalStatus, HeldTransaction, PendingTransaction},
+    backup::Backup,
+    clock::{self, Clock, FixedClock, SystemClock},
+    config::{Config, Fee, FeeSchedule, RateBounds},
+    crypto,
+    currency::{
+        self, BASE_CURRENCY, CURRENCIES_CODES, CURRENCIES_TITLES, Currency, MAX_AMOUNT, MAX_RATE, MIN_RATE,
+        RoundingPolicy,
+    },
+    event::{Event, EventSink},
+    history::{self, Record, RecordKind, Stamp},
+    interest::{self, Compounding, DayCount, InterestMode, InterestTerms, RateTier},
+    iso4217,
+    journal::{Entry, EntryKind, FeeReason, Journal, ReplayPoint},
+    locale::Locale,
+    rates::{RateError, RatePoint, RateProvider},
+    schedule::{Schedule, ScheduledTransaction},
+    schema::SCHEMA_VERSION,
+};
+
+/// The reasons a transaction can be rejected by the bank.
+#[derive(Debug)]
+pub enum BankError {
+    /// An account with the same name is already registered.
+    DuplicateAccount,
+    /// No account with the given name is registered.
+    AccountNotFound,
+    /// No currency with the given code is exchangeable.
+    UnknownCurrency,
+    /// A currency with the same code is already exchangeable.
+    DuplicateCurrency,
+    /// The currency code isn't made of three letters.
+    InvalidCurrencyCode,
+    /// The amount isn't positive, or is above the bank's maximum amount.
+    InvalidAmount {
+        /// The most that an amount can be, if there is a maximum.
+        max: Option<Decimal>,
+    },
+    /// The exchange rate isn't a positive number.
+    InvalidRate,
+    /// The exchange rate is outside the range configured for its currency.
+    RateOutOfBounds {
+        /// The code of the currency.
+        currency: String,
+        /// The range that the currency's rate must be within.
+        bounds: RateBounds,
+    },
+    /// The spread isn't a percentage from zero to less than a hundred.
+    InvalidSpread,
+    /// The account's balance is too low for the withdrawal.
+    InsufficientBalance,
+    /// The account is closed.
+    AccountClosed,
+    /// The account is frozen.
+    AccountFrozen,
+    /// The transfer is from and to the same account.
+    SelfTransfer,
+    /// The account can't be closed with a balance left.
+    NonzeroBalance,
+    /// The overdraft limit is negative.
+    InvalidOverdraftLimit,
+    /// The interest rate isn't a number above -100%.
+    InvalidInterestRate,
+    /// No days passed since the account's interest was last posted.
+    NoInterestDue,
+    /// The withdrawal is more than what is left of the account's daily withdrawal limit.
+    DailyLimitExceeded {
+        /// The amount that can still be withdrawn today, in the account's currency.
+        remaining: Decimal,
+        /// The currency of the account's balance.
+        currency: String,
+    },
+    /// The daily withdrawal limit is negative.
+    InvalidWithdrawalLimit,
+    /// The amount to exchange doesn't cover its fee.
+    FeeExceedsAmount,
+    /// The withdrawal would leave the account below its type's minimum balance, which doesn't allow it for a fee.
+    BelowMinimumBalance,
+    /// The minimum balance or one of its fees is negative.
+    InvalidMinimumBalance,
+    /// The email address is malformed.
+    InvalidEmail,
+    /// The phone number is malformed.
+    InvalidPhone,
+    /// The account would be left without an owner.
+    NoOwners,
+    /// The PIN isn't made of 4 to 6 digits.
+    InvalidPin,
+    /// The PIN isn't the account's.
+    IncorrectPin,
+    /// No transaction is left to undo.
+    NothingToUndo,
+    /// The interval between a scheduled transaction's runs is shorter than a day.
+    InvalidInterval,
+    /// No active schedule with the given ID exists.
+    ScheduleNotFound,
+    /// No active rate alert with the given ID exists.
+    AlertNotFound,
+    /// The transaction is large enough that it must be held for approval.
+    ApprovalRequired,
+    /// No transaction awaiting approval with the given ID exists.
+    PendingNotFound,
+    /// No deposit, withdrawal, transfer, or fee with the given reference number exists.
+    TransactionNotFound,
+    /// The transaction was already reversed or undone.
+    AlreadyReversed,
+    /// The exchange rate of the currency is out of date, and out-of-date rates aren't allowed.
+    StaleRate {
+        /// The code of the currency.
+        currency: String,
+    },
+    /// No exchange rate of the currency was recorded by the time a conversion is made as of.
+    NoRateAt {
+        /// The code of the currency.
+        currency: String,
+    },
+    /// The transaction couldn't be recorded in the journal.
+    Journal(io::Error),
+    /// The backup couldn't be written or read.
+    Backup(io::Error),
+    /// The exchange rates couldn't be fetched from their provider.
+    Rates(RateError),
+}
+impl fmt::Display for BankError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BankError::DuplicateAccount => write!(f, "An account with this name already exists!"),
+            BankError::AccountNotFound => write!(f, "No account with this name exists!"),
+            BankError::UnknownCurrency => write!(f, "No currency with this code exists!"),
+            BankError::DuplicateCurrency => write!(f, "A currency with this code already exists!"),
+            BankError::InvalidCurrencyCode => write!(f, "Currency codes must be made of three letters!"),
+            BankError::InvalidAmount { max: None } => write!(f, "Amounts must be positive!"),
+            BankError::InvalidAmount { max: Some(max) } => write!(f, "Amounts must be positive and at most {max}!"),
+            BankError::InvalidRate => write!(f, "Exchange rates must be numbers from {MIN_RATE} to {MAX_RATE}!"),
+            BankError::RateOutOfBounds { currency, bounds } => write!(
+                f,
+                "The exchange rate of {currency} must be from {} to {}!",
+                bounds.min, bounds.max
+            ),
+            BankError::InvalidSpread => write!(f, "Spreads must be from 0% to less than 100%!"),
+            BankError::InsufficientBalance => write!(f, "Withdraw amount must be less than the current balance!"),
+            BankError::AccountClosed => write!(f, "This account is closed!"),
+            BankError::AccountFrozen => write!(
+                f,
+                "This account is frozen, so it can't be deposited to or withdrawn from!"
+            ),
+            BankError::SelfTransfer => write!(f, "Transfers must be to another account!"),
+            BankError::NonzeroBalance => write!(f, "Only accounts with a zero balance can be closed!"),
+            BankError::InvalidOverdraftLimit => write!(f, "Overdraft limits must not be negative!"),
+            BankError::InvalidInterestRate => write!(f, "Interest rates must be numbers above -100%!"),
+            BankError::NoInterestDue => write!(f, "No interest has accrued since it was last posted!"),
+            BankError::DailyLimitExceeded { remaining, currency } => write!(
+                f,
+                "Withdraw amount must not exceed the {} left of today's withdrawal limit!",
+                Locale::default().format_money(*remaining, currency)
+            ),
+            BankError::InvalidWithdrawalLimit => write!(f, "Daily withdrawal limits must not be negative!"),
+            BankError::FeeExceedsAmount => write!(f, "Exchange amount must be greater than its fee!"),
+            BankError::BelowMinimumBalance => write!(
+                f,
+                "Withdraw amount must leave the account's minimum maintaining balance!"
+            ),
+            BankError::InvalidMinimumBalance => write!(f, "Minimum balances and their fees must not be negative!"),
+            BankError::InvalidEmail => write!(f, "Email addresses must be written like name@example.com!"),
+            BankError::InvalidPhone => write!(f, "Phone numbers must have 7 to 15 digits!"),
+            BankError::NoOwners => write!(f, "Accounts must have at least one owner!"),
+            BankError::InvalidPin => write!(f, "PINs must be made of 4 to 6 digits!"),
+            BankError::IncorrectPin => write!(f, "Incorrect PIN!"),
+            BankError::NothingToUndo => write!(f, "There is no transaction to undo!"),
+            BankError::InvalidInterval => write!(f, "Intervals must be at least one day!"),
+            BankError::ScheduleNotFound => write!(f, "No active schedule with this ID exists!"),
+            BankError::AlertNotFound => write!(f, "No active rate alert with this ID exists!"),
+            BankError::ApprovalRequired => write!(f, "Transactions this large must be held for approval!"),
+            BankError::PendingNotFound => write!(f, "No pending transaction with this ID exists!"),
+            BankError::TransactionNotFound => write!(f, "No reversible transaction with this reference number exists!"),
+            BankError::AlreadyReversed => write!(f, "This transaction was already reversed!"),
+            BankError::StaleRate { currency } => write!(
+                f,
+                "The exchange rate of {currency} is out of date, so it must be updated first!"
+            ),
+            BankError::NoRateAt { currency } => write!(f, "No exchange rate of {currency} was recorded by then!"),
+            BankError::Journal(err) => write!(f, "Failed to write to the journal: {err}"),
+            BankError::Backup(err) => write!(f, "Failed to access the backup: {err}"),
+            BankError::Rates(err) => err.fmt(f),
+        }
+    }
+}
+impl std::error::Error for BankError {}
+impl From<io::Error> for BankError {
+    fn from(err: io::Error) -> Self {
+        BankError::Journal(err)
+    }
+}
+
+/// A broken invariant of the bank's state, as found by [`Bank::verify`].
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(tag = "violation", rename_all = "snake_case")]
+pub enum Violation {
+    /// An account's balance is further below zero than its overdraft limit allows.
+    Overdrawn {
+        /// The name of the account.
+        account: String,
+        /// The account's balance.
+        balance: Decimal,
+        /// The account's overdraft limit.
+        overdraft_limit: Decimal,
+    },
+    /// An account's balance differs from its balance after its latest recorded transaction, or from its opening balance
+    /// without any.
+    BalanceMismatch {
+        /// The name of the account.
+        account: String,
+        /// The account's balance.
+        balance: Decimal,
+        /// The balance after the account's latest recorded transaction, or its opening balance without any.
+        recorded_balance: Decimal,
+    },
+    /// More than one account has the same name.
+    DuplicateName {
+        /// The shared name.
+        name: String,
+    },
+    /// More than one account has the same number.
+    DuplicateNumber {
+        /// The shared number.
+        number: String,
+    },
+}
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Violation::Overdrawn {
+                account,
+                balance,
+                overdraft_limit,
+            } => write!(
+                f,
+                "The balance of {account}, {balance}, is beyond its overdraft limit of {overdraft_limit}!"
+            ),
+            Violation::BalanceMismatch {
+                account,
+                balance,
+                recorded_balance,
+            } => write!(
+                f,
+                "The balance of {account}, {balance}, doesn't match its recorded balance of {recorded_balance}!"
+            ),
+            Violation::DuplicateName { name } => write!(f, "More than one account is named {name}!"),
+            Violation::DuplicateNumber { number } => write!(f, "More than one account is numbered {number}!"),
+        }
+    }
+}
+
+/// A copy of the bank's full state at some point in time.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// The version of the format the snapshot was saved with.
+    pub version: u32,
+    /// The registered user accounts.
+    pub accounts: Vec<Account>,
+    /// The value of one unit of each foreign currency in Philippine Pesos.
+    pub exchange_rates: HashMap<String, Decimal>,
+    /// The currencies registered at runtime, in the order they were registered.
+    pub currencies: Vec<Currency>,
+    /// Every exchange rate recorded for the foreign currencies, from oldest to newest, by their codes.
+    pub rate_history: HashMap<String, Vec<RatePoint>>,
+    /// The spreads between the rates the foreign currencies are bought and sold at, as percentages, by their codes.
+    pub spreads: HashMap<String, Decimal>,
+    /// The minimum balances of the types of accounts, in the order the types are listed.
+    pub minimum_balances: [MinimumBalance; 3],
+    /// The fees collected by the bank, per currency.
+    pub revenue: HashMap<String, Decimal>,
+    /// The income of the bank from the spreads of the exchange rates, per currency.
+    pub spread_income: HashMap<String, Decimal>,
+    /// The scheduled transactions, including the cancelled ones, in the order they were created.
+    pub schedules: Vec<Schedule>,
+    /// The transactions held for approval, including the decided ones, in the order they were held.
+    pub pending: Vec<PendingTransaction>,
+    /// The alerts on the exchange rates, including the removed ones, in the order they were added.
+    pub alerts: Vec<RateAlert>,
+    /// The reference numbers of the first transactions of the operations performed with idempotency keys, by their
+    /// keys.
+    pub idempotency_keys: HashMap<String, String>,
+}
+
+/// A fee charged to an account along with a transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Charge {
+    /// Why the fee was charged.
+    pub reason: FeeReason,
+    /// The fee, in the account's currency.
+    pub amount: Decimal,
+}
+
+/// The result of a transaction moving an account's balance.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Receipt {
+    /// The reference number of the transaction.
+    pub reference: String,
+    /// The updated balance of the account.
+    pub balance: Decimal,
+    /// The fees charged along with the transaction, in the order they were charged.
+    pub charges: Vec<Charge>,
+}
+
+/// The interest posted to an account's balance.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct InterestPosting {
+    /// The reference number of the posting.
+    pub reference: String,
+    /// The number of days the interest accrued over.
+    pub day_cnt: u32,
+    /// The interest credited before its tax was withheld, in the account's currency.
+    pub interest: Decimal,
+    /// The tax withheld from the interest, in the account's currency.
+    pub tax: Decimal,
+    /// The interest charged for an overdrawn balance, in the account's currency.
+    pub overdraft_charge: Decimal,
+    /// The updated balance of the account.
+    pub balance: Decimal,
+}
+
+/// A run of a scheduled transaction.
+#[derive(Debug)]
+pub struct ScheduledRun {
+    /// The ID of the schedule.
+    pub schedule: u64,
+    /// The time the run was due, in seconds since the Unix epoch.
+    pub due_at: u64,
+    /// The receipt of the transaction, or why it failed.
+    pub result: Result<Receipt, BankError>,
+}
+
+/// The breakdown of an exchange from one currency to another, before it is made.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ExchangeQuote {
+    /// The amount to exchange, in the source currency.
+    pub amount: Decimal,
+    /// The source currency.
+    pub currency: String,
+    /// The currency exchanged to.
+    pub exchange_currency: String,
+    /// The units of the currency exchanged to that a single unit of the source currency is exchanged for, at the
+    /// buying and selling rates.
+    pub rate: Decimal,
+    /// The fee deducted from the amount before it is exchanged, in the source currency.
+    pub fee: Decimal,
+    /// The bank's income from the spread, in the currency exchanged to.
+    pub spread: Decimal,
+    /// The amount received, in the currency exchanged to.
+    pub exchange_amount: Decimal,
+}
+
+/// The number of the most recent transactions that can be undone.
+pub const UNDO_LIMIT: usize = 10;
+
+/// A transaction that can be undone, along with the transactions undoing it.
+struct UndoStep {
+    /// The transaction to undo.
+    transaction: EntryKind,
+    /// The transactions undoing it, in the order they are committed.
+    reversals: Vec<EntryKind>,
+}
+impl UndoStep {
+    /// Creates a step undoing transactions committed one after another from a sequence number, the first of which is
+    /// shown as undone.
+    fn reverting(transactions: Vec<EntryKind>, first_seq: u64) -> UndoStep {
+        UndoStep {
+            transaction: transactions[0].clone(),
+            reversals: transactions
+                .into_iter()
+                .enumerate()
+                .rev()
+                .map(|(i, t)| EntryKind::Undo {
+                    transaction: Box::new(t),
+                    reference: Some(history::reference(first_seq + i as u64)),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A bank holding user accounts and the exchange rates between currencies.
+///
+/// Every transaction that goes through the bank is recorded in its journal, if it has one.
+pub struct Bank {
+    /// The registered user accounts.
+    accounts: Vec<Account>,
+    /// The value of one unit of each foreign currency in Philippine Pesos.
+    exchange_rates: HashMap<String, Decimal>,
+    /// The currencies registered at runtime, in the order they were registered.
+    currencies: Vec<Currency>,
+    /// Every exchange rate recorded for the foreign currencies, from oldest to newest, by their codes.
+    rate_history: HashMap<String, Vec<RatePoint>>,
+    /// The spreads between the rates the foreign currencies are bought and sold at, as percentages, by their codes.
+    spreads: HashMap<String, Decimal>,
+    /// The minimum balances of the types of accounts, in the order the types are listed.
+    minimum_balances: [MinimumBalance; 3],
+    /// The fees charged automatically for each kind of transaction.
+    fee_schedule: FeeSchedule,
+    /// The most that can be withdrawn from an account each day, unless the account sets its own.
+    default_withdrawal_limit: Option<Decimal>,
+    /// The fees collected by the bank, per currency.
+    revenue: HashMap<String, Decimal>,
+    /// The income of the bank from the spreads of the exchange rates, per currency.
+    spread_income: HashMap<String, Decimal>,
+    /// The scheduled transactions, including the cancelled ones, in the order they were created.
+    schedules: Vec<Schedule>,
+    /// The amount, in an account's currency, from which withdrawals and transfers are held for approval, if any.
+    approval_threshold: Option<Decimal>,
+    /// The number of seconds an exchange rate stays current after it is updated, if rates ever go out of date.
+    rate_ttl: Option<u64>,
+    /// Whether conversions can use out-of-date exchange rates.
+    allows_stale_rates: bool,
+    /// The way interest accrues when it is calculated, unless another way is chosen.
+    interest_mode: InterestMode,
+    /// The bands of balances that earn their own interest rates instead of the accounts' own.
+    interest_tiers: Vec<RateTier>,
+    /// How the days that interest accrues over are counted.
+    day_count: DayCount,
+    /// The share of interest withheld as tax when it is posted, as a fraction.
+    withholding_rate: Decimal,
+    /// How amounts are rounded to their currencies' minor units.
+    rounding: RoundingPolicy,
+    /// The most that a single transaction's amount can be, if there is a maximum.
+    max_amount: Option<Decimal>,
+    /// The range that each foreign currency's exchange rate must be within, by the currency's code.
+    rate_bounds: HashMap<String, RateBounds>,
+    /// The percentage by which a new exchange rate must differ from the previous one to be warned about, if any.
+    rate_change_warning: Option<Decimal>,
+    /// The transactions held for approval, including the decided ones, in the order they were held.
+    pending: Vec<PendingTransaction>,
+    /// The alerts on the exchange rates, including the removed ones, in the order they were added.
+    alerts: Vec<RateAlert>,
+    /// The notifications of the alerts triggered since they were last taken, from oldest to newest.
+    notifications: Vec<RateNotification>,
+    /// The deposits, withdrawals, transfers, and fees committed, by their reference numbers, for reversing them later.
+    ledger: HashMap<String, EntryKind>,
+    /// The reference numbers of the first transactions of the operations performed with idempotency keys, by their
+    /// keys.
+    idempotency_keys: HashMap<String, String>,
+    /// The idempotency key to stamp the next committed transaction with, if any.
+    next_key: Option<String>,
+    /// The journal recording the bank's transactions.
+    journal: Option<Journal>,
+    /// The number of transactions committed, which numbers their reference numbers.
+    transaction_cnt: u64,
+    /// The most recent transactions that can be undone, from oldest to newest.
+    undo_stack: VecDeque<UndoStep>,
+    /// The source of the time that transactions are committed at.
+    clock: Box<dyn Clock>,
+    /// The subscribers published the events of the committed transactions, in the order they subscribed.
+    subscribers: Vec<Box<dyn EventSink>>,
+}
+impl Bank {
+    /// Creates a new bank without accounts, journal, and with every exchange rate set to one.
+    pub fn new() -> Bank {
+        let mut exchange_rates = HashMap::new();
+
+        for code in CURRENCIES_CODES.iter().skip(1) {
+            exchange_rates.insert(code.to_string(), Decimal::ONE);
+        }
+
+        Bank {
+            accounts: Vec::new(),
+            exchange_rates,
+            currencies: Vec::new(),
+            rate_history: HashMap::new(),
+            spreads: HashMap::new(),
+            minimum_balances: [MinimumBalance::default(); 3],
+            fee_schedule: FeeSchedule::default(),
+            default_withdrawal_limit: None,
+            revenue: HashMap::new(),
+            spread_income: HashMap::new(),
+            schedules: Vec::new(),
+            approval_threshold: None,
+            rate_ttl: None,
+            allows_stale_rates: false,
+            interest_mode: InterestMode::default(),
+            interest_tiers: Vec::new(),
+            day_count: DayCount::default(),
+            withholding_rate: Decimal::ZERO,
+            rounding: RoundingPolicy::default(),
+            max_amount: None,
+            rate_bounds: HashMap::new(),
+            rate_change_warning: None,
+            pending: Vec::new(),
+            alerts: Vec::new(),
+            notifications: Vec::new(),
+            ledger: HashMap::new(),
+            idempotency_keys: HashMap::new(),
+            next_key: None,
+            journal: None,
+            transaction_cnt: 0,
+            undo_stack: VecDeque::new(),
+            clock: Box::new(SystemClock),
+            subscribers: Vec::new(),
+        }
+    }
+
+    /// Replaces the source of the time that transactions are committed at.
+    pub fn set_clock<C: Clock + 'static>(&mut self, clock: C) {
+        self.clock = Box::new(clock);
+    }
+
+    /// Subscribes to the events of the transactions committed from now on.
+    pub fn subscribe<S: EventSink + 'static>(&mut self, subscriber: S) {
+        self.subscribers.push(Box::new(subscriber));
+    }
+
+    /// Applies a configuration's fee schedule, default daily withdrawal limit, approval threshold, maximum amount, rate
+    /// TTL, interest settings, and rounding policy.
+    ///
+    /// The configuration isn't recorded, so it only applies to the transactions committed after.
+    pub fn configure(&mut self, config: &Config) {
+        self.fee_schedule = config.fees;
+        self.default_withdrawal_limit = config.daily_withdrawal_limit;
+        self.approval_threshold = config.approval_threshold;
+        self.max_amount = config.max_amount;
+        self.rate_bounds = config
+            .rate_bounds
+            .iter()
+            .map(|(code, bounds)| (code.to_uppercase(), *bounds))
+            .collect();
+        self.rate_change_warning = config.rate_change_warning;
+        self.rate_ttl = config.rate_ttl_hours.map(|hours| hours * 60 * 60);
+        self.interest_mode = config.interest_mode;
+        self.interest_tiers = config.interest_tiers.clone();
+        self.day_count = config.day_count;
+        self.withholding_rate = config.withholding_tax.unwrap_or_default() / Decimal::ONE_HUNDRED;
+        self.rounding = config.rounding;
+    }
+
+    /// Allows or disallows conversions using out-of-date exchange rates, which are rejected by default.
+    pub fn allow_stale_rates(&mut self, is_allowed: bool) {
+        self.allows_stale_rates = is_allowed;
+    }
+
+    /// Returns the terms that an account earns interest by, with its rate and compounding frequency and the bank's
+    /// interest mode, tiers, day-count convention, withholding tax, and rounding policy.
+    pub fn interest_terms(&self, account: &Account) -> InterestTerms<'_> {
+        InterestTerms {
+            annual_rate: account.interest_rate,
+            tiers: &self.interest_tiers,
+            mode: self.interest_mode,
+            compounding: account.compounding,
+            day_count: self.day_count,
+            withholding_rate: self.withholding_rate,
+            rounding: self.rounding,
+        }
+    }
+
+    /// Returns the fees charged automatically for each kind of transaction.
+    pub fn fee_schedule(&self) -> &FeeSchedule {
+        &self.fee_schedule
+    }
+
+    /// Returns the current time of the bank's clock, in seconds since the Unix epoch.
+    pub fn now(&self) -> u64 {
+        self.clock.now()
+    }
+
+    /// Rebuilds a bank's state by replaying every entry in a journal.
+    ///
+    /// The rebuilt bank keeps recording its transactions to the replayed journal.
+    pub fn replay(journal: Journal) -> io::Result<Bank> {
+        let mut bank = Bank::replay_entries(journal.entries()?)?;
+
+        debug!(path = %journal.path().display(), "replayed the journal");
+
+        bank.journal = Some(journal);
+
+        Ok(bank)
+    }
+
+    /// Rebuilds a bank's state by replaying every entry in the plaintext journal file at a path, which is created if it
+    /// doesn't exist yet.
+    ///
+    /// An entry left half-written at the end of the journal, like by a crash, is cut off first. The rebuilt bank keeps
+    /// recording its transactions to the journal.
+    pub fn from_journal<P: AsRef<Path>>(path: P) -> io::Result<Bank> {
+        Bank::replay(Journal::open(path)?)
+    }
+
+    /// Rebuilds a bank's state as it was at a point in a journal, by replaying only the entries before it.
+    ///
+    /// The rebuilt bank doesn't record its transactions, so that the journal's later entries are kept.
+    pub fn replay_to(journal: &Journal, point: ReplayPoint) -> io::Result<Bank> {
+        let entries = journal.entries()?;
+        let entry_cnt = entries.len();
+        let bank = Bank::replay_entries(
+            entries
+                .into_iter()
+                .enumerate()
+                .take_while(|(idx, entry)| point.includes(*idx, entry))
+                .map(|(_, entry)| entry),
+        )?;
+
+        debug!(path = %journal.path().display(), ?point, entry_cnt, "replayed the journal to a point");
+
+        Ok(bank)
+    }
+
+    /// Rebuilds a bank's state by applying entries in order.
+    fn replay_entries<I: IntoIterator<Item = Entry>>(entries: I) -> io::Result<Bank> {
+        let mut bank = Bank::new();
+
+        for entry in entries {
+            bank.transaction_cnt += 1;
+            bank.apply(&entry.kind, entry.timestamp)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+            bank.remember_key(entry.idempotency_key);
+        }
+
+        bank.report_violations();
+
+        Ok(bank)
+    }
+
+    /// Checks the bank's state for broken invariants, returning every one found.
+    ///
+    /// No account's balance may be further below zero than its overdraft limit, every account's balance must match the
+    /// balance after its latest recorded transaction or its opening balance without any, and no two accounts may share
+    /// a name or a number.
+    pub fn verify(&self) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        let mut names = HashSet::new();
+        let mut numbers = HashSet::new();
+
+        for account in &self.accounts {
+            let balance = account.balance.amount();
+            let recorded_balance = account.history.last().map_or(account.opening_balance, |r| r.balance);
+
+            if balance < -account.overdraft_limit {
+                violations.push(Violation::Overdrawn {
+                    account: account.name.clone(),
+                    balance,
+                    overdraft_limit: account.overdraft_limit,
+                });
+            }
+
+            if balance != recorded_balance {
+                violations.push(Violation::BalanceMismatch {
+                    account: account.name.clone(),
+                    balance,
+                    recorded_balance,
+                });
+            }
+
+            if !names.insert(&account.name) {
+                violations.push(Violation::DuplicateName {
+                    name: account.name.clone(),
+                });
+            }
+
+            if !numbers.insert(&account.number) {
+                violations.push(Violation::DuplicateNumber {
+                    number: account.number.clone(),
+                });
+            }
+        }
+
+        violations
+    }
+
+    /// Logs every broken invariant of the bank's state in debug builds, to catch bugs in the transactions as they
+    /// happen.
+    fn report_violations(&self) {
+        if cfg!(debug_assertions) {
+            for violation in self.verify() {
+                error!(%violation, "found a broken invariant");
+            }
+        }
+    }
+
+    /// Returns the registered user accounts.
+    pub fn accounts(&self) -> &[Account] {
+        &self.accounts
+    }
+
+    /// Returns the registered accounts meeting a filter's criteria, sorted in an order.
+    pub fn list_accounts(&self, filter: &AccountFilter, order: AccountOrder) -> Vec<&Account> {
+        let mut accounts: Vec<_> = self.accounts.iter().filter(|a| filter.matches(a)).collect();
+
+        accounts.sort_by(|a, b| order.compare(a, b));
+
+        accounts
+    }
+
+    /// Finds a registered account by its name.
+    pub fn account(&self, name: &str) -> Option<&Account> {
+        self.accounts.iter().find(|a| a.name == name)
+    }
+
+    /// Finds a registered account that isn't closed by its name, for a transaction against it.
+    pub fn open_account(&self, name: &str) -> Result<&Account, BankError> {
+        let account = self.account(name).ok_or(BankError::AccountNotFound)?;
+
+        if account.is_closed {
+            return Err(BankError::AccountClosed);
+        }
+
+        Ok(account)
+    }
+
+    /// Finds a registered account that is neither closed nor frozen by its name, for moving its balance.
+    pub fn active_account(&self, name: &str) -> Result<&Account, BankError> {
+        let account = self.open_account(name)?;
+
+        if account.is_frozen {
+            return Err(BankError::AccountFrozen);
+        }
+
+        Ok(account)
+    }
+
+    /// Finds a registered account by its name or, failing that, its number or the name of one of its owners.
+    pub fn find_account(&self, name_or_number: &str) -> Option<&Account> {
+        self.account(name_or_number)
+            .or_else(|| self.accounts.iter().find(|a| a.number == name_or_number))
+            .or_else(|| {
+                self.accounts
+                    .iter()
+                    .find(|a| !a.is_closed && a.owners.iter().any(|o| o == name_or_number))
+            })
+    }
+
+    /// Returns the number the next registered account will be given, following the last number in sequence.
+    fn next_number(&self) -> String {
+        let last_seq = self
+            .accounts
+            .iter()
+            .filter_map(|a| account::number_sequence(&a.number))
+            .max()
+            .unwrap_or_default();
+
+        account::generate_number(last_seq + 1)
+    }
+
+    /// Returns the value of one unit of each foreign currency in Philippine Pesos.
+    pub fn exchange_rates(&self) -> &HashMap<String, Decimal> {
+        &self.exchange_rates
+    }
+
+    /// Returns every exchange rate recorded for a foreign currency, from oldest to newest.
+    pub fn rate_history(&self, currency: &str) -> &[RatePoint] {
+        self.rate_history.get(currency).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Returns the spread between the rates a foreign currency is bought and sold at, as a percentage of its exchange
+    /// rate, which is zero unless set.
+    pub fn spread(&self, currency: &str) -> Decimal {
+        self.spreads.get(currency).copied().unwrap_or_default()
+    }
+
+    /// Returns the exchange rate of a foreign currency that was in effect at a time, in seconds since the Unix epoch,
+    /// which is the last one recorded by then, if any was.
+    pub fn rate_at(&self, currency: &str, timestamp: u64) -> Option<Decimal> {
+        self.rate_history(currency)
+            .iter()
+            .rev()
+            .find(|p| p.recorded_at <= timestamp)
+            .map(|p| p.rate)
+    }
+
+    /// Returns when the exchange rate of a foreign currency was last updated, in seconds since the Unix epoch, if it
+    /// ever was.
+    pub fn rate_updated_at(&self, currency: &str) -> Option<u64> {
+        self.rate_history(currency).last().map(|p| p.recorded_at)
+    }
+
+    /// Returns the code of the first foreign currency whose exchange rate a conversion from one currency to another
+    /// would use while it is out of date, if any.
+    ///
+    /// A rate is out of date once it is older than the rate TTL or if it was never updated. Rates never go out of
+    /// date without a rate TTL.
+    pub fn stale_rate(&self, src: &str, dest: &str) -> Option<String> {
+        let ttl = self.rate_ttl?;
+
+        if src == dest {
+            return None;
+        }
+
+        [src, dest]
+            .into_iter()
+            .filter(|c| *c != BASE_CURRENCY)
+            .find(|c| {
+                self.rate_updated_at(c)
+                    .is_none_or(|updated_at| self.clock.now().saturating_sub(updated_at) > ttl)
+            })
+            .map(str::to_string)
+    }
+
+    /// Checks that a conversion from one currency to another wouldn't use an out-of-date exchange rate, unless they
+    /// are allowed.
+    fn check_rates(&self, src: &str, dest: &str) -> Result<(), BankError> {
+        match self.stale_rate(src, dest) {
+            Some(currency) if !self.allows_stale_rates => Err(BankError::StaleRate { currency }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the currencies registered at runtime, in the order they were registered.
+    pub fn currencies(&self) -> &[Currency] {
+        &self.currencies
+    }
+
+    /// Returns the codes of the exchangeable currencies, the built-in ones first and then the registered ones in the
+    /// order they were registered.
+    pub fn currency_codes(&self) -> Vec<String> {
+        CURRENCIES_CODES
+            .iter()
+            .map(|c| c.to_string())
+            .chain(self.currencies.iter().map(|c| c.code.clone()))
+            .collect()
+    }
+
+    /// Returns the titles or labels of the exchangeable currencies, in the order their codes are listed.
+    pub fn currency_titles(&self) -> Vec<String> {
+        CURRENCIES_TITLES
+            .iter()
+            .map(|t| t.to_string())
+            .chain(self.currencies.iter().map(Currency::title))
+            .collect()
+    }
+
+    /// Returns the most that can be withdrawn from an account each day, if its withdrawals are limited.
+    pub fn daily_withdrawal_limit(&self, account: &Account) -> Option<Decimal> {
+        account.daily_withdrawal_limit.or(self.default_withdrawal_limit)
+    }
+
+    /// Returns how much more can be withdrawn from an account today, in its currency, if its withdrawals are limited.
+    pub fn daily_allowance(&self, account: &Account) -> Option<Decimal> {
+        self.daily_withdrawal_limit(account)
+            .map(|limit| (limit - account.withdrawn_on(self.clock.now())).max(Decimal::ZERO))
+    }
+
+    /// Returns the minimum balance that a type of account must maintain.
+    pub fn minimum_balance(&self, account_type: AccountType) -> &MinimumBalance {
+        &self.minimum_balances[account_type as usize]
+    }
+
+    /// Returns the fees collected by the bank, per currency.
+    pub fn revenue(&self) -> &HashMap<String, Decimal> {
+        &self.revenue
+    }
+
+    /// Returns the income of the bank from the spreads of the exchange rates, per currency.
+    pub fn spread_income(&self) -> &HashMap<String, Decimal> {
+        &self.spread_income
+    }
+
+    /// Returns the scheduled transactions, including the cancelled ones, in the order they were created.
+    pub fn schedules(&self) -> &[Schedule] {
+        &self.schedules
+    }
+
+    /// Returns the alerts on the exchange rates, including the removed ones, in the order they were added.
+    pub fn alerts(&self) -> &[RateAlert] {
+        &self.alerts
+    }
+
+    /// Returns the transactions held for approval, including the decided ones, in the order they were held.
+    pub fn pending(&self) -> &[PendingTransaction] {
+        &self.pending
+    }
+
+    /// Returns the reference number of the first transaction of the operation performed with an idempotency key, if
+    /// one was.
+    pub fn processed_key(&self, key: &str) -> Option<&str> {
+        self.idempotency_keys.get(key).map(String::as_str)
+    }
+
+    /// Sets the idempotency key to stamp the next committed transaction with, or unsets it.
+    ///
+    /// The key is remembered in the journal along with the transaction, marking its operation as performed. It is
+    /// unset once a transaction is committed, so only the first transaction of an operation is stamped.
+    pub fn set_idempotency_key(&mut self, key: Option<String>) {
+        self.next_key = key;
+    }
+
+    /// Copies the bank's current state.
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            version: SCHEMA_VERSION,
+            accounts: self.accounts.clone(),
+            exchange_rates: self.exchange_rates.clone(),
+            currencies: self.currencies.clone(),
+            rate_history: self.rate_history.clone(),
+            spreads: self.spreads.clone(),
+            minimum_balances: self.minimum_balances,
+            revenue: self.revenue.clone(),
+            spread_income: self.spread_income.clone(),
+            schedules: self.schedules.clone(),
+            pending: self.pending.clone(),
+            alerts: self.alerts.clone(),
+            idempotency_keys: self.idempotency_keys.clone(),
+        }
+    }
+
+    /// Snapshots the bank's current state to a new backup file in a directory.
+    ///
+    /// The backup is encrypted if the bank's journal is encrypted.
+    pub fn backup<P: AsRef<Path>>(&self, dir: P) -> Result<Backup, BankError> {
+        let cipher = self.journal.as_ref().and_then(|j| j.cipher());
+
+        let backup = Backup::create(dir, &self.snapshot(), cipher).map_err(BankError::Backup)?;
+
+        info!(path = %backup.path.display(), "backed up the bank's state");
+
+        Ok(backup)
+    }
+
+    /// Rolls the bank's state back to a backup.
+    pub fn restore(&mut self, backup: &Backup) -> Result<(), BankError> {
+        let cipher = self.journal.as_ref().and_then(|j| j.cipher());
+        let snapshot = backup.load(cipher).map_err(BankError::Backup)?;
+
+        if snapshot
+            .exchange_rates
+            .keys()
+            .any(|c| currency::find_code(c).is_none() && !snapshot.currencies.iter().any(|r| r.code == *c))
+        {
+            return Err(BankError::UnknownCurrency);
+        }
+
+        self.commit(EntryKind::Restore {
+            snapshot: Box::new(snapshot),
+        })?;
+        self.undo_stack.clear();
+
+        Ok(())
+    }
+
+    /// Registers a new account of a type with the default values, owned by its namesake and any joint owners, who can
+    /// be contacted through the contact details, and protected by a PIN.
+    ///
+    /// The account earns the given interest rate, or its type's if none is given. The account is opened at the bank
+    /// clock's current time. Only the PIN's hash is recorded.
+    pub fn register(
+        &mut self,
+        name: String,
+        joint_owners: Vec<String>,
+        account_type: AccountType,
+        interest_rate: Option<Decimal>,
+        contact: Contact,
+        pin: &str,
+    ) -> Result<(), BankError> {
+        if self.account(&name).is_some() {
+            return Err(BankError::DuplicateAccount);
+        }
+
+        let mut owners = vec![name.clone()];
+
+        for owner in joint_owners {
+            if !owners.contains(&owner) {
+                owners.push(owner);
+            }
+        }
+
+        if !contact.is_valid_email() {
+            return Err(BankError::InvalidEmail);
+        }
+
+        if !contact.is_valid_phone() {
+            return Err(BankError::InvalidPhone);
+        }
+
+        if !account::is_valid_pin(pin) {
+            return Err(BankError::InvalidPin);
+        }
+
+        let interest_rate = interest_rate.unwrap_or(account_type.interest_rate());
+
+        if interest_rate <= -Decimal::ONE {
+            return Err(BankError::InvalidInterestRate);
+        }
+
+        self.commit(EntryKind::Register {
+            account: name,
+            owners,
+            account_type,
+            interest_rate,
+            contact,
+            pin_hash: crypto::hash_secret(pin),
+        })
+    }
+
+    /// Verifies that a PIN is an account's.
+    pub fn verify_pin(&self, name: &str, pin: &str) -> Result<(), BankError> {
+        let account = self.account(name).ok_or(BankError::AccountNotFound)?;
+
+        if !account.verify_pin(pin) {
+            return Err(BankError::IncorrectPin);
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether a transaction's amount is positive and at most the bank's maximum amount, which is never more
+    /// than [`MAX_AMOUNT`].
+    fn check_amount(&self, amount: Decimal) -> Result<(), BankError> {
+        if currency::is_valid_amount(amount, self.max_amount) {
+            Ok(())
+        } else {
+            Err(BankError::InvalidAmount {
+                max: Some(self.max_amount.map_or(MAX_AMOUNT, |max| max.min(MAX_AMOUNT))),
+            })
+        }
+    }
+
+    /// Checks whether a foreign currency's exchange rate is from [`MIN_RATE`] to [`MAX_RATE`] and within the currency's
+    /// configured bounds, if it has any.
+    fn check_rate(&self, code: &str, rate: Decimal) -> Result<(), BankError> {
+        if !currency::is_valid_rate(rate) {
+            return Err(BankError::InvalidRate);
+        }
+
+        match self.rate_bounds.get(code) {
+            Some(bounds) if !bounds.contains(rate) => Err(BankError::RateOutOfBounds {
+                currency: code.to_string(),
+                bounds: *bounds,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the percentage by which a new exchange rate of a foreign currency differs from its current one, if the
+    /// difference is larger than the bank warns about.
+    pub fn rate_change_warning(&self, currency: &str, rate: Decimal) -> Option<Decimal> {
+        let threshold = self.rate_change_warning?;
+        let previous_rate = *self.exchange_rates.get(&self.find_currency(currency).ok()?)?;
+        let change = ((rate - previous_rate) / previous_rate * Decimal::ONE_HUNDRED).abs();
+
+        (change > threshold).then_some(change)
+    }
+
+    /// Deposits an amount in any currency to an account with an optional memo, returning its receipt.
+    pub fn deposit(
+        &mut self,
+        name: &str,
+        currency: &str,
+        amount: Decimal,
+        memo: Option<String>,
+    ) -> Result<Receipt, BankError> {
+        self.check_amount(amount)?;
+
+        let account = self.active_account(name)?;
+        let converted_amount = self.convert_to_account(account, currency, amount)?;
+
+        let reference = self.commit_undoable(vec![EntryKind::Deposit {
+            account: name.to_string(),
+            amount,
+            currency: currency.to_string(),
+            converted_amount,
+            memo,
+        }])?;
+
+        Ok(self.receipt(name, reference, Vec::new()))
+    }
+
+    /// Withdraws an amount in any currency from an account with an optional memo, returning its receipt.
+    ///
+    /// The withdrawal is rejected if the amount, its penalty, and its fees are greater than the account's current
+    /// balance and overdraft limit combined, or if the amount is more than what is left of its daily withdrawal limit.
+    ///
+    /// Withdrawals large enough to need approval are rejected, and must be held with [`Bank::hold`] instead.
+    pub fn withdraw(
+        &mut self,
+        name: &str,
+        currency: &str,
+        amount: Decimal,
+        memo: Option<String>,
+    ) -> Result<Receipt, BankError> {
+        let transaction = HeldTransaction::Withdrawal {
+            account: name.to_string(),
+            amount,
+            currency: currency.to_string(),
+        };
+
+        if self.requires_approval(&transaction)? {
+            return Err(BankError::ApprovalRequired);
+        }
+
+        let prepared = self.prepare(&transaction, memo)?;
+
+        self.commit_prepared(name, prepared, None)
+    }
+
+    /// Checks a withdrawal against its account's balance and limits, returning the transactions committing it along
+    /// with its fees.
+    fn prepare_withdrawal(
+        &self,
+        name: &str,
+        currency: &str,
+        amount: Decimal,
+        memo: Option<String>,
+    ) -> Result<(Vec<EntryKind>, Vec<Charge>), BankError> {
+        self.check_amount(amount)?;
+
+        let account = self.active_account(name)?;
+        let converted_amount = self.convert_to_account(account, currency, amount)?;
+
+        if let Some(remaining) = self.daily_allowance(account)
+            && converted_amount > remaining
+        {
+            return Err(BankError::DailyLimitExceeded {
+                remaining,
+                currency: account.currency.clone(),
+            });
+        }
+
+        let penalty = account.withdrawal_penalty(converted_amount, self.clock.now(), self.rounding);
+        let charges = self.charges(
+            account,
+            self.fee_schedule.withdrawal,
+            FeeReason::Withdrawal,
+            converted_amount,
+            penalty,
+        )?;
+
+        if account.available_balance() - converted_amount - penalty - total(&charges) < Decimal::ZERO {
+            return Err(BankError::InsufficientBalance);
+        }
+
+        let mut transactions = vec![EntryKind::Withdrawal {
+            account: name.to_string(),
+            amount,
+            currency: currency.to_string(),
+            converted_amount,
+            penalty,
+            memo,
+        }];
+
+        transactions.extend(fee_transactions(name, &charges));
+
+        Ok((transactions, charges))
+    }
+
+    /// Transfers an amount in the sending account's currency to another account, converting it to the receiving
+    /// account's currency, with an optional memo, returning the sending account's receipt.
+    ///
+    /// The transfer follows the same rules as a withdrawal from the sending account. Both of its sides are recorded as
+    /// one transaction, so either both succeed or neither does.
+    pub fn transfer(
+        &mut self,
+        name: &str,
+        recipient: &str,
+        amount: Decimal,
+        memo: Option<String>,
+    ) -> Result<Receipt, BankError> {
+        let transaction = HeldTransaction::Transfer {
+            account: name.to_string(),
+            recipient: recipient.to_string(),
+            amount,
+        };
+
+        if self.requires_approval(&transaction)? {
+            return Err(BankError::ApprovalRequired);
+        }
+
+        let prepared = self.prepare(&transaction, memo)?;
+
+        self.commit_prepared(name, prepared, None)
+    }
+
+    /// Checks a transfer against the sending account's balance and limits, returning the transactions committing it
+    /// along with its fees.
+    fn prepare_transfer(
+        &self,
+        name: &str,
+        recipient: &str,
+        amount: Decimal,
+        memo: Option<String>,
+    ) -> Result<(Vec<EntryKind>, Vec<Charge>), BankError> {
+        self.check_amount(amount)?;
+
+        let account = self.active_account(name)?;
+        let receiving_account = self.active_account(recipient)?;
+
+        if account.name == receiving_account.name {
+            return Err(BankError::SelfTransfer);
+        }
+
+        let received_amount = self.convert_to_account(receiving_account, &account.currency, amount)?;
+        let penalty = account.withdrawal_penalty(amount, self.clock.now(), self.rounding);
+        let charges = self.charges(
+            account,
+            self.fee_schedule.transfer,
+            FeeReason::Transfer,
+            amount,
+            penalty,
+        )?;
+
+        if account.available_balance() - amount - penalty - total(&charges) < Decimal::ZERO {
+            return Err(BankError::InsufficientBalance);
+        }
+
+        let mut transactions = vec![EntryKind::Transfer {
+            account: name.to_string(),
+            recipient: recipient.to_string(),
+            amount,
+            penalty,
+            received_amount,
+            memo,
+        }];
+
+        transactions.extend(fee_transactions(name, &charges));
+
+        Ok((transactions, charges))
+    }
+
+    /// Checks whether a withdrawal or transfer is large enough that it must be held for approval.
+    ///
+    /// The threshold is compared with the amount in the currency of the account the transaction is against, which a
+    /// transfer's amount is already in.
+    pub fn requires_approval(&self, transaction: &HeldTransaction) -> Result<bool, BankError> {
+        let account = self.active_account(transaction.account())?;
+        let converted_amount = match transaction {
+            HeldTransaction::Withdrawal { amount, currency, .. } => {
+                self.check_amount(*amount)?;
+                self.convert_to_account(account, currency, *amount)?
+            }
+            HeldTransaction::Transfer { amount, .. } => {
+                self.check_amount(*amount)?;
+                *amount
+            }
+        };
+
+        Ok(self.approval_threshold.is_some_and(|t| converted_amount >= t))
+    }
+
+    /// Holds a withdrawal or transfer for approval with an optional memo, returning its ID.
+    ///
+    /// The transaction is checked like it would be committed, but moves no balance until it is approved.
+    pub fn hold(&mut self, transaction: HeldTransaction, memo: Option<String>) -> Result<u64, BankError> {
+        self.prepare(&transaction, memo.clone())?;
+        self.commit(EntryKind::Hold { transaction, memo })?;
+
+        Ok(self.pending.len() as u64)
+    }
+
+    /// Approves a transaction held for approval, committing it and returning the receipt of the account it is against.
+    ///
+    /// The transaction is checked again against the account's current balance and limits. Undoing it returns it to
+    /// awaiting approval.
+    pub fn approve(&mut self, id: u64) -> Result<Receipt, BankError> {
+        let pending = self.awaiting_approval(id)?.clone();
+        let prepared = self.prepare(&pending.transaction, pending.memo)?;
+
+        self.commit_prepared(pending.transaction.account(), prepared, Some(EntryKind::Approve { id }))
+    }
+
+    /// Rejects a transaction held for approval, dropping it without moving any balance.
+    pub fn reject(&mut self, id: u64) -> Result<(), BankError> {
+        self.awaiting_approval(id)?;
+
+        self.commit(EntryKind::Reject { id })
+    }
+
+    /// Finds a transaction still awaiting approval by its ID.
+    fn awaiting_approval(&self, id: u64) -> Result<&PendingTransaction, BankError> {
+        self.pending
+            .iter()
+            .find(|p| p.id == id && p.is_pending())
+            .ok_or(BankError::PendingNotFound)
+    }
+
+    /// Checks a withdrawal or transfer, returning the transactions committing it along with its fees.
+    fn prepare(
+        &self,
+        transaction: &HeldTransaction,
+        memo: Option<String>,
+    ) -> Result<(Vec<EntryKind>, Vec<Charge>), BankError> {
+        match transaction {
+            HeldTransaction::Withdrawal {
+                account,
+                amount,
+                currency,
+            } => self.prepare_withdrawal(account, currency, *amount, memo),
+            HeldTransaction::Transfer {
+                account,
+                recipient,
+                amount,
+            } => self.prepare_transfer(account, recipient, *amount, memo),
+        }
+    }
+
+    /// Commits a checked withdrawal or transfer, followed by a transaction to undo along with it if any, returning the
+    /// receipt of the account it is against.
+    fn commit_prepared(
+        &mut self,
+        name: &str,
+        (mut transactions, charges): (Vec<EntryKind>, Vec<Charge>),
+        follow_up: Option<EntryKind>,
+    ) -> Result<Receipt, BankError> {
+        transactions.extend(follow_up);
+
+        let reference = self.commit_undoable(transactions)?;
+
+        Ok(self.receipt(name, reference, charges))
+    }
+
+    /// Closes an account, returning the amount withdrawn from it.
+    ///
+    /// The account's remaining balance, less any early withdrawal penalty, is withdrawn first if asked to. Otherwise,
+    /// the closure is rejected unless the balance is zero.
+    pub fn close(&mut self, name: &str, is_withdrawing_remainder: bool) -> Result<Decimal, BankError> {
+        let account = self.active_account(name)?;
+        let mut withdrawn_amount = Decimal::ZERO;
+
+        if account.balance.amount() != Decimal::ZERO {
+            if !is_withdrawing_remainder || account.balance.amount() < Decimal::ZERO {
+                return Err(BankError::NonzeroBalance);
+            }
+
+            let penalty = account.withdrawal_penalty(account.balance.amount(), self.clock.now(), self.rounding);
+
+            withdrawn_amount = account.balance.amount() - penalty;
+
+            self.commit(EntryKind::Withdrawal {
+                account: name.to_string(),
+                amount: withdrawn_amount,
+                currency: account.currency.clone(),
+                converted_amount: withdrawn_amount,
+                penalty,
+                memo: None,
+            })?;
+        }
+
+        self.commit(EntryKind::Close {
+            account: name.to_string(),
+        })?;
+
+        Ok(withdrawn_amount)
+    }
+
+    /// Freezes an account, rejecting its deposits and withdrawals, or unfreezes it.
+    pub fn set_frozen(&mut self, name: &str, is_frozen: bool) -> Result<(), BankError> {
+        self.open_account(name)?;
+
+        self.commit(EntryKind::Freeze {
+            account: name.to_string(),
+            is_frozen,
+        })
+    }
+
+    /// Sets how far below zero withdrawals can push an account's balance.
+    pub fn set_overdraft_limit(&mut self, name: &str, limit: Decimal) -> Result<(), BankError> {
+        self.open_account(name)?;
+
+        if limit < Decimal::ZERO {
+            return Err(BankError::InvalidOverdraftLimit);
+        }
+
+        self.commit(EntryKind::SetOverdraft {
+            account: name.to_string(),
+            limit,
+        })
+    }
+
+    /// Sets the annual interest rate earned by an account, as a fraction.
+    ///
+    /// The rate can be zero, or negative to charge the account for holding its balance instead.
+    pub fn set_interest_rate(&mut self, name: &str, rate: Decimal) -> Result<(), BankError> {
+        self.open_account(name)?;
+
+        if rate <= -Decimal::ONE {
+            return Err(BankError::InvalidInterestRate);
+        }
+
+        self.commit(EntryKind::SetInterestRate {
+            account: name.to_string(),
+            rate,
+        })
+    }
+
+    /// Sets how often the interest earned by an account is added to its balance.
+    pub fn set_compounding(&mut self, name: &str, compounding: Compounding) -> Result<(), BankError> {
+        self.open_account(name)?;
+
+        self.commit(EntryKind::SetCompounding {
+            account: name.to_string(),
+            compounding,
+        })
+    }
+
+    /// Sets the most that can be withdrawn from an account each day, or unsets it to use the bank's default.
+    pub fn set_withdrawal_limit(&mut self, name: &str, limit: Option<Decimal>) -> Result<(), BankError> {
+        self.open_account(name)?;
+
+        if limit.is_some_and(|l| l < Decimal::ZERO) {
+            return Err(BankError::InvalidWithdrawalLimit);
+        }
+
+        self.commit(EntryKind::SetWithdrawalLimit {
+            account: name.to_string(),
+            limit,
+        })
+    }
+
+    /// Sets the minimum balance that a type of account must maintain and the fees charged for falling below it.
+    pub fn set_minimum_balance(&mut self, account_type: AccountType, minimum: MinimumBalance) -> Result<(), BankError> {
+        if !minimum.is_valid() {
+            return Err(BankError::InvalidMinimumBalance);
+        }
+
+        self.commit(EntryKind::SetMinimumBalance { account_type, minimum })
+    }
+
+    /// Ends a period, charging the monthly fee to every account that isn't closed and is below its type's minimum
+    /// balance, returning the names of the charged accounts and their fees.
+    pub fn end_period(&mut self) -> Result<Vec<(String, Decimal)>, BankError> {
+        let charges: Vec<_> = self
+            .accounts
+            .iter()
+            .filter(|a| !a.is_closed)
+            .filter_map(|a| {
+                let minimum = self.minimum_balance(a.account_type);
+
+                (minimum.is_breached_by(a.balance.amount()) && minimum.monthly_fee > Decimal::ZERO)
+                    .then(|| (a.name.clone(), minimum.monthly_fee))
+            })
+            .collect();
+
+        for (name, fee) in &charges {
+            self.commit(EntryKind::Fee {
+                account: name.clone(),
+                amount: *fee,
+                reason: FeeReason::BelowMinimumPeriod,
+            })?;
+        }
+
+        Ok(charges)
+    }
+
+    /// Posts the interest that an account accrued over a number of days to its balance, returning the posting.
+    ///
+    /// If no number of days is given, the interest is posted for the days since it was last posted up to the bank
+    /// clock's current day. The interest accrues like in [`interest::schedule`] by the account's interest terms, from
+    /// the day it was last posted, and the withholding tax is recorded as withheld from it. Negative interest is
+    /// charged like a fee instead, becoming the bank's revenue.
+    pub fn post_interest(&mut self, name: &str, day_cnt: Option<u32>) -> Result<InterestPosting, BankError> {
+        let account = self.open_account(name)?;
+        let day_cnt = day_cnt.unwrap_or_else(|| {
+            clock::day(self.clock.now()).saturating_sub(clock::day(account.interest_posted_at)) as u32
+        });
+
+        if day_cnt == 0 {
+            return Err(BankError::NoInterestDue);
+        }
+
+        let schedule = interest::schedule(
+            account.balance.amount(),
+            &self.interest_terms(account),
+            clock::date(account.interest_posted_at),
+            day_cnt,
+            None,
+        );
+        let minor_units = self.minor_units(&account.currency);
+        let interest = self
+            .rounding
+            .round(schedule.iter().map(|r| r.interest).sum(), minor_units);
+        let tax = self.rounding.round(schedule.iter().map(|r| r.tax).sum(), minor_units);
+        let overdraft_charge = self
+            .rounding
+            .round(schedule.iter().map(|r| r.overdraft_charge).sum(), minor_units);
+        let until = account.interest_posted_at + day_cnt as u64 * clock::SECONDS_PER_DAY;
+
+        self.commit(EntryKind::PostInterest {
+            account: name.to_string(),
+            interest,
+            tax,
+            overdraft_charge,
+            until,
+        })?;
+
+        Ok(InterestPosting {
+            reference: history::reference(self.transaction_cnt),
+            day_cnt,
+            interest,
+            tax,
+            overdraft_charge,
+            balance: self.account(name).map(|a| a.balance.amount()).unwrap_or_default(),
+        })
+    }
+
+    /// Renames an account, keeping its balance, currency, and history.
+    pub fn rename(&mut self, name: &str, new_name: String) -> Result<(), BankError> {
+        self.open_account(name)?;
+
+        if self.account(&new_name).is_some() {
+            return Err(BankError::DuplicateAccount);
+        }
+
+        self.commit(EntryKind::Rename {
+            account: name.to_string(),
+            new_name,
+        })
+    }
+
+    /// Replaces the owners of an account, any of whom can transact with it.
+    pub fn set_owners(&mut self, name: &str, owners: Vec<String>) -> Result<(), BankError> {
+        self.open_account(name)?;
+
+        let mut unique_owners = Vec::new();
+
+        for owner in owners.into_iter().filter(|o| !o.is_empty()) {
+            if !unique_owners.contains(&owner) {
+                unique_owners.push(owner);
+            }
+        }
+
+        if unique_owners.is_empty() {
+            return Err(BankError::NoOwners);
+        }
+
+        self.commit(EntryKind::SetOwners {
+            account: name.to_string(),
+            owners: unique_owners,
+        })
+    }
+
+    /// Exchanges an amount from one currency to another, returning the exchanged amount.
+    ///
+    /// The fee schedule's exchange fee is deducted from the amount before it is exchanged, so the amount must cover it.
+    /// The source currency is bought from the customer at its buying rate and the other sold at its selling rate, the
+    /// difference from their exchange rates being the bank's income.
+    pub fn exchange(&mut self, src: &str, dest: &str, amount: Decimal) -> Result<Decimal, BankError> {
+        let quote = self.quote_exchange(src, dest, amount)?;
+
+        self.commit(EntryKind::Exchange {
+            amount,
+            currency: quote.currency,
+            exchange_currency: quote.exchange_currency,
+            exchange_amount: quote.exchange_amount,
+            fee: quote.fee,
+            spread: quote.spread,
+        })?;
+
+        Ok(quote.exchange_amount)
+    }
+
+    /// Breaks down an exchange of an amount from one currency to another, without making it.
+    ///
+    /// The exchange is checked like it is when made, so it fails the same way.
+    pub fn quote_exchange(&self, src: &str, dest: &str, amount: Decimal) -> Result<ExchangeQuote, BankError> {
+        self.check_amount(amount)?;
+
+        let src = self.find_currency(src)?;
+        let dest = self.find_currency(dest)?;
+        let fee = self.exchange_fee(&src, amount);
+
+        if fee > Decimal::ZERO && fee >= amount {
+            return Err(BankError::FeeExceedsAmount);
+        }
+
+        self.check_rates(&src, &dest)?;
+
+        let rates = self.quoted_rates(&src, &dest);
+        let exchange_amount = self.rounding.round(
+            currency::convert_currency(amount - fee, &src, &dest, &rates),
+            self.minor_units(&dest),
+        );
+
+        Ok(ExchangeQuote {
+            amount,
+            rate: currency::cross_rate(&src, &dest, &rates),
+            fee,
+            spread: self.rounding.round(
+                self.convert(&src, &dest, amount - fee)? - exchange_amount,
+                self.minor_units(&dest),
+            ),
+            exchange_amount,
+            currency: src,
+            exchange_currency: dest,
+        })
+    }
+
+    /// Returns the exchange rates that an exchange from one currency to another is made at, which are the buying rate
+    /// of the source currency and the selling rate of the other.
+    fn quoted_rates(&self, src: &str, dest: &str) -> HashMap<String, Decimal> {
+        let mut rates = self.exchange_rates.clone();
+
+        if src != dest {
+            if let Some(rate) = rates.get_mut(src) {
+                *rate = currency::buying_rate(*rate, self.spread(src));
+            }
+
+            if let Some(rate) = rates.get_mut(dest) {
+                *rate = currency::selling_rate(*rate, self.spread(dest));
+            }
+        }
+
+        rates
+    }
+
+    /// Returns the fee that exchanging an amount in a currency would be charged, in the amount's currency.
+    pub fn exchange_fee(&self, currency: &str, amount: Decimal) -> Decimal {
+        self.fee_schedule
+            .exchange
+            .map(|f| f.charge(amount, self.minor_units(currency), self.rounding))
+            .unwrap_or_default()
+    }
+
+    /// Converts an amount from one currency to another at the current exchange rates, without recording it.
+    ///
+    /// The converted amount is rounded to the minor units of the currency converted to.
+    pub fn convert(&self, src: &str, dest: &str, amount: Decimal) -> Result<Decimal, BankError> {
+        check_convertible(amount)?;
+
+        let src = self.find_currency(src)?;
+        let dest = self.find_currency(dest)?;
+        let converted_amount = if src == dest {
+            amount
+        } else {
+            currency::convert_currency(amount, &src, &dest, &self.exchange_rates)
+        };
+
+        Ok(self.rounding.round(converted_amount, self.minor_units(&dest)))
+    }
+
+    /// Converts an amount from one currency to another at the exchange rates in effect at a time, in seconds since the
+    /// Unix epoch, without recording it.
+    ///
+    /// Each foreign currency's rate must have been recorded by then. The converted amount is rounded to the minor units
+    /// of the currency converted to.
+    pub fn convert_at(&self, src: &str, dest: &str, amount: Decimal, timestamp: u64) -> Result<Decimal, BankError> {
+        check_convertible(amount)?;
+
+        let src = self.find_currency(src)?;
+        let dest = self.find_currency(dest)?;
+
+        if src == dest {
+            return Ok(self.rounding.round(amount, self.minor_units(&dest)));
+        }
+
+        let mut rates = HashMap::new();
+
+        for code in [&src, &dest].into_iter().filter(|c| *c != BASE_CURRENCY) {
+            let rate = self
+                .rate_at(code, timestamp)
+                .ok_or_else(|| BankError::NoRateAt { currency: code.clone() })?;
+
+            rates.insert(code.clone(), rate);
+        }
+
+        Ok(self.rounding.round(
+            currency::convert_currency(amount, &src, &dest, &rates),
+            self.minor_units(&dest),
+        ))
+    }
+
+    /// Returns the number of decimal places of a currency's minor units, which are the default ones for registered
+    /// currencies not listed in ISO 4217.
+    pub fn minor_units(&self, currency: &str) -> u32 {
+        self.currencies
+            .iter()
+            .find(|c| c.code == currency)
+            .map(|c| c.minor_units)
+            .or_else(|| currency::minor_units(currency))
+            .unwrap_or(currency::DEFAULT_MINOR_UNITS)
+    }
+
+    /// Updates the exchange rate between a foreign currency and Philippine Pesos, given as the value of one unit of the
+    /// currency in Philippine Pesos.
+    pub fn set_exchange_rate(&mut self, currency: &str, rate: Decimal) -> Result<(), BankError> {
+        let code = self.find_currency(currency)?;
+
+        if code == BASE_CURRENCY {
+            return Err(BankError::UnknownCurrency);
+        }
+
+        self.check_rate(&code, rate)?;
+
+        let previous_rate = self.exchange_rates.get(&code).copied().unwrap_or(Decimal::ONE);
+        let transaction = EntryKind::RateUpdate {
+            currency: code.clone(),
+            rate,
+        };
+
+        self.commit(transaction.clone())?;
+        self.remember(UndoStep {
+            transaction,
+            reversals: vec![EntryKind::RateUpdate {
+                currency: code,
+                rate: previous_rate,
+            }],
+        });
+
+        Ok(())
+    }
+
+    /// Updates the spread between the rates a foreign currency is bought and sold at, as a percentage of its exchange
+    /// rate.
+    pub fn set_spread(&mut self, currency: &str, spread: Decimal) -> Result<(), BankError> {
+        let code = self.find_currency(currency)?;
+
+        if code == BASE_CURRENCY {
+            return Err(BankError::UnknownCurrency);
+        }
+
+        if !(Decimal::ZERO..Decimal::ONE_HUNDRED).contains(&spread) {
+            return Err(BankError::InvalidSpread);
+        }
+
+        let previous_spread = self.spread(&code);
+        let transaction = EntryKind::SetSpread {
+            currency: code.clone(),
+            spread,
+        };
+
+        self.commit(transaction.clone())?;
+        self.remember(UndoStep {
+            transaction,
+            reversals: vec![EntryKind::SetSpread {
+                currency: code,
+                spread: previous_spread,
+            }],
+        });
+
+        Ok(())
+    }
+
+    /// Refreshes the exchange rates of the foreign currencies from a provider, returning the updated rates in the order
+    /// the currencies are listed.
+    ///
+    /// Currencies that the provider doesn't quote, or quotes with a rate that isn't positive, keep their rates. The
+    /// refresh is undone as a whole.
+    pub fn refresh_rates(&mut self, provider: &mut dyn RateProvider) -> Result<Vec<(String, Decimal)>, BankError> {
+        let codes: Vec<_> = self
+            .currency_codes()
+            .into_iter()
+            .filter(|c| c != BASE_CURRENCY)
+            .collect();
+        let rates = provider.fetch(&codes).map_err(BankError::Rates)?;
+        let updates: Vec<_> = codes
+            .into_iter()
+            .filter_map(|code| {
+                let rate = rates
+                    .get(&code)
+                    .copied()
+                    .filter(|r| self.check_rate(&code, *r).is_ok())?;
+
+                Some((code, rate))
+            })
+            .collect();
+        let reversals = updates
+            .iter()
+            .rev()
+            .map(|(code, _)| EntryKind::RateUpdate {
+                currency: code.clone(),
+                rate: self.exchange_rates.get(code).copied().unwrap_or(Decimal::ONE),
+            })
+            .collect();
+
+        for (code, rate) in &updates {
+            self.commit(EntryKind::RateUpdate {
+                currency: code.clone(),
+                rate: *rate,
+            })?;
+        }
+
+        if let Some((code, rate)) = updates.first() {
+            self.remember(UndoStep {
+                transaction: EntryKind::RateUpdate {
+                    currency: code.clone(),
+                    rate: *rate,
+                },
+                reversals,
+            });
+        }
+
+        Ok(updates)
+    }
+
+    /// Registers a new currency with a display name and its value in Philippine Pesos, making it exchangeable.
+    ///
+    /// The code is uppercased. If the name is blank, the currency's name in the ISO 4217 registry is used, or the code
+    /// itself if it isn't listed there.
+    pub fn add_currency(&mut self, code: &str, name: &str, rate: Decimal) -> Result<(), BankError> {
+        let code = code.trim().to_uppercase();
+        let name = name.trim();
+
+        if !currency::is_valid_code(&code) {
+            return Err(BankError::InvalidCurrencyCode);
+        }
+
+        if self.find_currency(&code).is_ok() {
+            return Err(BankError::DuplicateCurrency);
+        }
+
+        self.check_rate(&code, rate)?;
+
+        let iso = iso4217::find_by_code(&code);
+
+        self.commit(EntryKind::AddCurrency {
+            currency: Currency {
+                name: match (name, iso) {
+                    ("", Some(iso)) => iso.name.to_string(),
+                    ("", None) => code.clone(),
+                    (name, _) => name.to_string(),
+                },
+                minor_units: iso.map_or(currency::DEFAULT_MINOR_UNITS, |c| c.minor_units),
+                code,
+            },
+            rate,
+        })
+    }
+
+    /// Adds an alert on the exchange rate of a foreign currency reaching a threshold, returning the alert's ID.
+    ///
+    /// The alert notifies its user whenever a change in the rate crosses to its side of the threshold, until it is
+    /// removed.
+    pub fn add_alert(
+        &mut self,
+        currency: &str,
+        condition: AlertCondition,
+        threshold: Decimal,
+    ) -> Result<u64, BankError> {
+        let code = self.find_currency(currency)?;
+
+        if code == BASE_CURRENCY {
+            return Err(BankError::UnknownCurrency);
+        }
+
+        if threshold <= Decimal::ZERO {
+            return Err(BankError::InvalidRate);
+        }
+
+        self.commit(EntryKind::AddAlert {
+            currency: code,
+            condition,
+            threshold,
+        })?;
+
+        Ok(self.alerts.len() as u64)
+    }
+
+    /// Removes an active rate alert, stopping its notifications.
+    pub fn remove_alert(&mut self, id: u64) -> Result<(), BankError> {
+        if !self.alerts.iter().any(|a| a.id == id && !a.is_removed) {
+            return Err(BankError::AlertNotFound);
+        }
+
+        self.commit(EntryKind::RemoveAlert { id })
+    }
+
+    /// Takes the notifications of the alerts triggered since they were last taken, from oldest to newest.
+    pub fn take_notifications(&mut self) -> Vec<RateNotification> {
+        std::mem::take(&mut self.notifications)
+    }
+
+    /// Queues the notifications of the alerts that a change in the exchange rate of a currency triggers.
+    fn notify_alerts(&mut self, currency: &str, previous_rate: Decimal, rate: Decimal, timestamp: u64) {
+        let triggered = self
+            .alerts
+            .iter()
+            .filter(|a| a.currency == currency && a.is_triggered_by(previous_rate, rate))
+            .map(|alert| RateNotification {
+                alert: alert.clone(),
+                rate,
+                triggered_at: timestamp,
+            });
+
+        self.notifications.extend(triggered);
+    }
+
+    /// Schedules a transaction to repeat every number of days with an optional memo, returning the schedule's ID.
+    ///
+    /// The transaction first runs one interval from now.
+    pub fn schedule(
+        &mut self,
+        transaction: ScheduledTransaction,
+        interval_days: u64,
+        memo: Option<String>,
+    ) -> Result<u64, BankError> {
+        self.active_account(transaction.account())?;
+
+        match &transaction {
+            ScheduledTransaction::Deposit { currency, amount, .. } => {
+                self.find_currency(currency)?;
+                self.check_amount(*amount)?;
+            }
+            ScheduledTransaction::Transfer {
+                account,
+                recipient,
+                amount,
+            } => {
+                self.check_amount(*amount)?;
+
+                if self.active_account(recipient)?.name == *account {
+                    return Err(BankError::SelfTransfer);
+                }
+            }
+        }
+
+        if interval_days == 0 {
+            return Err(BankError::InvalidInterval);
+        }
+
+        self.commit(EntryKind::CreateSchedule {
+            transaction,
+            interval_days,
+            first_run_at: self.clock.now() + interval_days * clock::SECONDS_PER_DAY,
+            memo,
+        })?;
+
+        Ok(self.schedules.len() as u64)
+    }
+
+    /// Cancels an active schedule, stopping its runs.
+    pub fn cancel_schedule(&mut self, id: u64) -> Result<(), BankError> {
+        if !self.schedules.iter().any(|s| s.id == id && !s.is_cancelled) {
+            return Err(BankError::ScheduleNotFound);
+        }
+
+        self.commit(EntryKind::CancelSchedule { id })
+    }
+
+    /// Runs every scheduled transaction due by a time, in seconds since the Unix epoch, from the earliest due,
+    /// returning the runs.
+    ///
+    /// Each run is committed at the time it was due, and its schedule moves on to the next run even if it fails. The
+    /// runs can't be undone, so the transactions that could be undone before still can after.
+    pub fn run_schedules(&mut self, until: u64) -> Result<Vec<ScheduledRun>, BankError> {
+        let mut runs = Vec::new();
+        let clock = mem::replace(&mut self.clock, Box::new(SystemClock));
+        let undo_stack = mem::take(&mut self.undo_stack);
+
+        while let Some(schedule) = self
+            .schedules
+            .iter()
+            .filter(|s| s.is_due_by(until))
+            .min_by_key(|s| (s.next_run_at, s.id))
+            .cloned()
+        {
+            self.clock = Box::new(FixedClock(schedule.next_run_at));
+
+            let result = match self.run(&schedule) {
+                Err(BankError::Journal(err)) => Err(BankError::Journal(err)),
+                result => self
+                    .commit(EntryKind::AdvanceSchedule { id: schedule.id })
+                    .map(|()| result),
+            };
+
+            match result {
+                Ok(result) => runs.push(ScheduledRun {
+                    schedule: schedule.id,
+                    due_at: schedule.next_run_at,
+                    result,
+                }),
+                Err(err) => {
+                    self.clock = clock;
+                    self.undo_stack = undo_stack;
+
+                    return Err(err);
+                }
+            }
+        }
+
+        self.clock = clock;
+        self.undo_stack = undo_stack;
+
+        Ok(runs)
+    }
+
+    /// Runs a scheduled transaction once.
+    fn run(&mut self, schedule: &Schedule) -> Result<Receipt, BankError> {
+        match &schedule.transaction {
+            ScheduledTransaction::Deposit {
+                account,
+                amount,
+                currency,
+            } => self.deposit(account, currency, *amount, schedule.memo.clone()),
+            ScheduledTransaction::Transfer {
+                account,
+                recipient,
+                amount,
+            } => {
+                // Standing orders were already authorized when they were scheduled, so they aren't held for approval.
+                let transaction = HeldTransaction::Transfer {
+                    account: account.clone(),
+                    recipient: recipient.clone(),
+                    amount: *amount,
+                };
+                let prepared = self.prepare(&transaction, schedule.memo.clone())?;
+
+                self.commit_prepared(account, prepared, None)
+            }
+        }
+    }
+
+    /// Undoes the most recent transaction that can be undone, returning it.
+    ///
+    /// Only the last [`UNDO_LIMIT`] deposits, withdrawals, transfers, and exchange rate updates committed since the
+    /// bank was opened can be undone, from newest to oldest. Any fee charged along with a transaction is refunded with
+    /// it. A transaction reversed since, or involving an account closed since, is forgotten instead, so that the older
+    /// ones can still be undone.
+    pub fn undo(&mut self) -> Result<EntryKind, BankError> {
+        let step = self.undo_stack.back().ok_or(BankError::NothingToUndo)?;
+
+        for reversal in &step.reversals {
+            if let Err(err) = self.check_reversal(reversal) {
+                // These can never be undone anymore, unlike a transaction the account can't afford to give back yet.
+                if matches!(
+                    err,
+                    BankError::AlreadyReversed | BankError::AccountNotFound | BankError::AccountClosed
+                ) {
+                    self.undo_stack.pop_back();
+                }
+
+                return Err(err);
+            }
+        }
+
+        let step = self.undo_stack.pop_back().ok_or(BankError::NothingToUndo)?;
+
+        for reversal in step.reversals {
+            self.commit(reversal)?;
+        }
+
+        Ok(step.transaction)
+    }
+
+    /// Reverses a deposit, withdrawal, transfer, or fee by its reference number, returning the reversal's reference
+    /// number.
+    ///
+    /// The reversal is linked to the reversed transaction in the histories of the accounts involved. A transaction can
+    /// only be reversed once, whether by reversing or undoing it, and the reversal itself can't be undone.
+    pub fn reverse_transaction(&mut self, reference: &str) -> Result<String, BankError> {
+        let reference = reference.trim().to_uppercase();
+        let transaction = self
+            .ledger
+            .get(&reference)
+            .filter(|_| self.records(&reference).next().is_some())
+            .cloned()
+            .ok_or(BankError::TransactionNotFound)?;
+        let reversal = EntryKind::Undo {
+            transaction: Box::new(transaction),
+            reference: Some(reference),
+        };
+
+        self.check_reversal(&reversal)?;
+        self.commit(reversal)?;
+
+        Ok(history::reference(self.transaction_cnt))
+    }
+
+    /// Returns the records of a transaction in the histories of every account involved, by its reference number.
+    fn records(&self, reference: &str) -> impl Iterator<Item = &Record> {
+        self.accounts
+            .iter()
+            .flat_map(|a| &a.history)
+            .filter(move |r| r.reference == reference)
+    }
+
+    /// Checks whether a transaction undoing another can be committed, rejecting it if it was already reversed or if an
+    /// account involved was closed or can't afford to give back what it received.
+    fn check_reversal(&self, reversal: &EntryKind) -> Result<(), BankError> {
+        let EntryKind::Undo { transaction, reference } = reversal else {
+            return Ok(());
+        };
+
+        if let Some(reference) = reference
+            && self.records(reference).any(|r| r.reversed_by.is_some())
+        {
+            return Err(BankError::AlreadyReversed);
+        }
+
+        // Every account involved must be open, and the one giving back what it received must also afford it.
+        let (refunded, giving_back) = match transaction.as_ref() {
+            EntryKind::Deposit {
+                account,
+                converted_amount,
+                ..
+            } => (None, Some((account, converted_amount))),
+            EntryKind::Withdrawal { account, .. } | EntryKind::Fee { account, .. } => (Some(account), None),
+            EntryKind::Transfer {
+                account,
+                recipient,
+                received_amount,
+                ..
+            } => (Some(account), Some((recipient, received_amount))),
+            _ => (None, None),
+        };
+
+        if let Some(name) = refunded {
+            self.open_account(name)?;
+        }
+
+        if let Some((name, amount)) = giving_back
+            && self.open_account(name)?.available_balance() < *amount
+        {
+            return Err(BankError::InsufficientBalance);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the penalty that withdrawing an amount in any currency from an account would be charged now, in the
+    /// account's currency.
+    pub fn withdrawal_penalty(&self, name: &str, currency: &str, amount: Decimal) -> Result<Decimal, BankError> {
+        let account = self.account(name).ok_or(BankError::AccountNotFound)?;
+
+        Ok(account.withdrawal_penalty(
+            self.convert_to_account(account, currency, amount)?,
+            self.clock.now(),
+            self.rounding,
+        ))
+    }
+
+    /// Returns the fees that withdrawing an amount in any currency from an account would be charged now, in the
+    /// account's currency.
+    pub fn withdrawal_charges(&self, name: &str, currency: &str, amount: Decimal) -> Result<Vec<Charge>, BankError> {
+        let account = self.account(name).ok_or(BankError::AccountNotFound)?;
+        let converted_amount = self.convert_to_account(account, currency, amount)?;
+        let penalty = account.withdrawal_penalty(converted_amount, self.clock.now(), self.rounding);
+
+        self.charges(
+            account,
+            self.fee_schedule.withdrawal,
+            FeeReason::Withdrawal,
+            converted_amount,
+            penalty,
+        )
+    }
+
+    /// Returns the fees that transferring an amount from an account would be charged now, in the account's currency.
+    pub fn transfer_charges(&self, name: &str, amount: Decimal) -> Result<Vec<Charge>, BankError> {
+        let account = self.account(name).ok_or(BankError::AccountNotFound)?;
+        let penalty = account.withdrawal_penalty(amount, self.clock.now(), self.rounding);
+
+        self.charges(
+            account,
+            self.fee_schedule.transfer,
+            FeeReason::Transfer,
+            amount,
+            penalty,
+        )
+    }
+
+    /// Returns the fees for taking an amount and its penalty, in the account's currency, out of an account: the fee
+    /// schedule's fee for the transaction, then any fee for leaving the account below its type's minimum balance.
+    ///
+    /// The transaction is rejected if it leaves the account below the minimum and the minimum doesn't allow it for a
+    /// fee.
+    fn charges(
+        &self,
+        account: &Account,
+        fee: Option<Fee>,
+        reason: FeeReason,
+        amount: Decimal,
+        penalty: Decimal,
+    ) -> Result<Vec<Charge>, BankError> {
+        let mut charges = Vec::new();
+
+        if let Some(fee) = fee {
+            charges.push(Charge {
+                reason,
+                amount: fee.charge(amount, self.minor_units(&account.currency), self.rounding),
+            });
+        }
+
+        let minimum = self.minimum_balance(account.account_type);
+
+        if minimum.is_breached_by(account.balance.amount() - amount - penalty - total(&charges)) {
+            charges.push(Charge {
+                reason: FeeReason::BelowMinimumWithdrawal,
+                amount: minimum.breach_fee.ok_or(BankError::BelowMinimumBalance)?,
+            });
+        }
+
+        charges.retain(|c| c.amount > Decimal::ZERO);
+
+        Ok(charges)
+    }
+
+    /// Converts an amount in any currency to an account's currency, unless the conversion would use an out-of-date
+    /// exchange rate that isn't allowed.
+    fn convert_to_account(&self, account: &Account, currency: &str, amount: Decimal) -> Result<Decimal, BankError> {
+        let currency = self.find_currency(currency)?;
+
+        self.check_rates(&currency, &account.currency)?;
+        self.convert(&currency, &account.currency, amount)
+    }
+
+    /// Commits transactions one after another, remembering them to be undone together, returning the first one's
+    /// reference number.
+    fn commit_undoable(&mut self, transactions: Vec<EntryKind>) -> Result<String, BankError> {
+        let first_seq = self.transaction_cnt + 1;
+        let reference = history::reference(first_seq);
+
+        for transaction in &transactions {
+            self.commit(transaction.clone())?;
+        }
+
+        self.remember(UndoStep::reverting(transactions, first_seq));
+
+        Ok(reference)
+    }
+
+    /// Creates the receipt of a transaction against an account and its fees, with the account's current balance.
+    fn receipt(&self, name: &str, reference: String, charges: Vec<Charge>) -> Receipt {
+        Receipt {
+            reference,
+            balance: self.account(name).map(|a| a.balance.amount()).unwrap_or_default(),
+            charges,
+        }
+    }
+
+    /// Remembers a transaction to be undone, forgetting the oldest one if too many are remembered.
+    fn remember(&mut self, step: UndoStep) {
+        if self.undo_stack.len() == UNDO_LIMIT {
+            self.undo_stack.pop_front();
+        }
+
+        self.undo_stack.push_back(step);
+    }
+
+    /// Records a transaction in the journal, then applies it to the bank's state.
+    fn commit(&mut self, kind: EntryKind) -> Result<(), BankError> {
+        let mut entry = Entry::new(kind, self.clock.now());
+
+        entry.idempotency_key = self.next_key.take();
+
+        if let Some(journal) = &mut self.journal
+            && let Err(err) = journal.append(&entry)
+        {
+            error!(%err, "failed to record a transaction in the journal");
+
+            return Err(err.into());
+        }
+
+        let previous_rate = match &entry.kind {
+            EntryKind::RateUpdate { currency, .. } => self.exchange_rates.get(currency).copied(),
+            _ => None,
+        };
+
+        self.transaction_cnt += 1;
+        self.apply(&entry.kind, entry.timestamp)?;
+
+        // Alerts are only checked as rates change now, so that replaying the journal doesn't repeat old notifications.
+        if let (EntryKind::RateUpdate { currency, rate }, Some(previous_rate)) = (&entry.kind, previous_rate) {
+            self.notify_alerts(currency, previous_rate, *rate, entry.timestamp);
+        }
+
+        self.remember_key(entry.idempotency_key.clone());
+
+        info!(transaction = ?entry.kind, "committed a transaction");
+
+        if let Some(event) = Event::of(&entry, history::reference(self.transaction_cnt)) {
+            for subscriber in &mut self.subscribers {
+                subscriber.publish(&event);
+            }
+        }
+
+        self.report_violations();
+
+        Ok(())
+    }
+
+    /// Applies a transaction committed at a time, in seconds since the Unix epoch, to the bank's state without
+    /// recording it.
+    ///
+    /// The transaction is stamped with the reference number following the last committed transaction.
+    fn apply(&mut self, kind: &EntryKind, timestamp: u64) -> Result<(), BankError> {
+        let reference = history::reference(self.transaction_cnt);
+        let stamp = |memo: &Option<String>| Stamp {
+            timestamp,
+            reference: reference.clone(),
+            memo: memo.clone(),
+        };
+
+        match kind {
+            EntryKind::Register {
+                account,
+                owners,
+                account_type,
+                interest_rate,
+                contact,
+                pin_hash,
+            } => {
+                if self.account(account).is_some() {
+                    return Err(BankError::DuplicateAccount);
+                }
+
+                let number = self.next_number();
+                let mut new_account = Account::new(
+                    account.clone(),
+                    owners.clone(),
+                    number,
+                    *account_type,
+                    timestamp,
+                    contact.clone(),
+                    pin_hash.clone(),
+                );
+
+                new_account.interest_rate = *interest_rate;
+
+                self.accounts.push(new_account);
+            }
+            EntryKind::Deposit {
+                account,
+                amount,
+                currency,
+                converted_amount,
+                memo,
+            } => {
+                let account = self.account_mut(account)?;
+
+                account.balance += converted_amount;
+                account.record(RecordKind::Deposit, *amount, currency, &stamp(memo));
+            }
+            EntryKind::Withdrawal {
+                account,
+                amount,
+                currency,
+                converted_amount,
+                penalty,
+                memo,
+            } => {
+                let account = self.account_mut(account)?;
+
+                account.balance -= converted_amount;
+                account.count_withdrawal(*converted_amount, timestamp);
+                account.record(RecordKind::Withdrawal, *amount, currency, &stamp(memo));
+
+                if *penalty > Decimal::ZERO {
+                    let account_currency = account.currency.clone();
+
+                    account.balance -= penalty;
+                    account.record(RecordKind::Penalty, *penalty, &account_currency, &stamp(&None));
+                }
+            }
+            EntryKind::Transfer {
+                account,
+                recipient,
+                amount,
+                penalty,
+                received_amount,
+                memo,
+            } => {
+                // Both accounts are found before either is changed, so that a failed transfer leaves neither changed.
+                self.account_mut(account)?;
+
+                let receiving_account = self.account_mut(recipient)?;
+                let receiving_currency = receiving_account.currency.clone();
+
+                receiving_account.balance += received_amount;
+                receiving_account.record(
+                    RecordKind::TransferIn,
+                    *received_amount,
+                    &receiving_currency,
+                    &stamp(memo),
+                );
+
+                let account = self.account_mut(account)?;
+                let currency = account.currency.clone();
+
+                account.balance -= amount;
+                account.record(RecordKind::TransferOut, *amount, &currency, &stamp(memo));
+
+                if *penalty > Decimal::ZERO {
+                    account.balance -= penalty;
+                    account.record(RecordKind::Penalty, *penalty, &currency, &stamp(&None));
+                }
+            }
+            EntryKind::Close { account } => {
+                self.account_mut(account)?.is_closed = true;
+            }
+            EntryKind::Freeze { account, is_frozen } => {
+                self.account_mut(account)?.is_frozen = *is_frozen;
+            }
+            EntryKind::SetOverdraft { account, limit } => {
+                self.account_mut(account)?.overdraft_limit = *limit;
+            }
+            EntryKind::SetInterestRate { account, rate } => {
+                self.account_mut(account)?.interest_rate = *rate;
+            }
+            EntryKind::SetCompounding { account, compounding } => {
+                self.account_mut(account)?.compounding = *compounding;
+            }
+            EntryKind::SetWithdrawalLimit { account, limit } => {
+                self.account_mut(account)?.daily_withdrawal_limit = *limit;
+            }
+            EntryKind::SetMinimumBalance { account_type, minimum } => {
+                self.minimum_balances[*account_type as usize] = *minimum;
+            }
+            EntryKind::Fee { account, amount, .. } => {
+                let account = self.account_mut(account)?;
+                let currency = account.currency.clone();
+
+                account.balance -= amount;
+                account.record(RecordKind::Fee, *amount, &currency, &stamp(&None));
+
+                *self.revenue.entry(currency).or_default() += amount;
+            }
+            EntryKind::PostInterest {
+                account,
+                interest,
+                tax,
+                overdraft_charge,
+                until,
+            } => {
+                let account = self.account_mut(account)?;
+                let currency = account.currency.clone();
+                let mut charges = Decimal::ZERO;
+
+                account.interest_posted_at = *until;
+
+                if *interest > Decimal::ZERO {
+                    account.balance += interest;
+                    account.record(RecordKind::Interest, *interest, &currency, &stamp(&None));
+                } else if *interest < Decimal::ZERO {
+                    account.balance += interest;
+                    account.record(RecordKind::Fee, -interest, &currency, &stamp(&None));
+
+                    charges -= interest;
+                }
+
+                if *tax > Decimal::ZERO {
+                    account.balance -= tax;
+                    account.record(RecordKind::Tax, *tax, &currency, &stamp(&None));
+                }
+
+                if *overdraft_charge > Decimal::ZERO {
+                    account.balance -= overdraft_charge;
+                    account.record(RecordKind::Fee, *overdraft_charge, &currency, &stamp(&None));
+
+                    charges += overdraft_charge;
+                }
+
+                if charges > Decimal::ZERO {
+                    *self.revenue.entry(currency).or_default() += charges;
+                }
+            }
+            EntryKind::Rename { account, new_name } => {
+                if self.account(new_name).is_some() {
+                    return Err(BankError::DuplicateAccount);
+                }
+
+                self.account_mut(account)?.name = new_name.clone();
+
+                for schedule in &mut self.schedules {
+                    schedule.transaction.rename_account(account, new_name);
+                }
+
+                for pending in &mut self.pending {
+                    pending.transaction.rename_account(account, new_name);
+                }
+
+                for transaction in self.ledger.values_mut() {
+                    transaction.rename_account(account, new_name);
+                }
+
+                for step in &mut self.undo_stack {
+                    step.transaction.rename_account(account, new_name);
+
+                    for reversal in &mut step.reversals {
+                        reversal.rename_account(account, new_name);
+                    }
+                }
+            }
+            EntryKind::SetOwners { account, owners } => {
+                self.account_mut(account)?.owners = owners.clone();
+            }
+            EntryKind::Exchange {
+                currency,
+                fee,
+                exchange_currency,
+                spread,
+                ..
+            } => {
+                if *fee > Decimal::ZERO {
+                    *self.revenue.entry(currency.clone()).or_default() += fee;
+                }
+
+                if *spread > Decimal::ZERO {
+                    *self.spread_income.entry(exchange_currency.clone()).or_default() += spread;
+                }
+            }
+            EntryKind::SetSpread { currency, spread } => {
+                self.spreads.insert(self.find_currency(currency)?, *spread);
+            }
+            EntryKind::RateUpdate { currency, rate } => {
+                let code = self.find_currency(currency)?;
+
+                self.record_rate(code, *rate, timestamp);
+            }
+            EntryKind::AddCurrency { currency, rate } => {
+                self.currencies.push(currency.clone());
+                self.record_rate(currency.code.clone(), *rate, timestamp);
+            }
+            EntryKind::CreateSchedule {
+                transaction,
+                interval_days,
+                first_run_at,
+                memo,
+            } => {
+                self.schedules.push(Schedule {
+                    id: self.schedules.len() as u64 + 1,
+                    transaction: transaction.clone(),
+                    interval_days: *interval_days,
+                    next_run_at: *first_run_at,
+                    memo: memo.clone(),
+                    is_cancelled: false,
+                });
+            }
+            EntryKind::AdvanceSchedule { id } => self.schedule_mut(*id)?.advance(),
+            EntryKind::CancelSchedule { id } => self.schedule_mut(*id)?.is_cancelled = true,
+            EntryKind::AddAlert {
+                currency,
+                condition,
+                threshold,
+            } => {
+                self.alerts.push(RateAlert {
+                    id: self.alerts.len() as u64 + 1,
+                    currency: currency.clone(),
+                    condition: *condition,
+                    threshold: *threshold,
+                    is_removed: false,
+                });
+            }
+            EntryKind::RemoveAlert { id } => {
+                self.alerts
+                    .iter_mut()
+                    .find(|a| a.id == *id)
+                    .ok_or(BankError::AlertNotFound)?
+                    .is_removed = true
+            }
+            EntryKind::Hold { transaction, memo } => {
+                self.pending.push(PendingTransaction {
+                    id: self.pending.len() as u64 + 1,
+                    transaction: transaction.clone(),
+                    memo: memo.clone(),
+                    submitted_at: timestamp,
+                    status: ApprovalStatus::Pending,
+                });
+            }
+            EntryKind::Approve { id } => self.pending_mut(*id)?.status = ApprovalStatus::Approved,
+            EntryKind::Reject { id } => self.pending_mut(*id)?.status = ApprovalStatus::Rejected,
+            EntryKind::Undo {
+                transaction,
+                reference: undone,
+            } => {
+                match transaction.as_ref() {
+                    EntryKind::Deposit {
+                        account,
+                        converted_amount,
+                        ..
+                    } => self.reverse(account, -converted_amount, &stamp(&None))?,
+                    EntryKind::Withdrawal {
+                        account,
+                        converted_amount,
+                        penalty,
+                        ..
+                    } => {
+                        self.reverse(account, converted_amount + penalty, &stamp(&None))?;
+                        self.account_mut(account)?
+                            .count_withdrawal(-converted_amount, timestamp);
+                    }
+                    EntryKind::Transfer {
+                        account,
+                        recipient,
+                        amount,
+                        penalty,
+                        received_amount,
+                        ..
+                    } => {
+                        // Both accounts are found before either is changed, like the transfer itself.
+                        self.account_mut(account)?;
+                        self.reverse(recipient, -received_amount, &stamp(&None))?;
+                        self.reverse(account, amount + penalty, &stamp(&None))?;
+                    }
+                    EntryKind::Fee { account, amount, .. } => {
+                        self.reverse(account, *amount, &stamp(&None))?;
+
+                        let currency = self.account_mut(account)?.currency.clone();
+
+                        *self.revenue.entry(currency).or_default() -= amount;
+                    }
+                    EntryKind::Approve { id } => self.pending_mut(*id)?.status = ApprovalStatus::Pending,
+                    _ => {}
+                }
+
+                if let Some(undone) = undone {
+                    self.link_reversal(undone, &reference);
+                }
+            }
+            EntryKind::Restore { snapshot } => {
+                self.accounts = snapshot.accounts.clone();
+                self.minimum_balances = snapshot.minimum_balances;
+                self.revenue = snapshot.revenue.clone();
+                self.schedules = snapshot.schedules.clone();
+                self.pending = snapshot.pending.clone();
+                self.alerts = snapshot.alerts.clone();
+                self.idempotency_keys = snapshot.idempotency_keys.clone();
+                self.currencies = snapshot.currencies.clone();
+                self.rate_history = snapshot.rate_history.clone();
+                self.spreads = snapshot.spreads.clone();
+                self.spread_income = snapshot.spread_income.clone();
+                self.exchange_rates = snapshot
+                    .exchange_rates
+                    .iter()
+                    .filter(|(code, _)| self.find_currency(code).is_ok())
+                    .map(|(code, rate)| (code.clone(), *rate))
+                    .collect();
+            }
+        }
+
+        if let EntryKind::Deposit { .. }
+        | EntryKind::Withdrawal { .. }
+        | EntryKind::Transfer { .. }
+        | EntryKind::Fee { .. } = kind
+        {
+            self.ledger.insert(reference, kind.clone());
+        }
+
+        Ok(())
+    }
+
+    /// Updates the exchange rate of a currency, adding it to the currency's rate history.
+    fn record_rate(&mut self, code: String, rate: Decimal, timestamp: u64) {
+        self.rate_history.entry(code.clone()).or_default().push(RatePoint {
+            rate,
+            recorded_at: timestamp,
+        });
+        self.exchange_rates.insert(code, rate);
+    }
+
+    /// Remembers the idempotency key a transaction was stamped with, if any, by the transaction's reference number.
+    fn remember_key(&mut self, key: Option<String>) {
+        if let Some(key) = key {
+            self.idempotency_keys
+                .insert(key, history::reference(self.transaction_cnt));
+        }
+    }
+
+    /// Links a transaction to the one reversing it in the histories of every account involved, by their reference
+    /// numbers.
+    fn link_reversal(&mut self, reversed: &str, reversal: &str) {
+        for record in self.accounts.iter_mut().flat_map(|a| &mut a.history) {
+            if record.reference == reversed {
+                record.reversed_by = Some(reversal.to_string());
+            } else if record.reference == reversal {
+                record.reversal_of = Some(reversed.to_string());
+            }
+        }
+    }
+
+    /// Changes an account's balance by an amount, in its currency, to undo an earlier transaction with a stamp.
+    fn reverse(&mut self, name: &str, change: Decimal, stamp: &Stamp) -> Result<(), BankError> {
+        let account = self.account_mut(name)?;
+        let currency = account.currency.clone();
+
+        account.balance += change;
+        account.record(RecordKind::Reversal, change.abs(), &currency, stamp);
+
+        Ok(())
+    }
+
+    /// Finds a schedule by its ID, for mutation.
+    fn schedule_mut(&mut self, id: u64) -> Result<&mut Schedule, BankError> {
+        self.schedules
+            .iter_mut()
+            .find(|s| s.id == id)
+            .ok_or(BankError::ScheduleNotFound)
+    }
+
+    /// Finds the code of an exchangeable currency, whether built in or registered.
+    fn find_currency(&self, code: &str) -> Result<String, BankError> {
+        currency::find_code(code)
+            .or_else(|| self.currencies.iter().map(|c| c.code.as_str()).find(|c| *c == code))
+            .map(String::from)
+            .ok_or(BankError::UnknownCurrency)
+    }
+
+    /// Finds a transaction held for approval by its ID, for mutation.
+    fn pending_mut(&mut self, id: u64) -> Result<&mut PendingTransaction, BankError> {
+        self.pending
+            .iter_mut()
+            .find(|p| p.id == id)
+            .ok_or(BankError::PendingNotFound)
+    }
+
+    /// Finds a registered account by its name, for mutation.
+    fn account_mut(&mut self, name: &str) -> Result<&mut Account, BankError> {
+        self.accounts
+            .iter_mut()
+            .find(|a| a.name == name)
+            .ok_or(BankError::AccountNotFound)
+    }
+}
+impl Default for Bank {
+    fn default() -> Self {
+        Bank::new()
+    }
+}
+
+/// Adds up the fees charged along with a transaction.
+fn total(charges: &[Charge]) -> Decimal {
+    charges.iter().map(|c| c.amount).sum()
+}
+
+/// Creates the transactions charging an account's fees.
+fn fee_transactions(name: &str, charges: &[Charge]) -> impl Iterator<Item = EntryKind> {
+    charges.iter().map(move |c| EntryKind::Fee {
+        account: name.to_string(),
+        amount: c.amount,
+        reason: c.reason,
+    })
+}
+
+/// Checks whether an amount can be converted between currencies without overflowing, being at most [`MAX_AMOUNT`]
+/// either way.
+fn check_convertible(amount: Decimal) -> Result<(), BankError> {
+    if amount.abs() <= MAX_AMOUNT {
+        Ok(())
+    } else {
+        Err(BankError::InvalidAmount { max: Some(MAX_AMOUNT) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    /// Creates a bank without a journal, with savings accounts with the PIN 1234 named after each of some names.
+    fn bank_of(names: &[&str]) -> Bank {
+        let mut bank = Bank::new();
+
+        for name in names {
+            bank.register(
+                name.to_string(),
+                Vec::new(),
+                AccountType::Savings,
+                None,
+                Contact::default(),
+                "1234",
+            )
+            .unwrap();
+        }
+
+        bank
+    }
+
+    /// Returns the balance of an account.
+    fn balance(bank: &Bank, name: &str) -> Decimal {
+        bank.account(name).unwrap().balance.amount()
+    }
+
+    #[test]
+    fn undoing_follows_renamed_account() {
+        let mut bank = bank_of(&["Ana"]);
+
+        bank.deposit("Ana", BASE_CURRENCY, dec!(100), None).unwrap();
+        bank.rename("Ana", String::from("Ann")).unwrap();
+        bank.undo().unwrap();
+
+        assert_eq!(balance(&bank, "Ann"), Decimal::ZERO);
+    }
+
+    #[test]
+    fn reversing_follows_renamed_account() {
+        let mut bank = bank_of(&["Ana", "Ben"]);
+        bank.deposit("Ben", BASE_CURRENCY, dec!(100), None).unwrap();
+
+        let transfer = bank.transfer("Ben", "Ana", dec!(40), None).unwrap().reference;
+
+        bank.rename("Ana", String::from("Ann")).unwrap();
+        bank.rename("Ben", String::from("Benjamin")).unwrap();
+
+        let reversal = bank.reverse_transaction(&transfer).unwrap();
+
+        assert_eq!(balance(&bank, "Ann"), Decimal::ZERO);
+        assert_eq!(balance(&bank, "Benjamin"), dec!(100));
+
+        for name in ["Ann", "Benjamin"] {
+            let records = &bank.account(name).unwrap().history;
+            let reversed = records.iter().find(|r| r.reference == transfer).unwrap();
+            let reversing = records.iter().find(|r| r.reference == reversal).unwrap();
+
+            assert_eq!(reversed.reversed_by.as_ref(), Some(&reversal));
+            assert_eq!(reversing.reversal_of.as_ref(), Some(&transfer));
+        }
+    }
+
+    #[test]
+    fn undoing_forgets_transactions_of_closed_account() {
+        let mut bank = bank_of(&["Ana", "Ben"]);
+
+        bank.deposit("Ben", BASE_CURRENCY, dec!(100), None).unwrap();
+        bank.deposit("Ana", BASE_CURRENCY, dec!(50), None).unwrap();
+        bank.close("Ana", true).unwrap();
+
+        assert!(matches!(bank.undo(), Err(BankError::AccountClosed)));
+        bank.undo().unwrap();
+
+        assert_eq!(balance(&bank, "Ben"), Decimal::ZERO);
+        assert!(matches!(bank.undo(), Err(BankError::NothingToUndo)));
+    }
+
+    #[test]
+    fn undoing_spread_restores_previous_spread() {
+        let mut bank = Bank::new();
+
+        bank.set_spread("USD", dec!(1.5)).unwrap();
+        bank.set_spread("USD", dec!(2.5)).unwrap();
+        bank.undo().unwrap();
+
+        assert_eq!(bank.spread("USD"), dec!(1.5));
+
+        bank.undo().unwrap();
+
+        assert_eq!(bank.spread("USD"), Decimal::ZERO);
+    }
+
+    #[test]
+    fn verifying_finds_every_violation() {
+        let mut bank = bank_of(&["Ana", "Ben", "Cy"]);
+        bank.deposit("Ana", BASE_CURRENCY, dec!(100), None).unwrap();
+
+        bank.accounts[0].balance += dec!(5);
+        bank.accounts[1].balance -= dec!(10);
+        bank.accounts[1].opening_balance = dec!(-10);
+        bank.accounts[2].name = String::from("Ana");
+        bank.accounts[2].number = bank.accounts[0].number.clone();
+
+        assert_eq!(
+            bank.verify(),
+            vec![
+                Violation::BalanceMismatch {
+                    account: String::from("Ana"),
+                    balance: dec!(105),
+                    recorded_balance: dec!(100),
+                },
+                Violation::Overdrawn {
+                    account: String::from("Ben"),
+                    balance: dec!(-10),
+                    overdraft_limit: Decimal::ZERO,
+                },
+                Violation::DuplicateName {
+                    name: String::from("Ana"),
+                },
+                Violation::DuplicateNumber {
+                    number: bank.accounts[0].number.clone(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn verifying_accepts_opening_balance_without_history() {
+        let mut bank = bank_of(&["Ana"]);
+
+        bank.accounts[0].balance += dec!(100);
+        bank.accounts[0].opening_balance = dec!(100);
+
+        assert_eq!(bank.verify(), Vec::new());
+    }
+
+    /// Creates a bank without a journal that holds withdrawals and transfers of ₱500.00 or more for approval, with
+    /// savings accounts named Ana holding ₱1,000.00 and Ben holding nothing.
+    fn approving_bank() -> Bank {
+        let mut bank = bank_of(&["Ana", "Ben"]);
+
+        bank.deposit("Ana", BASE_CURRENCY, dec!(1000), None).unwrap();
+        bank.approval_threshold = Some(dec!(500));
+
+        bank
+    }
+
+    #[test]
+    fn transferring_past_threshold_requires_approval() {
+        let mut bank = approving_bank();
+        let transfer = |amount| HeldTransaction::Transfer {
+            account: String::from("Ana"),
+            recipient: String::from("Ben"),
+            amount,
+        };
+
+        assert!(!bank.requires_approval(&transfer(dec!(499.99))).unwrap());
+        assert!(bank.requires_approval(&transfer(dec!(500))).unwrap());
+        assert!(matches!(
+            bank.requires_approval(&transfer(dec!(-600))),
+            Err(BankError::InvalidAmount { .. })
+        ));
+        assert!(matches!(
+            bank.transfer("Ana", "Ben", dec!(600), None),
+            Err(BankError::ApprovalRequired)
+        ));
+    }
+
+    #[test]
+    fn approving_held_transfer_moves_balance() {
+        let mut bank = approving_bank();
+        let id = bank
+            .hold(
+                HeldTransaction::Transfer {
+                    account: String::from("Ana"),
+                    recipient: String::from("Ben"),
+                    amount: dec!(600),
+                },
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(balance(&bank, "Ana"), dec!(1000));
+        assert_eq!(balance(&bank, "Ben"), Decimal::ZERO);
+
+        bank.approve(id).unwrap();
+
+        assert_eq!(balance(&bank, "Ana"), dec!(400));
+        assert_eq!(balance(&bank, "Ben"), dec!(600));
+        assert!(matches!(bank.approve(id), Err(BankError::PendingNotFound)));
+    }
+
+    #[test]
+    fn rejecting_held_withdrawal_keeps_balance() {
+        let mut bank = approving_bank();
+        let id = bank
+            .hold(
+                HeldTransaction::Withdrawal {
+                    account: String::from("Ana"),
+                    amount: dec!(700),
+                    currency: BASE_CURRENCY.to_string(),
+                },
+                None,
+            )
+            .unwrap();
+
+        bank.reject(id).unwrap();
+
+        assert_eq!(balance(&bank, "Ana"), dec!(1000));
+        assert!(matches!(bank.approve(id), Err(BankError::PendingNotFound)));
+    }
+}
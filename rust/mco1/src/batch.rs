@@ -0,0 +1,170 @@
+//! The deposits applied in bulk from a CSV file.
+
+use std::{fmt, mem};
+
+use rust_decimal::Decimal;
+
+use crate::{
+    bank::{Bank, BankError, Receipt},
+    currency::{self, BASE_CURRENCY},
+};
+
+/// The header a CSV file of deposits may start with.
+const HEADER: [&str; 3] = ["account", "amount", "currency"];
+
+/// The reasons a row of deposits can be rejected.
+#[derive(Debug)]
+pub enum RowError {
+    /// The row lacks an account or an amount, or has more than three fields.
+    Malformed,
+    /// The amount isn't a positive number.
+    InvalidAmount,
+    /// The deposit was rejected by the bank.
+    Bank(BankError),
+}
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RowError::Malformed => write!(f, "Rows must have an account, an amount, and an optional currency!"),
+            RowError::InvalidAmount => write!(f, "Deposit amounts must be positive numbers!"),
+            RowError::Bank(err) => err.fmt(f),
+        }
+    }
+}
+impl std::error::Error for RowError {}
+impl From<BankError> for RowError {
+    fn from(err: BankError) -> Self {
+        RowError::Bank(err)
+    }
+}
+
+/// A row of a CSV file of deposits.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DepositRow {
+    /// The line the row is on, starting from one.
+    pub line: usize,
+    /// The name or number of the account to deposit to, as written.
+    pub account: String,
+    /// The amount to deposit, as written.
+    pub amount: String,
+    /// The currency of the amount, which is the base currency if left out.
+    pub currency: String,
+}
+
+/// The result of depositing a row of a CSV file.
+#[derive(Debug)]
+pub struct RowResult {
+    /// The deposited row.
+    pub row: DepositRow,
+    /// The receipt of the deposit, which is none for a valid row of a dry run, or why it was rejected.
+    pub result: Result<Option<Receipt>, RowError>,
+}
+
+/// Reads the rows of a CSV file of deposits, skipping blank lines and the header if there is one.
+///
+/// Each row is written as `account,amount,currency`, where the currency can be left out. Fields can be quoted to hold
+/// commas, with a doubled quote standing for a literal one.
+pub fn parse_deposits(csv: &str) -> Vec<Result<DepositRow, (usize, RowError)>> {
+    csv.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter(|(idx, line)| *idx != 0 || !is_header(line))
+        .map(|(idx, line)| {
+            let line_num = idx + 1;
+            let mut fields = split_fields(line).into_iter();
+
+            match (fields.next(), fields.next(), fields.next(), fields.next()) {
+                (Some(account), Some(amount), currency, None) if !account.is_empty() && !amount.is_empty() => {
+                    Ok(DepositRow {
+                        line: line_num,
+                        account,
+                        amount,
+                        currency: currency
+                            .filter(|c| !c.is_empty())
+                            .unwrap_or_else(|| BASE_CURRENCY.to_string())
+                            .to_uppercase(),
+                    })
+                }
+                _ => Err((line_num, RowError::Malformed)),
+            }
+        })
+        .collect()
+}
+
+/// Deposits every valid row of a CSV file in one pass, returning the result of each row.
+///
+/// A dry run only validates the rows, leaving the bank untouched. Rows that fail to be read are returned with only
+/// their line filled in.
+pub fn deposit_all(bank: &mut Bank, csv: &str, is_dry_run: bool) -> Vec<RowResult> {
+    parse_deposits(csv)
+        .into_iter()
+        .map(|row| match row {
+            Ok(row) => RowResult {
+                result: deposit(bank, &row, is_dry_run),
+                row,
+            },
+            Err((line, err)) => RowResult {
+                row: DepositRow {
+                    line,
+                    account: String::new(),
+                    amount: String::new(),
+                    currency: String::new(),
+                },
+                result: Err(err),
+            },
+        })
+        .collect()
+}
+
+/// Validates a row and deposits it unless it's a dry run.
+fn deposit(bank: &mut Bank, row: &DepositRow, is_dry_run: bool) -> Result<Option<Receipt>, RowError> {
+    let amount = row
+        .amount
+        .parse::<Decimal>()
+        .ok()
+        .filter(|a| currency::is_valid_amount(*a, None))
+        .ok_or(RowError::InvalidAmount)?;
+    let name = bank
+        .find_account(&row.account)
+        .map(|a| a.name.clone())
+        .ok_or(BankError::AccountNotFound)?;
+
+    if is_dry_run {
+        let account = bank.active_account(&name)?;
+
+        bank.convert(&row.currency, &account.currency, amount)?;
+
+        return Ok(None);
+    }
+
+    Ok(Some(bank.deposit(&name, &row.currency, amount, None)?))
+}
+
+/// Checks whether a line of CSV is the header naming the fields.
+fn is_header(line: &str) -> bool {
+    split_fields(line).iter().map(|f| f.to_lowercase()).eq(HEADER)
+}
+
+/// Splits a line of CSV into its trimmed fields, unquoting the quoted ones.
+pub(crate) fn split_fields(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut is_quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '"' if is_quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => is_quoted = !is_quoted,
+            ',' if !is_quoted => fields.push(mem::take(&mut field).trim().to_string()),
+            _ => field.push(char),
+        }
+    }
+
+    fields.push(field.trim().to_string());
+
+    fields
+}
@@ -0,0 +1,657 @@
+//! The command line interface's arguments and non-interactive subcommands.
+
+use std::{net::SocketAddr, path::PathBuf};
+
+#[cfg(feature = "live-rates")]
+use banking_and_currency_app::rates;
+use banking_and_currency_app::{
+    account::{AccountOrder, AccountType},
+    interest::{Compounding, InterestMode},
+    iso4217,
+    journal::ReplayPoint,
+    locale::Locale,
+    profile::{DEFAULT_PROFILE, Profile},
+};
+use chrono::NaiveDate;
+use clap::{ArgAction, Parser, Subcommand};
+use clap_complete::Shell;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use crate::{i18n::Lang, output::Format};
+
+/// A simple CLI banking and currency exchange app.
+///
+/// The interactive menu is opened when no subcommand is given.
+#[derive(Parser)]
+#[command(name = "mcos", version)]
+pub struct Cli {
+    /// The profile whose bank to open.
+    #[arg(long, global = true, default_value = DEFAULT_PROFILE, value_parser = parse_profile)]
+    pub profile: Profile,
+    /// The passphrase of an encrypted journal, instead of prompting for it.
+    #[arg(long, global = true, env = "MCOS_PASSPHRASE", hide_env_values = true)]
+    pub passphrase: Option<String>,
+    /// The format to print the non-interactive transactions' results in.
+    #[arg(long, global = true, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+    /// The language to print the messages in.
+    #[arg(long, global = true, value_enum, env = "MCOS_LANG", default_value_t = Lang::En)]
+    pub lang: Lang,
+    /// The locale to format amounts of money for, like `en-PH`, `de-DE`, or `fr-FR`.
+    #[arg(long, global = true, default_value_t = Locale::EnPh)]
+    pub locale: Locale,
+    /// Logs in more detail, repeatable up to four times.
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    pub verbose: u8,
+    /// Logs in less detail, repeatable.
+    #[arg(short, long, global = true, action = ArgAction::Count, conflicts_with = "verbose")]
+    pub quiet: u8,
+    /// A file to append the logs to, instead of the standard error.
+    #[arg(long, global = true)]
+    pub log_file: Option<PathBuf>,
+    /// A key identifying the transaction, which is skipped if a transaction with the same key was already performed.
+    #[arg(long, global = true)]
+    pub idempotency_key: Option<String>,
+    /// Disables the colors and emphasis in the output.
+    #[arg(long, global = true)]
+    pub no_color: bool,
+    /// The number of invalid responses a prompt accepts before cancelling the transaction, 0 meaning no limit.
+    #[arg(long, default_value_t = 3)]
+    pub retry_limit: usize,
+    /// The number of incorrect PINs the interactive menu accepts before denying the transaction, 0 meaning no limit.
+    #[arg(long, default_value_t = 3)]
+    pub pin_attempts: usize,
+    /// Lets the non-interactive transactions convert at out-of-date exchange rates instead of failing.
+    #[arg(long)]
+    pub allow_stale_rates: bool,
+    /// The value in Philippine Pesos above which a withdrawal in the interactive menu must be confirmed.
+    #[arg(long, default_value_t = dec!(10000))]
+    pub large_withdrawal: Decimal,
+    /// Opens the full-screen terminal user interface instead of the interactive menu.
+    #[cfg(feature = "tui")]
+    #[arg(long, conflicts_with = "script")]
+    pub tui: bool,
+    /// Populates the profile's empty bank with demo accounts, exchange rates, and history generated from a seed, which
+    /// is 1 if left out, before anything else.
+    #[arg(long, num_args = 0..=1, default_missing_value = "1", value_name = "SEED")]
+    pub demo: Option<u64>,
+    /// Opens the bank as it was at a point in its journal, after a number of its entries or at the end of a date
+    /// written as YYYY-MM-DD, without saving anything done to it.
+    #[arg(long, value_name = "POINT", value_parser = parse_replay_point, conflicts_with = "demo")]
+    pub replay: Option<ReplayPoint>,
+    /// A script file of commands to perform, one per line.
+    #[arg(long)]
+    pub script: Option<PathBuf>,
+    /// Keeps performing the script's commands after one fails.
+    #[arg(long, requires = "script")]
+    pub keep_going: bool,
+    /// The transaction to perform non-interactively.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// A line in a script file, written like the non-interactive subcommands.
+#[derive(Parser)]
+#[command(no_binary_name = true)]
+pub struct ScriptLine {
+    /// A key identifying the transaction, which is skipped if a transaction with the same key was already performed.
+    #[arg(long, global = true)]
+    pub idempotency_key: Option<String>,
+    /// The transaction to perform.
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// The transactions that can be performed non-interactively.
+#[derive(Subcommand)]
+pub enum Command {
+    /// Registers a new account.
+    Register {
+        /// The name of the account.
+        #[arg(long)]
+        account: String,
+        /// The name of a joint owner of the account, besides its namesake, repeatable.
+        #[arg(long = "owner")]
+        joint_owners: Vec<String>,
+        /// The type of the account, like `savings`, `checking`, or `time-deposit`.
+        #[arg(long = "type", default_value_t = AccountType::Savings)]
+        account_type: AccountType,
+        /// The annual interest rate percentage earned by the account, instead of its type's, which is negative to
+        /// charge the account for its balance instead.
+        #[arg(long, allow_negative_numbers = true)]
+        interest_rate: Option<Decimal>,
+        /// The email address of the account's owners.
+        #[arg(long)]
+        email: Option<String>,
+        /// The phone number of the account's owners.
+        #[arg(long)]
+        phone: Option<String>,
+        /// The PIN protecting the account, made of 4 to 6 digits.
+        #[arg(long)]
+        pin: String,
+    },
+    /// Deposits an amount to an account.
+    Deposit {
+        /// The name or number of the account.
+        #[arg(long)]
+        account: String,
+        /// The PIN of the account, if it has one.
+        #[arg(long, env = "MCOS_PIN", hide_env_values = true)]
+        pin: Option<String>,
+        /// The amount to deposit.
+        #[arg(long)]
+        amount: Decimal,
+        /// The code or name of the currency of the amount.
+        #[arg(long, default_value = "PHP", value_parser = parse_currency)]
+        currency: String,
+        /// A note to attach to the deposit.
+        #[arg(long)]
+        memo: Option<String>,
+    },
+    /// Withdraws an amount from an account, holding it for approval instead if it is large enough.
+    Withdraw {
+        /// The name or number of the account.
+        #[arg(long)]
+        account: String,
+        /// The PIN of the account, if it has one.
+        #[arg(long, env = "MCOS_PIN", hide_env_values = true)]
+        pin: Option<String>,
+        /// The amount to withdraw.
+        #[arg(long)]
+        amount: Decimal,
+        /// The code or name of the currency of the amount.
+        #[arg(long, default_value = "PHP", value_parser = parse_currency)]
+        currency: String,
+        /// A note to attach to the withdrawal.
+        #[arg(long)]
+        memo: Option<String>,
+    },
+    /// Transfers an amount from one account to another, holding it for approval instead if it is large enough.
+    Transfer {
+        /// The name or number of the account to transfer from.
+        #[arg(long)]
+        account: String,
+        /// The PIN of the account to transfer from, if it has one.
+        #[arg(long, env = "MCOS_PIN", hide_env_values = true)]
+        pin: Option<String>,
+        /// The name or number of the account to transfer to.
+        #[arg(long)]
+        to: String,
+        /// The amount to transfer, in the currency of the account to transfer from.
+        #[arg(long)]
+        amount: Decimal,
+        /// A note to attach to the transfer.
+        #[arg(long)]
+        memo: Option<String>,
+    },
+    /// Lists the withdrawals and transfers held for approval, including the decided ones.
+    Pending,
+    /// Approves a withdrawal or transfer held for approval, committing it.
+    Approve {
+        /// The ID of the held transaction, as listed by the `pending` subcommand.
+        #[arg(long)]
+        id: u64,
+    },
+    /// Rejects a withdrawal or transfer held for approval, dropping it.
+    Reject {
+        /// The ID of the held transaction, as listed by the `pending` subcommand.
+        #[arg(long)]
+        id: u64,
+    },
+    /// Schedules an amount to be deposited to an account every number of days, starting one interval from now.
+    RecurringDeposit {
+        /// The name or number of the account.
+        #[arg(long)]
+        account: String,
+        /// The PIN of the account, if it has one.
+        #[arg(long, env = "MCOS_PIN", hide_env_values = true)]
+        pin: Option<String>,
+        /// The amount to deposit.
+        #[arg(long)]
+        amount: Decimal,
+        /// The code or name of the currency of the amount.
+        #[arg(long, default_value = "PHP", value_parser = parse_currency)]
+        currency: String,
+        /// The number of days between the deposits.
+        #[arg(long)]
+        every: u64,
+        /// A note to attach to every deposit.
+        #[arg(long)]
+        memo: Option<String>,
+    },
+    /// Schedules an amount to be transferred from one account to another every number of days, starting one interval
+    /// from now.
+    StandingOrder {
+        /// The name or number of the account to transfer from.
+        #[arg(long)]
+        account: String,
+        /// The PIN of the account to transfer from, if it has one.
+        #[arg(long, env = "MCOS_PIN", hide_env_values = true)]
+        pin: Option<String>,
+        /// The name or number of the account to transfer to.
+        #[arg(long)]
+        to: String,
+        /// The amount to transfer, in the currency of the account to transfer from.
+        #[arg(long)]
+        amount: Decimal,
+        /// The number of days between the transfers.
+        #[arg(long)]
+        every: u64,
+        /// A note to attach to every transfer.
+        #[arg(long)]
+        memo: Option<String>,
+    },
+    /// Lists the scheduled transactions, including the cancelled ones.
+    Schedules,
+    /// Cancels a scheduled transaction, stopping its runs.
+    CancelSchedule {
+        /// The ID of the schedule, as listed by the `schedules` subcommand.
+        #[arg(long)]
+        id: u64,
+    },
+    /// Runs the scheduled transactions that will be due within a number of days, as if that much time passed.
+    Advance {
+        /// The number of days to advance by.
+        #[arg(long)]
+        days: u64,
+    },
+    /// Deposits every row of a CSV file of accounts, amounts, and currencies, reporting which rows failed.
+    BulkDeposit {
+        /// The path of the CSV file, whose rows are written as `account,amount,currency`.
+        #[arg(long)]
+        file: PathBuf,
+        /// Only validates the rows, without depositing any.
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Closes an account, keeping its record.
+    Close {
+        /// The name or number of the account.
+        #[arg(long)]
+        account: String,
+        /// The PIN of the account, if it has one.
+        #[arg(long, env = "MCOS_PIN", hide_env_values = true)]
+        pin: Option<String>,
+        /// Withdraws the account's remaining balance first, instead of requiring it to be zero.
+        #[arg(long)]
+        withdraw_remainder: bool,
+    },
+    /// Renames an account, keeping its balance, currency, and history.
+    Rename {
+        /// The name or number of the account.
+        #[arg(long)]
+        account: String,
+        /// The PIN of the account, if it has one.
+        #[arg(long, env = "MCOS_PIN", hide_env_values = true)]
+        pin: Option<String>,
+        /// The new name of the account.
+        #[arg(long)]
+        new_name: String,
+    },
+    /// Freezes an account, rejecting its deposits and withdrawals.
+    Freeze {
+        /// The name or number of the account.
+        #[arg(long)]
+        account: String,
+    },
+    /// Unfreezes a frozen account.
+    Unfreeze {
+        /// The name or number of the account.
+        #[arg(long)]
+        account: String,
+    },
+    /// Sets how far below zero withdrawals can push an account's balance.
+    Overdraft {
+        /// The name or number of the account.
+        #[arg(long)]
+        account: String,
+        /// The overdraft limit, in the account's currency.
+        #[arg(long)]
+        limit: Decimal,
+    },
+    /// Sets the most that can be withdrawn from an account each day.
+    WithdrawalLimit {
+        /// The name or number of the account.
+        #[arg(long)]
+        account: String,
+        /// The daily withdrawal limit, in the account's currency, or the bank's default if not given.
+        #[arg(long)]
+        limit: Option<Decimal>,
+    },
+    /// Sets the annual interest rate earned by an account.
+    InterestRate {
+        /// The name or number of the account.
+        #[arg(long)]
+        account: String,
+        /// The annual interest rate percentage, which is negative to charge the account for its balance instead.
+        #[arg(long, allow_negative_numbers = true)]
+        percent: Decimal,
+    },
+    /// Sets how often the interest earned by an account is added to its balance.
+    Compounding {
+        /// The name or number of the account.
+        #[arg(long)]
+        account: String,
+        /// The compounding frequency, either daily, monthly, quarterly, or annually.
+        #[arg(long)]
+        frequency: Compounding,
+    },
+    /// Sets the balance a type of account must maintain and the fees for falling below it.
+    MinimumBalance {
+        /// The type of account, like `savings`, `checking`, or `time-deposit`.
+        #[arg(long = "type")]
+        account_type: AccountType,
+        /// The lowest balance the accounts must maintain.
+        #[arg(long)]
+        amount: Decimal,
+        /// The fee for a withdrawal leaving an account below the minimum, which are rejected without one.
+        #[arg(long)]
+        breach_fee: Option<Decimal>,
+        /// The fee charged to every account below the minimum at the end of each period.
+        #[arg(long, default_value_t = Decimal::ZERO)]
+        monthly_fee: Decimal,
+    },
+    /// Charges the monthly fee to every account below its minimum balance.
+    EndPeriod,
+    /// Shows the fees and spread income the bank has collected in each currency.
+    Revenue,
+    /// Replaces the owners of an account.
+    Owners {
+        /// The name or number of the account.
+        #[arg(long)]
+        account: String,
+        /// The PIN of the account, if it has one.
+        #[arg(long, env = "MCOS_PIN", hide_env_values = true)]
+        pin: Option<String>,
+        /// The name of an owner of the account, repeatable.
+        #[arg(long = "owner", required = true)]
+        owners: Vec<String>,
+    },
+    /// Lists the registered accounts.
+    Accounts {
+        /// The order to list the accounts in, either `name` or `balance`.
+        #[arg(long, default_value_t = AccountOrder::Name)]
+        sort: AccountOrder,
+        /// Only lists the accounts with at least this balance.
+        #[arg(long)]
+        min_balance: Option<Decimal>,
+        /// Only lists the accounts of this type.
+        #[arg(long = "type")]
+        account_type: Option<AccountType>,
+    },
+    /// Shows the details of an account.
+    Details {
+        /// The name or number of the account.
+        #[arg(long)]
+        account: String,
+        /// The PIN of the account, if it has one.
+        #[arg(long, env = "MCOS_PIN", hide_env_values = true)]
+        pin: Option<String>,
+    },
+    /// Lists the transactions against an account, from newest to oldest.
+    History {
+        /// The name or number of the account.
+        #[arg(long)]
+        account: String,
+        /// The PIN of the account, if it has one.
+        #[arg(long, env = "MCOS_PIN", hide_env_values = true)]
+        pin: Option<String>,
+        /// Only lists this many of the newest transactions.
+        #[arg(long)]
+        limit: Option<usize>,
+        /// Only lists the transactions whose reference number or memo contains this text.
+        #[arg(long)]
+        search: Option<String>,
+    },
+    /// Calculates how much an amount in one currency is worth in another.
+    Exchange {
+        /// The code or name of the currency to exchange from.
+        #[arg(long, value_parser = parse_currency)]
+        from: String,
+        /// The code or name of the currency to exchange to.
+        #[arg(long, value_parser = parse_currency)]
+        to: String,
+        /// The amount to exchange.
+        #[arg(long)]
+        amount: Decimal,
+        /// Only shows the breakdown of the exchange, without making it.
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Converts an amount from one currency to another without exchanging it, at the current exchange rates or those in
+    /// effect on a date.
+    Convert {
+        /// The code or name of the currency to convert from.
+        #[arg(long, value_parser = parse_currency)]
+        from: String,
+        /// The code or name of the currency to convert to.
+        #[arg(long, value_parser = parse_currency)]
+        to: String,
+        /// The amount to convert.
+        #[arg(long)]
+        amount: Decimal,
+        /// The date, written as YYYY-MM-DD, whose exchange rates are converted at, which are the last ones recorded by
+        /// its end.
+        #[arg(long)]
+        as_of: Option<NaiveDate>,
+    },
+    /// Records the exchange rate between a foreign currency and Philippine Pesos.
+    SetRate {
+        /// The code or name of the foreign currency.
+        #[arg(long, value_parser = parse_currency)]
+        currency: String,
+        /// The value of one unit of the currency in Philippine Pesos.
+        #[arg(long)]
+        rate: Decimal,
+    },
+    /// Sets the spread between the rates a foreign currency is bought and sold at.
+    SetSpread {
+        /// The code or name of the foreign currency.
+        #[arg(long, value_parser = parse_currency)]
+        currency: String,
+        /// The spread, as a percentage of the currency's exchange rate.
+        #[arg(long)]
+        percent: Decimal,
+    },
+    /// Lists the current exchange rates of the foreign currencies, with their inverses and when they were last updated.
+    Rates,
+    /// Lists the recent exchange rates of a foreign currency, along with their lowest, highest, and average.
+    RateHistory {
+        /// The code or name of the foreign currency.
+        #[arg(long, value_parser = parse_currency)]
+        currency: String,
+        /// The number of most recent days to list the rates of.
+        #[arg(long, default_value_t = 30, value_parser = clap::value_parser!(u64).range(1..))]
+        days: u64,
+    },
+    /// Lists the alerts on the exchange rates, including the removed ones.
+    Alerts,
+    /// Adds an alert notifying when the exchange rate of a foreign currency reaches a threshold.
+    AddAlert {
+        /// The code or name of the foreign currency.
+        #[arg(long, value_parser = parse_currency)]
+        currency: String,
+        /// Notifies when the rate rises to at least this value in Philippine Pesos.
+        #[arg(long, conflicts_with = "at_most", required_unless_present = "at_most")]
+        at_least: Option<Decimal>,
+        /// Notifies when the rate falls to at most this value in Philippine Pesos.
+        #[arg(long)]
+        at_most: Option<Decimal>,
+    },
+    /// Removes a rate alert, stopping its notifications.
+    RemoveAlert {
+        /// The ID of the alert, as listed by the `alerts` subcommand.
+        #[arg(long)]
+        id: u64,
+    },
+    /// Writes the current exchange rates of the foreign currencies to a CSV file.
+    ExportRates {
+        /// The path of the CSV file, whose rows are written as `currency,rate`.
+        #[arg(long)]
+        file: PathBuf,
+    },
+    /// Refreshes the exchange rates from a CSV file, rejecting it whole if any row is invalid.
+    ImportRates {
+        /// The path of the CSV file, whose rows are written as `currency,rate`.
+        #[arg(long)]
+        file: PathBuf,
+    },
+    /// Refreshes the exchange rates of every foreign currency from the European Central Bank's daily reference rates.
+    #[cfg(feature = "live-rates")]
+    RefreshRates {
+        /// The URL of the rates' XML feed.
+        #[arg(long, default_value = rates::ECB_URL)]
+        url: String,
+    },
+    /// Registers a new currency with its exchange rate, making it exchangeable.
+    AddCurrency {
+        /// The three-letter code of the currency, or its name if it is listed in ISO 4217.
+        #[arg(long, value_parser = parse_currency)]
+        code: String,
+        /// The display name of the currency, which is its ISO 4217 name or else its code if left out.
+        #[arg(long)]
+        name: Option<String>,
+        /// The value of one unit of the currency in Philippine Pesos.
+        #[arg(long)]
+        rate: Decimal,
+    },
+    /// Undoes the most recent deposit, withdrawal, transfer, or exchange rate update, only useful in scripts.
+    Undo,
+    /// Reverses a deposit, withdrawal, transfer, or fee by its reference number, linking both in history.
+    Reverse {
+        /// The reference number of the transaction, like `TX00000001`.
+        #[arg(long)]
+        reference: String,
+    },
+    /// Shows the daily interest earned by an account.
+    Interest {
+        /// The name or number of the account.
+        #[arg(long)]
+        account: String,
+        /// The PIN of the account, if it has one.
+        #[arg(long, env = "MCOS_PIN", hide_env_values = true)]
+        pin: Option<String>,
+        /// The total number of days to calculate for.
+        #[arg(long)]
+        days: u32,
+        /// The way interest accrues, either simple or compound, instead of the configured one.
+        #[arg(long)]
+        mode: Option<InterestMode>,
+        /// How often the interest is added to the balance, instead of the account's compounding frequency.
+        #[arg(long)]
+        compounding: Option<Compounding>,
+        /// An amount planned to be deposited every interval, to project the growth of a savings plan.
+        #[arg(long, requires = "every")]
+        deposit: Option<Decimal>,
+        /// The number of days between the planned deposits.
+        #[arg(long, requires = "deposit", value_parser = clap::value_parser!(u64).range(1..))]
+        every: Option<u64>,
+        /// The path of a CSV file to write the schedule to, whose rows are written as
+        /// `day,interest,tax,overdraft_charge,deposit,balance`, instead of printing it.
+        #[arg(long)]
+        csv: Option<PathBuf>,
+    },
+    /// Credits the interest earned by an account to its balance.
+    PostInterest {
+        /// The name or number of the account.
+        #[arg(long)]
+        account: String,
+        /// The number of days to post interest for, or the days since it was last posted if not given.
+        #[arg(long)]
+        days: Option<u32>,
+    },
+    /// Converts between an annual percentage rate and the annual percentage yield it compounds to.
+    Yield {
+        /// The nominal annual rate percentage to find the yield of.
+        #[arg(long, conflicts_with = "apy", required_unless_present = "apy")]
+        apr: Option<Decimal>,
+        /// The effective annual yield percentage to find the nominal rate of.
+        #[arg(long)]
+        apy: Option<Decimal>,
+        /// How often the rate compounds, either daily, monthly, quarterly, or annually.
+        #[arg(long, default_value = "daily")]
+        compounding: Compounding,
+    },
+    /// Snapshots the bank's state to a new backup.
+    Backup,
+    /// Lists the available backups, from oldest to newest.
+    Backups,
+    /// Rolls the bank's state back to a backup.
+    Restore {
+        /// The ID of the backup, as listed by the `backups` subcommand.
+        #[arg(long)]
+        backup: usize,
+    },
+    /// Lists the available profiles.
+    Profiles,
+    /// Checks the bank's state for broken invariants, for administrators.
+    #[command(hide = true)]
+    Verify,
+    /// Stress-tests a scratch bank with random transactions, reporting their throughput and any broken invariants, for
+    /// administrators.
+    #[command(hide = true)]
+    Stress {
+        /// The number of accounts to register.
+        #[arg(long, default_value_t = 100)]
+        accounts: usize,
+        /// The number of random transactions to attempt.
+        #[arg(long, default_value_t = 10_000)]
+        transactions: usize,
+        /// The seed that the transactions are generated from.
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+    },
+    /// Serves the bank over HTTP as a JSON API, until interrupted.
+    #[cfg(feature = "serve")]
+    Serve {
+        /// The address to listen on.
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        addr: SocketAddr,
+    },
+    /// Serves the bank's accounts and the transactions against them over gRPC, as described by `proto/mcos.proto`,
+    /// until interrupted.
+    #[cfg(feature = "grpc")]
+    Grpc {
+        /// The address to listen on.
+        #[arg(long, default_value = "127.0.0.1:50051")]
+        addr: SocketAddr,
+    },
+    /// Serves the interactive menu over TCP, one session per connection sharing the bank, until interrupted.
+    ///
+    /// Connect with a line-based client like `telnet` or `nc`. PINs aren't masked, so only serve it on trusted
+    /// networks.
+    Telnet {
+        /// The address to listen on.
+        #[arg(long, default_value = "127.0.0.1:2323")]
+        addr: SocketAddr,
+    },
+    /// Generates the completion script of a shell.
+    Completions {
+        /// The shell to generate the completion script for.
+        shell: Shell,
+    },
+}
+
+/// Parses a profile from its name.
+fn parse_profile(name: &str) -> Result<Profile, String> {
+    Profile::new(name)
+        .ok_or_else(|| String::from("profile names may only contain letters, numbers, dashes, and underscores"))
+}
+
+/// Parses a point in the journal from a number of entries or a date, whose end is the point.
+fn parse_replay_point(point: &str) -> Result<ReplayPoint, String> {
+    if let Ok(entry_cnt) = point.parse::<usize>() {
+        return Ok(ReplayPoint::Entries(entry_cnt));
+    }
+
+    point
+        .parse::<NaiveDate>()
+        .map(|date| ReplayPoint::Time(crate::end_of_day(date)))
+        .map_err(|_| String::from("expected a number of journal entries or a date written as YYYY-MM-DD"))
+}
+
+/// Parses a currency code or the English name of a currency in the ISO 4217 registry into an uppercased code.
+pub(crate) fn parse_currency(query: &str) -> Result<String, String> {
+    Ok(iso4217::find(query).map_or_else(|| query.trim().to_uppercase(), |c| c.code.to_string()))
+}
@@ -0,0 +1,55 @@
+//! The source of the current time, which can be fixed for reproducible runs.
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, NaiveDate};
+
+/// The number of seconds in a day.
+pub const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Returns the day of a time, in days since the Unix epoch, which starts at midnight UTC.
+pub fn day(timestamp: u64) -> u64 {
+    timestamp / SECONDS_PER_DAY
+}
+
+/// Returns the date of a time in UTC, which the days of [`day`] start on.
+pub fn date(timestamp: u64) -> NaiveDate {
+    DateTime::from_timestamp(timestamp as i64, 0)
+        .unwrap_or_default()
+        .date_naive()
+}
+
+/// A source of the current time, in seconds since the Unix epoch, which can be sent to another thread along with its
+/// bank.
+pub trait Clock: Send {
+    /// Returns the current time, in seconds since the Unix epoch.
+    fn now(&self) -> u64;
+}
+
+/// A clock reading the system's time, or the browser's in WebAssembly, which has no system time.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+impl Clock for SystemClock {
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    fn now(&self) -> u64 {
+        (js_sys::Date::now() / 1000.0) as u64
+    }
+
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    fn now(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default()
+    }
+}
+
+/// A clock stuck at a fixed time.
+#[derive(Clone, Copy, Debug)]
+pub struct FixedClock(pub u64);
+impl Clock for FixedClock {
+    fn now(&self) -> u64 {
+        self.0
+    }
+}
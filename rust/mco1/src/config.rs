@@ -0,0 +1,178 @@
+//! The configuration of each profile's bank, read from its configuration file.
+
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, ErrorKind},
+    path::Path,
+};
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    currency::RoundingPolicy,
+    interest::{DayCount, InterestMode, RateTier},
+};
+
+/// The default path of the configuration file.
+pub const CONFIG_PATH: &str = "config.json";
+
+/// A fee charged for a transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Fee {
+    /// A flat amount, in the currency of the transacted amount.
+    Flat(Decimal),
+    /// A percentage of the transacted amount.
+    Percentage(Decimal),
+}
+impl Fee {
+    /// Returns the fee for transacting an amount, rounded to the minor units of the amount's currency by a rounding
+    /// policy.
+    pub fn charge(&self, amount: Decimal, minor_units: u32, rounding: RoundingPolicy) -> Decimal {
+        match self {
+            Fee::Flat(fee) => *fee,
+            Fee::Percentage(percentage) => rounding.round(amount * percentage / Decimal::ONE_HUNDRED, minor_units),
+        }
+    }
+
+    /// Checks whether the fee isn't negative.
+    pub fn is_valid(&self) -> bool {
+        let (Fee::Flat(value) | Fee::Percentage(value)) = self;
+
+        *value >= Decimal::ZERO
+    }
+}
+
+/// The fees charged automatically for each kind of transaction, if any.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FeeSchedule {
+    /// The fee for withdrawing from an account, in the account's currency.
+    pub withdrawal: Option<Fee>,
+    /// The fee for transferring between accounts, in the sending account's currency.
+    pub transfer: Option<Fee>,
+    /// The fee for exchanging currencies, in the source currency and deducted before the exchange.
+    pub exchange: Option<Fee>,
+}
+impl FeeSchedule {
+    /// Checks whether none of the fees are negative.
+    pub fn is_valid(&self) -> bool {
+        [self.withdrawal, self.transfer, self.exchange]
+            .iter()
+            .flatten()
+            .all(Fee::is_valid)
+    }
+}
+
+/// The range that a foreign currency's exchange rate must be within for it to be believable, as values of one unit of
+/// the currency in Philippine Pesos.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RateBounds {
+    /// The lowest rate.
+    pub min: Decimal,
+    /// The highest rate.
+    pub max: Decimal,
+}
+impl RateBounds {
+    /// Checks whether a rate is within the bounds.
+    pub fn contains(&self, rate: Decimal) -> bool {
+        (self.min..=self.max).contains(&rate)
+    }
+
+    /// Checks whether the lowest rate isn't negative and isn't above the highest.
+    pub fn is_valid(&self) -> bool {
+        self.min >= Decimal::ZERO && self.min <= self.max
+    }
+}
+
+/// The configuration of a profile's bank.
+///
+/// The configuration is written by hand as JSON, like `{ "fees": { "withdrawal": { "flat": 15.0 } } }`. Every field
+/// can be left out to use its default.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// The fees charged automatically for each kind of transaction.
+    pub fees: FeeSchedule,
+    /// The most that can be withdrawn from an account each day, in its currency, unless the account sets its own.
+    pub daily_withdrawal_limit: Option<Decimal>,
+    /// The amount, in an account's currency, from which withdrawals and transfers are held for approval, if any.
+    pub approval_threshold: Option<Decimal>,
+    /// The most that a single deposit, withdrawal, transfer, or exchange can be, in the transacted amount's currency,
+    /// if there is a maximum.
+    pub max_amount: Option<Decimal>,
+    /// The number of hours an exchange rate stays current after it is updated, if rates ever go out of date.
+    pub rate_ttl_hours: Option<u64>,
+    /// The range that each foreign currency's exchange rate must be within, by the currency's code, like
+    /// `{ "USD": { "min": 40.0, "max": 80.0 } }`.
+    pub rate_bounds: HashMap<String, RateBounds>,
+    /// The percentage by which a new exchange rate must differ from the previous one to be warned about, if any.
+    pub rate_change_warning: Option<Decimal>,
+    /// The way interest accrues when it is calculated, unless another way is chosen.
+    pub interest_mode: InterestMode,
+    /// The bands of balances, in an account's currency and from the lowest up, that earn their own interest rates
+    /// instead of the accounts' own, like `[{ "below": 10000.0, "percentage": 2.0 }, { "percentage": 5.0 }]`.
+    pub interest_tiers: Vec<RateTier>,
+    /// How the days that interest accrues over are counted, either `act/act`, `act/365`, `act/360`, or `30/360`.
+    pub day_count: DayCount,
+    /// The percentage of interest withheld as tax when it is posted, like `20.0` for the Philippines' final tax on
+    /// interest income, if any is withheld.
+    pub withholding_tax: Option<Decimal>,
+    /// How amounts are rounded to their currencies' minor units when they are converted, accrue interest, or are
+    /// charged fees, either `half-up`, `half-even`, or `truncate`.
+    pub rounding: RoundingPolicy,
+    /// The URLs that the JSON of each deposit, withdrawal, transfer, reversal, and rate change is posted to as it is
+    /// committed, like `["https://example.com/hooks/mcos"]`.
+    pub webhooks: Vec<String>,
+}
+impl Config {
+    /// Reads the configuration from a file, using the defaults if it doesn't exist.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Config> {
+        let json = match fs::read_to_string(path) {
+            Ok(json) => json,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Config::default()),
+            Err(err) => return Err(err),
+        };
+        let config: Config = serde_json::from_str(&json).map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?;
+
+        if !config.is_valid() {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                "Fees, limits, and thresholds must not be negative, rate bounds must go up, interest rates must be above -100%, interest tiers must go \
+                 up, withholding taxes must be at most 100%, and webhooks must be HTTP URLs...",
+            ));
+        }
+
+        Ok(config)
+    }
+
+    /// Checks whether none of the fees, limits, and thresholds are negative, whether the rate bounds go up, whether the
+    /// interest tiers' rates are above -100%, whether their bounds go up, with only the last one left unbounded, and
+    /// whether the withholding tax is at most the whole interest, and whether the webhooks are HTTP URLs.
+    pub fn is_valid(&self) -> bool {
+        let bounds = self.interest_tiers.iter().map(|t| t.below.unwrap_or(Decimal::MAX));
+
+        self.fees.is_valid()
+            && [
+                self.daily_withdrawal_limit,
+                self.approval_threshold,
+                self.max_amount,
+                self.rate_change_warning,
+            ]
+            .iter()
+            .flatten()
+            .all(|l| *l >= Decimal::ZERO)
+            && self.rate_bounds.values().all(RateBounds::is_valid)
+            && self.interest_tiers.iter().all(|t| t.percentage > -Decimal::ONE_HUNDRED)
+            && bounds.clone().zip(bounds.skip(1)).all(|(lower, upper)| lower < upper)
+            && self
+                .withholding_tax
+                .is_none_or(|t| (Decimal::ZERO..=Decimal::ONE_HUNDRED).contains(&t))
+            && self
+                .webhooks
+                .iter()
+                .all(|url| url.starts_with("http://") || url.starts_with("https://"))
+    }
+}
@@ -0,0 +1,228 @@
+//! The console that the CLI user interacts with, which can be swapped for a scripted one to test the transactions.
+
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    fmt,
+    io::{self, IsTerminal, Write},
+    process,
+};
+#[cfg(test)]
+use std::{collections::VecDeque, rc::Rc};
+
+use rustyline::{
+    Context, Editor, Helper, Hinter, Validator,
+    completion::Completer,
+    error::ReadlineError,
+    highlight::{CmdKind, Highlighter},
+    history::FileHistory,
+};
+
+/// Writes a line of formatted output to the console, like `println!`.
+macro_rules! outln {
+    () => {
+        $crate::console::write(format_args!("\n"))
+    };
+    ($($arg:tt)*) => {
+        $crate::console::write(format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+/// The path of the file storing the prompt history across sessions.
+const HISTORY_PATH: &str = ".mcos_history";
+
+/// The line editor's helper, completing the response from the current prompt's candidates.
+#[derive(Default, Helper, Hinter, Validator)]
+struct PromptHelper {
+    /// The responses the current prompt can be completed to.
+    candidates: Vec<String>,
+    /// Whether the response is masked with asterisks as it is inputted.
+    is_masked: bool,
+}
+impl Completer for PromptHelper {
+    type Candidate = String;
+
+    fn complete(&self, line: &str, pos: usize, _: &Context<'_>) -> rustyline::Result<(usize, Vec<String>)> {
+        let typed = line[..pos].to_lowercase();
+
+        Ok((
+            0,
+            self.candidates
+                .iter()
+                .filter(|c| c.to_lowercase().starts_with(&typed))
+                .cloned()
+                .collect(),
+        ))
+    }
+}
+
+impl Highlighter for PromptHelper {
+    fn highlight<'l>(&self, line: &'l str, _: usize) -> Cow<'l, str> {
+        if self.is_masked {
+            Cow::Owned("*".repeat(line.chars().count()))
+        } else {
+            Cow::Borrowed(line)
+        }
+    }
+
+    fn highlight_char(&self, _: &str, _: usize, _: CmdKind) -> bool {
+        self.is_masked
+    }
+}
+
+thread_local! {
+    /// The line editor shared by every prompt, providing line editing, history, and completion.
+    static EDITOR: RefCell<Editor<PromptHelper, FileHistory>> = RefCell::new({
+        let mut editor = Editor::new().expect("Failed to create the line editor...");
+
+        editor.set_helper(Some(PromptHelper::default()));
+
+        let _ = editor.load_history(HISTORY_PATH);
+
+        editor
+    });
+}
+
+/// A console reading the CLI user's responses and writing the output for them.
+pub trait Console {
+    /// Reads a line of the user's response to a message, completing it from the candidates.
+    ///
+    /// Masked responses are hidden as they are inputted, and only recorded responses are kept in the prompt history.
+    fn read_line(&mut self, msg: &str, is_recorded: bool, is_masked: bool, candidates: Vec<String>) -> String;
+
+    /// Writes output for the user.
+    fn write(&mut self, output: &str);
+}
+
+/// Exits the process once the events of the last transactions are delivered to the webhooks, if any.
+fn exit() -> ! {
+    #[cfg(feature = "webhooks")]
+    banking_and_currency_app::webhook::flush();
+
+    process::exit(0);
+}
+
+/// The console of the terminal the app runs in, reading from the standard input and writing to the standard output.
+pub struct TerminalConsole;
+impl Console for TerminalConsole {
+    /// Reads a line with the line editor, or directly from the standard input if it is piped.
+    ///
+    /// Masked responses are only visible if the terminal doesn't support raw mode. The program exits if the user ends
+    /// the input (`Ctrl-D`) or interrupts it (`Ctrl-C`), after flushing the webhooks.
+    fn read_line(&mut self, msg: &str, is_recorded: bool, is_masked: bool, candidates: Vec<String>) -> String {
+        if !io::stdin().is_terminal() {
+            self.write(msg);
+
+            let mut input = String::new();
+
+            if io::stdin()
+                .read_line(&mut input)
+                .expect("Failed to read the input string...")
+                == 0
+            {
+                println!();
+
+                exit();
+            }
+
+            return input.trim().to_string();
+        }
+
+        EDITOR.with_borrow_mut(|editor| {
+            if let Some(helper) = editor.helper_mut() {
+                helper.candidates = candidates;
+                helper.is_masked = is_masked;
+            }
+
+            match editor.readline(msg) {
+                Ok(input) => {
+                    let input = input.trim().to_string();
+
+                    if is_recorded && !input.is_empty() && editor.add_history_entry(&input).unwrap_or_default() {
+                        let _ = editor.append_history(HISTORY_PATH);
+                    }
+
+                    input
+                }
+                Err(ReadlineError::Eof | ReadlineError::Interrupted) => {
+                    println!();
+
+                    exit();
+                }
+                Err(err) => panic!("Failed to read the input string: {err}"),
+            }
+        })
+    }
+
+    fn write(&mut self, output: &str) {
+        let mut stdout = io::stdout();
+
+        stdout
+            .write_all(output.as_bytes())
+            .and_then(|_| stdout.flush())
+            .expect("Failed to write the output string...");
+    }
+}
+
+/// A console answering with scripted responses in order and recording the output, for testing the transactions.
+#[cfg(test)]
+pub struct ScriptedConsole {
+    /// The responses not yet read.
+    responses: VecDeque<String>,
+    /// The output written so far, including the prompts' messages and their responses.
+    output: Rc<RefCell<String>>,
+}
+#[cfg(test)]
+impl ScriptedConsole {
+    /// Creates a console answering with responses, returning it with a handle to its output.
+    pub fn new(responses: &[&str]) -> (ScriptedConsole, Rc<RefCell<String>>) {
+        let output = Rc::default();
+
+        (
+            ScriptedConsole {
+                responses: responses.iter().map(|r| r.to_string()).collect(),
+                output: Rc::clone(&output),
+            },
+            output,
+        )
+    }
+}
+#[cfg(test)]
+impl Console for ScriptedConsole {
+    /// Reads the next scripted response, panicking if there are none left.
+    fn read_line(&mut self, msg: &str, _: bool, _: bool, _: Vec<String>) -> String {
+        let response = self
+            .responses
+            .pop_front()
+            .unwrap_or_else(|| panic!("No scripted response is left for the prompt {msg:?}..."));
+
+        self.write(&format!("{msg}{response}\n"));
+
+        response
+    }
+
+    fn write(&mut self, output: &str) {
+        self.output.borrow_mut().push_str(output);
+    }
+}
+
+thread_local! {
+    /// The console that the prompts and output go through, which is separate for each thread so that each session of
+    /// the TCP server has its own.
+    static CONSOLE: RefCell<Box<dyn Console>> = RefCell::new(Box::new(TerminalConsole));
+}
+
+/// Replaces the console that the prompts and output of the current thread go through.
+pub fn set_console<C: Console + 'static>(console: C) {
+    CONSOLE.set(Box::new(console));
+}
+
+/// Reads a line of the user's response to a message from the console.
+pub fn read_line(msg: &str, is_recorded: bool, is_masked: bool, candidates: Vec<String>) -> String {
+    CONSOLE.with_borrow_mut(|console| console.read_line(msg, is_recorded, is_masked, candidates))
+}
+
+/// Writes formatted output to the console.
+pub fn write(output: fmt::Arguments) {
+    CONSOLE.with_borrow_mut(|console| console.write(&output.to_string()));
+}
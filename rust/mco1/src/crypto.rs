@@ -0,0 +1,100 @@
+//! The passphrase-based encryption of persisted data.
+
+use std::io;
+
+use aes_gcm::{
+    Aes256Gcm, Key, KeyInit, Nonce,
+    aead::{Aead, AeadCore, OsRng, rand_core::RngCore},
+};
+use argon2::{
+    Argon2,
+    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+};
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+
+/// The number of bytes in a key derivation salt.
+const SALT_LEN: usize = 16;
+/// The number of bytes in an AES-GCM nonce.
+const NONCE_LEN: usize = 12;
+
+/// Generates a new random salt for key derivation, encoded in Base64.
+pub fn generate_salt() -> String {
+    let mut salt = [0; SALT_LEN];
+
+    OsRng.fill_bytes(&mut salt);
+
+    BASE64.encode(salt)
+}
+
+/// An AES-256-GCM cipher keyed by a passphrase.
+pub struct Cipher {
+    /// The AES-256-GCM cipher instance.
+    cipher: Aes256Gcm,
+}
+impl Cipher {
+    /// Derives a cipher's key from a passphrase and a Base64-encoded salt using Argon2.
+    pub fn derive(passphrase: &str, salt: &str) -> io::Result<Cipher> {
+        let salt = BASE64.decode(salt).map_err(invalid_data)?;
+        let mut key = Key::<Aes256Gcm>::default();
+
+        Argon2::default()
+            .hash_password_into(passphrase.as_bytes(), &salt, &mut key)
+            .map_err(|err| io::Error::other(err.to_string()))?;
+
+        Ok(Cipher {
+            cipher: Aes256Gcm::new(&key),
+        })
+    }
+
+    /// Encrypts a plaintext string, returning the nonce and ciphertext encoded in Base64.
+    pub fn encrypt(&self, plaintext: &str) -> io::Result<String> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, plaintext.as_bytes())
+            .map_err(|_| io::Error::other("Failed to encrypt the data..."))?;
+
+        let mut data = nonce.to_vec();
+
+        data.extend(ciphertext);
+
+        Ok(BASE64.encode(data))
+    }
+
+    /// Decrypts a string encrypted by [`Cipher::encrypt`].
+    ///
+    /// Decryption fails if the data was encrypted with a different passphrase or was tampered with.
+    pub fn decrypt(&self, encrypted: &str) -> io::Result<String> {
+        let data = BASE64.decode(encrypted.trim()).map_err(invalid_data)?;
+
+        if data.len() < NONCE_LEN {
+            return Err(invalid_data("The encrypted data is too short..."));
+        }
+
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let plaintext = self
+            .cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| invalid_data("Failed to decrypt the data, the passphrase may be incorrect..."))?;
+
+        String::from_utf8(plaintext).map_err(invalid_data)
+    }
+}
+
+/// Hashes a secret, like a PIN, with Argon2 and a random salt, returning the hash in the PHC string format.
+pub fn hash_secret(secret: &str) -> String {
+    Argon2::default()
+        .hash_password(secret.as_bytes(), &SaltString::generate(&mut OsRng))
+        .expect("Failed to hash the secret")
+        .to_string()
+}
+
+/// Checks whether a secret matches a hash created by [`hash_secret`].
+pub fn verify_secret(secret: &str, hash: &str) -> bool {
+    PasswordHash::new(hash).is_ok_and(|hash| Argon2::default().verify_password(secret.as_bytes(), &hash).is_ok())
+}
+
+/// Wraps an error as an invalid data I/O error.
+fn invalid_data<E: Into<Box<dyn std::error::Error + Send + Sync>>>(err: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
@@ -0,0 +1,371 @@
+//! The exchangeable currencies and the conversion between them.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    ops::{AddAssign, SubAssign},
+    str::FromStr,
+};
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+use crate::iso4217;
+
+/// The number of exchangeable currencies.
+pub const CURRENCY_CNT: usize = 6;
+/// The titles or labels of the exchangeable currencies.
+pub const CURRENCIES_TITLES: [&str; CURRENCY_CNT] = [
+    "Philippine Peso (PHP)",
+    "United States Dollar (USD)",
+    "Japanese Yen (JPY)",
+    "British Pound Sterling (GBP)",
+    "Euro (EUR)",
+    "Chinese Yuan Renminni (CNY)",
+];
+/// The [ISO 4217](https://en.wikipedia.org/wiki/ISO_4217) codes of the exchangeable currencies.
+pub const CURRENCIES_CODES: [&str; CURRENCY_CNT] = ["PHP", "USD", "JPY", "GBP", "EUR", "CNY"];
+/// The symbols of the exchangeable currencies.
+pub const CURRENCIES_SYMBOLS: [&str; CURRENCY_CNT] = ["₱", "$", "¥", "£", "€", "¥"];
+/// The number of decimal places of the exchangeable currencies' minor units, like centavos.
+pub const CURRENCIES_MINOR_UNITS: [u32; CURRENCY_CNT] = [2, 2, 0, 2, 2, 2];
+
+/// The number of decimal places of the minor units of currencies that aren't known to have others.
+pub const DEFAULT_MINOR_UNITS: u32 = 2;
+
+/// The currency that the accounts' balances and the exchange rates are based on.
+pub const BASE_CURRENCY: &str = "PHP";
+
+/// The largest amount of a transaction, which keeps converted amounts and balances within what a decimal can hold.
+pub const MAX_AMOUNT: Decimal = dec!(1_000_000_000_000);
+
+/// The smallest exchange rate, which keeps amounts converted out of Philippine Pesos within what a decimal can hold.
+pub const MIN_RATE: Decimal = dec!(0.000001);
+
+/// The largest exchange rate, which keeps amounts converted into Philippine Pesos within what a decimal can hold.
+pub const MAX_RATE: Decimal = dec!(1_000_000);
+
+/// A currency registered at runtime, in addition to the built-in ones.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Currency {
+    /// The three-letter code of the currency, like an ISO 4217 code.
+    pub code: String,
+    /// The display name of the currency.
+    pub name: String,
+    /// The number of decimal places of the currency's minor units, like centavos.
+    pub minor_units: u32,
+}
+impl Currency {
+    /// Returns the title or label of the currency, written like the built-in currencies' titles.
+    pub fn title(&self) -> String {
+        format!("{} ({})", self.name, self.code)
+    }
+}
+
+/// Checks whether a currency code is made of three uppercase ASCII letters.
+pub fn is_valid_code(code: &str) -> bool {
+    code.len() == 3 && code.chars().all(|c| c.is_ascii_uppercase())
+}
+
+/// Finds the static ISO 4217 code matching a currency code.
+pub fn find_code(code: &str) -> Option<&'static str> {
+    CURRENCIES_CODES.iter().find(|c| **c == code).copied()
+}
+
+/// Returns the symbol of a currency, looking it up in the ISO 4217 registry if it isn't built in.
+pub fn symbol(code: &str) -> Option<&'static str> {
+    CURRENCIES_CODES
+        .iter()
+        .position(|c| *c == code)
+        .map(|i| CURRENCIES_SYMBOLS[i])
+        .or_else(|| iso4217::find_by_code(code)?.symbol)
+}
+
+/// Returns the number of decimal places of a currency's minor units, looking it up in the ISO 4217 registry if it
+/// isn't built in.
+pub fn minor_units(code: &str) -> Option<u32> {
+    CURRENCIES_CODES
+        .iter()
+        .position(|c| *c == code)
+        .map(|i| CURRENCIES_MINOR_UNITS[i])
+        .or_else(|| iso4217::find_by_code(code).map(|c| c.minor_units))
+}
+
+/// Checks whether an amount, like a deposit, is positive and at most a maximum, if there is one, and [`MAX_AMOUNT`].
+pub fn is_valid_amount(amount: Decimal, max: Option<Decimal>) -> bool {
+    amount > Decimal::ZERO && amount <= MAX_AMOUNT && max.is_none_or(|max| amount <= max)
+}
+
+/// Checks whether an exchange rate is from [`MIN_RATE`] to [`MAX_RATE`].
+pub fn is_valid_rate(rate: Decimal) -> bool {
+    (MIN_RATE..=MAX_RATE).contains(&rate)
+}
+
+/// An amount kept as a whole number of a currency's minor units, like centavos, so that it stays exact however it is
+/// changed and persisted.
+///
+/// It is converted to and from a decimal amount for display and arithmetic by the number of decimal places of the
+/// currency's minor units, its scale.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MinorAmount {
+    /// The whole number of minor units, persisted as a string like decimal amounts since JSON numbers can't hold all of
+    /// them.
+    #[serde(with = "units_string")]
+    pub units: i128,
+    /// The number of decimal places of the currency's minor units.
+    pub scale: u32,
+}
+impl MinorAmount {
+    /// Returns no amount of a currency with minor units of a scale.
+    pub fn zero(scale: u32) -> MinorAmount {
+        MinorAmount { units: 0, scale }
+    }
+
+    /// Converts a decimal amount to minor units of a scale, rounding any finer digits by a rounding policy.
+    pub fn from_decimal(amount: Decimal, scale: u32, rounding: RoundingPolicy) -> MinorAmount {
+        let mut rounded = rounding.round(amount, scale);
+
+        rounded.rescale(scale);
+
+        MinorAmount {
+            units: rounded.mantissa(),
+            scale,
+        }
+    }
+
+    /// Converts the minor units back to a decimal amount, like pesos.
+    pub fn amount(self) -> Decimal {
+        Decimal::try_from_i128_with_scale(self.units, self.scale).unwrap_or(if self.units < 0 {
+            Decimal::MIN
+        } else {
+            Decimal::MAX
+        })
+    }
+}
+impl AddAssign<Decimal> for MinorAmount {
+    /// Adds a decimal amount, rounding any digits finer than the minor units half up, though amounts are usually
+    /// rounded to them by the bank's rounding policy beforehand.
+    fn add_assign(&mut self, amount: Decimal) {
+        self.units += MinorAmount::from_decimal(amount, self.scale, RoundingPolicy::HalfUp).units;
+    }
+}
+impl AddAssign<&Decimal> for MinorAmount {
+    fn add_assign(&mut self, amount: &Decimal) {
+        *self += *amount;
+    }
+}
+impl SubAssign<Decimal> for MinorAmount {
+    /// Subtracts a decimal amount, rounding any digits finer than the minor units half up.
+    fn sub_assign(&mut self, amount: Decimal) {
+        *self += -amount;
+    }
+}
+impl SubAssign<&Decimal> for MinorAmount {
+    fn sub_assign(&mut self, amount: &Decimal) {
+        *self -= *amount;
+    }
+}
+impl fmt::Display for MinorAmount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.amount())
+    }
+}
+
+/// The (de)serialization of whole numbers of minor units as strings.
+mod units_string {
+    use serde::{Deserialize, Deserializer, Serializer, de};
+
+    /// Writes a whole number of minor units as a string.
+    pub fn serialize<S: Serializer>(units: &i128, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(units)
+    }
+
+    /// Reads a whole number of minor units from a string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i128, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+    }
+}
+
+/// The ways amounts are rounded to the minor units of their currencies.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RoundingPolicy {
+    /// Halves are rounded away from zero, like `0.125` to `0.13`.
+    #[default]
+    HalfUp,
+    /// Halves are rounded to the even digit, like `0.125` to `0.12`, so that they don't skew totals either way.
+    HalfEven,
+    /// Every digit past the minor units is dropped, like `0.129` to `0.12`.
+    Truncate,
+}
+impl RoundingPolicy {
+    /// The tags of the policies.
+    pub const TAGS: [&str; 3] = ["half-up", "half-even", "truncate"];
+
+    /// Rounds an amount to a number of decimal places, like those of a currency's minor units.
+    pub fn round(self, amount: Decimal, decimals: u32) -> Decimal {
+        let strategy = match self {
+            RoundingPolicy::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            RoundingPolicy::HalfEven => RoundingStrategy::MidpointNearestEven,
+            RoundingPolicy::Truncate => RoundingStrategy::ToZero,
+        };
+
+        amount.round_dp_with_strategy(decimals, strategy)
+    }
+}
+impl fmt::Display for RoundingPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", RoundingPolicy::TAGS[*self as usize])
+    }
+}
+impl FromStr for RoundingPolicy {
+    type Err = String;
+
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        match tag.to_lowercase().as_str() {
+            "half-up" => Ok(RoundingPolicy::HalfUp),
+            "half-even" => Ok(RoundingPolicy::HalfEven),
+            "truncate" => Ok(RoundingPolicy::Truncate),
+            _ => Err(format!(
+                "unknown rounding policy, expected one of: {}",
+                RoundingPolicy::TAGS.join(", ")
+            )),
+        }
+    }
+}
+
+/// Returns the inverse of an exchange rate, which is how many units of the foreign currency one Philippine Peso is
+/// worth.
+pub fn inverse(rate: Decimal) -> Decimal {
+    Decimal::ONE / rate
+}
+
+/// Returns the rate the bank buys one unit of a foreign currency at, which is below its exchange rate by half of its
+/// spread.
+///
+/// The spread is the percentage of the exchange rate separating the rates the bank buys and sells the currency at.
+pub fn buying_rate(rate: Decimal, spread: Decimal) -> Decimal {
+    rate * (Decimal::ONE - spread / dec!(200))
+}
+
+/// Returns the rate the bank sells one unit of a foreign currency at, which is above its exchange rate by half of its
+/// spread.
+pub fn selling_rate(rate: Decimal, spread: Decimal) -> Decimal {
+    rate * (Decimal::ONE + spread / dec!(200))
+}
+
+/// Returns how many units of one currency a single unit of another is worth.
+pub fn cross_rate(src: &str, dest: &str, rates: &HashMap<String, Decimal>) -> Decimal {
+    convert_currency(Decimal::ONE, src, dest, rates)
+}
+
+/// Converts an amount from one currency to another.
+///
+/// The rates are the value of one unit of each foreign currency in Philippine Pesos, so the amount is multiplied by
+/// the rate of its currency into Philippine Pesos, then divided by the other currency's rate out of them.
+pub fn convert_currency(amount: Decimal, src: &str, dest: &str, rates: &HashMap<String, Decimal>) -> Decimal {
+    let src_php_amount = if src == BASE_CURRENCY {
+        amount
+    } else {
+        amount * rates[src]
+    };
+
+    if dest == BASE_CURRENCY {
+        src_php_amount
+    } else {
+        src_php_amount / rates[dest]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::bank::Bank;
+
+    /// Generates an amount from one centavo to a billion units.
+    fn amount() -> impl Strategy<Value = Decimal> {
+        (1..100_000_000_000_i64).prop_map(|cents| Decimal::new(cents, 2))
+    }
+
+    /// Generates an exchange rate from 0.0001 to 10,000 Philippine Pesos.
+    fn rate() -> impl Strategy<Value = Decimal> {
+        (1..100_000_000_i64).prop_map(|rate| Decimal::new(rate, 4))
+    }
+
+    /// Generates the code of a built-in currency.
+    fn code() -> impl Strategy<Value = &'static str> {
+        prop::sample::select(CURRENCIES_CODES.to_vec())
+    }
+
+    /// Generates the exchange rates of the built-in foreign currencies.
+    fn rates() -> impl Strategy<Value = HashMap<String, Decimal>> {
+        prop::collection::vec(rate(), CURRENCY_CNT - 1)
+            .prop_map(|rates| CURRENCIES_CODES[1..].iter().map(|c| c.to_string()).zip(rates).collect())
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip_returns_amount(amount in amount(), src in code(), dest in code(), rates in rates()) {
+            let converted = convert_currency(amount, src, dest, &rates);
+            let returned = convert_currency(converted, dest, src, &rates);
+
+            prop_assert!((returned - amount).abs() <= dec!(0.000000001), "{amount} returned as {returned}");
+        }
+
+        #[test]
+        fn rounded_round_trip_is_within_minor_units(
+            amount in amount(),
+            src in code(),
+            dest in code(),
+            rates in rates(),
+        ) {
+            let mut bank = Bank::new();
+
+            for (code, rate) in &rates {
+                bank.set_exchange_rate(code, *rate).unwrap();
+            }
+
+            let converted = bank.convert(src, dest, amount).unwrap();
+
+            prop_assume!(converted <= MAX_AMOUNT, "too large to convert back");
+
+            let returned = bank.convert(dest, src, converted).unwrap();
+            // Each conversion is off by at most half of its currency's minor unit, the first by its value in the
+            // source currency.
+            let src_unit = Decimal::new(1, bank.minor_units(src));
+            let dest_unit = Decimal::new(1, bank.minor_units(dest));
+            let tolerance = (dest_unit * cross_rate(dest, src, &rates) + src_unit) / Decimal::TWO;
+
+            prop_assert!((returned - amount).abs() <= tolerance, "{amount} returned as {returned}");
+        }
+
+        #[test]
+        fn conversion_is_monotonic(
+            amount in amount(),
+            increase in amount(),
+            src in code(),
+            dest in code(),
+            rates in rates(),
+        ) {
+            prop_assert!(
+                convert_currency(amount, src, dest, &rates) <= convert_currency(amount + increase, src, dest, &rates)
+            );
+        }
+
+        #[test]
+        fn peso_to_peso_is_identity(amount in amount(), rates in rates()) {
+            prop_assert_eq!(convert_currency(amount, BASE_CURRENCY, BASE_CURRENCY, &rates), amount);
+        }
+
+        #[test]
+        fn rate_is_pesos_per_unit(code in code(), rates in rates()) {
+            let rate = rates.get(code).copied().unwrap_or(Decimal::ONE);
+
+            prop_assert_eq!(convert_currency(Decimal::ONE, code, BASE_CURRENCY, &rates), rate);
+            prop_assert_eq!(convert_currency(rate, BASE_CURRENCY, code, &rates), Decimal::ONE);
+        }
+    }
+}
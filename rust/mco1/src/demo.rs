@@ -0,0 +1,146 @@
+//! The demo data that an empty bank can be populated with, generated from a seed so that demos are reproducible.
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use crate::{
+    account::{AccountType, Contact},
+    bank::{Bank, BankError},
+    clock::{FixedClock, SECONDS_PER_DAY, SystemClock},
+};
+
+/// The time the demo data starts at, midnight UTC of January 1, 2024, in seconds since the Unix epoch.
+const START: u64 = 1_704_067_200;
+
+/// The number of days of transactions in the demo data.
+const DAY_CNT: u64 = 30;
+
+/// The PIN of every demo account.
+pub const DEMO_PIN: &str = "1234";
+
+/// The names that the demo accounts are given from.
+const NAMES: [&str; 8] = ["Alice", "Bea", "Carlo", "Dina", "Emil", "Faye", "Gino", "Hana"];
+
+/// The number of demo accounts.
+const ACCOUNT_CNT: usize = 4;
+
+/// The memos that the demo deposits and withdrawals are written with.
+const MEMOS: [&str; 5] = ["Salary", "Groceries", "Rent", "Allowance", "Utilities"];
+
+/// The exchange rates that the foreign currencies start at, by their codes.
+const RATES: [(&str, Decimal); 5] = [
+    ("USD", dec!(56.00)),
+    ("JPY", dec!(0.38)),
+    ("GBP", dec!(71.00)),
+    ("EUR", dec!(61.00)),
+    ("CNY", dec!(7.80)),
+];
+
+/// A small pseudorandom number generator ([SplitMix64](https://prng.di.unimi.it/splitmix64.c)), which gives the same
+/// numbers for the same seed on every platform.
+pub(crate) struct SplitMix64(pub(crate) u64);
+impl SplitMix64 {
+    /// Returns the next pseudorandom number.
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.0;
+
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        z ^ (z >> 31)
+    }
+
+    /// Returns a pseudorandom number from zero to less than a bound.
+    pub(crate) fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    /// Returns a pseudorandom amount from a number of whole units to less than another, with centavos.
+    pub(crate) fn amount(&mut self, min: u64, max: u64) -> Decimal {
+        Decimal::new((min * 100 + self.below((max - min) * 100)) as i64, 2)
+    }
+}
+
+/// Populates an empty bank with demo accounts, exchange rates, and a month of transactions, generated from a seed.
+///
+/// The same seed always gives the same data, stamped from January 1, 2024. Every account's PIN is [`DEMO_PIN`].
+/// Deposits, withdrawals, and transfers that the bank's configuration rejects are skipped. The bank's clock reads the
+/// system's time again afterwards.
+pub fn populate(bank: &mut Bank, seed: u64) -> Result<(), BankError> {
+    let mut rng = SplitMix64(seed);
+    let mut names = NAMES.to_vec();
+    let mut rates = RATES;
+
+    bank.set_clock(FixedClock(START));
+
+    for (code, rate) in rates {
+        bank.set_exchange_rate(code, rate)?;
+    }
+
+    let mut accounts = Vec::new();
+
+    for i in 0..ACCOUNT_CNT {
+        let name = names.remove(rng.below(names.len() as u64) as usize).to_string();
+        let account_type = if i % 2 == 0 {
+            AccountType::Savings
+        } else {
+            AccountType::Checking
+        };
+
+        bank.register(
+            name.clone(),
+            Vec::new(),
+            account_type,
+            None,
+            Contact::default(),
+            DEMO_PIN,
+        )?;
+        bank.deposit(
+            &name,
+            "PHP",
+            rng.amount(5_000, 50_000),
+            Some(String::from("Opening deposit")),
+        )
+        .ok();
+
+        accounts.push(name);
+    }
+
+    for day in 1..=DAY_CNT {
+        let morning = START + day * SECONDS_PER_DAY + 9 * 60 * 60;
+
+        bank.set_clock(FixedClock(morning));
+
+        // Each rate drifts by up to 1% either way, rounded to centavos, or hundredths of centavos for the Yen.
+        for (code, rate) in &mut rates {
+            let drift = Decimal::new(rng.below(201) as i64 - 100, 4);
+
+            *rate = (*rate * (Decimal::ONE + drift)).round_dp(if *code == "JPY" { 4 } else { 2 });
+
+            bank.set_exchange_rate(code, *rate)?;
+        }
+
+        for hour in 1..=rng.below(3) + 1 {
+            bank.set_clock(FixedClock(morning + hour * 60 * 60));
+
+            let name = &accounts[rng.below(accounts.len() as u64) as usize];
+            let memo = Some(MEMOS[rng.below(MEMOS.len() as u64) as usize].to_string());
+
+            match rng.below(4) {
+                0 | 1 => bank.deposit(name, "PHP", rng.amount(100, 5_000), memo).ok(),
+                2 => bank.withdraw(name, "PHP", rng.amount(100, 2_000), memo).ok(),
+                _ => {
+                    let recipient = &accounts[rng.below(accounts.len() as u64) as usize];
+
+                    bank.transfer(name, recipient, rng.amount(100, 1_000), memo).ok()
+                }
+            };
+        }
+    }
+
+    bank.set_clock(SystemClock);
+
+    Ok(())
+}
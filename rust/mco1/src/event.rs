@@ -0,0 +1,135 @@
+//! The events that a bank publishes to its subscribers as its transactions are committed.
+
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::journal::{Entry, EntryKind};
+
+/// The kinds of transactions that events are published for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKind {
+    /// Balance was deposited to an account.
+    Deposit,
+    /// Balance was withdrawn from an account.
+    Withdrawal,
+    /// Balance was transferred from one account to another.
+    Transfer,
+    /// The exchange rate of a currency was updated.
+    RateChange,
+    /// A deposit, withdrawal, transfer, or fee was undone or reversed by its reference number, as recorded along with
+    /// the transaction it reversed.
+    Reversal,
+}
+impl fmt::Display for EventKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EventKind::Deposit => write!(f, "deposit"),
+            EventKind::Withdrawal => write!(f, "withdrawal"),
+            EventKind::Transfer => write!(f, "transfer"),
+            EventKind::RateChange => write!(f, "rate_change"),
+            EventKind::Reversal => write!(f, "reversal"),
+        }
+    }
+}
+
+/// A committed transaction, as published to the bank's subscribers.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Event {
+    /// The kind of the transaction.
+    pub event: EventKind,
+    /// The reference number of the transaction.
+    pub reference: String,
+    /// The time the transaction was committed at, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The transaction, as recorded in the journal.
+    pub transaction: EntryKind,
+}
+impl Event {
+    /// Creates the event of a committed entry with its reference number, unless its kind of transaction isn't
+    /// published.
+    pub fn of(entry: &Entry, reference: String) -> Option<Event> {
+        let event = match entry.kind {
+            EntryKind::Deposit { .. } => EventKind::Deposit,
+            EntryKind::Withdrawal { .. } => EventKind::Withdrawal,
+            EntryKind::Transfer { .. } => EventKind::Transfer,
+            EntryKind::RateUpdate { .. } => EventKind::RateChange,
+            EntryKind::Undo { .. } => EventKind::Reversal,
+            _ => return None,
+        };
+
+        Some(Event {
+            event,
+            reference,
+            timestamp: entry.timestamp,
+            transaction: entry.kind.clone(),
+        })
+    }
+}
+
+/// A subscriber to the events of a bank, which can be sent to another thread along with its bank.
+///
+/// Events are only published once their transactions are recorded, so replaying the journal publishes none.
+pub trait EventSink: Send {
+    /// Handles an event, which can't reject the transaction since it is already committed.
+    ///
+    /// Events are published in the middle of the transaction's call, so anything slow is better handed off to another
+    /// thread.
+    fn publish(&mut self, event: &Event);
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    /// Returns the journal's deposit of ₱100.00 to an account named Ana.
+    fn deposit() -> EntryKind {
+        EntryKind::Deposit {
+            account: String::from("Ana"),
+            amount: dec!(100),
+            currency: String::from("PHP"),
+            converted_amount: dec!(100),
+            memo: None,
+        }
+    }
+
+    #[test]
+    fn deposit_is_published_with_its_reference() {
+        let event = Event::of(&Entry::new(deposit(), 42), String::from("TX00000001")).unwrap();
+
+        assert_eq!(event.event, EventKind::Deposit);
+        assert_eq!(event.reference, "TX00000001");
+        assert_eq!(event.timestamp, 42);
+        assert_eq!(event.transaction, deposit());
+    }
+
+    #[test]
+    fn reversal_is_published_with_reversed_transaction() {
+        let reversal = EntryKind::Undo {
+            transaction: Box::new(deposit()),
+            reference: Some(String::from("TX00000001")),
+        };
+        let event = Event::of(&Entry::new(reversal.clone(), 42), String::from("TX00000002")).unwrap();
+
+        assert_eq!(event.event, EventKind::Reversal);
+        assert_eq!(event.transaction, reversal);
+        assert!(
+            serde_json::to_string(&event)
+                .unwrap()
+                .starts_with(r#"{"event":"reversal""#)
+        );
+    }
+
+    #[test]
+    fn other_transactions_arent_published() {
+        let set_spread = EntryKind::SetSpread {
+            currency: String::from("USD"),
+            spread: dec!(1.5),
+        };
+
+        assert_eq!(Event::of(&Entry::new(set_spread, 42), String::from("TX00000001")), None);
+    }
+}
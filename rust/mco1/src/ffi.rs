@@ -0,0 +1,332 @@
+//! The C API of the core, so that the project's implementations in other languages can call the Rust engine directly.
+//!
+//! Build it with `cargo rustc --lib --features ffi --crate-type cdylib`, or `staticlib`, and include the header that
+//! the build generates at `include/mcos.h`. Strings are null-terminated UTF-8, and amounts and rates are passed as
+//! decimal strings, like `"1234.50"`, so that no precision is lost. The fallible functions return a status, and the
+//! message of the last error on the calling thread can be read with [`mcos_last_error`].
+
+use std::{
+    cell::RefCell,
+    ffi::{CStr, CString, c_char},
+    ptr,
+};
+
+use rust_decimal::Decimal;
+
+use crate::{
+    account::{AccountType, Contact},
+    bank::{Bank, BankError},
+    journal::Journal,
+};
+
+/// The statuses returned by the API's fallible functions.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum McosStatus {
+    /// The function succeeded.
+    Ok = 0,
+    /// An argument was null, not UTF-8, or not a decimal string.
+    InvalidArgument = 1,
+    /// The bank rejected the transaction.
+    Rejected = 2,
+    /// The bank's journal couldn't be read or written.
+    Io = 3,
+}
+
+/// A bank created or opened through the API, which is opaque to its callers.
+pub struct McosBank {
+    /// The bank that the transactions are performed on.
+    bank: Bank,
+}
+
+/// The reason an API function failed, with its status and message.
+struct Failure {
+    /// The status returned for the failure.
+    status: McosStatus,
+    /// The message readable with [`mcos_last_error`].
+    message: String,
+}
+impl Failure {
+    /// Creates the failure of an invalid argument.
+    fn invalid(message: &str) -> Failure {
+        Failure {
+            status: McosStatus::InvalidArgument,
+            message: message.to_string(),
+        }
+    }
+}
+impl From<BankError> for Failure {
+    fn from(err: BankError) -> Self {
+        let status = match err {
+            BankError::Journal(_) => McosStatus::Io,
+            _ => McosStatus::Rejected,
+        };
+
+        Failure {
+            status,
+            message: err.to_string(),
+        }
+    }
+}
+
+thread_local! {
+    /// The message of the last error on the thread.
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Records the message of the last error on the thread.
+fn set_last_error(message: String) {
+    LAST_ERROR.set(Some(CString::new(message).unwrap_or_default()));
+}
+
+/// Returns the status of a function's result, recording its error's message.
+fn status(result: Result<(), Failure>) -> McosStatus {
+    match result {
+        Ok(()) => McosStatus::Ok,
+        Err(failure) => {
+            set_last_error(failure.message);
+
+            failure.status
+        }
+    }
+}
+
+/// Returns the failure of a null argument.
+fn null_argument() -> Failure {
+    Failure::invalid("Arguments can't be null!")
+}
+
+/// Reads a string argument.
+///
+/// # Safety
+///
+/// The argument must be null or point to a null-terminated string that stays unchanged while it is read.
+unsafe fn text<'a>(arg: *const c_char) -> Result<&'a str, Failure> {
+    if arg.is_null() {
+        return Err(null_argument());
+    }
+
+    unsafe { CStr::from_ptr(arg) }
+        .to_str()
+        .map_err(|_| Failure::invalid("Strings must be UTF-8!"))
+}
+
+/// Reads a decimal string argument.
+///
+/// # Safety
+///
+/// The argument must be null or point to a null-terminated string that stays unchanged while it is read.
+unsafe fn decimal(arg: *const c_char) -> Result<Decimal, Failure> {
+    unsafe { text(arg) }?
+        .trim()
+        .parse::<Decimal>()
+        .map_err(|_| Failure::invalid("Amounts and rates must be decimal numbers!"))
+}
+
+/// Reads the bank argument.
+///
+/// # Safety
+///
+/// The argument must be null or a bank returned by [`mcos_bank_new`] or [`mcos_bank_open`] that wasn't freed, and
+/// which isn't used elsewhere while it is borrowed.
+unsafe fn bank<'a>(arg: *mut McosBank) -> Result<&'a mut Bank, Failure> {
+    unsafe { arg.as_mut() }.map(|b| &mut b.bank).ok_or_else(null_argument)
+}
+
+/// Writes an amount to an output argument, as a decimal string freed with [`mcos_string_free`], unless it is null.
+///
+/// # Safety
+///
+/// The output argument must be null or valid for writes.
+unsafe fn write_amount(out: *mut *mut c_char, amount: Decimal) {
+    if let Some(out) = unsafe { out.as_mut() } {
+        *out = CString::new(amount.to_string()).unwrap_or_default().into_raw();
+    }
+}
+
+/// Creates an empty bank that isn't saved anywhere, which must be freed with [`mcos_bank_free`].
+#[unsafe(no_mangle)]
+pub extern "C" fn mcos_bank_new() -> *mut McosBank {
+    Box::into_raw(Box::new(McosBank { bank: Bank::new() }))
+}
+
+/// Opens the bank recorded in a journal, like a profile's `journal.jsonl`, which records the transactions performed on
+/// it and must be freed with [`mcos_bank_free`].
+///
+/// Null is returned if the journal can't be read, with the error's message readable with [`mcos_last_error`].
+///
+/// # Safety
+///
+/// The path must be null or point to a null-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mcos_bank_open(journal_path: *const c_char) -> *mut McosBank {
+    let opened = unsafe { text(journal_path) }.and_then(|path| {
+        Journal::open(path)
+            .and_then(Bank::replay)
+            .map_err(|err| Failure::from(BankError::Journal(err)))
+    });
+
+    match opened {
+        Ok(bank) => Box::into_raw(Box::new(McosBank { bank })),
+        Err(failure) => {
+            set_last_error(failure.message);
+
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Frees a bank, doing nothing if it is null.
+///
+/// # Safety
+///
+/// The bank must be null or returned by [`mcos_bank_new`] or [`mcos_bank_open`], and not freed already.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mcos_bank_free(bank: *mut McosBank) {
+    if !bank.is_null() {
+        drop(unsafe { Box::from_raw(bank) });
+    }
+}
+
+/// Registers a new savings account, protected by a PIN.
+///
+/// # Safety
+///
+/// The bank must be null or a live bank that isn't used elsewhere during the call, and the strings must be null or
+/// null-terminated.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mcos_register(bank: *mut McosBank, name: *const c_char, pin: *const c_char) -> McosStatus {
+    status((|| {
+        let bank = unsafe { self::bank(bank) }?;
+        let name = unsafe { text(name) }?.to_string();
+        let pin = unsafe { text(pin) }?;
+
+        Ok(bank.register(name, Vec::new(), AccountType::Savings, None, Contact::default(), pin)?)
+    })())
+}
+
+/// Deposits an amount in a currency to an account, writing its updated balance to `balance_out` unless it is null.
+///
+/// # Safety
+///
+/// The bank must be null or a live bank that isn't used elsewhere during the call, the strings must be null or
+/// null-terminated, and `balance_out` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mcos_deposit(
+    bank: *mut McosBank,
+    account: *const c_char,
+    amount: *const c_char,
+    currency: *const c_char,
+    balance_out: *mut *mut c_char,
+) -> McosStatus {
+    status((|| {
+        let bank = unsafe { self::bank(bank) }?;
+        let receipt = bank.deposit(
+            unsafe { text(account) }?,
+            unsafe { text(currency) }?,
+            unsafe { decimal(amount) }?,
+            None,
+        )?;
+
+        unsafe { write_amount(balance_out, receipt.balance) };
+
+        Ok(())
+    })())
+}
+
+/// Withdraws an amount in a currency from an account, writing its updated balance to `balance_out` unless it is null.
+///
+/// # Safety
+///
+/// The bank must be null or a live bank that isn't used elsewhere during the call, the strings must be null or
+/// null-terminated, and `balance_out` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mcos_withdraw(
+    bank: *mut McosBank,
+    account: *const c_char,
+    amount: *const c_char,
+    currency: *const c_char,
+    balance_out: *mut *mut c_char,
+) -> McosStatus {
+    status((|| {
+        let bank = unsafe { self::bank(bank) }?;
+        let receipt = bank.withdraw(
+            unsafe { text(account) }?,
+            unsafe { text(currency) }?,
+            unsafe { decimal(amount) }?,
+            None,
+        )?;
+
+        unsafe { write_amount(balance_out, receipt.balance) };
+
+        Ok(())
+    })())
+}
+
+/// Sets the value of one unit of a foreign currency in Philippine Pesos.
+///
+/// # Safety
+///
+/// The bank must be null or a live bank that isn't used elsewhere during the call, and the strings must be null or
+/// null-terminated.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mcos_set_exchange_rate(
+    bank: *mut McosBank,
+    currency: *const c_char,
+    rate: *const c_char,
+) -> McosStatus {
+    status((|| {
+        let bank = unsafe { self::bank(bank) }?;
+
+        Ok(bank.set_exchange_rate(unsafe { text(currency) }?, unsafe { decimal(rate) }?)?)
+    })())
+}
+
+/// Converts an amount from one currency to another at the bank's rates, after its fees and spread, without exchanging
+/// it, writing the converted amount to `amount_out` unless it is null.
+///
+/// # Safety
+///
+/// The bank must be null or a live bank that isn't changed elsewhere during the call, the strings must be null or
+/// null-terminated, and `amount_out` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mcos_convert(
+    bank: *const McosBank,
+    amount: *const c_char,
+    from: *const c_char,
+    to: *const c_char,
+    amount_out: *mut *mut c_char,
+) -> McosStatus {
+    status((|| {
+        let bank = unsafe { bank.as_ref() }.ok_or_else(null_argument)?;
+        let quote = bank
+            .bank
+            .quote_exchange(unsafe { text(from) }?, unsafe { text(to) }?, unsafe {
+                decimal(amount)
+            }?)?;
+
+        unsafe { write_amount(amount_out, quote.exchange_amount) };
+
+        Ok(())
+    })())
+}
+
+/// Returns the message of the last error on the calling thread, or null if there was none.
+///
+/// The message stays valid until the next error on the thread, and mustn't be freed.
+#[unsafe(no_mangle)]
+pub extern "C" fn mcos_last_error() -> *const c_char {
+    LAST_ERROR.with_borrow(|message| message.as_ref().map_or(ptr::null(), |m| m.as_ptr()))
+}
+
+/// Frees a string returned by the API, doing nothing if it is null.
+///
+/// # Safety
+///
+/// The string must be null or written by the API, and not freed already.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mcos_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(unsafe { CString::from_raw(string) });
+    }
+}
@@ -0,0 +1,507 @@
+//! The GraphQL schema over the bank, an alternative to the HTTP server's JSON API for building web front-ends, which
+//! performs the same transactions as the non-interactive commands.
+//!
+//! The schema's types mirror the results of the JSON API, with their fields in camel case.
+
+use std::sync::Arc;
+
+use async_graphql::{
+    Context, EmptySubscription, Enum, Error, Object, Result, Schema, SimpleObject, Union, http::GraphiQLSource,
+};
+use axum::response::Html;
+use banking_and_currency_app::{account, bank::ExchangeQuote, currency::BASE_CURRENCY, history};
+use rust_decimal::Decimal;
+
+use crate::{
+    cli::{Command, parse_currency},
+    output::{AccountInfo, CurrentRate, Outcome},
+    server::Server,
+};
+
+/// The schema of the bank's queries and mutations.
+pub type BankSchema = Schema<Query, Mutation, EmptySubscription>;
+
+/// The types of accounts, each with its own interest rate and withdrawal rules.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Enum)]
+#[graphql(remote = "account::AccountType")]
+enum AccountType {
+    /// An account earning interest, which can be withdrawn from anytime.
+    #[default]
+    Savings,
+    /// An account earning no interest, which can be withdrawn from anytime.
+    Checking,
+    /// An account earning a higher interest, which is penalized when withdrawn from before its term ends.
+    TimeDeposit,
+}
+
+/// The statuses of an account, deciding which transactions it allows.
+#[derive(Clone, Copy, PartialEq, Eq, Enum)]
+#[graphql(remote = "account::AccountStatus")]
+enum AccountStatus {
+    /// The account allows every transaction.
+    Open,
+    /// The account rejects deposits and withdrawals until it is unfrozen.
+    Frozen,
+    /// The account rejects every transaction, but keeps its record.
+    Closed,
+}
+
+/// The orders that accounts can be listed in.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Enum)]
+#[graphql(remote = "account::AccountOrder")]
+enum AccountOrder {
+    /// Alphabetically by name.
+    #[default]
+    Name,
+    /// From the highest balance to the lowest.
+    Balance,
+}
+
+/// The kinds of transactions recorded in an account's history.
+#[derive(Clone, Copy, PartialEq, Eq, Enum)]
+#[graphql(remote = "history::RecordKind")]
+enum RecordKind {
+    /// Balance was deposited to the account.
+    Deposit,
+    /// Balance was withdrawn from the account.
+    Withdrawal,
+    /// Balance was transferred from the account to another.
+    TransferOut,
+    /// Balance was transferred to the account from another.
+    TransferIn,
+    /// An earlier transaction against the account was undone.
+    Reversal,
+    /// A penalty was charged for withdrawing from a time deposit early.
+    Penalty,
+    /// A fee was charged to the account.
+    Fee,
+    /// Interest was credited to the account.
+    Interest,
+    /// Tax was withheld from the interest credited to the account.
+    Tax,
+}
+
+/// An account of the bank.
+#[derive(SimpleObject)]
+struct Account {
+    /// The unique number of the account.
+    number: String,
+    /// The unique name of the account.
+    name: String,
+    /// The names of the account's owners, its namesake first.
+    owners: Vec<String>,
+    /// The type of the account.
+    account_type: AccountType,
+    /// The currency of the account's balance.
+    currency: String,
+    /// The current balance of the account.
+    balance: Decimal,
+    /// The status of the account.
+    status: AccountStatus,
+    /// The annual interest rate earned by the account, like 0.05 for 5%.
+    interest_rate: Decimal,
+    /// When the account was opened, in seconds since the Unix epoch.
+    opened_at: u64,
+}
+impl From<AccountInfo> for Account {
+    fn from(account: AccountInfo) -> Self {
+        Account {
+            number: account.number,
+            name: account.name,
+            owners: account.owners,
+            account_type: account.account_type.into(),
+            currency: account.currency,
+            balance: account.balance,
+            status: account.status.into(),
+            interest_rate: account.interest_rate,
+            opened_at: account.opened_at,
+        }
+    }
+}
+
+/// A transaction against an account, as recorded in its history.
+#[derive(SimpleObject)]
+struct Record {
+    /// The reference number of the transaction, or an empty string if it wasn't recorded.
+    reference: String,
+    /// What the transaction did.
+    kind: RecordKind,
+    /// The transacted amount, in the transacted currency.
+    amount: Decimal,
+    /// The currency of the transacted amount.
+    currency: String,
+    /// The account's balance after the transaction, in the account's currency.
+    balance: Decimal,
+    /// The note attached to the transaction, if any.
+    memo: Option<String>,
+    /// When the transaction was committed, in seconds since the Unix epoch.
+    timestamp: u64,
+    /// The reference number of the transaction this one reversed, if it is a reversal.
+    reversal_of: Option<String>,
+    /// The reference number of the transaction that reversed this one, if it was reversed.
+    reversed_by: Option<String>,
+}
+impl From<history::Record> for Record {
+    fn from(record: history::Record) -> Self {
+        Record {
+            reference: record.reference,
+            kind: record.kind.into(),
+            amount: record.amount,
+            currency: record.currency,
+            balance: record.balance,
+            memo: record.memo,
+            timestamp: record.timestamp,
+            reversal_of: record.reversal_of,
+            reversed_by: record.reversed_by,
+        }
+    }
+}
+
+/// The current exchange rate of a foreign currency.
+#[derive(SimpleObject)]
+struct Rate {
+    /// The code of the currency.
+    currency: String,
+    /// The value of one unit of the currency in Philippine Pesos.
+    rate: Decimal,
+    /// The number of units of the currency that one Philippine Peso is worth.
+    inverse: Decimal,
+    /// When the rate was last updated, in seconds since the Unix epoch, if it ever was.
+    updated_at: Option<u64>,
+}
+impl From<CurrentRate> for Rate {
+    fn from(rate: CurrentRate) -> Self {
+        Rate {
+            currency: rate.currency,
+            rate: rate.rate,
+            inverse: rate.inverse,
+            updated_at: rate.updated_at,
+        }
+    }
+}
+
+/// The receipt of a committed deposit, withdrawal, or transfer.
+#[derive(SimpleObject)]
+struct Receipt {
+    /// The reference number of the transaction.
+    reference: String,
+    /// The updated balance of the account.
+    balance: Decimal,
+    /// The currency of the account's balance.
+    currency: String,
+    /// The fees charged along with the transaction, in the account's currency, in the order they were charged.
+    fees: Vec<Decimal>,
+}
+
+/// A withdrawal or transfer held for approval instead of being committed.
+#[derive(SimpleObject)]
+struct Hold {
+    /// The ID of the held transaction.
+    id: u64,
+}
+
+/// The result of a withdrawal or transfer.
+#[derive(Union)]
+enum TransactionResult {
+    /// The transaction was committed.
+    Receipt(Receipt),
+    /// The transaction was held for approval.
+    Hold(Hold),
+}
+
+/// The breakdown of an exchange from one currency to another.
+#[derive(SimpleObject)]
+struct Exchange {
+    /// The amount to exchange, in the source currency.
+    amount: Decimal,
+    /// The source currency.
+    currency: String,
+    /// The currency exchanged to.
+    exchange_currency: String,
+    /// The units of the currency exchanged to that a single unit of the source currency is exchanged for.
+    rate: Decimal,
+    /// The fee deducted from the amount before it is exchanged, in the source currency.
+    fee: Decimal,
+    /// The bank's income from the spread, in the currency exchanged to.
+    spread: Decimal,
+    /// The amount received, in the currency exchanged to.
+    exchange_amount: Decimal,
+}
+impl From<ExchangeQuote> for Exchange {
+    fn from(quote: ExchangeQuote) -> Self {
+        Exchange {
+            amount: quote.amount,
+            currency: quote.currency,
+            exchange_currency: quote.exchange_currency,
+            rate: quote.rate,
+            fee: quote.fee,
+            spread: quote.spread,
+            exchange_amount: quote.exchange_amount,
+        }
+    }
+}
+
+/// The queries of the bank's accounts, exchange rates, and histories.
+pub struct Query;
+#[Object]
+impl Query {
+    /// The registered accounts, optionally only those with at least a balance or of a type.
+    async fn accounts(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default)] sort: AccountOrder,
+        min_balance: Option<Decimal>,
+        account_type: Option<AccountType>,
+    ) -> Result<Vec<Account>> {
+        let command = Command::Accounts {
+            sort: sort.into(),
+            min_balance,
+            account_type: account_type.map(AccountType::into),
+        };
+
+        match perform(ctx, command).await? {
+            Outcome::Accounts { accounts } => Ok(accounts.into_iter().map(Account::from).collect()),
+            _ => Err(unexpected()),
+        }
+    }
+
+    /// An account by its name or number.
+    async fn account(&self, ctx: &Context<'_>, account: String, pin: Option<String>) -> Result<Account> {
+        match perform(ctx, Command::Details { account, pin }).await? {
+            Outcome::Details { account } => Ok(account.into()),
+            _ => Err(unexpected()),
+        }
+    }
+
+    /// The transactions against an account, from newest to oldest, optionally only the newest or those whose reference
+    /// number or memo contains a text.
+    async fn history(
+        &self,
+        ctx: &Context<'_>,
+        account: String,
+        pin: Option<String>,
+        limit: Option<usize>,
+        search: Option<String>,
+    ) -> Result<Vec<Record>> {
+        let command = Command::History {
+            account,
+            pin,
+            limit,
+            search,
+        };
+
+        match perform(ctx, command).await? {
+            Outcome::History { records, .. } => Ok(records.into_iter().map(Record::from).collect()),
+            _ => Err(unexpected()),
+        }
+    }
+
+    /// The current exchange rates, in the order the currencies are listed.
+    async fn rates(&self, ctx: &Context<'_>) -> Result<Vec<Rate>> {
+        match perform(ctx, Command::Rates).await? {
+            Outcome::Rates { rates } => Ok(rates.into_iter().map(Rate::from).collect()),
+            _ => Err(unexpected()),
+        }
+    }
+}
+
+/// The transactions that can be performed on the bank.
+pub struct Mutation;
+#[Object]
+impl Mutation {
+    /// Registers a new account.
+    #[allow(clippy::too_many_arguments)]
+    async fn register(
+        &self,
+        ctx: &Context<'_>,
+        account: String,
+        #[graphql(default)] joint_owners: Vec<String>,
+        #[graphql(default)] account_type: AccountType,
+        interest_rate: Option<Decimal>,
+        email: Option<String>,
+        phone: Option<String>,
+        pin: String,
+    ) -> Result<Account> {
+        let command = Command::Register {
+            account: account.clone(),
+            joint_owners,
+            account_type: account_type.into(),
+            interest_rate,
+            email,
+            phone,
+            pin: pin.clone(),
+        };
+
+        perform(ctx, command).await?;
+
+        match perform(
+            ctx,
+            Command::Details {
+                account,
+                pin: Some(pin),
+            },
+        )
+        .await?
+        {
+            Outcome::Details { account } => Ok(account.into()),
+            _ => Err(unexpected()),
+        }
+    }
+
+    /// Deposits an amount in any currency, Philippine Pesos if not given, to an account.
+    async fn deposit(
+        &self,
+        ctx: &Context<'_>,
+        account: String,
+        pin: Option<String>,
+        amount: Decimal,
+        currency: Option<String>,
+        memo: Option<String>,
+    ) -> Result<Receipt> {
+        let command = Command::Deposit {
+            account,
+            pin,
+            amount,
+            currency: currency_code(currency)?,
+            memo,
+        };
+
+        match perform(ctx, command).await? {
+            Outcome::Deposited {
+                reference,
+                balance,
+                currency,
+                ..
+            } => Ok(Receipt {
+                reference,
+                balance,
+                currency,
+                fees: Vec::new(),
+            }),
+            _ => Err(unexpected()),
+        }
+    }
+
+    /// Withdraws an amount in any currency, Philippine Pesos if not given, from an account, holding it for approval
+    /// instead if it is large enough.
+    async fn withdraw(
+        &self,
+        ctx: &Context<'_>,
+        account: String,
+        pin: Option<String>,
+        amount: Decimal,
+        currency: Option<String>,
+        memo: Option<String>,
+    ) -> Result<TransactionResult> {
+        let command = Command::Withdraw {
+            account,
+            pin,
+            amount,
+            currency: currency_code(currency)?,
+            memo,
+        };
+
+        transaction_result(perform(ctx, command).await?)
+    }
+
+    /// Transfers an amount from one account to another, holding it for approval instead if it is large enough.
+    async fn transfer(
+        &self,
+        ctx: &Context<'_>,
+        account: String,
+        pin: Option<String>,
+        to: String,
+        amount: Decimal,
+        memo: Option<String>,
+    ) -> Result<TransactionResult> {
+        let command = Command::Transfer {
+            account,
+            pin,
+            to,
+            amount,
+            memo,
+        };
+
+        transaction_result(perform(ctx, command).await?)
+    }
+
+    /// Exchanges an amount from one currency to another, or only breaks the exchange down if it is a dry run.
+    async fn exchange(
+        &self,
+        ctx: &Context<'_>,
+        from: String,
+        to: String,
+        amount: Decimal,
+        #[graphql(default)] dry_run: bool,
+    ) -> Result<Exchange> {
+        let command = Command::Exchange {
+            from: currency_code(Some(from))?,
+            to: currency_code(Some(to))?,
+            amount,
+            dry_run,
+        };
+
+        match perform(ctx, command).await? {
+            Outcome::Exchanged { quote } | Outcome::ExchangeQuoted { quote } => Ok(quote.into()),
+            _ => Err(unexpected()),
+        }
+    }
+}
+
+/// Creates the schema over the bank served by a server.
+pub fn schema(server: Arc<Server>) -> BankSchema {
+    Schema::build(Query, Mutation, EmptySubscription).data(server).finish()
+}
+
+/// Serves the explorer of the schema, for browsing and trying it.
+pub async fn graphiql() -> Html<String> {
+    Html(GraphiQLSource::build().endpoint("/graphql").finish())
+}
+
+/// Performs a transaction on the bank, returning its results.
+async fn perform(ctx: &Context<'_>, command: Command) -> Result<Outcome> {
+    ctx.data_unchecked::<Arc<Server>>()
+        .perform(command, None)
+        .await
+        .map_err(|err| Error::new(err.message))
+}
+
+/// Returns the error of a transaction whose results don't fit its query, which is a bug.
+fn unexpected() -> Error {
+    Error::new("the transaction's results don't fit its query")
+}
+
+/// Parses the code or name of a currency, Philippine Pesos if not given.
+fn currency_code(query: Option<String>) -> Result<String> {
+    match query {
+        Some(query) => parse_currency(&query).map_err(Error::new),
+        None => Ok(BASE_CURRENCY.to_string()),
+    }
+}
+
+/// Converts the results of a withdrawal or transfer into their union.
+fn transaction_result(outcome: Outcome) -> Result<TransactionResult> {
+    match outcome {
+        Outcome::Withdrawn {
+            reference,
+            balance,
+            charges,
+            currency,
+            ..
+        }
+        | Outcome::Transferred {
+            reference,
+            balance,
+            charges,
+            currency,
+            ..
+        } => Ok(TransactionResult::Receipt(Receipt {
+            reference,
+            balance,
+            currency,
+            fees: charges.iter().map(|c| c.amount).collect(),
+        })),
+        Outcome::Held { id, .. } => Ok(TransactionResult::Hold(Hold { id })),
+        _ => Err(unexpected()),
+    }
+}
@@ -0,0 +1,337 @@
+//! The gRPC server exposing the bank's accounts and the transactions against them, as described by
+//! `proto/mcos.proto`, which performs the same transactions as the non-interactive commands.
+
+use std::{
+    error::Error,
+    io,
+    net::SocketAddr,
+    sync::{Mutex, PoisonError},
+};
+
+use banking_and_currency_app::{
+    account::{AccountOrder, AccountStatus, AccountType},
+    bank::{Bank, BankError, Charge},
+    currency::BASE_CURRENCY,
+    history::{Record, RecordKind},
+    profile::Profile,
+};
+use rust_decimal::Decimal;
+use tonic::{Code, Request, Response, Status};
+use tracing::info;
+
+use crate::{
+    cli::{Command, parse_currency},
+    execute,
+    i18n::{self, fill, messages},
+    output::{AccountInfo, Outcome},
+    style,
+};
+
+/// The messages and service generated from `proto/mcos.proto`.
+mod proto {
+    tonic::include_proto!("mcos.v1");
+}
+
+use proto::{
+    GetAccountRequest, ListAccountsRequest, ListAccountsResponse, ListTransactionsRequest, ListTransactionsResponse,
+    Money, Receipt, RegisterAccountRequest, TransactionResult, TransferRequest,
+    bank_server::{Bank as BankService, BankServer},
+    transaction_result,
+};
+
+/// The service performing the requests' transactions on a profile's bank, one at a time.
+struct Service {
+    /// The bank that the transactions are performed on.
+    bank: Mutex<Bank>,
+    /// The profile whose bank is served.
+    profile: Profile,
+}
+impl Service {
+    /// Performs transactions, returning the results of the last.
+    fn execute<const N: usize>(&self, commands: [Command; N]) -> Result<Outcome, Status> {
+        let mut bank = self.bank.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut outcome = None;
+
+        for command in commands {
+            outcome = Some(execute(command, &mut bank, &self.profile).map_err(status)?);
+        }
+
+        outcome.ok_or_else(|| Status::internal("no transactions were performed"))
+    }
+}
+#[tonic::async_trait]
+impl BankService for Service {
+    async fn register_account(
+        &self,
+        request: Request<RegisterAccountRequest>,
+    ) -> Result<Response<proto::Account>, Status> {
+        let request = request.into_inner();
+        let account_type = match request.r#type() {
+            proto::AccountType::Unspecified | proto::AccountType::Savings => AccountType::Savings,
+            proto::AccountType::Checking => AccountType::Checking,
+            proto::AccountType::TimeDeposit => AccountType::TimeDeposit,
+        };
+        let register = Command::Register {
+            account: request.name.clone(),
+            joint_owners: request.joint_owners,
+            account_type,
+            interest_rate: request.interest_rate.as_deref().map(decimal).transpose()?,
+            email: request.email,
+            phone: request.phone,
+            pin: request.pin.clone(),
+        };
+        let details = Command::Details {
+            account: request.name,
+            pin: Some(request.pin),
+        };
+
+        match self.execute([register, details])? {
+            Outcome::Details { account } => Ok(Response::new(account.into())),
+            _ => Err(unexpected()),
+        }
+    }
+
+    async fn get_account(&self, request: Request<GetAccountRequest>) -> Result<Response<proto::Account>, Status> {
+        let request = request.into_inner();
+
+        match self.execute([Command::Details {
+            account: request.account,
+            pin: Some(request.pin),
+        }])? {
+            Outcome::Details { account } => Ok(Response::new(account.into())),
+            _ => Err(unexpected()),
+        }
+    }
+
+    async fn list_accounts(&self, _: Request<ListAccountsRequest>) -> Result<Response<ListAccountsResponse>, Status> {
+        match self.execute([Command::Accounts {
+            sort: AccountOrder::Name,
+            min_balance: None,
+            account_type: None,
+        }])? {
+            Outcome::Accounts { accounts } => Ok(Response::new(ListAccountsResponse {
+                accounts: accounts.into_iter().map(proto::Account::from).collect(),
+            })),
+            _ => Err(unexpected()),
+        }
+    }
+
+    async fn deposit(&self, request: Request<proto::DepositRequest>) -> Result<Response<TransactionResult>, Status> {
+        let request = request.into_inner();
+        let (amount, currency) = parse_money(request.amount)?;
+
+        self.execute([Command::Deposit {
+            account: request.account,
+            pin: Some(request.pin),
+            amount,
+            currency,
+            memo: request.memo,
+        }])
+        .and_then(transaction_result)
+    }
+
+    async fn withdraw(&self, request: Request<proto::WithdrawRequest>) -> Result<Response<TransactionResult>, Status> {
+        let request = request.into_inner();
+        let (amount, currency) = parse_money(request.amount)?;
+
+        self.execute([Command::Withdraw {
+            account: request.account,
+            pin: Some(request.pin),
+            amount,
+            currency,
+            memo: request.memo,
+        }])
+        .and_then(transaction_result)
+    }
+
+    async fn transfer(&self, request: Request<TransferRequest>) -> Result<Response<TransactionResult>, Status> {
+        let request = request.into_inner();
+
+        self.execute([Command::Transfer {
+            account: request.account,
+            pin: Some(request.pin),
+            to: request.recipient,
+            amount: decimal(&request.amount)?,
+            memo: request.memo,
+        }])
+        .and_then(transaction_result)
+    }
+
+    async fn list_transactions(
+        &self,
+        request: Request<ListTransactionsRequest>,
+    ) -> Result<Response<ListTransactionsResponse>, Status> {
+        let request = request.into_inner();
+
+        match self.execute([Command::History {
+            account: request.account,
+            pin: Some(request.pin),
+            limit: (request.limit > 0).then_some(request.limit as usize),
+            search: None,
+        }])? {
+            Outcome::History { currency, records, .. } => Ok(Response::new(ListTransactionsResponse {
+                transactions: records.iter().map(|r| transaction(r, &currency)).collect(),
+            })),
+            _ => Err(unexpected()),
+        }
+    }
+}
+
+impl From<AccountInfo> for proto::Account {
+    fn from(account: AccountInfo) -> Self {
+        let account_type = match account.account_type {
+            AccountType::Savings => proto::AccountType::Savings,
+            AccountType::Checking => proto::AccountType::Checking,
+            AccountType::TimeDeposit => proto::AccountType::TimeDeposit,
+        };
+        let status = match account.status {
+            AccountStatus::Open => proto::AccountStatus::Open,
+            AccountStatus::Frozen => proto::AccountStatus::Frozen,
+            AccountStatus::Closed => proto::AccountStatus::Closed,
+        };
+
+        proto::Account {
+            number: account.number,
+            name: account.name,
+            owners: account.owners,
+            r#type: account_type.into(),
+            balance: Some(to_money(account.balance, &account.currency)),
+            status: status.into(),
+            interest_rate: account.interest_rate.to_string(),
+            opened_at: account.opened_at,
+        }
+    }
+}
+
+/// Serves a profile's bank over gRPC at an address, until the process is interrupted.
+pub fn run(bank: Bank, profile: Profile, addr: SocketAddr) -> io::Result<()> {
+    let service = BankServer::new(Service {
+        bank: Mutex::new(bank),
+        profile,
+    });
+
+    tokio::runtime::Runtime::new()?.block_on(async {
+        info!(%addr, "serving the bank over gRPC");
+        eprintln!("{}", style::header(fill(messages().serving_grpc, &[&addr])));
+
+        tonic::transport::Server::builder()
+            .add_service(service)
+            .serve(addr)
+            .await
+            .map_err(io::Error::other)
+    })
+}
+
+/// Returns the status of a failed transaction, fitting its error, with the error's message.
+fn status(err: Box<dyn Error>) -> Status {
+    let code = match err.downcast_ref::<BankError>() {
+        Some(
+            BankError::AccountNotFound
+            | BankError::ScheduleNotFound
+            | BankError::AlertNotFound
+            | BankError::PendingNotFound
+            | BankError::TransactionNotFound,
+        ) => Code::NotFound,
+        Some(BankError::IncorrectPin) => Code::Unauthenticated,
+        Some(BankError::DuplicateAccount | BankError::DuplicateCurrency) => Code::AlreadyExists,
+        Some(BankError::Journal(_) | BankError::Backup(_)) => Code::Internal,
+        _ if err.is::<io::Error>() => Code::Internal,
+        _ => Code::FailedPrecondition,
+    };
+
+    Status::new(code, i18n::describe(&err))
+}
+
+/// Returns the status of a transaction whose results don't fit its request, which is a bug.
+fn unexpected() -> Status {
+    Status::internal("the transaction's results don't fit its request")
+}
+
+/// Parses a decimal string.
+fn decimal(amount: &str) -> Result<Decimal, Status> {
+    amount
+        .parse::<Decimal>()
+        .map_err(|_| Status::invalid_argument(messages().invalid_amount))
+}
+
+/// Parses an amount of money into its amount and the code of its currency, Philippine Pesos if not given.
+fn parse_money(money: Option<Money>) -> Result<(Decimal, String), Status> {
+    let money = money.ok_or_else(|| Status::invalid_argument(messages().invalid_amount))?;
+    let currency = match money.currency.as_str() {
+        "" => BASE_CURRENCY.to_string(),
+        code => parse_currency(code).map_err(Status::invalid_argument)?,
+    };
+
+    Ok((decimal(&money.amount)?, currency))
+}
+
+/// Converts the results of a deposit, withdrawal, or transfer into their message.
+fn transaction_result(outcome: Outcome) -> Result<Response<TransactionResult>, Status> {
+    let result = match outcome {
+        Outcome::Deposited {
+            reference,
+            balance,
+            currency,
+            ..
+        } => receipt(reference, balance, currency, &[]),
+        Outcome::Withdrawn {
+            reference,
+            balance,
+            charges,
+            currency,
+            ..
+        }
+        | Outcome::Transferred {
+            reference,
+            balance,
+            charges,
+            currency,
+            ..
+        } => receipt(reference, balance, currency, &charges),
+        Outcome::Held { id, .. } => transaction_result::Result::HeldId(id),
+        _ => return Err(unexpected()),
+    };
+
+    Ok(Response::new(TransactionResult { result: Some(result) }))
+}
+
+/// Returns the receipt of a committed transaction, with the fees charged along with it in the account's currency.
+fn receipt(reference: String, balance: Decimal, currency: String, charges: &[Charge]) -> transaction_result::Result {
+    transaction_result::Result::Receipt(Receipt {
+        reference,
+        fees: charges.iter().map(|c| to_money(c.amount, &currency)).collect(),
+        balance: Some(to_money(balance, &currency)),
+    })
+}
+
+/// Converts an amount in a currency into its message.
+fn to_money(amount: Decimal, currency: &str) -> Money {
+    Money {
+        amount: amount.to_string(),
+        currency: currency.to_string(),
+    }
+}
+
+/// Converts a transaction recorded against an account into its message.
+fn transaction(record: &Record, currency: &str) -> proto::Transaction {
+    let kind = match record.kind {
+        RecordKind::Deposit => proto::TransactionKind::Deposit,
+        RecordKind::Withdrawal => proto::TransactionKind::Withdrawal,
+        RecordKind::TransferOut => proto::TransactionKind::TransferOut,
+        RecordKind::TransferIn => proto::TransactionKind::TransferIn,
+        RecordKind::Reversal => proto::TransactionKind::Reversal,
+        RecordKind::Penalty => proto::TransactionKind::Penalty,
+        RecordKind::Fee => proto::TransactionKind::Fee,
+        RecordKind::Interest => proto::TransactionKind::Interest,
+        RecordKind::Tax => proto::TransactionKind::Tax,
+    };
+
+    proto::Transaction {
+        reference: record.reference.clone(),
+        kind: kind.into(),
+        amount: Some(to_money(record.amount, &record.currency)),
+        balance: Some(to_money(record.balance, currency)),
+        memo: record.memo.clone(),
+        timestamp: record.timestamp,
+    }
+}
@@ -0,0 +1,78 @@
+//! The history of the transactions against each account.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// The kinds of transactions recorded in an account's history.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum RecordKind {
+    /// Balance was deposited to the account.
+    Deposit,
+    /// Balance was withdrawn from the account.
+    Withdrawal,
+    /// Balance was transferred from the account to another.
+    TransferOut,
+    /// Balance was transferred to the account from another.
+    TransferIn,
+    /// An earlier transaction against the account was undone.
+    Reversal,
+    /// A penalty was charged for withdrawing from a time deposit early.
+    Penalty,
+    /// A fee was charged to the account.
+    Fee,
+    /// Interest was credited to the account.
+    Interest,
+    /// Tax was withheld from the interest credited to the account.
+    Tax,
+}
+
+/// Formats the reference number of the transaction committed in a sequence, starting from one.
+pub fn reference(seq: u64) -> String {
+    format!("TX{seq:08}")
+}
+
+/// When and under which reference number a transaction was committed, along with its memo.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Stamp {
+    /// The time the transaction was committed, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The reference number of the transaction.
+    pub reference: String,
+    /// The note attached to the transaction, if any.
+    pub memo: Option<String>,
+}
+
+/// A transaction against an account, as recorded in its history.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Record {
+    /// The time the transaction was committed, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// What the transaction did.
+    pub kind: RecordKind,
+    /// The transacted amount, in the transacted currency.
+    pub amount: Decimal,
+    /// The currency of the transacted amount.
+    pub currency: String,
+    /// The account's balance after the transaction, in the account's currency.
+    pub balance: Decimal,
+    /// The reference number of the transaction, or an empty string if it wasn't recorded.
+    pub reference: String,
+    /// The note attached to the transaction, if any.
+    pub memo: Option<String>,
+    /// The reference number of the transaction this one reversed, if it is a reversal.
+    pub reversal_of: Option<String>,
+    /// The reference number of the transaction that reversed this one, if it was reversed.
+    pub reversed_by: Option<String>,
+}
+impl Record {
+    /// Checks whether the transaction's reference number or memo contains a query, ignoring case.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+
+        self.reference.to_lowercase().contains(&query)
+            || self.memo.as_ref().is_some_and(|m| m.to_lowercase().contains(&query))
+    }
+}
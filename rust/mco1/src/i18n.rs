@@ -0,0 +1,2236 @@
+//! The catalog of the user-facing messages, in every supported language.
+//!
+//! Messages with `{}` placeholders are filled in with [`fill`].
+
+use std::{any::Any, error::Error, fmt, sync::OnceLock};
+
+use banking_and_currency_app::{
+    bank::BankError,
+    batch::RowError,
+    currency::{MAX_RATE, MIN_RATE},
+    rates::RateError,
+};
+
+use crate::output::money;
+use clap::ValueEnum;
+
+/// The languages the messages are available in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum)]
+pub enum Lang {
+    /// English.
+    #[default]
+    En,
+    /// Filipino.
+    Fil,
+}
+
+/// The text describing a transaction in the menu.
+pub struct TransactionText {
+    /// The title shown in the menu.
+    pub title: &'static str,
+    /// What the transaction does.
+    pub description: &'static str,
+    /// The inputs the user is prompted for, in order.
+    pub inputs: &'static [&'static str],
+}
+
+/// The user-facing messages in a language.
+pub struct Messages {
+    /// The text of each transaction, in the order they are listed in the menu.
+    pub transactions: [TransactionText; 41],
+    /// The header of the main menu.
+    pub select_transaction: &'static str,
+    /// The prompt for returning to the main menu.
+    pub back_to_menu: &'static str,
+    /// The notice that a transaction was cancelled.
+    pub cancelled: &'static str,
+    /// The prompt for printing the next page of a long output.
+    pub more: &'static str,
+    /// The prompt for an account's name.
+    pub account_name: &'static str,
+    /// The prompt for a registered account's name or number.
+    pub account_name_or_number: &'static str,
+    /// The prompt for the name or number of the account to transfer to.
+    pub recipient_name_or_number: &'static str,
+    /// The notice that an account was registered, filled with its name.
+    pub registered_account: &'static str,
+    /// An account's number, filled with it.
+    pub account_number: &'static str,
+    /// The prompt for an account's type.
+    pub account_type: &'static str,
+    /// The header of the types of accounts to choose from.
+    pub account_type_options: &'static str,
+    /// The titles of the types of accounts, in the order they are listed.
+    pub account_types: [&'static str; 3],
+    /// The names of the kinds of transactions in an account's history.
+    pub record_kinds: [&'static str; 9],
+    /// An account's type, filled with its title.
+    pub account_type_label: &'static str,
+    /// The prompt for a new account's joint owners.
+    pub joint_owners: &'static str,
+    /// The prompt for a new account's email address.
+    pub email: &'static str,
+    /// The prompt for a new account's phone number.
+    pub phone: &'static str,
+    /// An account's owners, filled with their names.
+    pub owners_label: &'static str,
+    /// The prompt for an account's new owners.
+    pub new_owners: &'static str,
+    /// The notice that an account's owners were replaced, filled with its name and the owners' names.
+    pub updated_owners: &'static str,
+    /// The prompt for the order to list accounts in.
+    pub sort_order: &'static str,
+    /// The prompt for the lowest balance of the listed accounts.
+    pub min_balance: &'static str,
+    /// The prompt for the type of the listed accounts.
+    pub type_filter: &'static str,
+    /// The headers of the table of accounts.
+    pub account_headers: [&'static str; 6],
+    /// The statuses of accounts, in the order they are declared.
+    pub account_statuses: [&'static str; 3],
+    /// The notice that no accounts were listed.
+    pub no_accounts: &'static str,
+    /// The header of an account's details.
+    pub account_details: &'static str,
+    /// An account's name, filled with it.
+    pub name_label: &'static str,
+    /// An account's balance, filled with the amount.
+    pub balance_label: &'static str,
+    /// An account's status, filled with it.
+    pub status_label: &'static str,
+    /// An account's opening date, filled with it.
+    pub opened_on: &'static str,
+    /// An account's email address, filled with it.
+    pub email_label: &'static str,
+    /// An account's phone number, filled with it.
+    pub phone_label: &'static str,
+    /// The placeholder for a detail that wasn't given.
+    pub not_given: &'static str,
+    /// The placeholder for a date from before it was recorded.
+    pub unknown_date: &'static str,
+    /// The prompt for a new account's PIN.
+    pub new_pin: &'static str,
+    /// The prompt for an account's PIN.
+    pub pin: &'static str,
+    /// A type of account's interest, filled with its title and annual interest rate.
+    pub account_type_rate: &'static str,
+    /// The notice that a withdrawal is penalized, filled with the penalty.
+    pub early_withdrawal_penalty: &'static str,
+    /// The notice of a fee charged along with a transaction.
+    pub fee_charged: &'static str,
+    /// The names of the reasons a fee can be charged, in the order they are listed.
+    pub fee_reasons: [&'static str; 4],
+    /// The prompt confirming to withdraw an account's remaining balance before closing it, filled with the balance.
+    pub confirm_close_remainder: &'static str,
+    /// The notice that an account was closed, filled with its name.
+    pub closed_account: &'static str,
+    /// The notice that an account's remaining balance was withdrawn, filled with the amount.
+    pub withdrew_remainder: &'static str,
+    /// The prompt for an account's new name.
+    pub new_account_name: &'static str,
+    /// The notice that an account was renamed, filled with its previous and new names.
+    pub renamed_account: &'static str,
+    /// The notice that an account was frozen, filled with its name.
+    pub froze_account: &'static str,
+    /// The notice that an account was unfrozen, filled with its name.
+    pub unfroze_account: &'static str,
+    /// The prompt for an account's overdraft limit.
+    pub overdraft_limit: &'static str,
+    /// An account's overdraft limit, filled with the amount.
+    pub overdraft_limit_label: &'static str,
+    /// The notice that an account's overdraft limit was set, filled with its name and the limit.
+    pub set_overdraft: &'static str,
+    /// The notice that the interest rate of an account was set, filled with the account's name and the rate.
+    pub set_interest_rate: &'static str,
+    /// The notice that how often an account's interest compounds was set, filled with the account's name and the
+    /// frequency.
+    pub set_compounding: &'static str,
+    /// The prompt for an account's daily withdrawal limit.
+    pub withdrawal_limit: &'static str,
+    /// The label of an account's daily withdrawal limit.
+    pub withdrawal_limit_label: &'static str,
+    /// The description of a daily withdrawal limit left to the bank's default.
+    pub default_limit: &'static str,
+    /// The label of what is left of an account's daily withdrawal limit.
+    pub daily_allowance: &'static str,
+    /// The notice that an account's daily withdrawal limit was set.
+    pub set_withdrawal_limit: &'static str,
+    /// The notice that an account's daily withdrawal limit was reset to the default.
+    pub reset_withdrawal_limit: &'static str,
+    /// The prompt for the minimum balance of a type of account.
+    pub minimum_amount: &'static str,
+    /// The prompt for the fee charged for a withdrawal leaving an account below its minimum balance.
+    pub breach_fee: &'static str,
+    /// The prompt for the fee charged monthly to accounts below their minimum balance.
+    pub monthly_fee: &'static str,
+    /// The confirmation that the minimum balance of a type of account was set.
+    pub set_minimum_balance: &'static str,
+    /// The notice that an account was charged the monthly below-minimum fee.
+    pub charged_fee: &'static str,
+    /// The notice that no account was charged at the end of a period.
+    pub no_fees_charged: &'static str,
+    /// The current balance of an account, filled with the amount.
+    pub current_balance: &'static str,
+    /// The updated balance of an account, filled with the amount.
+    pub updated_balance: &'static str,
+    /// The reference number of a committed transaction.
+    pub reference_number: &'static str,
+    /// The prompt for the optional memo of a transaction.
+    pub memo: &'static str,
+    /// The prompt for the reference number or memo to search an account's history for.
+    pub history_search: &'static str,
+    /// The currency of an account, filled with its code.
+    pub account_currency: &'static str,
+    /// The prompt for a currency's code.
+    pub currency: &'static str,
+    /// The prompt for the amount to deposit.
+    pub deposit_amount: &'static str,
+    /// The prompt for the amount to withdraw.
+    pub withdraw_amount: &'static str,
+    /// The prompt for the amount to transfer.
+    pub transfer_amount: &'static str,
+    /// The confirmation that an amount was transferred to another account.
+    pub transferred: &'static str,
+    /// The confirmation that a deposit was undone.
+    pub undid_deposit: &'static str,
+    /// The confirmation that a withdrawal was undone.
+    pub undid_withdrawal: &'static str,
+    /// The confirmation that a transfer was undone.
+    pub undid_transfer: &'static str,
+    /// The confirmation that an exchange rate update was undone.
+    pub undid_rate: &'static str,
+    /// The confirmation that a spread update was undone.
+    pub undid_spread: &'static str,
+    /// The prompt for the number of days between a scheduled transaction's runs.
+    pub interval_days: &'static str,
+    /// The notice that a transaction was scheduled.
+    pub scheduled: &'static str,
+    /// The prompt for the ID of a schedule to cancel.
+    pub schedule_to_cancel: &'static str,
+    /// The notice that a schedule was cancelled.
+    pub cancelled_schedule: &'static str,
+    /// The headers of the table of scheduled transactions.
+    pub schedule_headers: [&'static str; 8],
+    /// The names of the transactions that can be scheduled, in the order they are listed.
+    pub scheduled_transactions: [&'static str; 2],
+    /// The interval between a scheduled transaction's runs.
+    pub interval: &'static str,
+    /// The names of the statuses of a schedule, active then cancelled.
+    pub schedule_statuses: [&'static str; 2],
+    /// The notice that no transactions are scheduled.
+    pub no_schedules: &'static str,
+    /// The notice that no alerts on the exchange rates were added.
+    pub no_alerts: &'static str,
+    /// The headers of the table of rate alerts.
+    pub alert_headers: [&'static str; 4],
+    /// The names of the statuses of a rate alert, active then removed.
+    pub alert_statuses: [&'static str; 2],
+    /// The prompt for whether to add or remove a rate alert, if either.
+    pub add_or_remove_alert: &'static str,
+    /// The prompt for the side of the threshold a rate alert waits for.
+    pub alert_condition: &'static str,
+    /// The prompt for the threshold of a rate alert.
+    pub alert_threshold: &'static str,
+    /// The prompt for the ID of the rate alert to remove.
+    pub alert_to_remove: &'static str,
+    /// The notice that a rate alert was added, filled with its ID.
+    pub added_alert: &'static str,
+    /// The notice that a rate alert was removed, filled with its ID.
+    pub removed_alert: &'static str,
+    /// The notice that a change in an exchange rate triggered an alert, filled with the alert's ID, the currency, the
+    /// rate, and the alert's condition.
+    pub alert_triggered: &'static str,
+    /// The notice that a scheduled transaction ran.
+    pub ran_schedule: &'static str,
+    /// The notice that a scheduled transaction failed to run.
+    pub failed_schedule: &'static str,
+    /// The notice that no scheduled transactions were due.
+    pub no_runs_due: &'static str,
+    /// The prompt for the path of a CSV file of deposits.
+    pub csv_path: &'static str,
+    /// The error when a CSV file of deposits can't be read, filled with the reason.
+    pub failed_csv_read: &'static str,
+    /// The error when a CSV file can't be written, filled with the reason.
+    pub failed_csv_write: &'static str,
+    /// The notice that a CSV file of deposits has no rows.
+    pub no_bulk_rows: &'static str,
+    /// The column titles of the table of deposited rows.
+    pub bulk_headers: [&'static str; 4],
+    /// The result of a valid row in a preview of deposits.
+    pub bulk_valid: &'static str,
+    /// The summary of a preview of deposits, filled with the number of valid and invalid rows.
+    pub bulk_preview: &'static str,
+    /// The summary of deposited rows, filled with the number of deposited and failed rows.
+    pub bulk_summary: &'static str,
+    /// The prompt confirming to deposit the valid rows of a CSV file.
+    pub confirm_bulk_deposit: &'static str,
+    /// The notice that a transaction was held for approval, filled with its ID.
+    pub held_for_approval: &'static str,
+    /// The notice that no transactions were ever held for approval.
+    pub no_pending: &'static str,
+    /// The column titles of the table of held transactions.
+    pub pending_headers: [&'static str; 7],
+    /// The names of the kinds of held transactions, in the order they are listed.
+    pub held_transactions: [&'static str; 2],
+    /// The names of the stages of a held transaction's approval, in the order they are listed.
+    pub approval_statuses: [&'static str; 3],
+    /// The prompt for the ID of a held transaction to decide, which can be left blank.
+    pub pending_to_decide: &'static str,
+    /// The prompt for approving or rejecting a held transaction.
+    pub approve_or_reject: &'static str,
+    /// The confirmation that a held transaction was approved, filled with its ID.
+    pub approved_pending: &'static str,
+    /// The confirmation that a held transaction was rejected, filled with its ID.
+    pub rejected_pending: &'static str,
+    /// The prompt for the reference number of a transaction to reverse.
+    pub reference_to_reverse: &'static str,
+    /// The confirmation that a transaction was reversed, filled with its reference number and the reversal's.
+    pub reversed_transaction: &'static str,
+    /// The notice that a transaction was skipped for its already performed idempotency key, filled with the key and the
+    /// reference number of the transaction performed with it.
+    pub skipped_transaction: &'static str,
+    /// The note on a reversal in an account's history, filled with the reversed transaction's reference number.
+    pub reversal_of: &'static str,
+    /// The note on a reversed transaction in an account's history, filled with the reversal's reference number.
+    pub reversed_by: &'static str,
+    /// The header of a large withdrawal's summary.
+    pub withdrawal_summary: &'static str,
+    /// The amount of a withdrawal or exchange, filled with the amount.
+    pub amount: &'static str,
+    /// The value of a withdrawal in the base currency, filled with its code and the amount.
+    pub value_in: &'static str,
+    /// The balance after a withdrawal, filled with the amount.
+    pub resulting_balance: &'static str,
+    /// The prompt for confirming a large withdrawal.
+    pub confirm_withdrawal: &'static str,
+    /// The warning that a conversion uses an out-of-date exchange rate, filled with the currency's code and when its
+    /// rate was last updated.
+    pub stale_rate_warning: &'static str,
+    /// The warning that a conversion uses an exchange rate that was never updated, filled with the currency's code.
+    pub never_updated_rate: &'static str,
+    /// The prompt confirming a conversion using an out-of-date exchange rate.
+    pub use_stale_rate: &'static str,
+    /// The header of the currencies to exchange from.
+    pub source_currency_options: &'static str,
+    /// The prompt for the currency to exchange from.
+    pub source_currency: &'static str,
+    /// The prompt for the amount to exchange.
+    pub source_amount: &'static str,
+    /// The header of the currencies to exchange to.
+    pub exchange_currency_options: &'static str,
+    /// The prompt for the currency to exchange to.
+    pub exchange_currency: &'static str,
+    /// The prompt for the date whose exchange rates are converted at, instead of exchanging at the current ones.
+    pub rate_date: &'static str,
+    /// The error when a date isn't written as YYYY-MM-DD.
+    pub invalid_date: &'static str,
+    /// The exchanged amount, filled with the amount.
+    pub exchange_amount: &'static str,
+    /// The amount an amount in a currency is worth in another, filled with the amounts.
+    pub converted: &'static str,
+    /// The amount an amount in a currency was worth in another on a date, filled with the date and the amounts.
+    pub converted_as_of: &'static str,
+    /// The notice of the fee deducted from an exchanged amount.
+    pub exchange_fee: &'static str,
+    /// The header of the breakdown of an exchange, before it is confirmed.
+    pub exchange_summary: &'static str,
+    /// The rate an exchange is made at, filled with the source currency, the rate, and the currency exchanged to.
+    pub exchange_rate_label: &'static str,
+    /// The fee deducted from an amount before it is exchanged, filled with the fee.
+    pub fee_label: &'static str,
+    /// The bank's income from the spread of an exchange, filled with the income.
+    pub spread_label: &'static str,
+    /// The amount received from an exchange, filled with the amount.
+    pub amount_received: &'static str,
+    /// The prompt for confirming an exchange after its breakdown is shown.
+    pub confirm_exchange: &'static str,
+    /// The header of the fees collected by the bank.
+    pub revenue_header: &'static str,
+    /// The header of the bank's income from the exchange spreads.
+    pub spread_income_header: &'static str,
+    /// The notice that the bank hasn't collected any fees or spread income.
+    pub no_revenue: &'static str,
+    /// The prompt for exchanging another amount.
+    pub convert_another: &'static str,
+    /// The column titles of the exchange rate table.
+    pub rate_headers: [&'static str; 4],
+    /// The column titles of the exchange spread table.
+    pub spread_headers: [&'static str; 5],
+    /// The column titles of the current exchange rate table.
+    pub current_rate_headers: [&'static str; 4],
+    /// The last-updated time of an exchange rate that was never updated.
+    pub never_updated: &'static str,
+    /// The prompt for the foreign currency whose rate to record.
+    pub select_foreign_currency: &'static str,
+    /// The prompt for a currency's exchange rate.
+    pub exchange_rate: &'static str,
+    /// The prompt for a currency's spread.
+    pub spread_percent: &'static str,
+    /// The notice that an exchange rate was recorded, filled with the currency's code.
+    pub recorded_rate: &'static str,
+    /// The notice that a currency's spread was set, filled with the currency's code and the spread.
+    pub set_spread: &'static str,
+    /// The header of the providers the exchange rates can be refreshed from.
+    pub rate_provider_options: &'static str,
+    /// The names of the providers the exchange rates can be refreshed from, manual entry first and the live provider
+    /// last.
+    pub rate_providers: [&'static str; 3],
+    /// The prompt for the provider to refresh the exchange rates from.
+    pub select_rate_provider: &'static str,
+    /// The prompt for a currency's exchange rate when entering every rate, filled with the currency's title.
+    pub currency_rate: &'static str,
+    /// The confirmation that exchange rates were refreshed, filled with the number of refreshed rates.
+    pub refreshed_rates: &'static str,
+    /// The notice that the exchange rates were exported, filled with how many there are and the file's path.
+    pub exported_rates: &'static str,
+    /// The notice that an interest schedule was exported, filled with the number of days and the file's path.
+    pub exported_interest: &'static str,
+    /// The notice that the provider didn't quote any exchangeable currency.
+    pub no_refreshed_rates: &'static str,
+    /// The prompt for the number of most recent days to list a currency's rates from.
+    pub rate_window: &'static str,
+    /// The header of a currency's recent exchange rates, filled with its code and the number of days.
+    pub rate_history_header: &'static str,
+    /// The column titles of the exchange rate history table.
+    pub rate_history_headers: [&'static str; 2],
+    /// The notice that a currency has no rates recorded within the days, filled with its code and the number of days.
+    pub no_rate_history: &'static str,
+    /// The lowest, highest, and average of a currency's recent rates, filled with each.
+    pub rate_stats: &'static str,
+    /// The prompt for the code of a currency to register, or its name if it is listed in ISO 4217.
+    pub currency_code: &'static str,
+    /// The description of a currency listed in ISO 4217, filled with its title, number of decimal places, and symbol.
+    pub iso_currency: &'static str,
+    /// The prompt for the display name of a currency to register.
+    pub currency_name: &'static str,
+    /// The confirmation that a currency was registered, filled with its title and exchange rate.
+    pub added_currency: &'static str,
+    /// The annual interest rate, filled with the percentage.
+    pub interest_rate: &'static str,
+    /// The effective annual rate of an interest rate as it compounds, filled with the percentage.
+    pub effective_rate: &'static str,
+    /// The prompt for whether a rate is a nominal annual rate or an annual yield.
+    pub rate_kind: &'static str,
+    /// The prompt for the percentage of a nominal annual rate or annual yield.
+    pub yield_rate: &'static str,
+    /// The nominal annual rate, filled with the percentage.
+    pub apr_label: &'static str,
+    /// The effective annual yield, filled with the percentage.
+    pub apy_label: &'static str,
+    /// The header of the interest rates earned by bands of balances.
+    pub interest_tiers: &'static str,
+    /// A band of balances' interest rate, filled with the percentage and the balance its balances are below.
+    pub interest_tier: &'static str,
+    /// The interest rate of the band of the remaining balances, filled with the percentage.
+    pub interest_tier_rest: &'static str,
+    /// The label of how often an account's interest compounds, filled with the frequency.
+    pub compounding_label: &'static str,
+    /// The prompt for the annual interest rate percentage earned by an account.
+    pub interest_rate_percent: &'static str,
+    /// The prompt for the number of days to calculate interest for.
+    pub day_count: &'static str,
+    /// The prompt for the number of days to post interest for.
+    pub posting_days: &'static str,
+    /// The notice that interest was posted to an account, filled with the interest, the number of days, and the
+    /// account's name.
+    pub posted_interest: &'static str,
+    /// The notice that tax was withheld from the posted interest, filled with the tax and the net interest.
+    pub withheld_tax: &'static str,
+    /// The notice that interest was charged for an account's overdrawn balance, filled with the charge.
+    pub posted_overdraft_charge: &'static str,
+    /// The prompt for whether interest is calculated simply or compounded.
+    pub interest_mode: &'static str,
+    /// The prompt for how often interest is added to the balance.
+    pub compounding: &'static str,
+    /// The prompt for whether to export an interest schedule to a CSV file instead of printing it.
+    pub export_interest: &'static str,
+    /// The prompt for an amount planned to be deposited regularly over an interest schedule, if any.
+    pub planned_deposit: &'static str,
+    /// The column titles of the interest schedule.
+    pub interest_headers: [&'static str; 3],
+    /// The headers of an account's history.
+    pub history_headers: [&'static str; 6],
+    /// The notice that an account has no transactions yet.
+    pub no_history: &'static str,
+    /// The column title of the interest schedule's overdraft charges.
+    pub overdraft_charge_header: &'static str,
+    /// The header of the tax withheld from the daily interest.
+    pub tax_header: &'static str,
+    /// The column title of the interest schedule's planned deposits.
+    pub deposit_header: &'static str,
+    /// The header of the daily interest after its tax is withheld.
+    pub net_interest_header: &'static str,
+    /// The total interest of a schedule, filled with the amount.
+    pub total_interest: &'static str,
+    /// The total tax withheld from the interest, filled with the amount.
+    pub total_tax: &'static str,
+    /// The total interest after its tax is withheld, filled with the amount.
+    pub net_interest: &'static str,
+    /// The total overdraft charge of a schedule, filled with the amount.
+    pub total_overdraft_charge: &'static str,
+    /// The total planned deposits of a schedule, filled with the amount.
+    pub total_deposits: &'static str,
+    /// The final balance of a schedule, filled with the amount.
+    pub final_balance: &'static str,
+    /// The prompt for a new journal's passphrase.
+    pub set_passphrase: &'static str,
+    /// The prompt for an encrypted journal's passphrase.
+    pub passphrase: &'static str,
+    /// The header of the available backups.
+    pub backup_options: &'static str,
+    /// The prompt for the backup to restore.
+    pub select_backup: &'static str,
+    /// The notice that a backup was saved, filled with its path.
+    pub saved_backup: &'static str,
+    /// The notice that the bank's state has no broken invariants.
+    pub no_violations: &'static str,
+    /// The error that demo data can only populate a bank without accounts.
+    pub demo_needs_empty_bank: &'static str,
+    /// The notice that the bank was populated with demo data, filled with the seed and the demo accounts' PIN.
+    pub populated_demo: &'static str,
+    /// The notice of a bank replayed up to a number of its journal entries, filled with the number.
+    pub replayed_entries: &'static str,
+    /// The notice of a bank replayed up to a time, filled with the time.
+    pub replayed_time: &'static str,
+    /// The summary of a stress test's transactions, filled with their number, the number of accounts, the seconds they
+    /// took, the number per second, and the number rejected.
+    pub stress_transactions: &'static str,
+    /// The timings of a stress test, filled with the seconds registering the accounts and replaying the journal took.
+    pub stress_timings: &'static str,
+    /// The error when replaying a stress test's journal rebuilt a different state.
+    pub inconsistent_replay: &'static str,
+    /// The notice that the bank is being served, filled with the address it is served at.
+    #[cfg(feature = "serve")]
+    pub serving: &'static str,
+    /// The notice that the bank is being served over gRPC, filled with the address it is served at.
+    #[cfg(feature = "grpc")]
+    pub serving_grpc: &'static str,
+    /// The notice that the interactive menu is being served over TCP, filled with the address it is served at.
+    pub serving_telnet: &'static str,
+    /// The notice that a backup was restored, filled with when it was created.
+    pub restored_backup: &'static str,
+    /// The header of the available profiles.
+    pub profiles: &'static str,
+    /// The prompt for a profile's name.
+    pub profile_name: &'static str,
+    /// The notice that the profile was switched, filled with its name.
+    pub switched_profile: &'static str,
+    /// The error when the profile is switched in a session sharing its bank with others.
+    pub shared_profile: &'static str,
+    /// The header of the transactions' descriptions.
+    pub transactions_header: &'static str,
+    /// A transaction's aliases, filled with the list.
+    pub aliases: &'static str,
+    /// A transaction's inputs, filled with the list.
+    pub inputs: &'static str,
+    /// How to cancel a transaction, filled with the cancel keywords.
+    pub cancel_hint: &'static str,
+    /// The header of the exchangeable currencies.
+    pub currencies: &'static str,
+    /// The header of the current configuration.
+    pub configuration: &'static str,
+    /// The base currency, filled with its code.
+    pub base_currency: &'static str,
+    /// The current profile, filled with its name.
+    pub profile: &'static str,
+    /// The error when no transaction has the chosen ID.
+    pub unknown_transaction_id: &'static str,
+    /// The error when no transaction has the chosen name.
+    pub unknown_transaction_name: &'static str,
+    /// The error when more than one transaction has the chosen name.
+    pub ambiguous_transaction: &'static str,
+    /// The error when a prompt's retry limit is reached.
+    pub too_many_retries: &'static str,
+    /// The error when a yes or no answer is neither.
+    pub invalid_yes_no: &'static str,
+    /// The error when an ID isn't a positive whole number.
+    pub invalid_id: &'static str,
+    /// The error for an unknown account type.
+    pub invalid_account_type: &'static str,
+    /// The error for a PIN that isn't 4 to 6 digits.
+    pub invalid_pin: &'static str,
+    /// The error for an unknown order to list accounts in.
+    pub invalid_sort_order: &'static str,
+    /// The error for an interest mode that is neither simple nor compound.
+    pub invalid_interest_mode: &'static str,
+    /// The error for a compounding frequency that isn't daily, monthly, quarterly, or annually.
+    pub invalid_compounding: &'static str,
+    /// The error for a rate type that isn't apr or apy.
+    pub invalid_rate_kind: &'static str,
+    /// The error for a rate that isn't above -100%.
+    pub invalid_yield_rate: &'static str,
+    /// The error for a PIN that isn't the account's.
+    pub incorrect_pin: &'static str,
+    /// The error when a number isn't a positive whole number.
+    pub invalid_number: &'static str,
+    /// The error when an amount isn't a number.
+    pub invalid_amount: &'static str,
+    /// The error when a deposit amount isn't a number.
+    pub invalid_deposit_amount: &'static str,
+    /// The error when a withdraw amount isn't a number.
+    pub invalid_withdraw_amount: &'static str,
+    /// The error for a transfer amount that isn't a number.
+    pub invalid_transfer_amount: &'static str,
+    /// The error for a transfer from and to the same account.
+    pub self_transfer: &'static str,
+    /// The error for undoing without a transaction left to undo.
+    pub nothing_to_undo: &'static str,
+    /// The error when the interval between a scheduled transaction's runs is shorter than a day.
+    pub invalid_interval: &'static str,
+    /// The error for a row of deposits lacking an account or amount.
+    pub malformed_row: &'static str,
+    /// The error for a row of deposits whose amount isn't a positive number.
+    pub invalid_row_amount: &'static str,
+    /// The error for an answer that is neither approve nor reject.
+    pub invalid_approve_reject: &'static str,
+    /// The error for an answer that is neither add nor remove.
+    pub invalid_add_remove: &'static str,
+    /// The error for a rate alert condition that is neither at least nor at most.
+    pub invalid_alert_condition: &'static str,
+    /// The error when no active rate alert has the given ID.
+    pub alert_not_found: &'static str,
+    /// The error when no active schedule has the given ID.
+    pub schedule_not_found: &'static str,
+    /// The error for a transaction that must be held for approval.
+    pub approval_required: &'static str,
+    /// The error for an unknown or already decided held transaction.
+    pub pending_not_found: &'static str,
+    /// The error for a reference number matching no reversible transaction.
+    pub transaction_not_found: &'static str,
+    /// The error for reversing a transaction that was already reversed or undone.
+    pub already_reversed: &'static str,
+    /// The error when no currency has the chosen code.
+    pub unknown_currency_code: &'static str,
+    /// The error when a currency listed in ISO 4217 isn't exchangeable yet, filled with its title.
+    pub unexchangeable_currency: &'static str,
+    /// The error when no currency has the chosen ID.
+    pub unknown_currency_id: &'static str,
+    /// The error when a currency with the same code is already exchangeable.
+    pub duplicate_currency: &'static str,
+    /// The error when a currency code isn't made of three letters.
+    pub invalid_currency_code: &'static str,
+    /// The error when an exchange rate isn't a number within the allowed range, filled with its least and greatest.
+    pub invalid_rate: &'static str,
+    /// The error that an exchange rate is outside its currency's configured range, filled with the currency's code and
+    /// the lowest and highest rates.
+    pub rate_out_of_bounds: &'static str,
+    /// The warning that a new exchange rate differs sharply from the previous one, filled with the currency's code and
+    /// the percentage of the difference.
+    pub sharp_rate_change: &'static str,
+    /// The error for an amount that isn't positive.
+    pub non_positive_amount: &'static str,
+    /// The error for an amount that isn't positive or is above the maximum, filled with the maximum.
+    pub amount_above_max: &'static str,
+    /// The error when a spread isn't a percentage from zero to less than a hundred.
+    pub invalid_spread: &'static str,
+    /// The error when a conversion would use an out-of-date exchange rate, filled with the currency's code.
+    pub stale_rate: &'static str,
+    /// The error when no exchange rate of a currency was recorded by the date a conversion is made as of, filled with
+    /// the currency.
+    pub no_rate_at: &'static str,
+    /// The error when no account has the chosen name.
+    pub unknown_account: &'static str,
+    /// The error for a transaction against a closed account.
+    pub account_closed: &'static str,
+    /// The error for depositing to or withdrawing from a frozen account.
+    pub account_frozen: &'static str,
+    /// The error for closing an account with a balance left.
+    pub nonzero_balance: &'static str,
+    /// The error for leaving an account without an owner.
+    pub no_owners: &'static str,
+    /// The error for a malformed email address.
+    pub invalid_email: &'static str,
+    /// The error for a malformed phone number.
+    pub invalid_phone: &'static str,
+    /// The error for a negative overdraft limit.
+    pub invalid_overdraft_limit: &'static str,
+    /// The error for an interest rate that is negative.
+    pub invalid_interest_rate: &'static str,
+    /// The error when no days passed since an account's interest was last posted.
+    pub no_interest_due: &'static str,
+    /// The error when a withdrawal is more than what is left of the account's daily limit.
+    pub daily_limit_exceeded: &'static str,
+    /// The error when a daily withdrawal limit is negative.
+    pub invalid_withdrawal_limit: &'static str,
+    /// The error when an exchanged amount doesn't cover its fee.
+    pub fee_exceeds_amount: &'static str,
+    /// The error for a withdrawal leaving an account below its minimum balance.
+    pub below_minimum_balance: &'static str,
+    /// The error for a negative minimum balance or fee.
+    pub invalid_minimum_balance: &'static str,
+    /// The error when an account already has the chosen name.
+    pub duplicate_account: &'static str,
+    /// The error when a withdrawal is greater than the balance.
+    pub insufficient_balance: &'static str,
+    /// The error when the journal can't be written to, filled with the cause.
+    pub failed_journal_write: &'static str,
+    /// The error when the journal can't be opened, filled with the cause.
+    pub failed_journal_open: &'static str,
+    /// The error when a journal's passphrase is incorrect.
+    pub incorrect_passphrase: &'static str,
+    /// The error when a backup can't be accessed, filled with the cause.
+    pub failed_backup: &'static str,
+    /// The error when the backups can't be listed, filled with the cause.
+    pub failed_backup_list: &'static str,
+    /// The error when the rate provider can't be reached, filled with the cause.
+    pub failed_rate_request: &'static str,
+    /// The error when the rate provider's response can't be read.
+    pub malformed_rates: &'static str,
+    /// The error when a row of a CSV file of rates is malformed, filled with its line.
+    pub malformed_rate_row: &'static str,
+    /// The error when a row of a CSV file of rates is of an unknown currency, filled with its line and the currency's
+    /// code.
+    pub unknown_rate_currency: &'static str,
+    /// The error when a row of a CSV file of rates has a rate outside the allowed range, filled with its line and the
+    /// range's least and greatest.
+    pub invalid_rate_row: &'static str,
+    /// The error when there are no backups to restore.
+    pub no_backups: &'static str,
+    /// The error when no backup has the chosen ID.
+    pub unknown_backup: &'static str,
+    /// The error when the profiles can't be listed, filled with the cause.
+    pub failed_profile_list: &'static str,
+    /// The error when a profile's name has invalid characters.
+    pub invalid_profile_name: &'static str,
+    /// The error when a script's line has unterminated quotes.
+    pub unterminated_quotes: &'static str,
+    /// The error of a script's line, filled with its number and the error.
+    pub script_line_error: &'static str,
+    /// The error when a script stops at a failed line, filled with its number.
+    pub script_stopped: &'static str,
+    /// The error when a script's commands failed, filled with their number.
+    pub script_failures: &'static str,
+    /// The error when a script generates completion scripts.
+    pub completions_in_script: &'static str,
+    /// The error when a script tries to serve the interactive menu.
+    pub telnet_in_script: &'static str,
+    /// The error when a script tries to serve the bank.
+    #[cfg(feature = "serve")]
+    pub serve_in_script: &'static str,
+    /// The error when a script tries to serve the bank over gRPC.
+    #[cfg(feature = "grpc")]
+    pub grpc_in_script: &'static str,
+    /// The error when a planned deposit lacks its amount or a positive number of days between deposits.
+    #[cfg(feature = "serve")]
+    pub invalid_contribution: &'static str,
+    /// The titles of the transactions that can be performed from the form.
+    #[cfg(feature = "tui")]
+    pub tui_forms: [&'static str; 4],
+    /// The label of an account's name.
+    #[cfg(feature = "tui")]
+    pub tui_account_name: &'static str,
+    /// The label of the TUI form's account type field.
+    #[cfg(feature = "tui")]
+    pub tui_account_type: &'static str,
+    /// The label of the TUI form's PIN field.
+    #[cfg(feature = "tui")]
+    pub tui_pin: &'static str,
+    /// The label of a currency.
+    #[cfg(feature = "tui")]
+    pub tui_currency: &'static str,
+    /// The label of an amount.
+    #[cfg(feature = "tui")]
+    pub tui_amount: &'static str,
+    /// The label of an exchange rate.
+    #[cfg(feature = "tui")]
+    pub tui_exchange_rate: &'static str,
+    /// The title of the list of accounts.
+    #[cfg(feature = "tui")]
+    pub tui_accounts: &'static str,
+    /// The title of the exchange rate table.
+    #[cfg(feature = "tui")]
+    pub tui_exchange_rates: &'static str,
+    /// The title of the transaction form.
+    #[cfg(feature = "tui")]
+    pub tui_transaction: &'static str,
+    /// The account selected for the form, filled with its name.
+    #[cfg(feature = "tui")]
+    pub tui_account: &'static str,
+    /// The placeholder of an unselected account.
+    #[cfg(feature = "tui")]
+    pub tui_none_selected: &'static str,
+    /// The error when no account is selected.
+    #[cfg(feature = "tui")]
+    pub tui_select_account: &'static str,
+    /// The help line listing the keys.
+    #[cfg(feature = "tui")]
+    pub tui_keys: &'static str,
+}
+
+/// The messages in English.
+pub const ENGLISH: Messages = Messages {
+    transactions: [
+        TransactionText {
+            title: "Register Account Name",
+            description: "Registers a new savings, checking, or time deposit account with a zero balance.",
+            inputs: &[
+                "Account Name",
+                "Joint Owners (comma-separated)",
+                "Account Type (ID or name)",
+                "Interest Rate (%)",
+                "Email (optional)",
+                "Phone (optional)",
+                "PIN",
+            ],
+        },
+        TransactionText {
+            title: "Deposit Amount",
+            description: "Deposits an amount in any currency to an account.",
+            inputs: &["Account Name or Number", "PIN", "Currency (code)", "Deposit Amount"],
+        },
+        TransactionText {
+            title: "Withdraw Amount",
+            description: "Withdraws an amount in any currency from an account, if its balance is enough.",
+            inputs: &["Account Name or Number", "PIN", "Currency (code)", "Withdraw Amount"],
+        },
+        TransactionText {
+            title: "Transfer Amount",
+            description: "Transfers an amount from one account to another, converting it if their currencies differ.",
+            inputs: &[
+                "Account Name or Number",
+                "PIN",
+                "Recipient Account Name or Number",
+                "Amount",
+            ],
+        },
+        TransactionText {
+            title: "Recurring Deposit",
+            description: "Schedules an amount in any currency to be deposited to an account every number of days.",
+            inputs: &[
+                "Account Name or Number",
+                "PIN",
+                "Currency (code)",
+                "Deposit Amount",
+                "Interval (days)",
+            ],
+        },
+        TransactionText {
+            title: "Standing Order",
+            description: "Schedules an amount to be transferred from one account to another every number of days.",
+            inputs: &[
+                "Account Name or Number",
+                "PIN",
+                "Recipient Account Name or Number",
+                "Amount",
+                "Interval (days)",
+            ],
+        },
+        TransactionText {
+            title: "Scheduled Transactions",
+            description: "Lists the scheduled transactions and their next runs, optionally cancelling one.",
+            inputs: &["Schedule to Cancel (ID, optional)"],
+        },
+        TransactionText {
+            title: "Bulk Deposit",
+            description: "Deposits every row of a CSV file of accounts, amounts, and currencies after previewing them.",
+            inputs: &["CSV File", "Apply Deposits (Y/N)"],
+        },
+        TransactionText {
+            title: "Approval Queue",
+            description: "Lists the large withdrawals and transfers held for approval, optionally approving or rejecting one.",
+            inputs: &["Transaction to Decide (ID, optional)", "Approve or Reject (A/R)"],
+        },
+        TransactionText {
+            title: "Currency Exchange",
+            description: "Exchanges an amount from one currency to another after breaking it down, or converts it at a past date's rates.",
+            inputs: &[
+                "Source Currency (ID)",
+                "Source Amount",
+                "Exchange Currency (ID)",
+                "Rate Date (YYYY-MM-DD, optional)",
+                "Confirm Exchange (Y/N)",
+            ],
+        },
+        TransactionText {
+            title: "Show Exchange Rates",
+            description: "Lists the current exchange rates of the foreign currencies, with their inverses and when they were last updated.",
+            inputs: &[],
+        },
+        TransactionText {
+            title: "Record Exchange Rates",
+            description: "Records the value of a foreign currency in the base currency.",
+            inputs: &["Foreign Currency (ID)", "Exchange Rate"],
+        },
+        TransactionText {
+            title: "Set Exchange Spread",
+            description: "Sets the spread between the rates the bank buys and sells a foreign currency at.",
+            inputs: &["Foreign Currency (ID)", "Spread (%)"],
+        },
+        TransactionText {
+            title: "Refresh Exchange Rates",
+            description: "Refreshes the rates of every foreign currency at once, from a CSV file, a live provider, or by entering each.",
+            inputs: &["Rate Provider (ID)", "Exchange Rates"],
+        },
+        TransactionText {
+            title: "Exchange Rate History",
+            description: "Lists a foreign currency's recent exchange rates with their lowest, highest, and average.",
+            inputs: &["Currency", "Number of Days"],
+        },
+        TransactionText {
+            title: "Rate Alerts",
+            description: "Lists the alerts on the exchange rates, optionally adding one that notifies when a rate reaches a threshold or removing one.",
+            inputs: &[
+                "Add or Remove (A/R, optional)",
+                "Currency",
+                "Condition (>= or <=)",
+                "Threshold",
+                "Alert to Remove (ID)",
+            ],
+        },
+        TransactionText {
+            title: "Export Exchange Rates",
+            description: "Writes the current exchange rates to a CSV file, which the rates can be refreshed from later.",
+            inputs: &["CSV File"],
+        },
+        TransactionText {
+            title: "Add Currency",
+            description: "Registers a new currency with its exchange rate, making it available for deposits, withdrawals, and exchanges.",
+            inputs: &["Currency Code or Name", "Currency Name", "Exchange Rate"],
+        },
+        TransactionText {
+            title: "Undo",
+            description: "Undoes the most recent deposit, withdrawal, transfer, or exchange rate update.",
+            inputs: &[],
+        },
+        TransactionText {
+            title: "Reverse Transaction",
+            description: "Reverses any deposit, withdrawal, transfer, or fee by its reference number, linking both in history.",
+            inputs: &["Reference Number"],
+        },
+        TransactionText {
+            title: "Show Interest Amount",
+            description: "Shows the daily interest an account earns over a number of days, with any planned deposits.",
+            inputs: &[
+                "Account Name or Number",
+                "PIN",
+                "Total Number of Days",
+                "Interest Mode (simple/compound)",
+                "Compounding (daily/monthly/quarterly/annually)",
+                "Planned Deposit (optional)",
+                "Interval in Days (if depositing)",
+                "Export to a CSV File (Y/N)",
+                "CSV File (if exported)",
+            ],
+        },
+        TransactionText {
+            title: "Post Interest",
+            description: "Credits the interest an account earned over a number of days, or since it was last posted, to its balance.",
+            inputs: &["Account Name or Number", "Total Number of Days (optional)"],
+        },
+        TransactionText {
+            title: "APR and APY Calculator",
+            description: "Converts between an annual percentage rate and the yield it compounds to.",
+            inputs: &[
+                "Rate Type (apr/apy)",
+                "Rate (%)",
+                "Compounding (daily/monthly/quarterly/annually)",
+            ],
+        },
+        TransactionText {
+            title: "Close Account",
+            description: "Closes an account with a zero balance, or after withdrawing the rest, but keeps its record.",
+            inputs: &["Account Name or Number", "PIN"],
+        },
+        TransactionText {
+            title: "Rename Account",
+            description: "Renames an account, keeping its balance, currency, and history.",
+            inputs: &["Account Name or Number", "PIN", "New Account Name"],
+        },
+        TransactionText {
+            title: "Freeze or Unfreeze Account",
+            description: "Freezes an account, rejecting its deposits and withdrawals, or unfreezes a frozen account.",
+            inputs: &["Account Name or Number"],
+        },
+        TransactionText {
+            title: "Set Overdraft Limit",
+            description: "Sets how far below zero withdrawals can push an account's balance.",
+            inputs: &["Account Name or Number", "Overdraft Limit"],
+        },
+        TransactionText {
+            title: "Set Daily Withdrawal Limit",
+            description: "Sets the most that can be withdrawn from an account each day, or resets it to the default.",
+            inputs: &["Account Name or Number", "Daily Withdrawal Limit (optional)"],
+        },
+        TransactionText {
+            title: "Set Interest Rate",
+            description: "Sets the annual interest rate an account earns, instead of its type's.",
+            inputs: &["Account Name or Number", "Interest Rate (%)"],
+        },
+        TransactionText {
+            title: "Set Compounding",
+            description: "Sets how often the interest an account earns is added to its balance.",
+            inputs: &["Account Name or Number", "Compounding Frequency"],
+        },
+        TransactionText {
+            title: "Set Minimum Balance",
+            description: "Sets the balance a type of account must maintain and the fees for falling below it.",
+            inputs: &[
+                "Account Type",
+                "Minimum Balance",
+                "Below-Minimum Withdrawal Fee",
+                "Monthly Below-Minimum Fee",
+            ],
+        },
+        TransactionText {
+            title: "End Period",
+            description: "Charges the monthly fee to every account below its minimum balance.",
+            inputs: &[],
+        },
+        TransactionText {
+            title: "Bank Revenue",
+            description: "Shows the fees the bank has collected in each currency.",
+            inputs: &[],
+        },
+        TransactionText {
+            title: "Edit Account Owners",
+            description: "Replaces the owners of an account, any of whom can transact with it.",
+            inputs: &["Account Name or Number", "PIN", "Owners (comma-separated)"],
+        },
+        TransactionText {
+            title: "List Accounts",
+            description: "Lists the registered accounts by name or balance, optionally only those of a minimum balance or type.",
+            inputs: &[
+                "Sort By (name or balance)",
+                "Minimum Balance (optional)",
+                "Account Type (optional)",
+            ],
+        },
+        TransactionText {
+            title: "Account Details",
+            description: "Shows an account's number, owners, type, balance, opening date, and contact details.",
+            inputs: &["Account Name or Number", "PIN"],
+        },
+        TransactionText {
+            title: "View History",
+            description: "Pages through an account's deposits, withdrawals, and fees, from newest to oldest.",
+            inputs: &["Account Name or Number", "PIN"],
+        },
+        TransactionText {
+            title: "Backup Bank State",
+            description: "Snapshots every account and exchange rate to a new backup file.",
+            inputs: &[],
+        },
+        TransactionText {
+            title: "Restore Backup",
+            description: "Rolls every account and exchange rate back to a backup.",
+            inputs: &["Backup (ID)"],
+        },
+        TransactionText {
+            title: "Switch Profile",
+            description: "Opens the bank of another profile, creating it if it doesn't exist yet.",
+            inputs: &["Profile Name"],
+        },
+        TransactionText {
+            title: "Help",
+            description: "Describes every transaction and the current configuration.",
+            inputs: &[],
+        },
+    ],
+    select_transaction: "Select Transaction:",
+    back_to_menu: "Back to the Main Menu (Y/N): ",
+    cancelled: "Cancelled the transaction.",
+    more: "More? (Enter/q): ",
+    account_name: "Account Name: ",
+    account_name_or_number: "Account Name or Number: ",
+    recipient_name_or_number: "Recipient Account Name or Number: ",
+    registered_account: "Registered the {} account.",
+    account_number: "Account Number: {}",
+    account_type: "Account Type: ",
+    account_type_options: "Account Type Options:",
+    account_types: ["Savings", "Checking", "Time Deposit"],
+    record_kinds: [
+        "Deposit",
+        "Withdrawal",
+        "Transfer Out",
+        "Transfer In",
+        "Reversal",
+        "Penalty",
+        "Fee",
+        "Interest",
+        "Withholding Tax",
+    ],
+    account_type_label: "Account Type: {}",
+    joint_owners: "Joint Owners (comma-separated, optional): ",
+    email: "Email (optional): ",
+    phone: "Phone (optional): ",
+    owners_label: "Owners: {}",
+    new_owners: "Owners (comma-separated): ",
+    updated_owners: "Updated the owners of the {} account to {}.",
+    sort_order: "Sort By (name/balance): ",
+    min_balance: "Minimum Balance (optional): ",
+    type_filter: "Account Type (optional): ",
+    account_headers: ["Number", "Name", "Type", "Currency", "Balance", "Status"],
+    account_statuses: ["Open", "Frozen", "Closed"],
+    no_accounts: "No accounts match.",
+    account_details: "Account Details:",
+    name_label: "Name: {}",
+    balance_label: "Balance: {}",
+    status_label: "Status: {}",
+    opened_on: "Opened On: {}",
+    email_label: "Email: {}",
+    phone_label: "Phone: {}",
+    not_given: "(none)",
+    unknown_date: "(unknown)",
+    new_pin: "PIN (4 to 6 digits): ",
+    pin: "PIN: ",
+    account_type_rate: "{}: {}% annual interest",
+    early_withdrawal_penalty: "This time deposit's term hasn't ended, so the withdrawal is charged a penalty of {}.",
+    fee_charged: "Charged a {} of {}.",
+    fee_reasons: [
+        "below-minimum fee",
+        "monthly below-minimum fee",
+        "withdrawal fee",
+        "transfer fee",
+    ],
+    confirm_close_remainder: "This account still has {}. Withdraw it and close the account? (Y/N): ",
+    closed_account: "Closed the {} account.",
+    withdrew_remainder: "Withdrew the remaining {}.",
+    new_account_name: "New Account Name: ",
+    renamed_account: "Renamed the {} account to {}.",
+    froze_account: "Froze the {} account.",
+    unfroze_account: "Unfroze the {} account.",
+    overdraft_limit: "Overdraft Limit: ",
+    overdraft_limit_label: "Overdraft Limit: {}",
+    set_overdraft: "Set the overdraft limit of the {} account to {}.",
+    set_interest_rate: "Set the interest rate of the {} account to {}%.",
+    set_compounding: "Set the interest of the {} account to compound {}.",
+    withdrawal_limit: "Daily Withdrawal Limit (blank for the default): ",
+    withdrawal_limit_label: "Daily Withdrawal Limit: {}",
+    default_limit: "(default)",
+    daily_allowance: "Left of Today's Withdrawal Limit: {}",
+    set_withdrawal_limit: "Set the daily withdrawal limit of the {} account to {}.",
+    reset_withdrawal_limit: "Reset the daily withdrawal limit of the {} account to the default.",
+    minimum_amount: "Minimum Balance: ",
+    breach_fee: "Below-Minimum Withdrawal Fee (blank to reject such withdrawals): ",
+    monthly_fee: "Monthly Below-Minimum Fee: ",
+    set_minimum_balance: "Set the minimum balance of {} accounts to {}.",
+    charged_fee: "Charged the {} account a below-minimum fee of {}.",
+    no_fees_charged: "No account was below its minimum balance.",
+    current_balance: "Current Balance: {}",
+    updated_balance: "Updated Balance: {}",
+    reference_number: "Reference Number: {}",
+    memo: "Memo (blank for none): ",
+    history_search: "Search by Reference Number or Memo (blank for all): ",
+    account_currency: "Currency: {}",
+    currency: "Currency: ",
+    deposit_amount: "Deposit Amount: ",
+    withdraw_amount: "Withdraw Amount: ",
+    transfer_amount: "Transfer Amount: ",
+    transferred: "Transferred {} to the {} account.",
+    undid_deposit: "Undid the deposit of {} to the {} account.",
+    undid_withdrawal: "Undid the withdrawal of {} from the {} account.",
+    undid_transfer: "Undid the transfer of {} from the {} account to the {} account.",
+    undid_rate: "Undid the update of the {} exchange rate to {}.",
+    undid_spread: "Undid the update of the {} spread to {}%.",
+    interval_days: "Interval (days): ",
+    scheduled: "Scheduled the transaction as #{}, first running on {}.",
+    schedule_to_cancel: "Schedule to Cancel (ID, blank for none): ",
+    cancelled_schedule: "Cancelled schedule #{}.",
+    schedule_headers: [
+        "ID",
+        "Transaction",
+        "Account",
+        "Recipient",
+        "Amount",
+        "Every",
+        "Next Run",
+        "Status",
+    ],
+    scheduled_transactions: ["Deposit", "Transfer"],
+    interval: "{} day(s)",
+    schedule_statuses: ["Active", "Cancelled"],
+    no_schedules: "No transactions are scheduled.",
+    no_alerts: "No rate alerts were added.",
+    alert_headers: ["ID", "Currency", "Alert When", "Status"],
+    alert_statuses: ["Active", "Removed"],
+    add_or_remove_alert: "Add or Remove an Alert (A/R, blank for neither): ",
+    alert_condition: "Condition (>= or <=): ",
+    alert_threshold: "Threshold (PHP per Unit): ",
+    alert_to_remove: "Alert to Remove (ID): ",
+    added_alert: "Added rate alert #{}.",
+    removed_alert: "Removed rate alert #{}.",
+    alert_triggered: "Alert #{}: the exchange rate of {} is now {}, reaching {}.",
+    ran_schedule: "Ran scheduled transaction #{} due on {}.",
+    failed_schedule: "Scheduled transaction #{} due on {} failed: {}",
+    no_runs_due: "No scheduled transactions were due.",
+    csv_path: "CSV File: ",
+    failed_csv_read: "Failed to read the CSV file: {}",
+    failed_csv_write: "Failed to write the CSV file: {}",
+    no_bulk_rows: "The CSV file has no rows.",
+    bulk_headers: ["Line", "Account", "Amount", "Result"],
+    bulk_valid: "Valid",
+    bulk_preview: "{} row(s) would be deposited and {} would fail.",
+    bulk_summary: "Deposited {} row(s); {} failed.",
+    confirm_bulk_deposit: "Deposit the valid rows? (Y/N): ",
+    held_for_approval: "This transaction needs approval, so it was held as #{} without moving any balance yet.",
+    no_pending: "No transactions have been held for approval.",
+    pending_headers: [
+        "ID",
+        "Transaction",
+        "Account",
+        "Recipient",
+        "Amount",
+        "Held On",
+        "Status",
+    ],
+    held_transactions: ["Withdrawal", "Transfer"],
+    approval_statuses: ["Pending", "Approved", "Rejected"],
+    pending_to_decide: "Transaction to Decide (ID, blank to skip): ",
+    approve_or_reject: "Approve or reject? (A/R): ",
+    approved_pending: "Approved transaction #{}.",
+    rejected_pending: "Rejected transaction #{}.",
+    reference_to_reverse: "Reference Number: ",
+    reversed_transaction: "Reversed transaction {} as {}.",
+    skipped_transaction: "Skipped the transaction, since key {} was already performed as {}.",
+    reversal_of: "Reverses {}",
+    reversed_by: "Reversed by {}",
+    withdrawal_summary: "Withdrawal Summary:",
+    amount: "Amount: {}",
+    value_in: "Value in {}: {}",
+    resulting_balance: "Resulting Balance: {}",
+    confirm_withdrawal: "Confirm Withdrawal (Y/N): ",
+    stale_rate_warning: "The exchange rate of {} is out of date, last updated on {}.",
+    never_updated_rate: "The exchange rate of {} was never updated.",
+    use_stale_rate: "Use It Anyway (Y/N): ",
+    source_currency_options: "Source Currency Options:",
+    source_currency: "Source Currency: ",
+    source_amount: "Source Amount: ",
+    exchange_currency_options: "Exchanged Currency Options:",
+    exchange_currency: "Exchange Currency: ",
+    rate_date: "Rate Date (YYYY-MM-DD, blank to exchange now): ",
+    invalid_date: "Dates must be written as YYYY-MM-DD!",
+    exchange_amount: "Exchange Amount: {}",
+    converted: "{} is worth {}.",
+    converted_as_of: "On {}, {} was worth {}.",
+    exchange_fee: "Charged an exchange fee of {}.",
+    exchange_summary: "Exchange Summary:",
+    exchange_rate_label: "Rate: 1 {} = {} {}",
+    fee_label: "Fee: {}",
+    spread_label: "Spread: {}",
+    amount_received: "Amount Received: {}",
+    confirm_exchange: "Confirm Exchange (Y/N): ",
+    revenue_header: "Collected Fees",
+    spread_income_header: "Spread Income",
+    no_revenue: "The bank hasn't collected any fees or spread income yet.",
+    convert_another: "Convert another currency? (Y/N): ",
+    rate_headers: ["ID", "Currency", "PHP per Unit", "Units per PHP"],
+    spread_headers: ["ID", "Currency", "Spread (%)", "Buying Rate", "Selling Rate"],
+    current_rate_headers: ["Currency", "PHP per Unit", "Units per PHP", "Last Updated"],
+    never_updated: "Never",
+    select_foreign_currency: "Select Foreign Currency: ",
+    exchange_rate: "Exchange Rate: ",
+    spread_percent: "Spread (%): ",
+    recorded_rate: "Recorded the exchange rate of {}.",
+    set_spread: "Set the spread of {} to {}%.",
+    rate_provider_options: "Rate Providers",
+    rate_providers: ["Manual Entry", "CSV File", "European Central Bank"],
+    select_rate_provider: "Select Rate Provider: ",
+    currency_rate: "{} Rate: ",
+    refreshed_rates: "Refreshed the exchange rates of {} currencies.",
+    exported_rates: "Exported the exchange rates of {} currencies to {}.",
+    exported_interest: "Exported the interest of {} days to {}.",
+    no_refreshed_rates: "The provider didn't quote any of the exchangeable currencies.",
+    rate_window: "Number of Days: ",
+    rate_history_header: "Rates of {} over the Last {} Day(s)",
+    rate_history_headers: ["Recorded On", "Rate"],
+    no_rate_history: "No rates of {} were recorded over the last {} day(s).",
+    rate_stats: "Lowest: {}, Highest: {}, Average: {}",
+    currency_code: "Currency Code or Name: ",
+    iso_currency: "{}: {} decimal place(s), written with {}.",
+    currency_name: "Currency Name: ",
+    added_currency: "Added {} at an exchange rate of {}.",
+    interest_rate: "Interest Rate: {}%",
+    effective_rate: "Effective Annual Rate: {}%",
+    rate_kind: "Rate Type (apr/apy): ",
+    yield_rate: "Rate (%): ",
+    apr_label: "Annual Percentage Rate (APR): {}%",
+    apy_label: "Annual Percentage Yield (APY): {}%",
+    interest_tiers: "Interest Rates by Balance:",
+    interest_tier: "  {}% below {}",
+    interest_tier_rest: "  {}% otherwise",
+    compounding_label: "Compounding: {}",
+    interest_rate_percent: "Interest Rate (%): ",
+    day_count: "Total Number of Days: ",
+    posting_days: "Total Number of Days (blank for since the last posting): ",
+    posted_interest: "Posted {} of interest over {} days to the {} account.",
+    withheld_tax: "Withheld {} of tax, crediting {} net.",
+    posted_overdraft_charge: "Charged {} of interest for the overdrawn balance.",
+    interest_mode: "Interest Mode (simple/compound): ",
+    compounding: "Compounding (daily/monthly/quarterly/annually): ",
+    export_interest: "Export the Schedule to a CSV File Instead (Y/N): ",
+    planned_deposit: "Planned Deposit (blank for none): ",
+    interest_headers: ["Day", "Interest", "Balance"],
+    history_headers: ["Date", "Reference", "Type", "Amount", "Balance", "Memo"],
+    no_history: "This account has no transactions yet.",
+    overdraft_charge_header: "Overdraft Charge",
+    tax_header: "Tax",
+    deposit_header: "Deposit",
+    net_interest_header: "Net Interest",
+    total_interest: "Total Interest: {}",
+    total_tax: "Total Tax Withheld: {}",
+    net_interest: "Net Interest: {}",
+    total_overdraft_charge: "Total Overdraft Charge: {}",
+    total_deposits: "Total Deposits: {}",
+    final_balance: "Final Balance: {}",
+    set_passphrase: "Set Passphrase (leave blank to not encrypt): ",
+    passphrase: "Passphrase: ",
+    backup_options: "Backup Options:",
+    select_backup: "Select Backup: ",
+    saved_backup: "Saved the backup to {}.",
+    no_violations: "No broken invariants were found.",
+    demo_needs_empty_bank: "Demo data can only populate a bank without accounts!",
+    populated_demo: "Populated the bank with demo data from seed {}. Every demo account's PIN is {}.",
+    replayed_entries: "Opened the bank as of its first {} journal entries, so nothing done now is saved.",
+    replayed_time: "Opened the bank as of {}, so nothing done now is saved.",
+    stress_transactions: "Attempted {} transactions on {} accounts in {} seconds, {} per second, of which {} were rejected.",
+    stress_timings: "Registered the accounts in {} seconds and replayed the journal in {} seconds.",
+    inconsistent_replay: "Replaying the journal rebuilt a different state than the transactions left!",
+    #[cfg(feature = "serve")]
+    serving: "Serving the bank at http://{} until interrupted.",
+    #[cfg(feature = "grpc")]
+    serving_grpc: "Serving the bank over gRPC at {} until interrupted.",
+    serving_telnet: "Serving the interactive menu at {} until interrupted.",
+    restored_backup: "Restored the backup from {}.",
+    profiles: "Profiles:",
+    profile_name: "Profile Name: ",
+    switched_profile: "Switched to the {} profile.",
+    shared_profile: "The profile can't be switched while its bank is shared with other sessions!",
+    transactions_header: "Transactions:",
+    aliases: "Aliases: {}",
+    inputs: "Inputs: {}",
+    cancel_hint: "Input {} at any prompt to cancel the transaction.",
+    currencies: "Currencies:",
+    configuration: "Configuration:",
+    base_currency: "Base Currency: {}",
+    profile: "Profile: {}",
+    unknown_transaction_id: "No transaction with this ID exists!",
+    unknown_transaction_name: "No transaction with this name exists!",
+    ambiguous_transaction: "More than one transaction matches this name!",
+    too_many_retries: "Too many invalid responses, cancelling the transaction!",
+    invalid_yes_no: "Only accepting a [Y]es or [N]o answer!",
+    invalid_id: "ID must be a positive whole number (integer)!",
+    invalid_account_type: "Only accepting the ID or name of an account type!",
+    invalid_pin: "PINs must be made of 4 to 6 digits!",
+    invalid_sort_order: "Only accepting name or balance!",
+    invalid_interest_mode: "Only accepting simple or compound!",
+    invalid_compounding: "Only accepting daily, monthly, quarterly, or annually!",
+    invalid_rate_kind: "Only accepting apr or apy!",
+    invalid_yield_rate: "Rates must be numbers above -100%!",
+    incorrect_pin: "Incorrect PIN!",
+    invalid_number: "Number must be a positive whole number (integer)!",
+    invalid_amount: "Amount must be a decimal number!",
+    invalid_deposit_amount: "Deposit amount must be a decimal number!",
+    invalid_withdraw_amount: "Withdraw amount must be a decimal number!",
+    invalid_transfer_amount: "Transfer amount must be a decimal number!",
+    self_transfer: "Transfers must be to another account!",
+    nothing_to_undo: "There is no transaction to undo!",
+    invalid_interval: "Intervals must be at least one day!",
+    malformed_row: "Rows must have an account, an amount, and an optional currency!",
+    invalid_row_amount: "Deposit amounts must be positive numbers!",
+    invalid_approve_reject: "Only accepting an [A]pprove or [R]eject answer!",
+    invalid_add_remove: "Only accepting an [A]dd or [R]emove answer!",
+    invalid_alert_condition: "Conditions must be >= or <=!",
+    alert_not_found: "No active rate alert with this ID exists!",
+    schedule_not_found: "No active schedule with this ID exists!",
+    approval_required: "Transactions this large must be held for approval!",
+    pending_not_found: "No pending transaction with this ID exists!",
+    transaction_not_found: "No reversible transaction with this reference number exists!",
+    already_reversed: "This transaction was already reversed!",
+    unknown_currency_code: "No currency with this code exists!",
+    unexchangeable_currency: "{} isn't exchangeable yet, so it must be added first!",
+    unknown_currency_id: "No currency with this ID exists!",
+    duplicate_currency: "A currency with this code already exists!",
+    invalid_currency_code: "Currency codes must be made of three letters!",
+    invalid_rate: "Exchange rates must be numbers from {} to {}!",
+    rate_out_of_bounds: "The exchange rate of {} must be from {} to {}!",
+    sharp_rate_change: "The new exchange rate of {} differs from the previous one by {}%.",
+    non_positive_amount: "Amounts must be positive!",
+    amount_above_max: "Amounts must be positive and at most {}!",
+    invalid_spread: "Spreads must be from 0% to less than 100%!",
+    stale_rate: "The exchange rate of {} is out of date, so it must be updated first!",
+    no_rate_at: "No exchange rate of {} was recorded by then!",
+    unknown_account: "No account with this name exists!",
+    account_closed: "This account is closed!",
+    account_frozen: "This account is frozen, so it can't be deposited to or withdrawn from!",
+    nonzero_balance: "Only accounts with a zero balance can be closed!",
+    no_owners: "Accounts must have at least one owner!",
+    invalid_email: "Email addresses must be written like name@example.com!",
+    invalid_phone: "Phone numbers must have 7 to 15 digits!",
+    invalid_overdraft_limit: "Overdraft limits must not be negative!",
+    invalid_interest_rate: "Interest rates must be numbers above -100%!",
+    no_interest_due: "No interest has accrued since it was last posted!",
+    daily_limit_exceeded: "Withdraw amount must not exceed the {} left of today's withdrawal limit!",
+    invalid_withdrawal_limit: "Daily withdrawal limits must not be negative!",
+    fee_exceeds_amount: "Exchange amount must be greater than its fee!",
+    below_minimum_balance: "Withdraw amount must leave the account's minimum maintaining balance!",
+    invalid_minimum_balance: "Minimum balances and their fees must not be negative!",
+    duplicate_account: "An account with this name already exists!",
+    insufficient_balance: "Withdraw amount must be less than the current balance!",
+    failed_journal_write: "Failed to write to the journal: {}",
+    failed_journal_open: "Failed to open the journal file: {}",
+    incorrect_passphrase: "Incorrect passphrase!",
+    failed_backup: "Failed to access the backup: {}",
+    failed_backup_list: "Failed to list the backups: {}",
+    failed_rate_request: "Failed to reach the rate provider: {}",
+    malformed_rates: "The rate provider's response couldn't be read!",
+    malformed_rate_row: "Line {} must have a currency and a rate!",
+    unknown_rate_currency: "Line {} has {}, which isn't an exchangeable foreign currency!",
+    invalid_rate_row: "Line {} must have a rate from {} to {}!",
+    no_backups: "No backups exist yet!",
+    unknown_backup: "No backup with this ID exists!",
+    failed_profile_list: "Failed to list the profiles: {}",
+    invalid_profile_name: "Profile names may only contain letters, numbers, dashes, and underscores!",
+    unterminated_quotes: "Unterminated quotes in the command!",
+    script_line_error: "Line {}: {}",
+    script_stopped: "The script stopped at line {}!",
+    script_failures: "{} command(s) in the script failed!",
+    completions_in_script: "Completion scripts can't be generated by a script!",
+    telnet_in_script: "The interactive menu can't be served by a script!",
+    #[cfg(feature = "serve")]
+    serve_in_script: "The bank can't be served by a script!",
+    #[cfg(feature = "grpc")]
+    grpc_in_script: "The bank can't be served over gRPC by a script!",
+    #[cfg(feature = "serve")]
+    invalid_contribution: "A planned deposit must have an amount and a positive number of days between deposits!",
+    #[cfg(feature = "tui")]
+    tui_forms: [
+        "Register Account",
+        "Deposit Amount",
+        "Withdraw Amount",
+        "Record Exchange Rate",
+    ],
+    #[cfg(feature = "tui")]
+    tui_account_name: "Account Name",
+    #[cfg(feature = "tui")]
+    tui_account_type: "Account Type (savings/checking/time-deposit)",
+    #[cfg(feature = "tui")]
+    tui_pin: "PIN",
+    #[cfg(feature = "tui")]
+    tui_currency: "Currency",
+    #[cfg(feature = "tui")]
+    tui_amount: "Amount",
+    #[cfg(feature = "tui")]
+    tui_exchange_rate: "Exchange Rate",
+    #[cfg(feature = "tui")]
+    tui_accounts: "Accounts",
+    #[cfg(feature = "tui")]
+    tui_exchange_rates: "Exchange Rates",
+    #[cfg(feature = "tui")]
+    tui_transaction: "Transaction",
+    #[cfg(feature = "tui")]
+    tui_account: "Account: {}",
+    #[cfg(feature = "tui")]
+    tui_none_selected: "(none selected)",
+    #[cfg(feature = "tui")]
+    tui_select_account: "Select an account first!",
+    #[cfg(feature = "tui")]
+    tui_keys: "Tab: switch pane | ↑/↓: select | ←/→: transaction | Enter: submit | Esc: quit",
+};
+
+/// The messages in Filipino.
+pub const FILIPINO: Messages = Messages {
+    transactions: [
+        TransactionText {
+            title: "Magrehistro ng Pangalan ng Account",
+            description: "Nagrerehistro ng bagong impok, checking, o time deposit na account na may zero na balanse.",
+            inputs: &[
+                "Pangalan ng Account",
+                "Mga Kasamang May-ari (hiwalay ng kuwit)",
+                "Uri ng Account (ID o pangalan)",
+                "Antas ng Interes (%)",
+                "Email (opsyonal)",
+                "Telepono (opsyonal)",
+                "PIN",
+            ],
+        },
+        TransactionText {
+            title: "Magdeposito ng Halaga",
+            description: "Nagdedeposito ng halaga sa anumang salapi sa isang account.",
+            inputs: &[
+                "Pangalan o Numero ng Account",
+                "PIN",
+                "Salapi (code)",
+                "Halagang Ideposito",
+            ],
+        },
+        TransactionText {
+            title: "Kumuha ng Halaga",
+            description: "Kumukuha ng halaga sa anumang salapi mula sa isang account, kung sapat ang balanse nito.",
+            inputs: &[
+                "Pangalan o Numero ng Account",
+                "PIN",
+                "Salapi (code)",
+                "Halagang Kukunin",
+            ],
+        },
+        TransactionText {
+            title: "Maglipat ng Halaga",
+            description: "Inililipat ang isang halaga mula sa isang account patungo sa iba, pinapalitan ito kung magkaiba ang salapi nila.",
+            inputs: &[
+                "Pangalan o Numero ng Account",
+                "PIN",
+                "Pangalan o Numero ng Tatanggap na Account",
+                "Halaga",
+            ],
+        },
+        TransactionText {
+            title: "Paulit-ulit na Deposito",
+            description: "Iniiskedyul ang pagdeposito ng isang halaga sa anumang salapi sa isang account kada ilang araw.",
+            inputs: &[
+                "Pangalan o Numero ng Account",
+                "PIN",
+                "Salapi (code)",
+                "Halagang Ideposito",
+                "Pagitan (araw)",
+            ],
+        },
+        TransactionText {
+            title: "Nakatayong Utos",
+            description: "Iniiskedyul ang paglipat ng isang halaga mula sa isang account patungo sa iba kada ilang araw.",
+            inputs: &[
+                "Pangalan o Numero ng Account",
+                "PIN",
+                "Pangalan o Numero ng Tatanggap na Account",
+                "Halaga",
+                "Pagitan (araw)",
+            ],
+        },
+        TransactionText {
+            title: "Mga Nakaiskedyul na Transaksyon",
+            description: "Inililista ang mga nakaiskedyul na transaksyon at ang susunod nilang takbo, at maaaring kanselahin ang isa.",
+            inputs: &["Iskedyul na Kakanselahin (ID, opsyonal)"],
+        },
+        TransactionText {
+            title: "Maramihang Deposito",
+            description: "Idinedeposito ang bawat hanay ng isang CSV file ng mga account, halaga, at salapi matapos silang silipin.",
+            inputs: &["CSV File", "Ituloy ang mga Deposito (Y/N)"],
+        },
+        TransactionText {
+            title: "Pila ng Pag-apruba",
+            description: "Inililista ang malalaking pag-withdraw at paglipat na itinabi para sa pag-apruba, at opsyonal na inaaprubahan o tinatanggihan ang isa.",
+            inputs: &[
+                "Transaksyong Pagpapasyahan (ID, opsyonal)",
+                "Aprubahan o Tanggihan (A/R)",
+            ],
+        },
+        TransactionText {
+            title: "Palitan ng Salapi",
+            description: "Pinapalitan ang isang halaga mula sa isang salapi patungo sa iba matapos itong isa-isahin, o kinakalkula ito sa antas ng isang nakaraang petsa.",
+            inputs: &[
+                "Pinagmulang Salapi (ID)",
+                "Halagang Papalitan",
+                "Salaping Papalitan (ID)",
+                "Petsa ng Antas (YYYY-MM-DD, opsyonal)",
+                "Kumpirmahin ang Palitan (Y/N)",
+            ],
+        },
+        TransactionText {
+            title: "Ipakita ang mga Antas ng Palitan",
+            description: "Inililista ang kasalukuyang antas ng palitan ng mga dayuhang salapi, kasama ang kabaligtaran nito at kung kailan ito huling na-update.",
+            inputs: &[],
+        },
+        TransactionText {
+            title: "Itala ang mga Antas ng Palitan",
+            description: "Itinatala ang halaga ng isang dayuhang salapi sa batayang salapi.",
+            inputs: &["Dayuhang Salapi (ID)", "Antas ng Palitan"],
+        },
+        TransactionText {
+            title: "Itakda ang Spread ng Palitan",
+            description: "Itinatakda ang agwat ng mga antas kung saan bumibili at nagbebenta ang bangko ng isang dayuhang salapi.",
+            inputs: &["Dayuhang Salapi (ID)", "Spread (%)"],
+        },
+        TransactionText {
+            title: "I-refresh ang mga Antas ng Palitan",
+            description: "Sabay-sabay na nire-refresh ang antas ng bawat dayuhang salapi, mula sa isang CSV file, isang live na tagapagbigay, o sa paglalagay ng bawat isa.",
+            inputs: &["Tagapagbigay ng Antas (ID)", "Mga Antas ng Palitan"],
+        },
+        TransactionText {
+            title: "Kasaysayan ng Antas ng Palitan",
+            description: "Inililista ang mga kamakailang antas ng palitan ng isang dayuhang salapi kasama ang pinakamababa, pinakamataas, at karaniwan nito.",
+            inputs: &["Salapi", "Bilang ng mga Araw"],
+        },
+        TransactionText {
+            title: "Mga Alerto sa Antas",
+            description: "Inililista ang mga alerto sa antas ng palitan, na may opsyong magdagdag ng isang nag-aabiso kapag umabot ang antas sa isang hangganan o mag-alis ng isa.",
+            inputs: &[
+                "Magdagdag o Mag-alis (A/R, opsyonal)",
+                "Salapi",
+                "Kondisyon (>= o <=)",
+                "Hangganan",
+                "Alertong Aalisin (ID)",
+            ],
+        },
+        TransactionText {
+            title: "I-export ang mga Antas ng Palitan",
+            description: "Isinusulat ang kasalukuyang antas ng palitan sa isang CSV file, na mapagkukunan ng mga antas sa pag-refresh sa susunod.",
+            inputs: &["CSV File"],
+        },
+        TransactionText {
+            title: "Magdagdag ng Salapi",
+            description: "Nagrerehistro ng bagong salapi kasama ang antas ng palitan nito, upang magamit ito sa mga deposito, pag-withdraw, at palitan.",
+            inputs: &["Code o Pangalan ng Salapi", "Pangalan ng Salapi", "Antas ng Palitan"],
+        },
+        TransactionText {
+            title: "Bawiin",
+            description: "Binabawi ang pinakahuling deposito, pag-withdraw, paglipat, o pagtakda ng antas ng palitan.",
+            inputs: &[],
+        },
+        TransactionText {
+            title: "Ibalik ang Transaksyon",
+            description: "Ibinabalik ang anumang deposito, pag-withdraw, paglipat, o bayad ayon sa numero ng sanggunian nito, at iniuugnay ang dalawa sa kasaysayan.",
+            inputs: &["Numero ng Sanggunian"],
+        },
+        TransactionText {
+            title: "Ipakita ang Halaga ng Interes",
+            description: "Ipinapakita ang pang-araw-araw na interes na kinikita ng isang account sa loob ng ilang araw, kasama ang anumang planong deposito.",
+            inputs: &[
+                "Pangalan o Numero ng Account",
+                "PIN",
+                "Kabuuang Bilang ng Araw",
+                "Paraan ng Interes (simple/compound)",
+                "Pag-compound (daily/monthly/quarterly/annually)",
+                "Planong Deposito (opsyonal)",
+                "Pagitan sa Araw (kung magdedeposito)",
+                "I-export sa CSV File (Y/N)",
+                "CSV File (kung ie-export)",
+            ],
+        },
+        TransactionText {
+            title: "I-post ang Interes",
+            description: "Idinaragdag sa balanse ang interes na kinita ng isang account sa loob ng ilang araw, o mula nang huli itong i-post.",
+            inputs: &["Pangalan o Numero ng Account", "Kabuuang Bilang ng Araw (opsyonal)"],
+        },
+        TransactionText {
+            title: "Calculator ng APR at APY",
+            description: "Nagpapalit sa pagitan ng taunang porsiyento ng antas at ng kitang nabubuo nito sa pag-compound.",
+            inputs: &[
+                "Uri ng Antas (apr/apy)",
+                "Antas (%)",
+                "Pag-compound (daily/monthly/quarterly/annually)",
+            ],
+        },
+        TransactionText {
+            title: "Isara ang Account",
+            description: "Isinasara ang account na walang balanse, o matapos kunin ang natitira, nang itinatago ang rekord nito.",
+            inputs: &["Pangalan o Numero ng Account", "PIN"],
+        },
+        TransactionText {
+            title: "Palitan ang Pangalan ng Account",
+            description: "Pinapalitan ang pangalan ng isang account, nang pinananatili ang balanse, salapi, at kasaysayan nito.",
+            inputs: &["Pangalan o Numero ng Account", "PIN", "Bagong Pangalan ng Account"],
+        },
+        TransactionText {
+            title: "I-freeze o I-unfreeze ang Account",
+            description: "Fini-freeze ang isang account, na tumatanggi sa mga deposito at pagkuha nito, o ina-unfreeze ito.",
+            inputs: &["Pangalan o Numero ng Account"],
+        },
+        TransactionText {
+            title: "Itakda ang Limitasyon ng Overdraft",
+            description: "Itinatakda kung gaano kababa sa zero maaaring ibaba ng mga pagkuha ang balanse ng isang account.",
+            inputs: &["Pangalan o Numero ng Account", "Limitasyon ng Overdraft"],
+        },
+        TransactionText {
+            title: "Itakda ang Limitasyon ng Pag-withdraw Bawat Araw",
+            description: "Itinatakda ang pinakamalaking maaaring i-withdraw sa isang account bawat araw, o ibinabalik ito sa default.",
+            inputs: &[
+                "Pangalan o Numero ng Account",
+                "Limitasyon ng Pag-withdraw Bawat Araw (opsyonal)",
+            ],
+        },
+        TransactionText {
+            title: "Itakda ang Antas ng Interes",
+            description: "Itinatakda ang taunang antas ng interes na kinikita ng isang account, sa halip na ang sa uri nito.",
+            inputs: &["Pangalan o Numero ng Account", "Antas ng Interes (%)"],
+        },
+        TransactionText {
+            title: "Itakda ang Pag-compound",
+            description: "Itinatakda kung gaano kadalas idinaragdag sa balanse ang interes na kinikita ng isang account.",
+            inputs: &["Pangalan o Numero ng Account", "Dalas ng Pag-compound"],
+        },
+        TransactionText {
+            title: "Itakda ang Minimum na Balanse",
+            description: "Itinatakda ang balanseng dapat panatilihin ng isang uri ng account at ang mga multa kapag bumaba rito.",
+            inputs: &[
+                "Uri ng Account",
+                "Minimum na Balanse",
+                "Multa sa Pag-withdraw nang Mas Mababa sa Minimum",
+                "Buwanang Multa sa Mas Mababa sa Minimum",
+            ],
+        },
+        TransactionText {
+            title: "Tapusin ang Panahon",
+            description: "Sinisingil ang buwanang multa sa bawat account na mas mababa sa minimum na balanse nito.",
+            inputs: &[],
+        },
+        TransactionText {
+            title: "Kita ng Bangko",
+            description: "Ipinapakita ang mga bayad na nakolekta ng bangko sa bawat salapi.",
+            inputs: &[],
+        },
+        TransactionText {
+            title: "Baguhin ang mga May-ari ng Account",
+            description: "Pinapalitan ang mga may-ari ng isang account, na sinuman sa kanila ay maaaring makipagtransaksyon dito.",
+            inputs: &["Pangalan o Numero ng Account", "PIN", "Mga May-ari (hiwalay ng kuwit)"],
+        },
+        TransactionText {
+            title: "Ilista ang mga Account",
+            description: "Inililista ang mga nakarehistrong account ayon sa pangalan o balanse, at maaaring salain ayon sa balanse o uri.",
+            inputs: &[
+                "Isaayos Ayon sa (name o balance)",
+                "Pinakamababang Balanse (opsyonal)",
+                "Uri ng Account (opsyonal)",
+            ],
+        },
+        TransactionText {
+            title: "Mga Detalye ng Account",
+            description: "Ipinapakita ang numero, mga may-ari, uri, balanse, petsa ng pagbubukas, at contact ng isang account.",
+            inputs: &["Pangalan o Numero ng Account", "PIN"],
+        },
+        TransactionText {
+            title: "Tingnan ang Kasaysayan",
+            description: "Inililipat ang mga pahina ng mga deposito, pag-withdraw, at bayad ng isang account, mula pinakabago.",
+            inputs: &["Pangalan o Numero ng Account", "PIN"],
+        },
+        TransactionText {
+            title: "I-backup ang Estado ng Bangko",
+            description: "Kinukuhanan ng snapshot ang bawat account at antas ng palitan sa isang bagong backup file.",
+            inputs: &[],
+        },
+        TransactionText {
+            title: "Ibalik ang Backup",
+            description: "Ibinabalik ang bawat account at antas ng palitan sa isang backup.",
+            inputs: &["Backup (ID)"],
+        },
+        TransactionText {
+            title: "Lumipat ng Profile",
+            description: "Binubuksan ang bangko ng ibang profile, at ginagawa ito kung wala pa.",
+            inputs: &["Pangalan ng Profile"],
+        },
+        TransactionText {
+            title: "Tulong",
+            description: "Inilalarawan ang bawat transaksyon at ang kasalukuyang kumpigurasyon.",
+            inputs: &[],
+        },
+    ],
+    select_transaction: "Pumili ng Transaksyon:",
+    back_to_menu: "Bumalik sa Pangunahing Menu (Y/N): ",
+    cancelled: "Kinansela ang transaksyon.",
+    more: "Marami pa? (Enter/q): ",
+    account_name: "Pangalan ng Account: ",
+    account_name_or_number: "Pangalan o Numero ng Account: ",
+    recipient_name_or_number: "Pangalan o Numero ng Tatanggap na Account: ",
+    registered_account: "Nairehistro ang account na {}.",
+    account_number: "Numero ng Account: {}",
+    account_type: "Uri ng Account: ",
+    account_type_options: "Mga Pagpipiliang Uri ng Account:",
+    account_types: ["Impok", "Checking", "Time Deposit"],
+    record_kinds: [
+        "Deposito",
+        "Pag-withdraw",
+        "Palabas na Paglipat",
+        "Papasok na Paglipat",
+        "Pagbawi",
+        "Multa",
+        "Bayad",
+        "Interes",
+        "Buwis na Kinaltas",
+    ],
+    account_type_label: "Uri ng Account: {}",
+    joint_owners: "Mga Kasamang May-ari (hiwalay ng kuwit, opsyonal): ",
+    email: "Email (opsyonal): ",
+    phone: "Telepono (opsyonal): ",
+    owners_label: "Mga May-ari: {}",
+    new_owners: "Mga May-ari (hiwalay ng kuwit): ",
+    updated_owners: "Na-update ang mga may-ari ng account na {} sa {}.",
+    sort_order: "Isaayos Ayon sa (name/balance): ",
+    min_balance: "Pinakamababang Balanse (opsyonal): ",
+    type_filter: "Uri ng Account (opsyonal): ",
+    account_headers: ["Numero", "Pangalan", "Uri", "Salapi", "Balanse", "Katayuan"],
+    account_statuses: ["Bukas", "Naka-freeze", "Sarado"],
+    no_accounts: "Walang tumutugmang account.",
+    account_details: "Mga Detalye ng Account:",
+    name_label: "Pangalan: {}",
+    balance_label: "Balanse: {}",
+    status_label: "Katayuan: {}",
+    opened_on: "Binuksan Noong: {}",
+    email_label: "Email: {}",
+    phone_label: "Telepono: {}",
+    not_given: "(wala)",
+    unknown_date: "(hindi alam)",
+    new_pin: "PIN (4 hanggang 6 na digit): ",
+    pin: "PIN: ",
+    account_type_rate: "{}: {}% taunang interes",
+    early_withdrawal_penalty: "Hindi pa tapos ang termino ng time deposit na ito, kaya may multang {} ang pag-withdraw.",
+    fee_charged: "Siningil ng {} na {}.",
+    fee_reasons: [
+        "multa sa pagbaba sa minimum",
+        "buwanang multa sa pagbaba sa minimum",
+        "bayad sa pag-withdraw",
+        "bayad sa paglipat",
+    ],
+    confirm_close_remainder: "May natitira pang {} sa account na ito. Kunin ito at isara ang account? (Y/N): ",
+    closed_account: "Isinara ang account na {}.",
+    withdrew_remainder: "Nakuha ang natitirang {}.",
+    new_account_name: "Bagong Pangalan ng Account: ",
+    renamed_account: "Pinalitan ang pangalan ng account na {} ng {}.",
+    froze_account: "Na-freeze ang account na {}.",
+    unfroze_account: "Na-unfreeze ang account na {}.",
+    overdraft_limit: "Limitasyon ng Overdraft: ",
+    overdraft_limit_label: "Limitasyon ng Overdraft: {}",
+    set_overdraft: "Itinakda ang limitasyon ng overdraft ng account na {} sa {}.",
+    set_interest_rate: "Itinakda ang antas ng interes ng account na {} sa {}%.",
+    set_compounding: "Itinakda ang interes ng account na {} na mag-compound nang {}.",
+    withdrawal_limit: "Limitasyon ng Pag-withdraw Bawat Araw (blangko para sa default): ",
+    withdrawal_limit_label: "Limitasyon ng Pag-withdraw Bawat Araw: {}",
+    default_limit: "(default)",
+    daily_allowance: "Natitira sa Limitasyon Ngayong Araw: {}",
+    set_withdrawal_limit: "Itinakda ang limitasyon ng pag-withdraw bawat araw ng account na {} sa {}.",
+    reset_withdrawal_limit: "Ibinalik sa default ang limitasyon ng pag-withdraw bawat araw ng account na {}.",
+    minimum_amount: "Minimum na Balanse: ",
+    breach_fee: "Multa sa Pag-withdraw nang Mas Mababa sa Minimum (blangko upang tanggihan ang mga ito): ",
+    monthly_fee: "Buwanang Multa sa Mas Mababa sa Minimum: ",
+    set_minimum_balance: "Itinakda ang minimum na balanse ng mga {} account sa {}.",
+    charged_fee: "Siningil ang account na {} ng multang {} dahil mas mababa ito sa minimum.",
+    no_fees_charged: "Walang account na mas mababa sa minimum na balanse nito.",
+    current_balance: "Kasalukuyang Balanse: {}",
+    updated_balance: "Bagong Balanse: {}",
+    reference_number: "Numero ng Sanggunian: {}",
+    memo: "Tala (blangko kung wala): ",
+    history_search: "Maghanap ayon sa Numero ng Sanggunian o Tala (blangko para sa lahat): ",
+    account_currency: "Salapi: {}",
+    currency: "Salapi: ",
+    deposit_amount: "Halagang Ideposito: ",
+    withdraw_amount: "Halagang Kukunin: ",
+    transfer_amount: "Halagang Ililipat: ",
+    transferred: "Nailipat ang {} sa account na {}.",
+    undid_deposit: "Binawi ang deposito na {} sa account na {}.",
+    undid_withdrawal: "Binawi ang pag-withdraw na {} mula sa account na {}.",
+    undid_transfer: "Binawi ang paglipat na {} mula sa account na {} patungo sa account na {}.",
+    undid_rate: "Binawi ang pagtakda ng antas ng palitan ng {} sa {}.",
+    undid_spread: "Binawi ang pagtakda ng spread ng {} sa {}%.",
+    interval_days: "Pagitan (araw): ",
+    scheduled: "Naiskedyul ang transaksyon bilang #{}, na unang tatakbo sa {}.",
+    schedule_to_cancel: "Iskedyul na Kakanselahin (ID, blangko kung wala): ",
+    cancelled_schedule: "Kinansela ang iskedyul #{}.",
+    schedule_headers: [
+        "ID",
+        "Transaksyon",
+        "Account",
+        "Tatanggap",
+        "Halaga",
+        "Kada",
+        "Susunod na Takbo",
+        "Katayuan",
+    ],
+    scheduled_transactions: ["Deposito", "Paglipat"],
+    interval: "{} (na) araw",
+    schedule_statuses: ["Aktibo", "Kinansela"],
+    no_schedules: "Walang nakaiskedyul na transaksyon.",
+    no_alerts: "Walang naidagdag na alerto sa antas.",
+    alert_headers: ["ID", "Salapi", "Mag-alerto Kapag", "Katayuan"],
+    alert_statuses: ["Aktibo", "Inalis"],
+    add_or_remove_alert: "Magdagdag o Mag-alis ng Alerto (A/R, blanko para sa wala): ",
+    alert_condition: "Kondisyon (>= o <=): ",
+    alert_threshold: "Hangganan (PHP bawat Yunit): ",
+    alert_to_remove: "Alertong Aalisin (ID): ",
+    added_alert: "Naidagdag ang alerto sa antas #{}.",
+    removed_alert: "Inalis ang alerto sa antas #{}.",
+    alert_triggered: "Alerto #{}: ang antas ng palitan ng {} ay {} na, umabot sa {}.",
+    ran_schedule: "Tumakbo ang nakaiskedyul na transaksyon #{} na nakatakda sa {}.",
+    failed_schedule: "Nabigo ang nakaiskedyul na transaksyon #{} na nakatakda sa {}: {}",
+    no_runs_due: "Walang nakaiskedyul na transaksyong nakatakda.",
+    csv_path: "CSV File: ",
+    failed_csv_read: "Hindi nabasa ang CSV file: {}",
+    failed_csv_write: "Hindi naisulat ang CSV file: {}",
+    no_bulk_rows: "Walang hanay ang CSV file.",
+    bulk_headers: ["Linya", "Account", "Halaga", "Resulta"],
+    bulk_valid: "Wasto",
+    bulk_preview: "{} hanay ang maidedeposito at {} ang mabibigo.",
+    bulk_summary: "Naideposito ang {} hanay; {} ang nabigo.",
+    confirm_bulk_deposit: "Ideposito ang mga wastong hanay? (Y/N): ",
+    held_for_approval: "Kailangan ng pag-apruba ang transaksyong ito, kaya itinabi ito bilang #{} nang hindi pa gumagalaw ang anumang balanse.",
+    no_pending: "Walang transaksyong naitabi para sa pag-apruba.",
+    pending_headers: [
+        "ID",
+        "Transaksyon",
+        "Account",
+        "Tatanggap",
+        "Halaga",
+        "Itinabi Noong",
+        "Katayuan",
+    ],
+    held_transactions: ["Pag-withdraw", "Paglipat"],
+    approval_statuses: ["Naghihintay", "Inaprubahan", "Tinanggihan"],
+    pending_to_decide: "Transaksyong Pagpapasyahan (ID, blangko upang laktawan): ",
+    approve_or_reject: "Aprubahan o tanggihan? (A/R): ",
+    approved_pending: "Inaprubahan ang transaksyon #{}.",
+    rejected_pending: "Tinanggihan ang transaksyon #{}.",
+    reference_to_reverse: "Numero ng Sanggunian: ",
+    reversed_transaction: "Ibinalik ang transaksyon {} bilang {}.",
+    skipped_transaction: "Nilaktawan ang transaksyon, dahil naisagawa na ang susi {} bilang {}.",
+    reversal_of: "Ibinabalik ang {}",
+    reversed_by: "Ibinalik ng {}",
+    withdrawal_summary: "Buod ng Pagkuha:",
+    amount: "Halaga: {}",
+    value_in: "Halaga sa {}: {}",
+    resulting_balance: "Matitirang Balanse: {}",
+    confirm_withdrawal: "Kumpirmahin ang Pagkuha (Y/N): ",
+    stale_rate_warning: "Luma na ang antas ng palitan ng {}, na huling na-update noong {}.",
+    never_updated_rate: "Hindi pa kailanman na-update ang antas ng palitan ng {}.",
+    use_stale_rate: "Gamitin Pa Rin (Y/N): ",
+    source_currency_options: "Mga Pagpipiliang Pinagmulang Salapi:",
+    source_currency: "Pinagmulang Salapi: ",
+    source_amount: "Halagang Papalitan: ",
+    exchange_currency_options: "Mga Pagpipiliang Salaping Papalitan:",
+    exchange_currency: "Salaping Papalitan: ",
+    rate_date: "Petsa ng Antas (YYYY-MM-DD, blanko para magpalit ngayon): ",
+    invalid_date: "Dapat nakasulat ang mga petsa bilang YYYY-MM-DD!",
+    exchange_amount: "Halaga ng Palitan: {}",
+    converted: "Ang {} ay katumbas ng {}.",
+    converted_as_of: "Noong {}, ang {} ay katumbas ng {}.",
+    exchange_fee: "Siningil ng bayad sa palitan na {}.",
+    exchange_summary: "Buod ng Palitan:",
+    exchange_rate_label: "Palitan: 1 {} = {} {}",
+    fee_label: "Bayad: {}",
+    spread_label: "Spread: {}",
+    amount_received: "Halagang Matatanggap: {}",
+    confirm_exchange: "Kumpirmahin ang Palitan (Y/N): ",
+    revenue_header: "Mga Nakolektang Bayad",
+    spread_income_header: "Kita mula sa Spread",
+    no_revenue: "Wala pang nakokolektang bayad o kita mula sa spread ang bangko.",
+    convert_another: "Magpapalit pa ng ibang salapi? (Y/N): ",
+    rate_headers: ["ID", "Salapi", "PHP bawat Yunit", "Yunit bawat PHP"],
+    spread_headers: ["ID", "Salapi", "Spread (%)", "Antas ng Pagbili", "Antas ng Pagbenta"],
+    current_rate_headers: ["Salapi", "PHP bawat Yunit", "Yunit bawat PHP", "Huling Na-update"],
+    never_updated: "Hindi pa",
+    select_foreign_currency: "Pumili ng Dayuhang Salapi: ",
+    exchange_rate: "Antas ng Palitan: ",
+    spread_percent: "Spread (%): ",
+    recorded_rate: "Naitala ang antas ng palitan ng {}.",
+    set_spread: "Itinakda ang spread ng {} sa {}%.",
+    rate_provider_options: "Mga Tagapagbigay ng Antas",
+    rate_providers: ["Manwal na Paglalagay", "CSV File", "European Central Bank"],
+    select_rate_provider: "Pumili ng Tagapagbigay ng Antas: ",
+    currency_rate: "Antas ng {}: ",
+    refreshed_rates: "Nai-refresh ang mga antas ng palitan ng {} na salapi.",
+    exported_rates: "Nai-export ang antas ng palitan ng {} na salapi sa {}.",
+    exported_interest: "Nai-export ang interes ng {} na araw sa {}.",
+    no_refreshed_rates: "Walang sinipi ang tagapagbigay sa mga maipapalit na salapi.",
+    rate_window: "Bilang ng mga Araw: ",
+    rate_history_header: "Mga Antas ng {} sa Nakaraang {} (na) Araw",
+    rate_history_headers: ["Naitala Noong", "Antas"],
+    no_rate_history: "Walang naitalang antas ng {} sa nakaraang {} (na) araw.",
+    rate_stats: "Pinakamababa: {}, Pinakamataas: {}, Karaniwan: {}",
+    currency_code: "Code o Pangalan ng Salapi: ",
+    iso_currency: "{}: {} (na) decimal place, isinusulat gamit ang {}.",
+    currency_name: "Pangalan ng Salapi: ",
+    added_currency: "Naidagdag ang {} sa antas ng palitan na {}.",
+    interest_rate: "Antas ng Interes: {}%",
+    effective_rate: "Epektibong Taunang Antas: {}%",
+    rate_kind: "Uri ng Antas (apr/apy): ",
+    yield_rate: "Antas (%): ",
+    apr_label: "Taunang Porsiyento ng Antas (APR): {}%",
+    apy_label: "Taunang Porsiyento ng Kita (APY): {}%",
+    interest_tiers: "Mga Antas ng Interes Ayon sa Balanse:",
+    interest_tier: "  {}% kapag mas mababa sa {}",
+    interest_tier_rest: "  {}% kung hindi",
+    compounding_label: "Pag-compound: {}",
+    interest_rate_percent: "Antas ng Interes (%): ",
+    day_count: "Kabuuang Bilang ng Araw: ",
+    posting_days: "Kabuuang Bilang ng Araw (blangko para mula sa huling pag-post): ",
+    posted_interest: "Nai-post ang interes na {} para sa {} na araw sa account na {}.",
+    withheld_tax: "Kinaltasan ng {} na buwis, kaya {} ang netong naidagdag.",
+    posted_overdraft_charge: "Siningil ang {} na interes para sa na-overdraw na balanse.",
+    interest_mode: "Paraan ng Interes (simple/compound): ",
+    compounding: "Pag-compound (daily/monthly/quarterly/annually): ",
+    export_interest: "I-export ang Talaan sa isang CSV File sa Halip (Y/N): ",
+    planned_deposit: "Planong Deposito (blangko kung wala): ",
+    interest_headers: ["Araw", "Interes", "Balanse"],
+    history_headers: ["Petsa", "Sanggunian", "Uri", "Halaga", "Balanse", "Tala"],
+    no_history: "Wala pang transaksiyon ang account na ito.",
+    overdraft_charge_header: "Singil sa Overdraft",
+    tax_header: "Buwis",
+    deposit_header: "Deposito",
+    net_interest_header: "Netong Interes",
+    total_interest: "Kabuuang Interes: {}",
+    total_tax: "Kabuuang Buwis na Kinaltas: {}",
+    net_interest: "Netong Interes: {}",
+    total_overdraft_charge: "Kabuuang Singil sa Overdraft: {}",
+    total_deposits: "Kabuuang Deposito: {}",
+    final_balance: "Huling Balanse: {}",
+    set_passphrase: "Magtakda ng Passphrase (iwanang blangko para hindi i-encrypt): ",
+    passphrase: "Passphrase: ",
+    backup_options: "Mga Pagpipiliang Backup:",
+    select_backup: "Pumili ng Backup: ",
+    saved_backup: "Nai-save ang backup sa {}.",
+    no_violations: "Walang nakitang sirang invariant.",
+    demo_needs_empty_bank: "Sa bangkong walang account lamang maaaring ilagay ang demo data!",
+    populated_demo: "Nalagyan ang bangko ng demo data mula sa seed {}. Ang PIN ng bawat demo account ay {}.",
+    replayed_entries: "Binuksan ang bangko ayon sa unang {} entry ng journal nito, kaya walang gagawin ngayon ang mase-save.",
+    replayed_time: "Binuksan ang bangko ayon sa {}, kaya walang gagawin ngayon ang mase-save.",
+    stress_transactions: "Sinubukan ang {} transaksyon sa {} account sa loob ng {} segundo, {} bawat segundo, at {} ang tinanggihan.",
+    stress_timings: "Nairehistro ang mga account sa loob ng {} segundo at na-replay ang journal sa loob ng {} segundo.",
+    inconsistent_replay: "Iba ang estadong nabuo sa pag-replay ng journal kaysa sa iniwan ng mga transaksyon!",
+    #[cfg(feature = "serve")]
+    serving: "Sine-serve ang bangko sa http://{} hanggang maantala.",
+    #[cfg(feature = "grpc")]
+    serving_grpc: "Sine-serve ang bangko sa gRPC sa {} hanggang maantala.",
+    serving_telnet: "Sine-serve ang interactive menu sa {} hanggang maantala.",
+    restored_backup: "Naibalik ang backup mula {}.",
+    profiles: "Mga Profile:",
+    profile_name: "Pangalan ng Profile: ",
+    switched_profile: "Lumipat sa profile na {}.",
+    shared_profile: "Hindi mailipat ang profile habang ibinabahagi ang bangko nito sa ibang mga session!",
+    transactions_header: "Mga Transaksyon:",
+    aliases: "Mga Alyas: {}",
+    inputs: "Mga Input: {}",
+    cancel_hint: "I-input ang {} sa anumang prompt para kanselahin ang transaksyon.",
+    currencies: "Mga Salapi:",
+    configuration: "Kumpigurasyon:",
+    base_currency: "Batayang Salapi: {}",
+    profile: "Profile: {}",
+    unknown_transaction_id: "Walang transaksyon na may ganitong ID!",
+    unknown_transaction_name: "Walang transaksyon na may ganitong pangalan!",
+    ambiguous_transaction: "Higit sa isang transaksyon ang tumutugma sa pangalang ito!",
+    too_many_retries: "Masyadong maraming maling sagot, kinakansela ang transaksyon!",
+    invalid_yes_no: "[Y]es o [N]o lamang ang tinatanggap na sagot!",
+    invalid_id: "Ang ID ay dapat positibong buong numero (integer)!",
+    invalid_account_type: "Tumatanggap lamang ng ID o pangalan ng uri ng account!",
+    invalid_pin: "Dapat binubuo ng 4 hanggang 6 na digit ang PIN!",
+    invalid_sort_order: "Tumatanggap lamang ng name o balance!",
+    invalid_interest_mode: "Simple o compound lamang ang tinatanggap!",
+    invalid_compounding: "Daily, monthly, quarterly, o annually lamang ang tinatanggap!",
+    invalid_rate_kind: "apr o apy lamang ang tinatanggap!",
+    invalid_yield_rate: "Dapat numerong higit sa -100% ang antas!",
+    incorrect_pin: "Maling PIN!",
+    invalid_number: "Ang numero ay dapat positibong buong numero (integer)!",
+    invalid_amount: "Ang halaga ay dapat decimal na numero!",
+    invalid_deposit_amount: "Ang halagang ideposito ay dapat decimal na numero!",
+    invalid_withdraw_amount: "Ang halagang kukunin ay dapat decimal na numero!",
+    invalid_transfer_amount: "Dapat decimal na numero ang halagang ililipat!",
+    self_transfer: "Dapat sa ibang account ang paglilipat!",
+    nothing_to_undo: "Walang transaksiyong mababawi!",
+    invalid_interval: "Dapat hindi bababa sa isang araw ang pagitan!",
+    malformed_row: "Dapat may account, halaga, at opsyonal na salapi ang bawat hanay!",
+    invalid_row_amount: "Dapat positibong numero ang mga halagang idedeposito!",
+    invalid_approve_reject: "[A]prubahan o [R] (tanggihan) lamang ang tinatanggap na sagot!",
+    invalid_add_remove: "[A] (idagdag) o [R] (alisin) lamang ang tinatanggap na sagot!",
+    invalid_alert_condition: "Dapat >= o <= ang kondisyon!",
+    alert_not_found: "Walang aktibong alerto sa antas na may ganitong ID!",
+    schedule_not_found: "Walang aktibong iskedyul na may ganitong ID!",
+    approval_required: "Kailangang itabi para sa pag-apruba ang mga transaksyong ganito kalaki!",
+    pending_not_found: "Walang naghihintay na transaksyon na may ganitong ID!",
+    transaction_not_found: "Walang maibabalik na transaksyon na may ganitong numero ng sanggunian!",
+    already_reversed: "Naibalik na ang transaksyong ito!",
+    unknown_currency_code: "Walang salapi na may ganitong code!",
+    unexchangeable_currency: "Hindi pa maipapalit ang {}, kaya kailangan muna itong idagdag!",
+    unknown_currency_id: "Walang salapi na may ganitong ID!",
+    duplicate_currency: "Mayroon nang salapi na may ganitong code!",
+    invalid_currency_code: "Dapat binubuo ng tatlong titik ang code ng salapi!",
+    invalid_rate: "Dapat numero mula {} hanggang {} ang antas ng palitan!",
+    rate_out_of_bounds: "Ang antas ng palitan ng {} ay dapat mula {} hanggang {}!",
+    sharp_rate_change: "Ang bagong antas ng palitan ng {} ay naiiba nang {}% sa nauna.",
+    non_positive_amount: "Dapat positibo ang halaga!",
+    amount_above_max: "Dapat positibo at hindi hihigit sa {} ang halaga!",
+    invalid_spread: "Dapat mula 0% hanggang mas mababa sa 100% ang spread!",
+    stale_rate: "Luma na ang antas ng palitan ng {}, kaya dapat muna itong i-update!",
+    no_rate_at: "Walang naitalang antas ng palitan ng {} noon!",
+    unknown_account: "Walang account na may ganitong pangalan!",
+    account_closed: "Sarado na ang account na ito!",
+    account_frozen: "Naka-freeze ang account na ito, kaya hindi ito mapagdedepositohan o mapagkukunan!",
+    nonzero_balance: "Ang mga account lamang na may zero na balanse ang maaaring isara!",
+    no_owners: "Dapat may kahit isang may-ari ang account!",
+    invalid_email: "Dapat isulat ang email tulad ng name@example.com!",
+    invalid_phone: "Dapat may 7 hanggang 15 na digit ang numero ng telepono!",
+    invalid_overdraft_limit: "Hindi dapat negatibo ang limitasyon ng overdraft!",
+    invalid_interest_rate: "Dapat numerong higit sa -100% ang antas ng interes!",
+    no_interest_due: "Walang naipong interes mula nang huli itong i-post!",
+    daily_limit_exceeded: "Hindi dapat lumampas ang halagang iwi-withdraw sa natitirang {} ng limitasyon ngayong araw!",
+    invalid_withdrawal_limit: "Hindi dapat negatibo ang limitasyon ng pag-withdraw bawat araw!",
+    fee_exceeds_amount: "Dapat mas malaki ang halagang papalitan kaysa sa bayad nito!",
+    below_minimum_balance: "Dapat maiwan ng halagang kukunin ang minimum na balanse ng account!",
+    invalid_minimum_balance: "Hindi dapat negatibo ang mga minimum na balanse at ang mga multa nito!",
+    duplicate_account: "May account na sa ganitong pangalan!",
+    insufficient_balance: "Ang halagang kukunin ay dapat mas mababa sa kasalukuyang balanse!",
+    failed_journal_write: "Hindi maisulat sa journal: {}",
+    failed_journal_open: "Hindi mabuksan ang journal file: {}",
+    incorrect_passphrase: "Maling passphrase!",
+    failed_backup: "Hindi ma-access ang backup: {}",
+    failed_backup_list: "Hindi mailista ang mga backup: {}",
+    failed_rate_request: "Hindi maabot ang tagapagbigay ng antas: {}",
+    malformed_rates: "Hindi mabasa ang tugon ng tagapagbigay ng antas!",
+    malformed_rate_row: "Dapat may salapi at antas ang linya {}!",
+    unknown_rate_currency: "Ang linya {} ay may {}, na hindi napapalitang dayuhang salapi!",
+    invalid_rate_row: "Sa linya {}, dapat mula {} hanggang {} ang antas!",
+    no_backups: "Wala pang backup!",
+    unknown_backup: "Walang backup na may ganitong ID!",
+    failed_profile_list: "Hindi mailista ang mga profile: {}",
+    invalid_profile_name: "Ang pangalan ng profile ay maaari lamang maglaman ng mga titik, numero, gitling, at underscore!",
+    unterminated_quotes: "May hindi naisarang panipi sa utos!",
+    script_line_error: "Linya {}: {}",
+    script_stopped: "Huminto ang script sa linya {}!",
+    script_failures: "{} na utos sa script ang pumalya!",
+    completions_in_script: "Hindi makakagawa ng completion script mula sa isang script!",
+    telnet_in_script: "Hindi maise-serve ang interactive menu mula sa isang script!",
+    #[cfg(feature = "serve")]
+    serve_in_script: "Hindi maise-serve ang bangko mula sa isang script!",
+    #[cfg(feature = "grpc")]
+    grpc_in_script: "Hindi maise-serve ang bangko sa gRPC mula sa isang script!",
+    #[cfg(feature = "serve")]
+    invalid_contribution: "Dapat may halaga at positibong bilang ng araw sa pagitan ng mga deposito ang nakaplanong deposito!",
+    #[cfg(feature = "tui")]
+    tui_forms: [
+        "Magrehistro ng Account",
+        "Magdeposito ng Halaga",
+        "Kumuha ng Halaga",
+        "Itala ang Antas ng Palitan",
+    ],
+    #[cfg(feature = "tui")]
+    tui_account_name: "Pangalan ng Account",
+    #[cfg(feature = "tui")]
+    tui_account_type: "Uri ng Account (savings/checking/time-deposit)",
+    #[cfg(feature = "tui")]
+    tui_pin: "PIN",
+    #[cfg(feature = "tui")]
+    tui_currency: "Salapi",
+    #[cfg(feature = "tui")]
+    tui_amount: "Halaga",
+    #[cfg(feature = "tui")]
+    tui_exchange_rate: "Antas ng Palitan",
+    #[cfg(feature = "tui")]
+    tui_accounts: "Mga Account",
+    #[cfg(feature = "tui")]
+    tui_exchange_rates: "Mga Antas ng Palitan",
+    #[cfg(feature = "tui")]
+    tui_transaction: "Transaksyon",
+    #[cfg(feature = "tui")]
+    tui_account: "Account: {}",
+    #[cfg(feature = "tui")]
+    tui_none_selected: "(walang napili)",
+    #[cfg(feature = "tui")]
+    tui_select_account: "Pumili muna ng account!",
+    #[cfg(feature = "tui")]
+    tui_keys: "Tab: lumipat ng pane | ↑/↓: pumili | ←/→: transaksyon | Enter: isumite | Esc: umalis",
+};
+
+/// The language the messages are printed in.
+static LANG: OnceLock<Lang> = OnceLock::new();
+
+/// Sets the language the messages are printed in.
+pub fn init(lang: Lang) {
+    let _ = LANG.set(lang);
+}
+
+/// Returns the messages in the chosen language.
+pub fn messages() -> &'static Messages {
+    match LANG.get().copied().unwrap_or_default() {
+        Lang::En => &ENGLISH,
+        Lang::Fil => &FILIPINO,
+    }
+}
+
+/// Fills a message's `{}` placeholders with the arguments, in order.
+pub fn fill(template: &str, args: &[&dyn fmt::Display]) -> String {
+    let mut parts = template.split("{}");
+    let mut filled = parts.next().unwrap_or_default().to_string();
+
+    for (i, part) in parts.enumerate() {
+        if let Some(arg) = args.get(i) {
+            filled.push_str(&arg.to_string());
+        }
+
+        filled.push_str(part);
+    }
+
+    filled
+}
+
+/// Describes a bank's error in the chosen language.
+pub fn bank_error(err: &BankError) -> String {
+    let messages = messages();
+
+    match err {
+        BankError::DuplicateAccount => messages.duplicate_account.to_string(),
+        BankError::AccountNotFound => messages.unknown_account.to_string(),
+        BankError::UnknownCurrency => messages.unknown_currency_code.to_string(),
+        BankError::DuplicateCurrency => messages.duplicate_currency.to_string(),
+        BankError::InvalidCurrencyCode => messages.invalid_currency_code.to_string(),
+        BankError::InvalidAmount { max: None } => messages.non_positive_amount.to_string(),
+        BankError::InvalidAmount { max: Some(max) } => fill(messages.amount_above_max, &[max]),
+        BankError::InvalidRate => fill(messages.invalid_rate, &[&MIN_RATE, &MAX_RATE]),
+        BankError::RateOutOfBounds { currency, bounds } => {
+            fill(messages.rate_out_of_bounds, &[currency, &bounds.min, &bounds.max])
+        }
+        BankError::InvalidSpread => messages.invalid_spread.to_string(),
+        BankError::StaleRate { currency } => fill(messages.stale_rate, &[currency]),
+        BankError::NoRateAt { currency } => fill(messages.no_rate_at, &[currency]),
+        BankError::InsufficientBalance => messages.insufficient_balance.to_string(),
+        BankError::AccountClosed => messages.account_closed.to_string(),
+        BankError::AccountFrozen => messages.account_frozen.to_string(),
+        BankError::SelfTransfer => messages.self_transfer.to_string(),
+        BankError::NonzeroBalance => messages.nonzero_balance.to_string(),
+        BankError::InvalidOverdraftLimit => messages.invalid_overdraft_limit.to_string(),
+        BankError::InvalidInterestRate => messages.invalid_interest_rate.to_string(),
+        BankError::NoInterestDue => messages.no_interest_due.to_string(),
+        BankError::DailyLimitExceeded { remaining, currency } => {
+            fill(messages.daily_limit_exceeded, &[&money(*remaining, currency)])
+        }
+        BankError::InvalidWithdrawalLimit => messages.invalid_withdrawal_limit.to_string(),
+        BankError::FeeExceedsAmount => messages.fee_exceeds_amount.to_string(),
+        BankError::BelowMinimumBalance => messages.below_minimum_balance.to_string(),
+        BankError::InvalidMinimumBalance => messages.invalid_minimum_balance.to_string(),
+        BankError::InvalidEmail => messages.invalid_email.to_string(),
+        BankError::InvalidPhone => messages.invalid_phone.to_string(),
+        BankError::NoOwners => messages.no_owners.to_string(),
+        BankError::InvalidPin => messages.invalid_pin.to_string(),
+        BankError::NothingToUndo => messages.nothing_to_undo.to_string(),
+        BankError::InvalidInterval => messages.invalid_interval.to_string(),
+        BankError::ScheduleNotFound => messages.schedule_not_found.to_string(),
+        BankError::AlertNotFound => messages.alert_not_found.to_string(),
+        BankError::ApprovalRequired => messages.approval_required.to_string(),
+        BankError::PendingNotFound => messages.pending_not_found.to_string(),
+        BankError::TransactionNotFound => messages.transaction_not_found.to_string(),
+        BankError::AlreadyReversed => messages.already_reversed.to_string(),
+        BankError::IncorrectPin => messages.incorrect_pin.to_string(),
+        BankError::Journal(err) => fill(messages.failed_journal_write, &[err]),
+        BankError::Backup(err) => fill(messages.failed_backup, &[err]),
+        BankError::Rates(err) => rate_error(err),
+    }
+}
+
+/// Describes why the exchange rates couldn't be fetched in the chosen language.
+pub fn rate_error(err: &RateError) -> String {
+    let messages = messages();
+
+    match err {
+        RateError::Request(err) => fill(messages.failed_rate_request, &[err]),
+        RateError::Malformed => messages.malformed_rates.to_string(),
+        RateError::Cancelled => messages.cancelled.to_string(),
+        RateError::MalformedRow { line } => fill(messages.malformed_rate_row, &[line]),
+        RateError::UnknownCurrency { line, code } => fill(messages.unknown_rate_currency, &[line, code]),
+        RateError::InvalidRate { line } => fill(messages.invalid_rate_row, &[line, &MIN_RATE, &MAX_RATE]),
+    }
+}
+
+/// Describes why a row of deposits was rejected in the chosen language.
+pub fn row_error(err: &RowError) -> String {
+    let messages = messages();
+
+    match err {
+        RowError::Malformed => messages.malformed_row.to_string(),
+        RowError::InvalidAmount => messages.invalid_row_amount.to_string(),
+        RowError::Bank(err) => bank_error(err),
+    }
+}
+
+/// Describes an error in the chosen language, if it is a bank's error.
+pub fn describe<T: fmt::Display + 'static>(err: &T) -> String {
+    let err_any = err as &dyn Any;
+    let bank_err = err_any.downcast_ref::<BankError>().or_else(|| {
+        err_any
+            .downcast_ref::<Box<dyn Error>>()
+            .and_then(|e| e.downcast_ref::<BankError>())
+    });
+
+    bank_err.map_or_else(|| err.to_string(), bank_error)
+}
@@ -0,0 +1,451 @@
+//! The interest earned by the accounts' balances.
+
+use std::{fmt, str::FromStr};
+
+use chrono::{Datelike, Days, NaiveDate};
+use rust_decimal::{Decimal, MathematicalOps};
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+use crate::currency::RoundingPolicy;
+
+/// The annual interest rate of savings accounts, as a fraction.
+pub const SAVINGS_INTEREST_RATE: Decimal = dec!(0.05);
+/// The annual interest rate of time deposits, as a fraction.
+pub const TIME_DEPOSIT_INTEREST_RATE: Decimal = dec!(0.065);
+/// The annual interest rate charged on overdrawn balances, as a fraction.
+pub const OVERDRAFT_INTEREST_RATE: Decimal = dec!(0.18);
+
+/// The header of a CSV file of an interest schedule.
+const CSV_HEADER: [&str; 6] = ["day", "interest", "tax", "overdraft_charge", "deposit", "balance"];
+
+/// The number of days in a year that the compounding periods divide.
+const DAYS_PER_YEAR: u32 = 365;
+
+/// The ways that interest accrues over a number of days.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum InterestMode {
+    /// Each day's interest is calculated from the starting balance.
+    #[default]
+    Simple,
+    /// Each day's interest is calculated from the balance after the previous days' interest.
+    Compound,
+}
+impl InterestMode {
+    /// The tags of the modes.
+    pub const TAGS: [&str; 2] = ["simple", "compound"];
+}
+impl fmt::Display for InterestMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", InterestMode::TAGS[*self as usize])
+    }
+}
+impl FromStr for InterestMode {
+    type Err = String;
+
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        match tag.to_lowercase().as_str() {
+            "simple" => Ok(InterestMode::Simple),
+            "compound" => Ok(InterestMode::Compound),
+            _ => Err(format!(
+                "unknown interest mode, expected one of: {}",
+                InterestMode::TAGS.join(", ")
+            )),
+        }
+    }
+}
+
+/// How often earned interest is added to a balance, dividing the year into equal periods.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Compounding {
+    /// Interest is added every day.
+    #[default]
+    Daily,
+    /// Interest is added every month, a twelfth of the year.
+    Monthly,
+    /// Interest is added every quarter, a fourth of the year.
+    Quarterly,
+    /// Interest is added once a year.
+    Annually,
+}
+impl Compounding {
+    /// The tags of the frequencies.
+    pub const TAGS: [&str; 4] = ["daily", "monthly", "quarterly", "annually"];
+
+    /// Returns the number of periods that interest is added in each year.
+    pub fn periods_per_year(self) -> u32 {
+        match self {
+            Compounding::Daily => DAYS_PER_YEAR,
+            Compounding::Monthly => 12,
+            Compounding::Quarterly => 4,
+            Compounding::Annually => 1,
+        }
+    }
+
+    /// Returns the effective annual rate, or the annual percentage yield, of a nominal annual rate compounded at the
+    /// frequency.
+    pub fn effective_rate(self, nominal_rate: Decimal) -> Decimal {
+        let periods = Decimal::from(self.periods_per_year());
+
+        (Decimal::ONE + nominal_rate / periods)
+            .checked_powu(self.periods_per_year() as u64)
+            .unwrap_or(Decimal::MAX)
+            - Decimal::ONE
+    }
+
+    /// Returns the nominal annual rate, or the annual percentage rate, that yields an effective annual rate when
+    /// compounded at the frequency.
+    pub fn nominal_rate(self, effective_rate: Decimal) -> Decimal {
+        let periods = Decimal::from(self.periods_per_year());
+
+        periods * ((Decimal::ONE + effective_rate).powd(Decimal::ONE / periods) - Decimal::ONE)
+    }
+
+    /// Checks whether a day, numbered from one, is the last of a period.
+    ///
+    /// The periods split each year of days as evenly as whole days allow, so a month lasts 30 or 31 days.
+    pub fn ends_period(self, day: u32) -> bool {
+        let periods = self.periods_per_year();
+        let period_of = |day: u32| (day - 1) % DAYS_PER_YEAR * periods / DAYS_PER_YEAR;
+
+        day.is_multiple_of(DAYS_PER_YEAR) || period_of(day) != period_of(day + 1)
+    }
+}
+impl fmt::Display for Compounding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Compounding::TAGS[*self as usize])
+    }
+}
+impl FromStr for Compounding {
+    type Err = String;
+
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        match tag.to_lowercase().as_str() {
+            "daily" => Ok(Compounding::Daily),
+            "monthly" => Ok(Compounding::Monthly),
+            "quarterly" => Ok(Compounding::Quarterly),
+            "annually" => Ok(Compounding::Annually),
+            _ => Err(format!(
+                "unknown compounding frequency, expected one of: {}",
+                Compounding::TAGS.join(", ")
+            )),
+        }
+    }
+}
+
+/// The conventions for counting the days between two dates as a fraction of a year.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum DayCount {
+    /// Every actual day counts, out of the actual number of days in its year, 365 or 366 in a leap year.
+    #[default]
+    #[serde(rename = "act/act")]
+    ActualActual,
+    /// Every actual day counts, out of a year of 365 days, even in a leap year.
+    #[serde(rename = "act/365")]
+    Actual365,
+    /// Every actual day counts, out of a year of 360 days.
+    #[serde(rename = "act/360")]
+    Actual360,
+    /// Every month counts as 30 days, out of a year of 360 days, so the 31st of a month counts for nothing and the end
+    /// of February counts for the rest of its month.
+    #[serde(rename = "30/360")]
+    Thirty360,
+}
+impl DayCount {
+    /// The tags of the conventions.
+    pub const TAGS: [&str; 4] = ["act/act", "act/365", "act/360", "30/360"];
+
+    /// Returns the fraction of a year between two dates.
+    pub fn year_fraction(self, from: NaiveDate, to: NaiveDate) -> Decimal {
+        match self {
+            DayCount::ActualActual => {
+                let mut fraction = Decimal::ZERO;
+                let mut from = from;
+
+                // The days are counted a year at a time, since each year divides its own days by its length.
+                while from < to {
+                    let next_year = NaiveDate::from_ymd_opt(from.year() + 1, 1, 1).unwrap_or(NaiveDate::MAX);
+                    let year_start = NaiveDate::from_ymd_opt(from.year(), 1, 1).unwrap_or(NaiveDate::MIN);
+                    let until = next_year.min(to);
+
+                    fraction +=
+                        Decimal::from((until - from).num_days()) / Decimal::from((next_year - year_start).num_days());
+                    from = until;
+                }
+
+                fraction
+            }
+            DayCount::Actual365 => Decimal::from((to - from).num_days()) / dec!(365),
+            DayCount::Actual360 => Decimal::from((to - from).num_days()) / dec!(360),
+            DayCount::Thirty360 => {
+                let day_cnt = 360 * (to.year() - from.year())
+                    + 30 * (to.month() as i32 - from.month() as i32)
+                    + (to.day().min(30) as i32 - from.day().min(30) as i32);
+
+                Decimal::from(day_cnt) / dec!(360)
+            }
+        }
+    }
+}
+impl fmt::Display for DayCount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", DayCount::TAGS[*self as usize])
+    }
+}
+impl FromStr for DayCount {
+    type Err = String;
+
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        match tag.to_lowercase().as_str() {
+            "act/act" => Ok(DayCount::ActualActual),
+            "act/365" => Ok(DayCount::Actual365),
+            "act/360" => Ok(DayCount::Actual360),
+            "30/360" => Ok(DayCount::Thirty360),
+            _ => Err(format!(
+                "unknown day-count convention, expected one of: {}",
+                DayCount::TAGS.join(", ")
+            )),
+        }
+    }
+}
+
+/// A band of balances that earns its own annual interest rate, instead of the account's.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RateTier {
+    /// The balance that the band's balances are below, or none if the band has no upper bound.
+    pub below: Option<Decimal>,
+    /// The annual interest rate percentage earned by balances in the band.
+    pub percentage: Decimal,
+}
+
+/// Finds the annual interest rate earned by a balance from the tiers it falls in, if any are given.
+///
+/// The tiers are ordered from the lowest band up, so the first one whose upper bound is above the balance is used. A
+/// balance above every bound earns nothing from the tiers.
+pub fn tiered_rate(tiers: &[RateTier], balance: Decimal) -> Option<Decimal> {
+    tiers
+        .iter()
+        .find(|t| t.below.is_none_or(|below| balance < below))
+        .map(|t| t.percentage / Decimal::ONE_HUNDRED)
+}
+
+/// The terms that a balance earns interest by.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InterestTerms<'a> {
+    /// The annual interest rate, as a fraction.
+    pub annual_rate: Decimal,
+    /// The bands of balances that earn their own interest rates instead of the annual rate.
+    pub tiers: &'a [RateTier],
+    /// The way the interest accrues.
+    pub mode: InterestMode,
+    /// How often the interest is added to the balance.
+    pub compounding: Compounding,
+    /// How the days that the interest accrues over are counted.
+    pub day_count: DayCount,
+    /// The share of the interest withheld as tax, as a fraction.
+    pub withholding_rate: Decimal,
+    /// How the interest, tax, and balance are rounded to the cent.
+    pub rounding: RoundingPolicy,
+}
+impl InterestTerms<'_> {
+    /// Returns the effective annual rate of the annual rate, which only compounds in the compound mode.
+    pub fn effective_rate(&self) -> Decimal {
+        match self.mode {
+            InterestMode::Simple => self.annual_rate,
+            InterestMode::Compound => self.compounding.effective_rate(self.annual_rate),
+        }
+    }
+}
+
+/// A deposit planned to be made regularly, for projecting the growth of a savings plan.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Contribution {
+    /// The amount deposited each time, in the balance's currency.
+    pub amount: Decimal,
+    /// The number of days between the deposits, the first being made at the end of the first interval.
+    pub interval_days: u64,
+}
+
+/// A day in an interest schedule.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct InterestRow {
+    /// The number of the day, starting from one.
+    pub day: u32,
+    /// The interest earned on the day, before its tax is withheld.
+    pub interest: Decimal,
+    /// The tax withheld from the interest earned on the day.
+    pub tax: Decimal,
+    /// The interest charged on the day for an overdrawn balance.
+    pub overdraft_charge: Decimal,
+    /// The planned deposit made at the end of the day.
+    pub deposit: Decimal,
+    /// The balance at the end of the day.
+    pub balance: Decimal,
+}
+
+/// Calculates the daily change to a balance from its interest terms over a number of days, starting from a date, with
+/// any planned deposits.
+///
+/// Interest is added at the end of each compounding period, at the period's share of the annual rate, and rounded to
+/// two decimal places by the rounding policy. It is calculated from the period's starting principal, the starting
+/// balance and the planned deposits made so far, in the simple mode, or from the period's starting balance in the
+/// compound mode, so a deposit only earns interest from the next period. Daily periods, and any period cut short by the
+/// end of the schedule, earn the fraction of the annual rate that their dates span by the day-count convention instead.
+/// A negative rate takes interest from the balance instead. An overdrawn balance earns no interest, but is charged at
+/// the overdraft interest rate instead. The withholding tax is deducted from positive interest before it is added.
+///
+/// If rate tiers are given, each day earns the rate of the tier that the day's balance falls in, falling back to the
+/// annual rate for balances outside every tier. A period whose balance crosses into another tier partway through, like
+/// by a planned deposit, earns each tier's rate for the days its balance spent in that tier.
+pub fn schedule(
+    balance: Decimal,
+    terms: &InterestTerms,
+    start: NaiveDate,
+    day_cnt: u32,
+    contribution: Option<Contribution>,
+) -> Vec<InterestRow> {
+    let InterestTerms {
+        annual_rate,
+        tiers,
+        mode,
+        compounding,
+        day_count,
+        withholding_rate,
+        rounding,
+    } = *terms;
+    let mut balance = balance;
+    let mut principal = balance;
+    let mut period_balance = balance;
+    let mut period_principal = balance;
+    let mut period_start = start;
+    let mut period_rates = Decimal::ZERO;
+    let mut period_days = 0;
+
+    (1..=day_cnt)
+        .map(|day| {
+            let date = start + Days::new(day as u64);
+            let is_period_end = compounding.ends_period(day);
+
+            period_rates += tiered_rate(tiers, balance).unwrap_or(annual_rate);
+            period_days += 1;
+            let mut row = InterestRow {
+                day,
+                interest: Decimal::ZERO,
+                tax: Decimal::ZERO,
+                overdraft_charge: Decimal::ZERO,
+                deposit: Decimal::ZERO,
+                balance,
+            };
+
+            if is_period_end || day == day_cnt {
+                let basis = match mode {
+                    InterestMode::Simple => period_principal,
+                    InterestMode::Compound => period_balance,
+                };
+                let share = if is_period_end && compounding != Compounding::Daily {
+                    Decimal::ONE / Decimal::from(compounding.periods_per_year())
+                } else {
+                    day_count.year_fraction(period_start, date)
+                };
+                // Each day of the period earns the rate of its own balance's tier, so the period earns their average.
+                let annual_rate = period_rates / Decimal::from(period_days);
+
+                row.interest = rounding.round(basis.max(Decimal::ZERO) * annual_rate * share, 2);
+                row.tax = rounding.round(row.interest.max(Decimal::ZERO) * withholding_rate, 2);
+                row.overdraft_charge =
+                    rounding.round(basis.min(Decimal::ZERO).abs() * OVERDRAFT_INTEREST_RATE * share, 2);
+
+                // The balance is kept to the cent too, so that the noise of adding the interest doesn't build up.
+                balance = rounding.round(balance + row.interest - row.tax - row.overdraft_charge, 2);
+            }
+
+            if let Some(contribution) = contribution
+                && u64::from(day).is_multiple_of(contribution.interval_days)
+            {
+                row.deposit = contribution.amount;
+                principal += contribution.amount;
+                balance = rounding.round(balance + contribution.amount, 2);
+            }
+
+            row.balance = balance;
+
+            if is_period_end {
+                period_balance = balance;
+                period_principal = principal;
+                period_start = date;
+                period_rates = Decimal::ZERO;
+                period_days = 0;
+            }
+
+            row
+        })
+        .collect()
+}
+
+/// Writes an interest schedule as CSV, under a header naming the fields, with a row for each day.
+pub fn to_csv(schedule: &[InterestRow]) -> String {
+    let mut csv = CSV_HEADER.join(",");
+
+    for row in schedule {
+        csv.push_str(&format!(
+            "\n{},{},{},{},{},{}",
+            row.day, row.interest, row.tax, row.overdraft_charge, row.deposit, row.balance
+        ));
+    }
+
+    csv.push('\n');
+
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tiers_apply_per_day_within_compounding_period() {
+        let tiers = [
+            RateTier {
+                below: Some(dec!(10000)),
+                percentage: dec!(2),
+            },
+            RateTier {
+                below: None,
+                percentage: dec!(5),
+            },
+        ];
+        let terms = InterestTerms {
+            annual_rate: dec!(0.05),
+            tiers: &tiers,
+            mode: InterestMode::Simple,
+            compounding: Compounding::Monthly,
+            day_count: DayCount::Actual365,
+            withholding_rate: Decimal::ZERO,
+            rounding: RoundingPolicy::HalfUp,
+        };
+        let contribution = Contribution {
+            amount: dec!(2000),
+            interval_days: 15,
+        };
+
+        let rows = schedule(
+            dec!(9000),
+            &terms,
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            31,
+            Some(contribution),
+        );
+
+        // The first 15 days earn 2% below the tier's bound, and the last 16 earn 5% after the deposit crosses it.
+        assert!(rows[..30].iter().all(|r| r.interest == Decimal::ZERO));
+        assert_eq!(rows[30].interest, dec!(26.61));
+    }
+}
@@ -0,0 +1,91 @@
+//! The [ISO 4217](https://en.wikipedia.org/wiki/ISO_4217) registry of real-world currencies, bundled with the app along
+//! with the common cryptocurrencies that ISO 4217 doesn't list.
+
+use std::sync::LazyLock;
+
+/// The bundled table of currencies, written as CSV with a header of `code,name,minor_units,symbol`.
+const TABLE: &str = include_str!("iso4217.csv");
+
+/// The cryptocurrencies that can be registered like the currencies listed in ISO 4217.
+///
+/// Ether is kept to the gwei, nine decimal places, since its smallest unit, the wei, is finer than amounts can hold.
+const CRYPTOCURRENCIES: [IsoCurrency; 3] = [
+    IsoCurrency {
+        code: "BTC",
+        name: "Bitcoin",
+        minor_units: 8,
+        symbol: Some("₿"),
+    },
+    IsoCurrency {
+        code: "ETH",
+        name: "Ether",
+        minor_units: 9,
+        symbol: Some("Ξ"),
+    },
+    IsoCurrency {
+        code: "LTC",
+        name: "Litecoin",
+        minor_units: 8,
+        symbol: Some("Ł"),
+    },
+];
+
+/// The currencies of the bundled table, read on first use, followed by the cryptocurrencies.
+static CURRENCIES: LazyLock<Vec<IsoCurrency>> = LazyLock::new(|| {
+    TABLE
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let mut fields = line.split(',');
+
+            Some(IsoCurrency {
+                code: fields.next()?,
+                name: fields.next()?,
+                minor_units: fields.next()?.parse().ok()?,
+                symbol: fields.next().filter(|s| !s.is_empty()),
+            })
+        })
+        .chain(CRYPTOCURRENCIES)
+        .collect()
+});
+
+/// A real-world currency listed in ISO 4217, or a cryptocurrency listed alongside them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IsoCurrency {
+    /// The three-letter code of the currency.
+    pub code: &'static str,
+    /// The English name of the currency.
+    pub name: &'static str,
+    /// The number of decimal places of the currency's minor units, like centavos.
+    pub minor_units: u32,
+    /// The symbol the currency is commonly written with, if it has one besides its code.
+    pub symbol: Option<&'static str>,
+}
+impl IsoCurrency {
+    /// Returns the title or label of the currency, written like the built-in currencies' titles.
+    pub fn title(&self) -> String {
+        format!("{} ({})", self.name, self.code)
+    }
+}
+
+/// Returns every currency in the registry, those listed in ISO 4217 sorted by code and then the cryptocurrencies.
+pub fn currencies() -> &'static [IsoCurrency] {
+    &CURRENCIES
+}
+
+/// Finds a currency by its code, ignoring case.
+pub fn find_by_code(code: &str) -> Option<&'static IsoCurrency> {
+    CURRENCIES.iter().find(|c| c.code.eq_ignore_ascii_case(code.trim()))
+}
+
+/// Finds a currency by its English name, ignoring case.
+pub fn find_by_name(name: &str) -> Option<&'static IsoCurrency> {
+    let name = name.trim().to_lowercase();
+
+    CURRENCIES.iter().find(|c| c.name.to_lowercase() == name)
+}
+
+/// Finds a currency by its code or, failing that, its English name, ignoring case.
+pub fn find(query: &str) -> Option<&'static IsoCurrency> {
+    find_by_code(query).or_else(|| find_by_name(query))
+}
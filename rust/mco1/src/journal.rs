@@ -0,0 +1,713 @@
+//! The append-only journal of the bank's transactions.
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+};
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use serde_json::Value;
+use tracing::warn;
+
+use crate::{
+    account::{AccountType, Contact, MinimumBalance},
+    alert::AlertCondition,
+    approval::HeldTransaction,
+    bank::Snapshot,
+    crypto::{self, Cipher},
+    currency::Currency,
+    interest::Compounding,
+    schedule::ScheduledTransaction,
+    schema::{self, SCHEMA_VERSION},
+};
+
+/// The default path of the journal file.
+pub const JOURNAL_PATH: &str = "journal.jsonl";
+
+/// A point in a journal that a bank's state can be replayed up to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReplayPoint {
+    /// Right after a number of entries, counted from the oldest.
+    Entries(usize),
+    /// The end of a time, in seconds since the Unix epoch, including the entries committed at it.
+    Time(u64),
+}
+impl ReplayPoint {
+    /// Checks whether an entry, at an index counted from the oldest, comes before the point.
+    pub fn includes(self, idx: usize, entry: &Entry) -> bool {
+        match self {
+            ReplayPoint::Entries(entry_cnt) => idx < entry_cnt,
+            ReplayPoint::Time(timestamp) => entry.timestamp <= timestamp,
+        }
+    }
+}
+
+/// A transaction recorded in the journal.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    /// The version of the format the entry was saved with.
+    pub version: u32,
+    /// The time the transaction was committed, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The idempotency key of the operation the transaction was the first of, if it was given one.
+    pub idempotency_key: Option<String>,
+    /// What the transaction did.
+    #[serde(flatten)]
+    pub kind: EntryKind,
+}
+impl Entry {
+    /// Creates a new entry timestamped with a time, in seconds since the Unix epoch.
+    pub fn new(kind: EntryKind, timestamp: u64) -> Entry {
+        Entry {
+            version: SCHEMA_VERSION,
+            timestamp,
+            idempotency_key: None,
+            kind,
+        }
+    }
+}
+
+/// The reasons a fee can be charged to an account.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum FeeReason {
+    /// A withdrawal left the account below its type's minimum balance.
+    BelowMinimumWithdrawal,
+    /// The account was below its type's minimum balance at the end of a period.
+    BelowMinimumPeriod,
+    /// The fee schedule charges for withdrawals.
+    Withdrawal,
+    /// The fee schedule charges for transfers.
+    Transfer,
+}
+
+/// The kinds of transactions recorded in the journal.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EntryKind {
+    /// A new account was registered.
+    Register {
+        /// The name of the registered account.
+        account: String,
+        /// The names of the registered account's owners.
+        owners: Vec<String>,
+        /// The type of the registered account.
+        account_type: AccountType,
+        /// The annual interest rate earned by the registered account, as a fraction.
+        interest_rate: Decimal,
+        /// The contact details of the registered account's owners.
+        contact: Contact,
+        /// The Argon2 hash of the registered account's PIN.
+        pin_hash: String,
+    },
+    /// Balance was deposited to an account.
+    Deposit {
+        /// The name of the account deposited to.
+        account: String,
+        /// The deposited amount, in the deposited currency.
+        amount: Decimal,
+        /// The currency of the deposited amount.
+        currency: String,
+        /// The deposited amount, in the account's currency.
+        converted_amount: Decimal,
+        /// The note attached to the deposit, if any.
+        memo: Option<String>,
+    },
+    /// Balance was withdrawn from an account.
+    Withdrawal {
+        /// The name of the account withdrawn from.
+        account: String,
+        /// The withdrawn amount, in the withdrawn currency.
+        amount: Decimal,
+        /// The currency of the withdrawn amount.
+        currency: String,
+        /// The withdrawn amount, in the account's currency.
+        converted_amount: Decimal,
+        /// The penalty charged for the withdrawal, in the account's currency.
+        penalty: Decimal,
+        /// The note attached to the withdrawal, if any.
+        memo: Option<String>,
+    },
+    /// Balance was transferred from one account to another.
+    Transfer {
+        /// The name of the account transferred from.
+        account: String,
+        /// The name of the account transferred to.
+        recipient: String,
+        /// The transferred amount, in the sending account's currency.
+        amount: Decimal,
+        /// The penalty charged to the sending account for the transfer, in its currency.
+        penalty: Decimal,
+        /// The transferred amount, in the receiving account's currency.
+        received_amount: Decimal,
+        /// The note attached to the transfer, if any.
+        memo: Option<String>,
+    },
+    /// An account with a zero balance was closed.
+    Close {
+        /// The name of the closed account.
+        account: String,
+    },
+    /// An account was frozen or unfrozen.
+    Freeze {
+        /// The name of the account.
+        account: String,
+        /// Whether the account is now frozen.
+        is_frozen: bool,
+    },
+    /// The overdraft limit of an account was set.
+    SetOverdraft {
+        /// The name of the account.
+        account: String,
+        /// How far below zero withdrawals can now push the account's balance.
+        limit: Decimal,
+    },
+    /// The annual interest rate earned by an account was set.
+    SetInterestRate {
+        /// The name of the account.
+        account: String,
+        /// The new annual interest rate, as a fraction.
+        rate: Decimal,
+    },
+    /// How often the interest earned by an account compounds was set.
+    SetCompounding {
+        /// The name of the account.
+        account: String,
+        /// The new compounding frequency.
+        compounding: Compounding,
+    },
+    /// The daily withdrawal limit of an account was set.
+    SetWithdrawalLimit {
+        /// The name of the account.
+        account: String,
+        /// The most that can now be withdrawn from the account each day, or none to use the bank's default.
+        limit: Option<Decimal>,
+    },
+    /// The minimum balance of a type of account was set.
+    SetMinimumBalance {
+        /// The type of account.
+        account_type: AccountType,
+        /// The new minimum balance and its fees.
+        minimum: MinimumBalance,
+    },
+    /// A fee was charged to an account.
+    Fee {
+        /// The name of the account charged.
+        account: String,
+        /// The charged amount, in the account's currency.
+        amount: Decimal,
+        /// Why the fee was charged.
+        reason: FeeReason,
+    },
+    /// The interest earned by an account was posted to its balance.
+    PostInterest {
+        /// The name of the account.
+        account: String,
+        /// The interest credited before its tax was withheld, in the account's currency.
+        interest: Decimal,
+        /// The tax withheld from the interest, in the account's currency.
+        tax: Decimal,
+        /// The interest charged for an overdrawn balance, in the account's currency.
+        overdraft_charge: Decimal,
+        /// The time the interest was posted up to, in seconds since the Unix epoch.
+        until: u64,
+    },
+    /// An account was renamed.
+    Rename {
+        /// The previous name of the account.
+        account: String,
+        /// The new name of the account.
+        new_name: String,
+    },
+    /// The owners of an account were replaced.
+    SetOwners {
+        /// The name of the account.
+        account: String,
+        /// The names of the account's new owners.
+        owners: Vec<String>,
+    },
+    /// An amount was exchanged from one currency to another.
+    Exchange {
+        /// The exchanged amount, in the source currency.
+        amount: Decimal,
+        /// The source currency.
+        currency: String,
+        /// The currency exchanged to.
+        exchange_currency: String,
+        /// The exchanged amount, in the currency exchanged to.
+        exchange_amount: Decimal,
+        /// The fee deducted from the amount before it was exchanged, in the source currency.
+        fee: Decimal,
+        /// The bank's income from the spreads of the currencies' rates, in the currency exchanged to.
+        spread: Decimal,
+    },
+    /// The exchange rate of a currency was updated.
+    RateUpdate {
+        /// The currency whose rate was updated.
+        currency: String,
+        /// The new exchange rate.
+        rate: Decimal,
+    },
+    /// The spread between the rates a foreign currency is bought and sold at was updated.
+    SetSpread {
+        /// The currency whose spread was updated.
+        currency: String,
+        /// The new spread, as a percentage of the currency's exchange rate.
+        spread: Decimal,
+    },
+    /// A currency was registered along with its exchange rate.
+    AddCurrency {
+        /// The registered currency.
+        currency: Currency,
+        /// The value of one unit of the currency in Philippine Pesos.
+        rate: Decimal,
+    },
+    /// A transaction was scheduled to repeat.
+    CreateSchedule {
+        /// The repeated transaction.
+        transaction: ScheduledTransaction,
+        /// The number of days between the transaction's runs.
+        interval_days: u64,
+        /// The time of the transaction's first run, in seconds since the Unix epoch.
+        first_run_at: u64,
+        /// The note attached to every run of the transaction, if any.
+        memo: Option<String>,
+    },
+    /// A schedule's next run was moved an interval later, after its transaction ran or failed to.
+    AdvanceSchedule {
+        /// The ID of the schedule.
+        id: u64,
+    },
+    /// A schedule was cancelled.
+    CancelSchedule {
+        /// The ID of the schedule.
+        id: u64,
+    },
+    /// An alert on the exchange rate of a foreign currency reaching a threshold was added.
+    AddAlert {
+        /// The code of the currency whose rate is watched.
+        currency: String,
+        /// The side of the threshold the rate must reach.
+        condition: AlertCondition,
+        /// The threshold, as a value of one unit of the currency in Philippine Pesos.
+        threshold: Decimal,
+    },
+    /// A rate alert was removed.
+    RemoveAlert {
+        /// The ID of the alert.
+        id: u64,
+    },
+    /// A withdrawal or transfer was held for approval.
+    Hold {
+        /// The held transaction.
+        transaction: HeldTransaction,
+        /// The note attached to the transaction, if any.
+        memo: Option<String>,
+    },
+    /// A held transaction was approved, right after the transaction itself was committed.
+    Approve {
+        /// The ID of the held transaction.
+        id: u64,
+    },
+    /// A held transaction was rejected.
+    Reject {
+        /// The ID of the held transaction.
+        id: u64,
+    },
+    /// A deposit, withdrawal, transfer, or fee was undone or reversed.
+    Undo {
+        /// The undone transaction.
+        transaction: Box<EntryKind>,
+        /// The reference number of the undone transaction, if it was known.
+        reference: Option<String>,
+    },
+    /// The bank's state was rolled back to a backup.
+    Restore {
+        /// The state the bank was rolled back to.
+        snapshot: Box<Snapshot>,
+    },
+}
+
+impl EntryKind {
+    /// Follows an account involved in the transaction to its new name.
+    ///
+    /// The name a renaming gives an account is left as is, since it only refers to the account being renamed.
+    pub fn rename_account(&mut self, name: &str, new_name: &str) {
+        let accounts = match self {
+            EntryKind::Register { account, .. }
+            | EntryKind::Deposit { account, .. }
+            | EntryKind::Withdrawal { account, .. }
+            | EntryKind::Close { account, .. }
+            | EntryKind::Freeze { account, .. }
+            | EntryKind::SetOverdraft { account, .. }
+            | EntryKind::SetInterestRate { account, .. }
+            | EntryKind::SetCompounding { account, .. }
+            | EntryKind::SetWithdrawalLimit { account, .. }
+            | EntryKind::Fee { account, .. }
+            | EntryKind::PostInterest { account, .. }
+            | EntryKind::Rename { account, .. }
+            | EntryKind::SetOwners { account, .. } => vec![account],
+            EntryKind::Transfer { account, recipient, .. } => vec![account, recipient],
+            EntryKind::CreateSchedule { transaction, .. } => return transaction.rename_account(name, new_name),
+            EntryKind::Hold { transaction, .. } => return transaction.rename_account(name, new_name),
+            EntryKind::Undo { transaction, .. } => return transaction.rename_account(name, new_name),
+            _ => Vec::new(),
+        };
+
+        for account in accounts.into_iter().filter(|a| *a == name) {
+            *account = new_name.to_string();
+        }
+    }
+}
+
+/// The first line of an encrypted journal, describing how to derive its key.
+#[derive(Serialize, Deserialize)]
+struct Header {
+    /// The Base64-encoded salt used to derive the key from the passphrase.
+    salt: String,
+    /// An encrypted empty string, used to verify the passphrase before reading any entries.
+    check: String,
+}
+
+/// An append-only journal file, storing one JSON line per entry.
+///
+/// The entries of an encrypted journal are individually encrypted, with the file starting with a [`Header`] line.
+pub struct Journal {
+    /// The path of the journal file.
+    path: PathBuf,
+    /// The journal file opened in append mode.
+    file: File,
+    /// The cipher encrypting the entries, if the journal is encrypted.
+    cipher: Option<Cipher>,
+}
+impl Journal {
+    /// Opens a plaintext journal file, creating it if it doesn't exist yet.
+    ///
+    /// Opening an encrypted journal this way fails, use [`Journal::open_encrypted`] instead.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Journal> {
+        let path = path.as_ref().to_path_buf();
+
+        Journal::cut_torn_entry(&path)?;
+
+        if Journal::is_encrypted(&path)? {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "The journal file is encrypted...",
+            ));
+        }
+
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+
+        Ok(Journal {
+            path,
+            file,
+            cipher: None,
+        })
+    }
+
+    /// Opens an encrypted journal file, creating it with a key derived from the passphrase if it doesn't exist yet.
+    ///
+    /// Opening fails if the passphrase doesn't match the one the journal was created with.
+    pub fn open_encrypted<P: AsRef<Path>>(path: P, passphrase: &str) -> io::Result<Journal> {
+        let path = path.as_ref().to_path_buf();
+
+        Journal::cut_torn_entry(&path)?;
+
+        if Journal::is_new(&path) {
+            let salt = crypto::generate_salt();
+            let cipher = Cipher::derive(passphrase, &salt)?;
+            let header = Header {
+                check: cipher.encrypt("")?,
+                salt,
+            };
+            let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+
+            writeln!(file, "{}", serde_json::to_string(&header)?)?;
+
+            return Ok(Journal {
+                path,
+                file,
+                cipher: Some(cipher),
+            });
+        }
+
+        let header = Journal::read_header(&path)?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "The journal file isn't encrypted..."))?;
+        let cipher = Cipher::derive(passphrase, &header.salt)?;
+
+        cipher.decrypt(&header.check)?;
+
+        let file = OpenOptions::new().append(true).open(&path)?;
+
+        Ok(Journal {
+            path,
+            file,
+            cipher: Some(cipher),
+        })
+    }
+
+    /// Cuts off an entry left half-written at the end of a journal file, like by a crash, so that the entries before it
+    /// can be replayed and new ones appended after them.
+    ///
+    /// Every complete entry ends with a newline, so anything after the last one is the torn entry.
+    fn cut_torn_entry(path: &Path) -> io::Result<()> {
+        let contents = match fs::read(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err),
+        };
+
+        if contents.last().is_none_or(|b| *b == b'\n') {
+            return Ok(());
+        }
+
+        let len = contents.iter().rposition(|b| *b == b'\n').map_or(0, |idx| idx + 1);
+
+        warn!(path = %path.display(), bytes = contents.len() - len, "cut off a torn journal entry");
+
+        OpenOptions::new().write(true).open(path)?.set_len(len as u64)
+    }
+
+    /// Checks whether a journal file doesn't exist yet or is empty.
+    pub fn is_new<P: AsRef<Path>>(path: P) -> bool {
+        fs::metadata(path).map(|m| m.len() == 0).unwrap_or(true)
+    }
+
+    /// Checks whether a journal file is encrypted.
+    pub fn is_encrypted<P: AsRef<Path>>(path: P) -> io::Result<bool> {
+        Ok(Journal::read_header(path)?.is_some())
+    }
+
+    /// Reads the header of a journal file, if it exists and is encrypted.
+    fn read_header<P: AsRef<Path>>(path: P) -> io::Result<Option<Header>> {
+        if Journal::is_new(&path) {
+            return Ok(None);
+        }
+
+        let mut line = String::new();
+
+        BufReader::new(File::open(path)?).read_line(&mut line)?;
+
+        Ok(serde_json::from_str(&line).ok())
+    }
+
+    /// Returns the path of the journal file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the cipher encrypting the entries, if the journal is encrypted.
+    pub fn cipher(&self) -> Option<&Cipher> {
+        self.cipher.as_ref()
+    }
+
+    /// Appends an entry to the end of the journal.
+    pub fn append(&mut self, entry: &Entry) -> io::Result<()> {
+        let mut line = serde_json::to_string(entry)?;
+
+        if let Some(cipher) = &self.cipher {
+            line = cipher.encrypt(&line)?;
+        }
+
+        line.push('\n');
+
+        self.file.write_all(line.as_bytes())?;
+        self.file.flush()
+    }
+
+    /// Reads all the entries in the journal, from oldest to newest.
+    ///
+    /// Entries saved with an older format are migrated to the current one.
+    pub fn entries(&self) -> io::Result<Vec<Entry>> {
+        let reader = BufReader::new(File::open(&self.path)?);
+        let mut entries = Vec::new();
+
+        for line in reader.lines().skip(usize::from(self.cipher.is_some())) {
+            let mut line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            if let Some(cipher) = &self.cipher {
+                line = cipher.decrypt(&line)?;
+            }
+
+            let mut value = serde_json::from_str::<Value>(&line)?;
+
+            schema::migrate_entry(&mut value)?;
+
+            entries.push(serde_json::from_value(value)?);
+        }
+
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::bank::Bank;
+
+    /// The start of an entry left half-written by a crash.
+    const TORN_ENTRY: &[u8] = br#"{"version":28,"timestamp":17920"#;
+
+    /// Returns the path of a scratch journal file for a test, removing any left over from an earlier run.
+    fn scratch_path(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("mcos-journal-{name}-{}.jsonl", process::id()));
+
+        let _ = fs::remove_file(&path);
+
+        path
+    }
+
+    /// Records a registration and a deposit in a journal.
+    fn record_deposit(journal: Journal) {
+        let mut bank = Bank::replay(journal).unwrap();
+
+        bank.register(
+            String::from("Ana"),
+            Vec::new(),
+            AccountType::Savings,
+            None,
+            Contact::default(),
+            "1234",
+        )
+        .unwrap();
+        bank.deposit("Ana", "PHP", dec!(500), None).unwrap();
+    }
+
+    /// Appends the start of an entry to a journal file, as if a crash interrupted writing it.
+    fn tear(path: &Path) {
+        OpenOptions::new()
+            .append(true)
+            .open(path)
+            .unwrap()
+            .write_all(TORN_ENTRY)
+            .unwrap();
+    }
+
+    /// Checks that the torn entry is cut off, leaving the file ending with the last complete entry.
+    fn assert_cut(path: &Path, before: &[u8]) {
+        let after = fs::read(path).unwrap();
+
+        assert_eq!(after, before);
+        assert_eq!(after.last(), Some(&b'\n'));
+    }
+
+    #[test]
+    fn open_cuts_torn_entry_and_appends_after_it() {
+        let path = scratch_path("torn");
+
+        record_deposit(Journal::open(&path).unwrap());
+
+        let before = fs::read(&path).unwrap();
+
+        tear(&path);
+
+        let journal = Journal::open(&path).unwrap();
+
+        assert_cut(&path, &before);
+        assert_eq!(journal.entries().unwrap().len(), 2);
+
+        Bank::replay(journal)
+            .unwrap()
+            .deposit("Ana", "PHP", dec!(250), None)
+            .unwrap();
+
+        let bank = Bank::from_journal(&path).unwrap();
+
+        assert_eq!(bank.account("Ana").unwrap().balance.amount(), dec!(750));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn open_encrypted_cuts_torn_entry_and_appends_after_it() {
+        let path = scratch_path("torn-encrypted");
+
+        record_deposit(Journal::open_encrypted(&path, "passphrase").unwrap());
+
+        let before = fs::read(&path).unwrap();
+
+        tear(&path);
+
+        let journal = Journal::open_encrypted(&path, "passphrase").unwrap();
+
+        assert_cut(&path, &before);
+        assert_eq!(journal.entries().unwrap().len(), 2);
+
+        Bank::replay(journal)
+            .unwrap()
+            .deposit("Ana", "PHP", dec!(250), None)
+            .unwrap();
+
+        let bank = Bank::replay(Journal::open_encrypted(&path, "passphrase").unwrap()).unwrap();
+
+        assert_eq!(bank.account("Ana").unwrap().balance.amount(), dec!(750));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn open_encrypted_round_trips_entries() {
+        let path = scratch_path("round-trip-encrypted");
+
+        record_deposit(Journal::open_encrypted(&path, "passphrase").unwrap());
+
+        let journal = Journal::open_encrypted(&path, "passphrase").unwrap();
+        let entries = journal.entries().unwrap();
+
+        assert!(matches!(&entries[0].kind, EntryKind::Register { account, .. } if account == "Ana"));
+        assert!(matches!(&entries[1].kind, EntryKind::Deposit { amount, .. } if *amount == dec!(500)));
+
+        let bank = Bank::replay(journal).unwrap();
+
+        assert_eq!(bank.account("Ana").unwrap().balance.amount(), dec!(500));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn open_encrypted_rejects_wrong_passphrase() {
+        let path = scratch_path("wrong-passphrase");
+
+        record_deposit(Journal::open_encrypted(&path, "passphrase").unwrap());
+
+        let before = fs::read(&path).unwrap();
+        let err = Journal::open_encrypted(&path, "wrong passphrase").err().unwrap();
+
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(fs::read(&path).unwrap(), before);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn open_encrypted_keeps_plaintext_out_of_file() {
+        let plain_path = scratch_path("plaintext");
+        let path = scratch_path("ciphertext");
+
+        record_deposit(Journal::open(&plain_path).unwrap());
+        record_deposit(Journal::open_encrypted(&path, "passphrase").unwrap());
+
+        let plain_contents = fs::read_to_string(&plain_path).unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+
+        for plaintext in [r#""account":"Ana""#, r#""type":"deposit""#, r#""amount":"500""#] {
+            assert!(plain_contents.contains(plaintext));
+            assert!(!contents.contains(plaintext));
+        }
+
+        fs::remove_file(&plain_path).unwrap();
+        fs::remove_file(&path).unwrap();
+    }
+}
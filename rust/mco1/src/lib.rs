@@ -0,0 +1,36 @@
+/*
+ * Last Names: Panaligan (Author), Casihan, Cotoco, Mascardo
+ * Language: Rust
+ * Paradigm(s): Procedural, Object-Oriented, Functional
+ */
+
+//! The core of the banking and currency exchange app, shared by the CLI.
+
+pub mod account;
+pub mod alert;
+pub mod approval;
+pub mod backup;
+pub mod bank;
+pub mod batch;
+pub mod clock;
+pub mod config;
+pub mod crypto;
+pub mod currency;
+pub mod demo;
+pub mod event;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod history;
+pub mod interest;
+pub mod iso4217;
+pub mod journal;
+pub mod locale;
+pub mod profile;
+pub mod rates;
+pub mod schedule;
+pub mod schema;
+pub mod stress;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "webhooks")]
+pub mod webhook;
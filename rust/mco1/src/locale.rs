@@ -0,0 +1,98 @@
+//! The formatting of amounts of money for the supported locales.
+
+use std::{fmt, str::FromStr};
+
+use rust_decimal::Decimal;
+
+use crate::currency;
+
+/// The locales that amounts of money can be formatted for.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Locale {
+    /// English (Philippines), like `₱10,000.33`.
+    #[default]
+    EnPh,
+    /// German (Germany), like `10.000,33 ₱`.
+    DeDe,
+    /// French (France), like `10 000,33 ₱`.
+    FrFr,
+}
+impl Locale {
+    /// The tags of the supported locales.
+    pub const TAGS: [&str; 3] = ["en-PH", "de-DE", "fr-FR"];
+
+    /// Returns the separator between groups of three digits.
+    fn group_separator(self) -> &'static str {
+        match self {
+            Locale::EnPh => ",",
+            Locale::DeDe => ".",
+            Locale::FrFr => "\u{202f}",
+        }
+    }
+
+    /// Returns the separator between the whole and fractional parts.
+    fn decimal_separator(self) -> &'static str {
+        match self {
+            Locale::EnPh => ".",
+            Locale::DeDe | Locale::FrFr => ",",
+        }
+    }
+
+    /// Formats an amount of money in a currency, with its symbol and the currency's number of decimal places.
+    ///
+    /// Currencies without a symbol are written with their code instead.
+    pub fn format_money(self, amount: Decimal, code: &str) -> String {
+        let decimals = currency::minor_units(code).unwrap_or(currency::DEFAULT_MINOR_UNITS) as usize;
+        let digits = format!(
+            "{:.decimals$}",
+            currency::RoundingPolicy::HalfUp.round(amount.abs(), decimals as u32)
+        );
+        let (whole, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+        let mut grouped = String::new();
+
+        for (i, digit) in whole.chars().enumerate() {
+            if i > 0 && (whole.len() - i) % 3 == 0 {
+                grouped.push_str(self.group_separator());
+            }
+
+            grouped.push(digit);
+        }
+
+        if !fraction.is_empty() {
+            grouped.push_str(self.decimal_separator());
+            grouped.push_str(fraction);
+        }
+
+        let sign = if amount < Decimal::ZERO && digits.chars().any(|d| ('1'..='9').contains(&d)) {
+            "-"
+        } else {
+            ""
+        };
+
+        match (self, currency::symbol(code)) {
+            (Locale::EnPh, Some(symbol)) => format!("{sign}{symbol}{grouped}"),
+            (Locale::EnPh, None) => format!("{sign}{code} {grouped}"),
+            (_, symbol) => format!("{sign}{grouped} {}", symbol.unwrap_or(code)),
+        }
+    }
+}
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Locale::TAGS[*self as usize])
+    }
+}
+impl FromStr for Locale {
+    type Err = String;
+
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        match tag.replace('_', "-").to_lowercase().as_str() {
+            "en-ph" => Ok(Locale::EnPh),
+            "de-de" => Ok(Locale::DeDe),
+            "fr-fr" => Ok(Locale::FrFr),
+            _ => Err(format!(
+                "unsupported locale, expected one of: {}",
+                Locale::TAGS.join(", ")
+            )),
+        }
+    }
+}
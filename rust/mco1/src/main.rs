@@ -4,397 +4,4583 @@
  * Paradigm(s): Procedural, Object-Oriented, Functional
  */
 
+mod cli;
+#[macro_use]
+mod console;
+#[cfg(feature = "graphql")]
+mod graphql;
+#[cfg(feature = "grpc")]
+mod grpc;
+mod i18n;
+mod menu;
+mod output;
+mod prompt;
+#[cfg(feature = "serve")]
+mod server;
+mod style;
+mod table;
+mod telnet;
+#[cfg(feature = "tui")]
+mod tui;
+
 use std::{
-    collections::HashMap,
-    fmt,
-    io::{self, Write},
+    collections::{BTreeMap, HashMap},
+    env,
+    error::Error,
+    fs::{self, OpenOptions},
+    io::{self, IsTerminal},
+    mem,
+    path::Path,
+    process,
+    sync::Mutex,
+};
+
+#[cfg(feature = "live-rates")]
+use banking_and_currency_app::rates::EcbRates;
+#[cfg(feature = "webhooks")]
+use banking_and_currency_app::webhook::Webhooks;
+use banking_and_currency_app::{
+    account::{self, Account, AccountFilter, AccountOrder, AccountType, Contact, MinimumBalance},
+    alert::{AlertCondition, RateAlert, RateNotification},
+    approval::HeldTransaction,
+    backup::Backup,
+    bank::{Bank, BankError, Charge, ExchangeQuote, InterestPosting},
+    batch, clock,
+    config::Config,
+    currency::{self, BASE_CURRENCY, CURRENCIES_CODES, RoundingPolicy},
+    demo,
+    history::Record,
+    interest::{self, Compounding, Contribution, InterestMode, InterestRow, InterestTerms, RateTier},
+    iso4217,
+    journal::{EntryKind, Journal, ReplayPoint},
+    profile::Profile,
+    rates::{self, CsvRates, RateError, RatePoint, RateProvider, RateStats, StaticRateProvider},
+    schedule::ScheduledTransaction,
+    stress::{self, StressReport},
+};
+use chrono::{DateTime, Local, NaiveDate};
+use clap::{CommandFactory, Parser, error::ErrorKind};
+use rust_decimal::Decimal;
+use tracing::{error, level_filters::LevelFilter};
+
+use crate::{
+    cli::{Cli, Command, ScriptLine},
+    i18n::{fill, messages},
+    menu::TransactionKind,
+    output::{
+        AccountInfo, BackupInfo, CurrentRate, FeeCharge, Format, Outcome, PendingInfo, RateInfo, RowInfo, RunInfo,
+        ScheduleInfo, eprint_error, money, print_error, print_reference, sharp_rate_change,
+    },
+    prompt::{
+        parse_choice, print_choices, prompt, prompt_cancellable, prompt_completed, prompt_defaulted, prompt_more,
+        prompt_pin, prompt_private, prompt_private_validated, prompt_validated, select_from,
+    },
+    table::{Align, Table},
 };
 
-/// Prints an array's contents as CLI prompt choices.
+/// Reads or changes a bank shared between the sessions of the menu, locking it only for as long as that takes.
+///
+/// Nothing is prompted or printed while the bank is locked, so that a session waiting on its user never holds up the
+/// others, and a session that disconnects can't poison the lock. If a session still panics while holding it, the bank
+/// is recovered, but the panic is logged.
+fn with_bank<T>(bank: &Mutex<Bank>, f: impl FnOnce(&mut Bank) -> T) -> T {
+    let mut bank = bank.lock().unwrap_or_else(|err| {
+        error!("recovered the bank from a session that panicked while holding it");
+
+        bank.clear_poison();
+
+        err.into_inner()
+    });
+
+    f(&mut bank)
+}
+
+/// Returns an account's interest terms without its bank's rate tiers, along with a copy of the tiers, so that the terms
+/// can be used after the bank is unlocked once the tiers are put back.
+fn detached_terms(bank: &Bank, account: &Account) -> (InterestTerms<'static>, Vec<RateTier>) {
+    let terms = bank.interest_terms(account);
+
+    (
+        InterestTerms {
+            annual_rate: terms.annual_rate,
+            tiers: &[],
+            mode: terms.mode,
+            compounding: terms.compounding,
+            day_count: terms.day_count,
+            withholding_rate: terms.withholding_rate,
+            rounding: terms.rounding,
+        },
+        terms.tiers.to_vec(),
+    )
+}
+
+/// Prompts a CLI user to input the name or number of a registered account, completing it from the bank's accounts.
+///
+/// An account's number is resolved to its name.
+fn prompt_account_name(bank: &Mutex<Bank>) -> Option<String> {
+    prompt_account(bank, messages().account_name_or_number)
+}
+
+/// Prompts a CLI user with a message to input the name or number of a registered account, completing it from the
+/// bank's accounts.
+///
+/// An account's number is resolved to its name.
+fn prompt_account(bank: &Mutex<Bank>, msg: &str) -> Option<String> {
+    let candidates = with_bank(bank, |bank| {
+        bank.accounts()
+            .iter()
+            .flat_map(|a| [a.name.clone(), a.number.clone()].into_iter().chain(a.owners.clone()))
+            .collect::<Vec<_>>()
+    });
+    let input = prompt_cancellable(msg, candidates)?;
+
+    Some(with_bank(bank, |bank| account_name(bank, input)))
+}
+
+/// Prompts a CLI user to input an account's PIN until it is correct, if the account has one.
 ///
-/// The array's elements are stringified and printed along with their index incremented by one (`i + 1`), serving as the
-/// choice's identifier.
-fn print_choices<T: fmt::Display>(choices: &[T]) {
-    for (i, val) in choices.iter().enumerate() {
-        println!("[{}] {val}", i + 1)
+/// Nothing is returned if the user cancels the transaction or inputs too many incorrect PINs.
+fn verify_pin(bank: &Mutex<Bank>, name: &str) -> Option<()> {
+    if !with_bank(bank, |bank| bank.account(name).map(Account::has_pin))? {
+        return Some(());
     }
+
+    prompt_pin(messages().pin, |input| {
+        with_bank(bank, |bank| bank.verify_pin(name, input))
+    })
+}
+
+/// Splits a comma-separated list of owners' names, skipping blank names.
+fn parse_owners(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|o| !o.is_empty())
+        .map(String::from)
+        .collect()
 }
 
-/// Prompts a CLI user to input a response.
+/// Prompts a CLI user to input the optional contact details of an account's owners until they are valid.
 ///
-/// A message is printed before awaiting the user's response, which is inputted on the same line in the console.
-fn prompt(msg: &str) -> String {
-    print!("{msg}");
+/// Nothing is returned if the user cancels the transaction.
+fn prompt_contact() -> Option<Contact> {
+    let messages = messages();
+
+    let optional = |input: &str| Some(input.to_string()).filter(|i| !i.is_empty());
+    let email = prompt_validated(messages.email, Vec::new(), |input| {
+        let contact = Contact {
+            email: optional(input),
+            phone: None,
+        };
+
+        if contact.is_valid_email() {
+            Ok(contact.email)
+        } else {
+            Err(messages.invalid_email)
+        }
+    })?;
+    let phone = prompt_validated(messages.phone, Vec::new(), |input| {
+        let contact = Contact {
+            email: None,
+            phone: optional(input),
+        };
+
+        if contact.is_valid_phone() {
+            Ok(contact.phone)
+        } else {
+            Err(messages.invalid_phone)
+        }
+    })?;
+
+    Some(Contact { email, phone })
+}
 
-    io::stdout().flush().expect("Failed to flush the output string...");
+/// Prompts a CLI user to choose the type of an account by its ID or name until it is valid.
+///
+/// Inputting nothing chooses a savings account.
+fn prompt_account_type() -> Option<AccountType> {
+    let messages = messages();
 
-    let mut input = String::new();
+    outln!("{}", style::header(messages.account_type_options));
+    print_choices(&messages.account_types);
 
-    io::stdin()
-        .read_line(&mut input)
-        .expect("Failed to read the input string...");
+    outln!();
 
-    input.trim().to_string()
+    prompt_defaulted(
+        messages.account_type,
+        Some(AccountType::TAGS[0]),
+        AccountType::TAGS.map(String::from).to_vec(),
+        |input| {
+            parse_choice(input, AccountType::ALL.len(), messages.invalid_account_type)
+                .ok()
+                .map(|idx| AccountType::ALL[idx])
+                .or_else(|| input.parse().ok())
+                .ok_or(messages.invalid_account_type)
+        },
+    )
 }
 
-/// The number of exchangeable currencies.
-const CURRENCY_CNT: usize = 6;
-/// The titles or labels of the exchangeable currencies.
-const CURRENCIES_TITLES: [&str; CURRENCY_CNT] = [
-    "Philippine Peso (PHP)",
-    "United States Dollar (USD)",
-    "Japanese Yen (JPY)",
-    "British Pound Sterling (GBP)",
-    "Euro (EUR)",
-    "Chinese Yuan Renminni (CNY)",
-];
-/// The [ISO 4217](https://en.wikipedia.org/wiki/ISO_4217) codes of the exchangeable currencies.
-const CURRENCIES_CODES: [&str; CURRENCY_CNT] = ["PHP", "USD", "JPY", "GBP", "EUR", "CNY"];
+/// Prompts a CLI user to input an annual interest rate percentage until it is above -100%, returning it as a fraction.
+///
+/// Inputting nothing chooses the default rate, given as a fraction. Nothing is returned if the user cancels the
+/// transaction.
+fn prompt_interest_rate(default: Decimal) -> Option<Decimal> {
+    let messages = messages();
+
+    prompt_defaulted(
+        messages.interest_rate_percent,
+        Some(&format_rate(default * Decimal::ONE_HUNDRED)),
+        Vec::new(),
+        |input| match input.parse::<Decimal>() {
+            Ok(rate) if rate > -Decimal::ONE_HUNDRED => Ok(rate / Decimal::ONE_HUNDRED),
+            _ => Err(messages.invalid_interest_rate),
+        },
+    )
+}
 
-/// The titles of the available transactional procedures.
-const TRANSACTION_TITLES: [&str; 6] = [
-    "Register Account Name",
-    "Deposit Amount",
-    "Withdraw Amount",
-    "Currency Exchange",
-    "Record Exchange Rates",
-    "Show Interest Amount",
-];
+/// Prompts a CLI user to input a compounding frequency until it is valid, completing it from the frequencies.
+///
+/// Inputting nothing chooses the default frequency. Nothing is returned if the user cancels the transaction.
+fn prompt_compounding(default: Compounding) -> Option<Compounding> {
+    let messages = messages();
 
-/// A simple user bank account.
-#[derive(PartialEq)]
-struct Account {
-    /// The name of the owner of the account.
-    name: String,
-    /// The current balance of the account.
-    balance: f64,
-    /// The currency that the account's balance is based on.
-    currency: String,
+    prompt_defaulted(
+        messages.compounding,
+        Some(Compounding::TAGS[default as usize]),
+        Compounding::TAGS.map(String::from).to_vec(),
+        |input| input.parse::<Compounding>().map_err(|_| messages.invalid_compounding),
+    )
 }
-impl Account {
-    /// Creates a new account with the default values.
-    fn new(name: String) -> Account {
-        Account {
-            name,
-            balance: 0.0,
-            currency: String::from("PHP"),
+
+/// Prompts a CLI user to input a currency code until it is valid, completing it from the exchangeable currencies.
+///
+/// The name of a currency listed in ISO 4217 can be inputted instead of its code. Inputting nothing chooses the default
+/// currency.
+fn prompt_currency(bank: &Mutex<Bank>, default: &str) -> Option<String> {
+    let messages = messages();
+
+    let codes = with_bank(bank, |bank| bank.currency_codes());
+
+    prompt_defaulted(messages.currency, Some(default), codes.clone(), |input| {
+        let iso = iso4217::find(input);
+        let code = iso.map_or_else(|| input.to_uppercase(), |c| c.code.to_string());
+
+        match iso {
+            _ if codes.contains(&code) => Ok(code),
+            Some(iso) => Err(fill(messages.unexchangeable_currency, &[&iso.title()])),
+            None => Err(messages.unknown_currency_code.to_string()),
         }
-    }
+    })
 }
 
-/// Converts an amount from one currency to another.
-fn convert_currency(amount: f64, src: &&str, dest: &&str, rates: &HashMap<&str, f64>) -> f64 {
-    let src_php_amount = if *src == "PHP" { amount } else { amount * rates[src] };
+/// Prompts a CLI user to input the ID of one of a number of exchangeable currencies until it is valid, returning its
+/// index.
+///
+/// Inputting nothing chooses the last valid response.
+fn prompt_currency_id(msg: &str, currency_cnt: usize) -> Option<usize> {
+    let messages = messages();
 
-    if *dest == "PHP" {
-        src_php_amount
-    } else {
-        src_php_amount * rates[dest]
-    }
+    prompt_defaulted(msg, None, Vec::new(), |input| {
+        parse_choice(input, currency_cnt, messages.unknown_currency_id)
+    })
+}
+
+/// Prompts a CLI user to input an amount until it is a valid decimal number.
+///
+/// Inputting nothing chooses the last valid response.
+fn prompt_amount(msg: &str, err: &'static str) -> Option<Decimal> {
+    prompt_defaulted(msg, None, Vec::new(), |input| input.parse::<Decimal>().map_err(|_| err))
+}
+
+/// Prompts a CLI user to input the number of days between a scheduled transaction's runs until it is valid.
+fn prompt_interval() -> Option<u64> {
+    let messages = messages();
+
+    prompt_validated(messages.interval_days, Vec::new(), |input| match input.parse::<u64>() {
+        Ok(days) if days > 0 => Ok(days),
+        _ => Err(messages.invalid_interval),
+    })
+}
+
+/// Prompts a CLI user to input an optional memo for a transaction.
+///
+/// Inputting nothing attaches no memo.
+fn prompt_memo() -> Option<Option<String>> {
+    let memo = prompt_cancellable(messages().memo, Vec::new())?;
+
+    Some(Some(memo).filter(|m| !m.is_empty()))
 }
 
 /// Deposits balance to a user's account.
 ///
-/// The user is prompted to input the currency and amount of balance to deposit.
-fn deposit_balance(account: &mut Account, rates: &HashMap<&str, f64>) {
-    println!("Current Balance: {}", account.balance);
+/// The user is prompted to input the currency and amount of balance to deposit. Nothing is returned if the user
+/// cancels the transaction.
+fn deposit_balance(bank: &Mutex<Bank>, name: &str) -> Option<()> {
+    let messages = messages();
 
-    let currency = prompt("Currency: ").to_uppercase();
+    let account = with_bank(bank, |bank| bank.account(name).cloned())?;
 
-    if !CURRENCIES_CODES.iter().any(|c| *c == currency) {
-        println!("No currency with this code exists!");
+    outln!(
+        "{}",
+        fill(
+            messages.current_balance,
+            &[&money(account.balance.amount(), &account.currency)]
+        )
+    );
 
-        return;
-    }
+    let currency = &prompt_currency(bank, &account.currency)?;
 
-    println!();
+    confirm_rates(bank, currency, &account.currency)?;
 
-    if let Ok(amount) = prompt("Deposit Amount: ").parse::<f64>() {
-        account.balance += if currency == "PHP" {
-            amount
-        } else {
-            convert_currency(amount, &currency.as_str(), &"PHP", rates)
-        };
+    outln!();
 
-        println!("Updated Balance: {}", account.balance);
-    } else {
-        println!("Deposit amount must be a floating point number!");
+    let amount = prompt_amount(messages.deposit_amount, messages.invalid_deposit_amount)?;
+    let memo = prompt_memo()?;
+
+    match with_bank(bank, |bank| {
+        bank.deposit(name, currency, amount, memo)
+            .map(|receipt| (receipt, account_currency(bank, name)))
+    }) {
+        Ok((receipt, currency)) => {
+            outln!(
+                "{}",
+                fill(
+                    messages.updated_balance,
+                    &[&style::increase(money(receipt.balance, &currency))]
+                )
+            );
+            print_reference(&receipt.reference);
+        }
+        Err(err) => print_error(err),
     }
+
+    Some(())
 }
 
 /// Withdraws balance from a user's account.
 ///
 /// The user is prompted to input the currency and amount of balance to withdraw. If the amount is greater than the
-/// account's current balance, the transaction is cancelled.
-fn withdraw_balance(account: &mut Account, rates: &HashMap<&str, f64>) {
-    println!("Current Balance: {}", account.balance);
+/// account's current balance, the transaction is cancelled. A withdrawal worth more than the large withdrawal
+/// threshold in Philippine Pesos is summarized and must be confirmed. Nothing is returned if the user cancels the
+/// transaction.
+fn withdraw_balance(bank: &Mutex<Bank>, name: &str, large_withdrawal: Decimal) -> Option<()> {
+    let messages = messages();
 
-    let currency = prompt("Currency: ").to_uppercase();
+    let account = with_bank(bank, |bank| bank.account(name).cloned())?;
 
-    if !CURRENCIES_CODES.iter().any(|c| *c == currency) {
-        println!("No currency with this code exists!");
+    outln!(
+        "{}",
+        fill(
+            messages.current_balance,
+            &[&money(account.balance.amount(), &account.currency)]
+        )
+    );
 
-        return;
+    if let Some(remaining) = with_bank(bank, |bank| bank.daily_allowance(&account)) {
+        outln!(
+            "{}",
+            fill(messages.daily_allowance, &[&money(remaining, &account.currency)])
+        );
     }
 
-    println!();
+    let currency = &prompt_currency(bank, &account.currency)?;
 
-    if let Ok(mut amount) = prompt("Withdraw Amount: ").parse::<f64>() {
-        amount = if currency == "PHP" {
-            amount
-        } else {
-            convert_currency(amount, &currency.as_str(), &"PHP", rates)
-        };
+    confirm_rates(bank, currency, &account.currency)?;
+
+    outln!();
+
+    let amount = prompt_amount(messages.withdraw_amount, messages.invalid_withdraw_amount)?;
+    let (penalty, fee, base_amount, converted_amount) = with_bank(bank, |bank| {
+        (
+            bank.withdrawal_penalty(name, currency, amount).unwrap_or_default(),
+            bank.withdrawal_charges(name, currency, amount)
+                .unwrap_or_default()
+                .iter()
+                .map(|c| c.amount)
+                .sum::<Decimal>(),
+            bank.convert(currency, BASE_CURRENCY, amount),
+            bank.convert(currency, &account.currency, amount),
+        )
+    });
+
+    if penalty > Decimal::ZERO {
+        outln!();
+
+        outln!(
+            "{}",
+            fill(messages.early_withdrawal_penalty, &[&money(penalty, &account.currency)])
+        );
+    }
+
+    if let (Ok(base_amount), Ok(converted_amount)) = (base_amount, converted_amount)
+        && base_amount > large_withdrawal
+        && account.available_balance() - converted_amount - penalty - fee >= Decimal::ZERO
+    {
+        outln!();
+
+        outln!("{}", style::header(messages.withdrawal_summary));
+        outln!("{}", fill(messages.amount, &[&money(amount, currency)]));
+        outln!(
+            "{}",
+            fill(messages.value_in, &[&BASE_CURRENCY, &money(base_amount, BASE_CURRENCY)])
+        );
+        outln!(
+            "{}",
+            fill(
+                messages.resulting_balance,
+                &[&money(
+                    account.balance.amount() - converted_amount - penalty - fee,
+                    &account.currency
+                )]
+            )
+        );
+
+        outln!();
 
-        if account.balance - amount < 0.0 {
-            println!("Withdraw amount must be less than the current balance!");
+        let is_confirmed = prompt_validated(messages.confirm_withdrawal, Vec::new(), |input| {
+            match input.to_uppercase().as_str() {
+                "Y" => Ok(true),
+                "N" => Ok(false),
+                _ => Err(messages.invalid_yes_no),
+            }
+        })?;
 
-            return;
+        if !is_confirmed {
+            return None;
         }
+    }
 
-        account.balance -= amount;
+    let memo = prompt_memo()?;
+    let transaction = HeldTransaction::Withdrawal {
+        account: name.to_string(),
+        amount,
+        currency: currency.to_string(),
+    };
 
-        println!("Updated Balance: {}", account.balance);
-    } else {
-        println!("Withdraw amount must be a floating point number!");
+    if with_bank(bank, |bank| bank.requires_approval(&transaction).unwrap_or_default()) {
+        match with_bank(bank, |bank| bank.hold(transaction, memo)) {
+            Ok(id) => outln!("{}", fill(messages.held_for_approval, &[&id])),
+            Err(err) => print_error(err),
+        }
+
+        return Some(());
+    }
+
+    match with_bank(bank, |bank| {
+        bank.withdraw(name, currency, amount, memo)
+            .map(|receipt| (receipt, account_currency(bank, name)))
+    }) {
+        Ok((receipt, currency)) => {
+            outln!(
+                "{}",
+                fill(
+                    messages.updated_balance,
+                    &[&style::decrease(money(receipt.balance, &currency))]
+                )
+            );
+            print_charges(&receipt.charges, &currency);
+            print_reference(&receipt.reference);
+        }
+        Err(err) => print_error(err),
     }
+
+    Some(())
 }
 
-/// Calculates and prints how much one currency is worth in another.
+/// Schedules balance to be deposited to a user's account every number of days.
 ///
-/// The user is prompted to input the amount and what currencies to exchange.
-fn exchange_currencies(rates: &HashMap<&str, f64>) {
-    println!("Source Currency Options:");
-    print_choices(&CURRENCIES_TITLES);
+/// The user is prompted to input the currency, amount, and interval of the deposits. Nothing is returned if the user
+/// cancels the transaction.
+fn schedule_deposit(bank: &Mutex<Bank>, name: &str) -> Option<()> {
+    let messages = messages();
 
-    println!();
+    let account = with_bank(bank, |bank| bank.account(name).cloned())?;
+    let currency = &prompt_currency(bank, &account.currency)?;
 
-    let src_idx = match prompt("Source Currency: ").parse::<usize>() {
-        Ok(idx) => idx - 1,
-        Err(_) => {
-            println!("ID must be a positive whole number (integer)!");
+    outln!();
 
-            return;
-        }
+    let amount = prompt_amount(messages.deposit_amount, messages.invalid_deposit_amount)?;
+    let interval_days = prompt_interval()?;
+    let memo = prompt_memo()?;
+
+    outln!();
+
+    let transaction = ScheduledTransaction::Deposit {
+        account: name.to_string(),
+        amount,
+        currency: currency.to_string(),
     };
 
-    if src_idx >= CURRENCY_CNT {
-        println!("No currency with this ID exists!");
+    match with_bank(bank, |bank| {
+        bank.schedule(transaction, interval_days, memo)
+            .map(|id| (id, bank.schedules()[id as usize - 1].next_run_at))
+    }) {
+        Ok((id, next_run_at)) => print_scheduled(id, next_run_at),
+        Err(err) => print_error(err),
+    }
+
+    Some(())
+}
 
-        return;
+/// Schedules balance to be transferred from a user's account to another account every number of days.
+///
+/// The user is prompted to input the account to transfer to and the amount and interval of the transfers. Nothing is
+/// returned if the user cancels the transaction.
+fn schedule_transfer(bank: &Mutex<Bank>, name: &str) -> Option<()> {
+    let messages = messages();
+
+    let recipient = prompt_account(bank, messages.recipient_name_or_number)?;
+    let amount = prompt_amount(messages.transfer_amount, messages.invalid_transfer_amount)?;
+    let interval_days = prompt_interval()?;
+    let memo = prompt_memo()?;
+
+    outln!();
+
+    let transaction = ScheduledTransaction::Transfer {
+        account: name.to_string(),
+        recipient,
+        amount,
+    };
+
+    match with_bank(bank, |bank| {
+        bank.schedule(transaction, interval_days, memo)
+            .map(|id| (id, bank.schedules()[id as usize - 1].next_run_at))
+    }) {
+        Ok((id, next_run_at)) => print_scheduled(id, next_run_at),
+        Err(err) => print_error(err),
     }
 
-    let src_amount = match prompt("Source Amount: ").parse::<f64>() {
-        Ok(amount) => amount,
-        Err(_) => {
-            println!("Amount must be a floating point number!");
+    Some(())
+}
 
-            return;
-        }
+/// Returns the scheduled transactions, including the cancelled ones, along with the currencies of their amounts.
+fn schedules(bank: &Bank) -> Vec<ScheduleInfo> {
+    bank.schedules()
+        .iter()
+        .map(|s| ScheduleInfo {
+            currency: match &s.transaction {
+                ScheduledTransaction::Deposit { currency, .. } => currency.clone(),
+                ScheduledTransaction::Transfer { account, .. } => account_currency(bank, account),
+            },
+            schedule: s.clone(),
+        })
+        .collect()
+}
+
+/// Returns the transactions held for approval, including the decided ones, along with the currencies of their amounts.
+fn pending(bank: &Bank) -> Vec<PendingInfo> {
+    bank.pending()
+        .iter()
+        .map(|p| PendingInfo {
+            currency: match &p.transaction {
+                HeldTransaction::Withdrawal { currency, .. } => currency.clone(),
+                HeldTransaction::Transfer { account, .. } => account_currency(bank, account),
+            },
+            pending: p.clone(),
+        })
+        .collect()
+}
+
+/// Lists the transactions held for approval, then approves or rejects one if the user chooses to.
+///
+/// Nothing is returned if the user cancels the transaction.
+fn manage_pending(bank: &Mutex<Bank>) -> Option<()> {
+    let messages = messages();
+
+    let pending = with_bank(bank, |bank| pending(bank));
+
+    print_pending(&pending);
+
+    if !pending.iter().any(|p| p.pending.is_pending()) {
+        return Some(());
+    }
+
+    outln!();
+
+    let id = prompt_validated(messages.pending_to_decide, Vec::new(), |input| match input {
+        "" => Ok(None),
+        _ => input.parse::<u64>().map(Some).map_err(|_| messages.invalid_id),
+    })?;
+    let Some(id) = id else {
+        return Some(());
     };
+    let is_approving = prompt_validated(messages.approve_or_reject, Vec::new(), |input| {
+        match input.to_uppercase().as_str() {
+            "A" => Ok(true),
+            "R" => Ok(false),
+            _ => Err(messages.invalid_approve_reject),
+        }
+    })?;
 
-    println!();
+    outln!();
 
-    println!("Exchanged Currency Options:");
-    print_choices(&CURRENCIES_TITLES);
+    if !is_approving {
+        match with_bank(bank, |bank| bank.reject(id)) {
+            Ok(()) => outln!("{}", fill(messages.rejected_pending, &[&id])),
+            Err(err) => print_error(err),
+        }
 
-    println!();
+        return Some(());
+    }
 
-    let exchange_idx = match prompt("Exchange Currency: ").parse::<usize>() {
-        Ok(idx) => idx - 1,
-        Err(_) => {
-            println!("ID must be a positive whole number (integer)!");
+    match with_bank(bank, |bank| {
+        bank.approve(id).map(|receipt| {
+            let currency = bank
+                .pending()
+                .iter()
+                .find(|p| p.id == id)
+                .map(|p| account_currency(bank, p.transaction.account()))
+                .unwrap_or_default();
 
-            return;
+            (receipt, currency)
+        })
+    }) {
+        Ok((receipt, currency)) => {
+            outln!("{}", fill(messages.approved_pending, &[&id]));
+            outln!(
+                "{}",
+                fill(
+                    messages.updated_balance,
+                    &[&style::decrease(money(receipt.balance, &currency))]
+                )
+            );
+            print_charges(&receipt.charges, &currency);
+            print_reference(&receipt.reference);
         }
-    };
+        Err(err) => print_error(err),
+    }
+
+    Some(())
+}
 
-    if exchange_idx >= CURRENCY_CNT {
-        println!("No currency with this ID exists!");
+/// Prints the transactions held for approval in a table.
+fn print_pending(pending: &[PendingInfo]) {
+    let messages = messages();
+
+    if pending.is_empty() {
+        outln!("{}", messages.no_pending);
 
         return;
     }
 
-    println!(
-        "Exchange Amount: {}",
-        convert_currency(
-            src_amount,
-            &CURRENCIES_CODES[src_idx],
-            &CURRENCIES_CODES[exchange_idx],
-            rates
-        )
-    );
+    let mut table = Table::new(messages.pending_headers)
+        .align(0, Align::Right)
+        .align(4, Align::Right);
+
+    for PendingInfo { pending, currency } in pending {
+        let (kind, recipient, amount) = match &pending.transaction {
+            HeldTransaction::Withdrawal { amount, .. } => (0, "", amount),
+            HeldTransaction::Transfer { recipient, amount, .. } => (1, recipient.as_str(), amount),
+        };
+
+        table.push_row([
+            pending.id.to_string(),
+            messages.held_transactions[kind].to_string(),
+            pending.transaction.account().to_string(),
+            recipient.to_string(),
+            money(*amount, currency),
+            local_date(pending.submitted_at),
+            messages.approval_statuses[pending.status as usize].to_string(),
+        ]);
+    }
+
+    outln!("{table}");
 }
 
-/// Updates the exchange rate between a currency and Philippine Pesos.
+/// Lists the scheduled transactions, then cancels one if the user chooses to.
 ///
-/// The user is prompted to input the currency and its value in PHP.
-fn set_exchange_rate(rates: &mut HashMap<&str, f64>) {
-    print_choices(&CURRENCIES_TITLES[1..]);
+/// Nothing is returned if the user cancels the transaction.
+fn manage_schedules(bank: &Mutex<Bank>) -> Option<()> {
+    let messages = messages();
+
+    let schedules = with_bank(bank, |bank| schedules(bank));
+
+    print_schedules(&schedules);
+
+    if schedules.iter().all(|s| s.schedule.is_cancelled) {
+        return Some(());
+    }
 
-    println!();
+    outln!();
 
-    let idx = match prompt("Select Foreign Currency: ").parse::<usize>() {
-        Ok(idx) => idx,
-        Err(_) => {
-            println!("ID must be a positive whole number (integer)!");
+    let id = prompt_validated(messages.schedule_to_cancel, Vec::new(), |input| match input {
+        "" => Ok(None),
+        _ => input.parse::<u64>().map(Some).map_err(|_| messages.invalid_id),
+    })?;
 
-            return;
+    if let Some(id) = id {
+        match with_bank(bank, |bank| bank.cancel_schedule(id)) {
+            Ok(()) => outln!("{}", fill(messages.cancelled_schedule, &[&id])),
+            Err(err) => print_error(err),
         }
-    };
+    }
+
+    Some(())
+}
+
+/// Prints the ID of a new schedule and when its transaction first runs.
+fn print_scheduled(id: u64, next_run_at: u64) {
+    outln!("{}", fill(messages().scheduled, &[&id, &local_date(next_run_at)]));
+}
+
+/// Prints the scheduled transactions in a table.
+fn print_schedules(schedules: &[ScheduleInfo]) {
+    let messages = messages();
 
-    if idx >= CURRENCY_CNT {
-        println!("No currency with this ID exists!");
+    if schedules.is_empty() {
+        outln!("{}", messages.no_schedules);
 
         return;
     }
 
-    let rate = match prompt("Exchange Rate: ").parse::<f64>() {
-        Ok(rate) => rate,
-        Err(_) => {
-            println!("Amount must be a floating point number!");
+    let mut table = Table::new(messages.schedule_headers)
+        .align(0, Align::Right)
+        .align(4, Align::Right);
 
-            return;
-        }
-    };
+    for ScheduleInfo { schedule, currency } in schedules {
+        let (kind, recipient, amount) = match &schedule.transaction {
+            ScheduledTransaction::Deposit { amount, .. } => (0, "", amount),
+            ScheduledTransaction::Transfer { recipient, amount, .. } => (1, recipient.as_str(), amount),
+        };
+
+        table.push_row([
+            schedule.id.to_string(),
+            messages.scheduled_transactions[kind].to_string(),
+            schedule.transaction.account().to_string(),
+            recipient.to_string(),
+            money(*amount, currency),
+            fill(messages.interval, &[&schedule.interval_days]),
+            local_date(schedule.next_run_at),
+            messages.schedule_statuses[usize::from(schedule.is_cancelled)].to_string(),
+        ]);
+    }
 
-    rates.insert(CURRENCIES_CODES[idx], rate);
+    outln!("{table}");
 }
 
-/// The fixed annual interest rate percentage.
-const ANNUAL_INTEREST_RATE: f64 = 0.05;
+/// Runs the scheduled transactions due by now, printing their runs.
+fn run_due_schedules(bank: &Mutex<Bank>) {
+    match with_bank(bank, |bank| bank.run_schedules(bank.now())) {
+        Ok(runs) if !runs.is_empty() => {
+            print_scheduled_runs(&runs.into_iter().map(RunInfo::from).collect::<Vec<_>>());
 
-/// Calculates and prints the daily increase to an account's balance from interest.
-///
-/// The user is prompted to input the number of days to calculate for.
-fn calculate_interest(account: &Account) {
-    let mut balance = account.balance;
+            outln!();
+        }
+        Ok(_) => {}
+        Err(err) => print_error(err),
+    }
+}
 
-    println!("Current Balance: {balance}");
-    println!("Currency: {}", account.currency);
-    println!("Interest Rate: {}%", (ANNUAL_INTEREST_RATE * 100.0) as i32);
+/// Prints whether each run of a scheduled transaction succeeded.
+fn print_scheduled_runs(runs: &[RunInfo]) {
+    let messages = messages();
 
-    println!();
+    for run in runs {
+        let due_on = local_date(run.due_at);
 
-    if let Ok(day_cnt) = prompt("Total Number of Days: ").parse::<u32>() {
-        println!();
+        match (&run.reference, &run.error) {
+            (Some(reference), _) => {
+                outln!("{}", fill(messages.ran_schedule, &[&run.schedule, &due_on]));
+                print_reference(reference);
+            }
+            (None, Some(err)) => outln!(
+                "{}",
+                style::error(fill(messages.failed_schedule, &[&run.schedule, &due_on, err]))
+            ),
+            (None, None) => {}
+        }
+    }
+}
 
-        println!("Day | Interest | Balance |");
+/// Deposits the rows of a CSV file read from a path the user inputs, after previewing them.
+///
+/// The user is prompted to confirm depositing the valid rows. Nothing is returned if the user cancels the transaction.
+fn bulk_deposit(bank: &Mutex<Bank>) -> Option<()> {
+    let messages = messages();
 
-        let daily_interest = (balance * (ANNUAL_INTEREST_RATE / 365.0) * 100.0).round() / 100.0;
+    let csv = prompt_validated(messages.csv_path, Vec::new(), |input| {
+        fs::read_to_string(input).map_err(|err| fill(messages.failed_csv_read, &[&err]))
+    })?;
+    let preview = with_bank(bank, |bank| bulk_rows(bank, &csv, true));
 
-        for i in 1..=day_cnt {
-            balance += daily_interest;
+    outln!();
+    print_bulk_deposit(&preview, true);
 
-            println!(
-                "{day:<3} | {interest:<8} | {balance:<7.2} |",
-                day = i,
-                interest = daily_interest,
-                balance = balance
-            );
+    if preview.iter().all(|r| r.error.is_some()) {
+        return Some(());
+    }
+
+    outln!();
+
+    let is_confirmed = prompt_validated(messages.confirm_bulk_deposit, Vec::new(), |input| {
+        match input.to_uppercase().as_str() {
+            "Y" => Ok(true),
+            "N" => Ok(false),
+            _ => Err(messages.invalid_yes_no),
         }
-    } else {
-        println!("Number must be a positive whole number (integer)!");
+    })?;
+
+    if !is_confirmed {
+        return None;
     }
+
+    outln!();
+    print_bulk_deposit(&with_bank(bank, |bank| bulk_rows(bank, &csv, false)), false);
+
+    Some(())
 }
 
-fn main() {
-    let mut accounts = Vec::new();
-    let mut exchange_rates = HashMap::<&str, f64>::new();
+/// Deposits the rows of a CSV file, or only validates them in a dry run, returning the result of each row.
+fn bulk_rows(bank: &mut Bank, csv: &str, is_dry_run: bool) -> Vec<RowInfo> {
+    batch::deposit_all(bank, csv, is_dry_run)
+        .into_iter()
+        .map(RowInfo::from)
+        .collect()
+}
 
-    for code in CURRENCIES_CODES.iter().skip(1) {
-        exchange_rates.insert(code, 1.0);
+/// Prints the result of each row of a CSV file of deposits in a table, followed by how many succeeded and failed.
+fn print_bulk_deposit(rows: &[RowInfo], is_dry_run: bool) {
+    let messages = messages();
+
+    if rows.is_empty() {
+        outln!("{}", messages.no_bulk_rows);
+
+        return;
     }
 
-    'main_menu: loop {
-        println!("Select Transaction:");
-        print_choices(&TRANSACTION_TITLES);
+    let mut table = Table::new(messages.bulk_headers)
+        .align(0, Align::Right)
+        .align(2, Align::Right);
+
+    for row in rows {
+        let amount = match row.amount.parse::<Decimal>() {
+            Ok(amount) => money(amount, &row.currency),
+            Err(_) => row.amount.clone(),
+        };
+        let result = match (&row.reference, &row.error) {
+            (_, Some(err)) => err.clone(),
+            (Some(reference), None) => reference.clone(),
+            (None, None) => messages.bulk_valid.to_string(),
+        };
+
+        table.push_row([row.line.to_string(), row.account.clone(), amount, result]);
+    }
+
+    outln!("{table}");
+
+    let failed_cnt = rows.iter().filter(|r| r.error.is_some()).count();
+    let summary = if is_dry_run {
+        messages.bulk_preview
+    } else {
+        messages.bulk_summary
+    };
+
+    outln!("{}", fill(summary, &[&(rows.len() - failed_cnt), &failed_cnt]));
+}
+
+/// Warns a CLI user that converting from one currency to another would use an out-of-date exchange rate, if it would,
+/// and prompts them to confirm using it anyway.
+///
+/// Nothing is returned if the user doesn't confirm.
+fn confirm_rates(bank: &Mutex<Bank>, src: &str, dest: &str) -> Option<()> {
+    let messages = messages();
+
+    let stale = with_bank(bank, |bank| {
+        bank.stale_rate(src, dest).map(|c| {
+            let updated_at = bank.rate_updated_at(&c);
 
-        println!();
+            (c, updated_at)
+        })
+    });
+    let Some((currency, updated_at)) = stale else {
+        return Some(());
+    };
 
-        let chosen_idx = prompt("> ").parse::<usize>().unwrap_or_default();
+    outln!();
 
-        println!();
+    match updated_at {
+        Some(updated_at) => outln!(
+            "{}",
+            fill(
+                messages.stale_rate_warning,
+                &[&style::code(&currency), &local_date(updated_at)]
+            )
+        ),
+        None => outln!("{}", fill(messages.never_updated_rate, &[&style::code(&currency)])),
+    }
 
-        if chosen_idx > 0 && chosen_idx <= TRANSACTION_TITLES.len() {
-            println!("{}", TRANSACTION_TITLES[chosen_idx - 1]);
+    let is_confirmed = prompt_validated(messages.use_stale_rate, Vec::new(), |input| {
+        match input.to_uppercase().as_str() {
+            "Y" => Ok(true),
+            "N" => Ok(false),
+            _ => Err(messages.invalid_yes_no),
         }
+    })?;
 
-        match chosen_idx {
-            1 => {
-                let account = Account::new(prompt("Account Name: "));
+    is_confirmed.then_some(())
+}
 
-                if !accounts.contains(&account) {
-                    accounts.push(account);
-                } else {
-                    println!("An account with this name already exists!");
-                }
-            }
-            2 | 3 => {
-                if let Some(account) = accounts.iter_mut().find(|a| a.name == prompt("Account Name: ")) {
-                    if chosen_idx == 2 {
-                        deposit_balance(account, &exchange_rates);
-                    } else {
-                        withdraw_balance(account, &exchange_rates);
-                    }
-                } else {
-                    println!("No account with this name exists!");
-                }
-            }
-            4 => 'currency_exchange: loop {
-                exchange_currencies(&exchange_rates);
+/// Formats a time, in seconds since the Unix epoch, as a local date.
+fn local_date(timestamp: u64) -> String {
+    DateTime::from_timestamp(timestamp as i64, 0)
+        .map(|d| d.with_timezone(&Local).format("%Y-%m-%d").to_string())
+        .unwrap_or_default()
+}
 
-                println!();
+/// Transfers balance from a user's account to another account.
+///
+/// The user is prompted to input the account to transfer to and the amount to transfer. Nothing is returned if the
+/// user cancels the transaction.
+fn transfer_balance(bank: &Mutex<Bank>, name: &str) -> Option<()> {
+    let messages = messages();
 
-                'repeat_prompt: loop {
-                    let is_repeating = prompt("Convert another currency? (Y/N): ").to_uppercase();
+    let account = with_bank(bank, |bank| bank.account(name).cloned())?;
+    let currency = account.currency.clone();
 
-                    if is_repeating == "Y" {
-                        println!();
+    outln!(
+        "{}",
+        fill(messages.current_balance, &[&money(account.balance.amount(), &currency)])
+    );
 
-                        break 'repeat_prompt;
-                    } else if is_repeating == "N" {
-                        break 'currency_exchange;
-                    } else {
-                        println!("Only accepting a [Y]es or [N]o answer!");
+    let recipient = prompt_account(bank, messages.recipient_name_or_number)?;
 
-                        println!();
-                    }
-                }
-            },
-            5 => {
-                println!();
+    confirm_rates(
+        bank,
+        &currency,
+        &with_bank(bank, |bank| account_currency(bank, &recipient)),
+    )?;
 
-                set_exchange_rate(&mut exchange_rates);
-            }
-            6 => {
-                if let Some(account) = accounts.iter().find(|a| a.name == prompt("Account Name: ")) {
-                    calculate_interest(account);
-                } else {
-                    println!("No account with this name exists!");
-                }
-            }
-            _ => {
-                println!("No transaction with this ID exists!")
-            }
+    let amount = prompt_amount(messages.transfer_amount, messages.invalid_transfer_amount)?;
+    let memo = prompt_memo()?;
+
+    outln!();
+
+    let transaction = HeldTransaction::Transfer {
+        account: name.to_string(),
+        recipient: recipient.clone(),
+        amount,
+    };
+
+    if with_bank(bank, |bank| bank.requires_approval(&transaction).unwrap_or_default()) {
+        match with_bank(bank, |bank| bank.hold(transaction, memo)) {
+            Ok(id) => outln!("{}", fill(messages.held_for_approval, &[&id])),
+            Err(err) => print_error(err),
         }
 
-        println!();
+        return Some(());
+    }
 
-        'exit_prompt: loop {
-            let is_continuing = prompt("Back to the Main Menu (Y/N): ").to_uppercase();
+    match with_bank(bank, |bank| bank.transfer(name, &recipient, amount, memo)) {
+        Ok(receipt) => {
+            print_transfer(&recipient, amount, receipt.balance, &currency);
+            print_charges(&receipt.charges, &currency);
+            print_reference(&receipt.reference);
+        }
+        Err(err) => print_error(err),
+    }
 
-            if is_continuing == "Y" {
-                println!();
+    Some(())
+}
 
-                break 'exit_prompt;
-            } else if is_continuing == "N" {
-                break 'main_menu;
-            } else {
-                println!("Only accepting a [Y]es or [N]o answer!");
+/// Prints the amount transferred to an account, followed by the sending account's updated balance.
+fn print_transfer(recipient: &str, amount: Decimal, balance: Decimal, currency: &str) {
+    let messages = messages();
 
-                println!();
-            }
+    outln!(
+        "{}",
+        fill(messages.transferred, &[&money(amount, currency), &recipient])
+    );
+    outln!(
+        "{}",
+        fill(messages.updated_balance, &[&style::decrease(money(balance, currency))])
+    );
+}
+
+/// Prints the daily withdrawal limit an account was set to, or that it was reset to the default.
+fn print_withdrawal_limit_set(account: &str, limit: Option<Decimal>, currency: &str) {
+    let messages = messages();
+
+    match limit {
+        Some(limit) => outln!(
+            "{}",
+            fill(messages.set_withdrawal_limit, &[&account, &money(limit, currency)])
+        ),
+        None => outln!("{}", fill(messages.reset_withdrawal_limit, &[&account])),
+    }
+}
+
+/// Prints the fees charged to an account along with a transaction, in the account's currency.
+fn print_charges(charges: &[Charge], currency: &str) {
+    let messages = messages();
+
+    for charge in charges {
+        outln!(
+            "{}",
+            fill(
+                messages.fee_charged,
+                &[
+                    &messages.fee_reasons[charge.reason as usize],
+                    &style::decrease(money(charge.amount, currency))
+                ]
+            )
+        );
+    }
+}
+
+/// Returns the currency of the balance of the account that an undone transaction was against, if any.
+fn undone_currency(bank: &Bank, transaction: &EntryKind) -> String {
+    match transaction {
+        EntryKind::Deposit { account, .. }
+        | EntryKind::Withdrawal { account, .. }
+        | EntryKind::Transfer { account, .. } => account_currency(bank, account),
+        _ => String::new(),
+    }
+}
+
+/// Prints what an undone transaction did.
+fn print_undone(transaction: &EntryKind, currency: &str) {
+    let messages = messages();
+
+    match transaction {
+        EntryKind::Deposit {
+            account,
+            amount,
+            currency,
+            ..
+        } => outln!(
+            "{}",
+            fill(messages.undid_deposit, &[&money(*amount, currency), account])
+        ),
+        EntryKind::Withdrawal {
+            account,
+            amount,
+            currency,
+            ..
+        } => outln!(
+            "{}",
+            fill(messages.undid_withdrawal, &[&money(*amount, currency), account])
+        ),
+        EntryKind::Transfer {
+            account,
+            recipient,
+            amount,
+            ..
+        } => outln!(
+            "{}",
+            fill(
+                messages.undid_transfer,
+                &[&money(*amount, currency), account, recipient]
+            )
+        ),
+        EntryKind::RateUpdate { currency, rate } => {
+            outln!("{}", fill(messages.undid_rate, &[&style::code(currency), rate]))
         }
+        EntryKind::SetSpread { currency, spread } => {
+            outln!("{}", fill(messages.undid_spread, &[&style::code(currency), spread]))
+        }
+        _ => {}
+    }
+}
+
+/// Calculates and prints how much one currency is worth in another.
+///
+/// The user is prompted to input the amount and what currencies to exchange. Giving a date instead converts the amount
+/// at the exchange rates in effect then, without exchanging it, and otherwise the exchange is broken down for the user
+/// to confirm. Nothing is returned if the user cancels the transaction.
+fn exchange_currencies(bank: &Mutex<Bank>) -> Option<()> {
+    let messages = messages();
+
+    let (codes, titles) = with_bank(bank, |bank| (bank.currency_codes(), bank.currency_titles()));
+
+    outln!("{}", style::header(messages.source_currency_options));
+    print_choices(&titles);
+
+    outln!();
+
+    let src_idx = prompt_currency_id(messages.source_currency, codes.len())?;
+    let src_amount = prompt_amount(messages.source_amount, messages.invalid_amount)?;
+
+    outln!();
+
+    outln!("{}", style::header(messages.exchange_currency_options));
+    print_choices(&titles);
+
+    outln!();
+
+    let exchange_idx = prompt_currency_id(messages.exchange_currency, codes.len())?;
+    let as_of = prompt_validated(messages.rate_date, Vec::new(), |input| {
+        if input.is_empty() {
+            return Ok(None);
+        }
+
+        input.parse::<NaiveDate>().map(Some).map_err(|_| messages.invalid_date)
+    })?;
+
+    if let Some(date) = as_of {
+        match with_bank(bank, |bank| {
+            bank.convert_at(&codes[src_idx], &codes[exchange_idx], src_amount, end_of_day(date))
+        }) {
+            Ok(exchange_amount) => print_conversion(
+                src_amount,
+                &codes[src_idx],
+                exchange_amount,
+                &codes[exchange_idx],
+                Some(&date.to_string()),
+            ),
+            Err(err) => print_error(err),
+        }
+
+        return Some(());
+    }
+
+    confirm_rates(bank, &codes[src_idx], &codes[exchange_idx])?;
+
+    let quote = match with_bank(bank, |bank| {
+        bank.quote_exchange(&codes[src_idx], &codes[exchange_idx], src_amount)
+    }) {
+        Ok(quote) => quote,
+        Err(err) => {
+            print_error(err);
+
+            return Some(());
+        }
+    };
+
+    outln!();
+
+    print_exchange_quote(&quote);
+
+    outln!();
+
+    let is_confirmed = prompt_validated(messages.confirm_exchange, Vec::new(), |input| {
+        match input.to_uppercase().as_str() {
+            "Y" => Ok(true),
+            "N" => Ok(false),
+            _ => Err(messages.invalid_yes_no),
+        }
+    })?;
+
+    if !is_confirmed {
+        return None;
+    }
+
+    match with_bank(bank, |bank| {
+        bank.exchange(&codes[src_idx], &codes[exchange_idx], src_amount)
+    }) {
+        Ok(exchange_amount) => outln!(
+            "{}",
+            fill(
+                messages.exchange_amount,
+                &[&money(exchange_amount, &codes[exchange_idx])]
+            )
+        ),
+        Err(err) => print_error(err),
+    }
+
+    Some(())
+}
+
+/// Prints how much an amount in a currency is worth in another, at the current exchange rates or those in effect on a
+/// date.
+fn print_conversion(
+    amount: Decimal,
+    currency: &str,
+    exchange_amount: Decimal,
+    exchange_currency: &str,
+    as_of: Option<&str>,
+) {
+    let messages = messages();
+
+    let amount = money(amount, currency);
+    let exchange_amount = money(exchange_amount, exchange_currency);
+
+    match as_of {
+        Some(date) => outln!(
+            "{}",
+            fill(messages.converted_as_of, &[&date, &amount, &exchange_amount])
+        ),
+        None => outln!("{}", fill(messages.converted, &[&amount, &exchange_amount])),
+    }
+}
+
+/// Returns the last second of a local date, in seconds since the Unix epoch.
+fn end_of_day(date: NaiveDate) -> u64 {
+    date.and_hms_opt(23, 59, 59)
+        .and_then(|d| d.and_local_timezone(Local).latest())
+        .map_or(0, |d| d.timestamp().max(0) as u64)
+}
+
+/// Prints the breakdown of an exchange: the amount, the rate it is exchanged at, the fee and spread taken, and the
+/// amount received.
+fn print_exchange_quote(quote: &ExchangeQuote) {
+    let messages = messages();
+
+    outln!("{}", style::header(messages.exchange_summary));
+    outln!("{}", fill(messages.amount, &[&money(quote.amount, &quote.currency)]));
+    outln!(
+        "{}",
+        fill(
+            messages.exchange_rate_label,
+            &[
+                &style::code(&quote.currency),
+                &format_rate(quote.rate),
+                &style::code(&quote.exchange_currency)
+            ]
+        )
+    );
+    outln!("{}", fill(messages.fee_label, &[&money(quote.fee, &quote.currency)]));
+    outln!(
+        "{}",
+        fill(messages.spread_label, &[&money(quote.spread, &quote.exchange_currency)])
+    );
+    outln!(
+        "{}",
+        fill(
+            messages.amount_received,
+            &[&money(quote.exchange_amount, &quote.exchange_currency)]
+        )
+    );
+}
+
+/// Prints the fee deducted from an amount in a currency before exchanging it, if any, followed by the exchanged amount.
+fn print_exchange(exchange_amount: Decimal, exchange_currency: &str, fee: Decimal, currency: &str) {
+    let messages = messages();
+
+    if fee > Decimal::ZERO {
+        outln!("{}", fill(messages.exchange_fee, &[&money(fee, currency)]));
+    }
+
+    outln!(
+        "{}",
+        fill(messages.exchange_amount, &[&money(exchange_amount, exchange_currency)])
+    );
+}
+
+/// Prints the fees and spread income collected by the bank in each currency.
+fn print_revenue(revenue: &BTreeMap<String, Decimal>, spread_income: &BTreeMap<String, Decimal>) {
+    let messages = messages();
+    let is_collected = |amounts: &BTreeMap<String, Decimal>| amounts.values().any(|&amount| amount != Decimal::ZERO);
+
+    if !is_collected(revenue) && !is_collected(spread_income) {
+        outln!("{}", messages.no_revenue);
+
+        return;
+    }
+
+    if is_collected(revenue) {
+        print_collected(messages.revenue_header, revenue);
+    }
+
+    if is_collected(spread_income) {
+        if is_collected(revenue) {
+            outln!();
+        }
+
+        print_collected(messages.spread_income_header, spread_income);
+    }
+}
+
+/// Prints a header followed by the amounts collected in each currency, the built-in currencies first in the order they
+/// are listed.
+fn print_collected(header: &str, amounts: &BTreeMap<String, Decimal>) {
+    outln!("{}", style::header(header));
+
+    let registered_codes = amounts.keys().filter(|c| currency::find_code(c).is_none());
+
+    for code in CURRENCIES_CODES.into_iter().chain(registered_codes.map(String::as_str)) {
+        if let Some(&amount) = amounts.get(code)
+            && amount != Decimal::ZERO
+        {
+            outln!("{}", money(amount, code));
+        }
+    }
+}
+
+/// Updates the exchange rate between a currency and Philippine Pesos.
+///
+/// The user is prompted to input the currency and its value in PHP. Nothing is returned if the user cancels the
+/// transaction.
+fn set_exchange_rate(bank: &Mutex<Bank>) -> Option<()> {
+    let messages = messages();
+
+    let mut table = Table::new(messages.rate_headers)
+        .align(0, Align::Right)
+        .align(2, Align::Right)
+        .align(3, Align::Right);
+
+    let (codes, titles, rates) = with_bank(bank, |bank| {
+        (
+            bank.currency_codes(),
+            bank.currency_titles(),
+            bank.exchange_rates().clone(),
+        )
+    });
+
+    for (i, (code, title)) in codes.iter().zip(titles).skip(1).enumerate() {
+        let rate = rates.get(code).copied().unwrap_or_default();
+
+        table.push_row([
+            (i + 1).to_string(),
+            title,
+            rate.to_string(),
+            format_rate(currency::inverse(rate)),
+        ]);
+    }
+
+    outln!("{table}");
+
+    outln!();
+
+    // Philippine Pesos aren't listed, so the choices are the foreign currencies after them.
+    let foreign_codes = &codes[1..];
+    let code = &foreign_codes[select_from(
+        messages.select_foreign_currency,
+        foreign_codes,
+        messages.unknown_currency_id,
+    )?];
+    let rate = prompt_amount(messages.exchange_rate, messages.invalid_amount)?;
+    let (change, result) = with_bank(bank, |bank| {
+        let change = bank.rate_change_warning(code, rate);
+
+        (change, bank.set_exchange_rate(code, rate))
+    });
+
+    match result {
+        Ok(()) => {
+            if let Some(change) = change {
+                outln!();
+                outln!("{}", sharp_rate_change(style::code(code), change));
+            }
+        }
+        Err(err) => print_error(err),
+    }
+
+    Some(())
+}
+
+/// Updates the spread between the rates the bank buys and sells a foreign currency at.
+///
+/// The user is prompted to input the currency and its spread as a percentage. Nothing is returned if the user cancels
+/// the transaction.
+fn set_spread(bank: &Mutex<Bank>) -> Option<()> {
+    let messages = messages();
+
+    let mut table = Table::new(messages.spread_headers)
+        .align(0, Align::Right)
+        .align(2, Align::Right)
+        .align(3, Align::Right)
+        .align(4, Align::Right);
+
+    let (codes, titles, rates, spreads) = with_bank(bank, |bank| {
+        let codes = bank.currency_codes();
+        let spreads: Vec<_> = codes.iter().map(|c| bank.spread(c)).collect();
+
+        (codes, bank.currency_titles(), bank.exchange_rates().clone(), spreads)
+    });
+
+    for (i, ((code, title), spread)) in codes.iter().zip(titles).zip(spreads).skip(1).enumerate() {
+        let rate = rates.get(code).copied().unwrap_or_default();
+
+        table.push_row([
+            (i + 1).to_string(),
+            title,
+            spread.to_string(),
+            format_rate(currency::buying_rate(rate, spread)),
+            format_rate(currency::selling_rate(rate, spread)),
+        ]);
+    }
+
+    outln!("{table}");
+
+    outln!();
+
+    // Philippine Pesos aren't listed, so the choices are the foreign currencies after them.
+    let foreign_codes = &codes[1..];
+    let code = &foreign_codes[select_from(
+        messages.select_foreign_currency,
+        foreign_codes,
+        messages.unknown_currency_id,
+    )?];
+    let spread = prompt_validated(messages.spread_percent, Vec::new(), |input| {
+        match input.parse::<Decimal>() {
+            Ok(spread) if (Decimal::ZERO..Decimal::ONE_HUNDRED).contains(&spread) => Ok(spread),
+            _ => Err(messages.invalid_spread),
+        }
+    })?;
+
+    if let Err(err) = with_bank(bank, |bank| bank.set_spread(code, spread)) {
+        print_error(err);
+    }
+
+    Some(())
+}
+
+/// The exchange rates entered by a CLI user one currency at a time, as a rate provider.
+struct ManualRates {
+    /// The titles and current rates of the foreign currencies, by their codes.
+    currencies: HashMap<String, (String, Decimal)>,
+}
+impl ManualRates {
+    /// Creates a provider prompting for the rates of a bank's currencies, defaulting to their current rates.
+    fn new(bank: &Bank) -> ManualRates {
+        ManualRates {
+            currencies: bank
+                .currency_codes()
+                .into_iter()
+                .zip(bank.currency_titles())
+                .map(|(code, title)| {
+                    let rate = bank.exchange_rates().get(&code).copied().unwrap_or(Decimal::ONE);
+
+                    (code, (title, rate))
+                })
+                .collect(),
+        }
+    }
+}
+impl RateProvider for ManualRates {
+    fn fetch(&mut self, codes: &[String]) -> Result<HashMap<String, Decimal>, RateError> {
+        let messages = messages();
+
+        codes
+            .iter()
+            .filter_map(|code| Some((code, self.currencies.get(code)?)))
+            .map(|(code, (title, rate))| {
+                let rate = prompt_defaulted(
+                    &fill(messages.currency_rate, &[title]),
+                    Some(&rate.to_string()),
+                    Vec::new(),
+                    |input| input.parse::<Decimal>().map_err(|_| messages.invalid_amount),
+                )
+                .ok_or(RateError::Cancelled)?;
+
+                Ok((code.clone(), rate))
+            })
+            .collect()
+    }
+}
+
+/// Refreshes the exchange rates of every foreign currency at once.
+///
+/// The user is prompted to choose the rate provider, then to input every rate if they chose to enter them manually or
+/// the CSV file if they chose to read them from one. The rates are fetched before the bank is locked to apply them, so
+/// that entering them or waiting on the live provider doesn't hold up the other sessions. Nothing is returned if the
+/// user cancels the transaction.
+fn refresh_rates(bank: &Mutex<Bank>) -> Option<()> {
+    let messages = messages();
+
+    // The live provider is listed last, so it is left out without live rates.
+    let providers = &messages.rate_providers[..if cfg!(feature = "live-rates") { 3 } else { 2 }];
+
+    outln!("{}", style::header(messages.rate_provider_options));
+    print_choices(providers);
+
+    outln!();
+
+    let idx = select_from(messages.select_rate_provider, providers, messages.invalid_id)?;
+
+    outln!();
+
+    let mut provider: Box<dyn RateProvider> = match idx {
+        0 => Box::new(with_bank(bank, |bank| ManualRates::new(bank))),
+        #[cfg(feature = "live-rates")]
+        2 => Box::new(EcbRates::default()),
+        _ => Box::new(CsvRates {
+            csv: prompt_validated(messages.csv_path, Vec::new(), |input| {
+                fs::read_to_string(input).map_err(|err| fill(messages.failed_csv_read, &[&err]))
+            })?,
+        }),
+    };
+
+    let codes: Vec<_> = with_bank(bank, |bank| bank.currency_codes())
+        .into_iter()
+        .filter(|c| c != BASE_CURRENCY)
+        .collect();
+    let refreshed = provider
+        .fetch(&codes)
+        .map_err(BankError::Rates)
+        .and_then(|rates| with_bank(bank, |bank| bank.refresh_rates(&mut StaticRateProvider { rates })));
+
+    match refreshed {
+        Ok(rates) => {
+            outln!();
+
+            print_refreshed_rates(&rates);
+        }
+        Err(BankError::Rates(RateError::Cancelled)) => return None,
+        Err(err) => print_error(err),
+    }
+
+    Some(())
+}
+
+/// Returns the exchange rates of the foreign currencies, in the order they are listed.
+fn foreign_rates(bank: &Bank) -> Vec<(String, Decimal)> {
+    bank.currency_codes()
+        .into_iter()
+        .skip(1)
+        .map(|code| {
+            let rate = bank.exchange_rates().get(&code).copied().unwrap_or_default();
+
+            (code, rate)
+        })
+        .collect()
+}
+
+/// Writes the exchange rates of the foreign currencies to a CSV file.
+///
+/// The user is prompted to input the path of the file. Nothing is returned if the user cancels the transaction.
+fn export_rates(bank: &Mutex<Bank>) -> Option<()> {
+    let messages = messages();
+
+    let rates = with_bank(bank, |bank| foreign_rates(bank));
+
+    prompt_validated(messages.csv_path, Vec::new(), |input| {
+        fs::write(input, rates::to_csv(&rates))
+            .map(|_| outln!("{}", fill(messages.exported_rates, &[&rates.len(), &input])))
+            .map_err(|err| fill(messages.failed_csv_write, &[&err]))
+    })?;
+
+    Some(())
+}
+
+/// Prints how many exchange rates were refreshed, followed by the refreshed rates in the order they are listed.
+fn print_refreshed_rates(rates: &[(String, Decimal)]) {
+    let messages = messages();
+
+    if rates.is_empty() {
+        outln!("{}", messages.no_refreshed_rates);
+
+        return;
+    }
+
+    outln!("{}", fill(messages.refreshed_rates, &[&rates.len()]));
+
+    let mut table = Table::new(&messages.rate_headers[1..3]).align(1, Align::Right);
+
+    for (code, rate) in rates {
+        table.push_row([code.clone(), rate.to_string()]);
+    }
+
+    outln!("{table}");
+}
+
+/// Lists a foreign currency's exchange rates recorded over a number of most recent days, with their lowest, highest,
+/// and average.
+///
+/// The user is prompted to input the currency and the number of days. Nothing is returned if the user cancels the
+/// transaction.
+fn show_rate_history(bank: &Mutex<Bank>) -> Option<()> {
+    let messages = messages();
+
+    let currency = prompt_currency(bank, "USD")?;
+    let days = prompt_defaulted(messages.rate_window, Some("30"), Vec::new(), |input| {
+        match input.parse::<u64>() {
+            Ok(days) if days > 0 => Ok(days),
+            _ => Err(messages.invalid_interval),
+        }
+    })?;
+
+    outln!();
+
+    let rates = with_bank(bank, |bank| recent_rates(bank, &currency, days));
+
+    print_rate_history(&currency, days, &rates, RateStats::of(&rates).as_ref());
+
+    Some(())
+}
+
+/// Lists the alerts on the exchange rates, then lets a CLI user add an alert or remove one.
+///
+/// The user is prompted to choose whether to add or remove an alert, if either, followed by the currency, condition,
+/// and threshold of the alert to add, or the ID of the alert to remove. Nothing is returned if the user cancels the
+/// transaction.
+fn manage_alerts(bank: &Mutex<Bank>) -> Option<()> {
+    let messages = messages();
+
+    print_alerts(&with_bank(bank, |bank| bank.alerts().to_vec()));
+
+    outln!();
+
+    let is_adding = prompt_validated(messages.add_or_remove_alert, Vec::new(), |input| {
+        match input.to_uppercase().as_str() {
+            "" => Ok(None),
+            "A" => Ok(Some(true)),
+            "R" => Ok(Some(false)),
+            _ => Err(messages.invalid_add_remove),
+        }
+    })?;
+
+    match is_adding {
+        Some(true) => {
+            let currency = prompt_currency(bank, "USD")?;
+            let condition = prompt_validated(messages.alert_condition, Vec::new(), |input| match input {
+                ">=" | "≥" => Ok(AlertCondition::AtLeast),
+                "<=" | "≤" => Ok(AlertCondition::AtMost),
+                _ => Err(messages.invalid_alert_condition),
+            })?;
+            let threshold = prompt_amount(messages.alert_threshold, messages.invalid_amount)?;
+
+            outln!();
+
+            match with_bank(bank, |bank| bank.add_alert(&currency, condition, threshold)) {
+                Ok(id) => outln!("{}", fill(messages.added_alert, &[&id])),
+                Err(err) => print_error(err),
+            }
+        }
+        Some(false) => {
+            let id = prompt_validated(messages.alert_to_remove, Vec::new(), |input| {
+                input.parse::<u64>().map_err(|_| messages.invalid_id)
+            })?;
+
+            outln!();
+
+            match with_bank(bank, |bank| bank.remove_alert(id)) {
+                Ok(()) => outln!("{}", fill(messages.removed_alert, &[&id])),
+                Err(err) => print_error(err),
+            }
+        }
+        None => {}
+    }
+
+    Some(())
+}
+
+/// Prints the alerts on the exchange rates in a table.
+fn print_alerts(alerts: &[RateAlert]) {
+    let messages = messages();
+
+    if alerts.is_empty() {
+        outln!("{}", messages.no_alerts);
+
+        return;
+    }
+
+    let mut table = Table::new(messages.alert_headers).align(0, Align::Right);
+
+    for alert in alerts {
+        table.push_row([
+            alert.id.to_string(),
+            alert.currency.clone(),
+            format!("{} {}", alert.condition, format_rate(alert.threshold)),
+            messages.alert_statuses[usize::from(alert.is_removed)].to_string(),
+        ]);
+    }
+
+    outln!("{table}");
+}
+
+/// Prints the notifications of the alerts that changes in the exchange rates triggered.
+fn print_triggered_alerts(notifications: &[RateNotification]) {
+    let messages = messages();
+
+    for RateNotification { alert, rate, .. } in notifications {
+        outln!(
+            "{}",
+            fill(
+                messages.alert_triggered,
+                &[
+                    &alert.id,
+                    &style::code(&alert.currency),
+                    &format_rate(*rate),
+                    &format!("{} {}", alert.condition, format_rate(alert.threshold)),
+                ]
+            )
+        );
+    }
+}
+
+/// Prints the notifications of the alerts triggered by the transactions performed against a bank since they were last
+/// printed, as taken from the bank, if any, in a format.
+fn notify_alerts(notifications: Vec<RateNotification>, format: Format) {
+    if !notifications.is_empty() {
+        Outcome::AlertsTriggered { notifications }.print(format);
+    }
+}
+
+/// Returns a currency's exchange rates recorded over a number of most recent days, from newest to oldest.
+fn recent_rates(bank: &Bank, currency: &str, days: u64) -> Vec<RatePoint> {
+    let since = bank.now().saturating_sub(days * clock::SECONDS_PER_DAY);
+
+    bank.rate_history(currency)
+        .iter()
+        .rev()
+        .take_while(|p| p.recorded_at >= since)
+        .copied()
+        .collect()
+}
+
+/// Returns the current exchange rates of the foreign currencies, in the order they are listed.
+fn current_rates(bank: &Bank) -> Vec<CurrentRate> {
+    bank.currency_codes()
+        .into_iter()
+        .skip(1)
+        .map(|code| {
+            let rate = bank.exchange_rates().get(&code).copied().unwrap_or_default();
+
+            CurrentRate {
+                rate,
+                inverse: currency::inverse(rate),
+                updated_at: bank.rate_updated_at(&code),
+                currency: code,
+            }
+        })
+        .collect()
+}
+
+/// Prints the current exchange rates of the foreign currencies, with their inverses and when they were last updated.
+fn print_current_rates(rates: &[CurrentRate]) {
+    let messages = messages();
+
+    let mut table = Table::new(messages.current_rate_headers)
+        .align(1, Align::Right)
+        .align(2, Align::Right);
+
+    for rate in rates {
+        let updated_on = rate
+            .updated_at
+            .and_then(|t| DateTime::from_timestamp(t as i64, 0))
+            .map(|d| d.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|| messages.never_updated.to_string());
+
+        table.push_row([
+            rate.currency.clone(),
+            rate.rate.to_string(),
+            format_rate(rate.inverse),
+            updated_on,
+        ]);
+    }
+
+    outln!("{table}");
+}
+
+/// Prints a currency's exchange rates recorded over a number of most recent days, followed by their lowest, highest,
+/// and average.
+fn print_rate_history(currency: &str, days: u64, rates: &[RatePoint], stats: Option<&RateStats>) {
+    let messages = messages();
+
+    let Some(stats) = stats else {
+        outln!("{}", fill(messages.no_rate_history, &[&style::code(currency), &days]));
+
+        return;
+    };
+
+    outln!(
+        "{}",
+        style::header(&fill(messages.rate_history_header, &[&currency, &days]))
+    );
+
+    let mut table = Table::new(messages.rate_history_headers).align(1, Align::Right);
+
+    for point in rates {
+        let date = DateTime::from_timestamp(point.recorded_at as i64, 0)
+            .map(|d| d.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default();
+
+        table.push_row([date, point.rate.to_string()]);
+    }
+
+    outln!("{table}");
+
+    outln!();
+
+    outln!(
+        "{}",
+        fill(
+            messages.rate_stats,
+            &[
+                &format_rate(stats.min),
+                &format_rate(stats.max),
+                &format_rate(stats.average)
+            ]
+        )
+    );
+}
+
+/// Formats an exchange rate with at most six decimal places, or six significant digits if it is finer, like the rates
+/// of cryptocurrencies, without trailing zeroes.
+fn format_rate(rate: Decimal) -> String {
+    let mut magnitude = 0;
+    let mut scaled = rate.abs();
+
+    // The leading zeroes of a rate below one are counted, so that its significant digits are kept.
+    while scaled != Decimal::ZERO && scaled < Decimal::ONE {
+        scaled *= Decimal::TEN;
+        magnitude += 1;
+    }
+
+    RoundingPolicy::HalfUp
+        .round(rate, (5 + magnitude).clamp(6, 15))
+        .normalize()
+        .to_string()
+}
+
+/// Registers a new currency with its exchange rate.
+///
+/// The user is prompted to input the currency's code, name, and value in PHP. A currency listed in ISO 4217 can be
+/// chosen by its name instead, and is described before its name defaults to the listed one. Nothing is returned if the
+/// user cancels the transaction.
+fn add_currency(bank: &Mutex<Bank>) -> Option<()> {
+    let messages = messages();
+
+    let code = prompt_validated(
+        messages.currency_code,
+        iso4217::currencies().iter().map(|c| c.code.to_string()).collect(),
+        |input| match iso4217::find(input) {
+            Some(iso) => Ok(iso.code.to_string()),
+            None if currency::is_valid_code(&input.trim().to_uppercase()) => Ok(input.trim().to_uppercase()),
+            None => Err(messages.invalid_currency_code),
+        },
+    )?;
+    let iso = iso4217::find_by_code(&code);
+
+    if with_bank(bank, |bank| bank.currency_codes().contains(&code)) {
+        print_error(BankError::DuplicateCurrency);
+
+        return Some(());
+    }
+
+    if let Some(iso) = iso {
+        outln!(
+            "{}",
+            fill(
+                messages.iso_currency,
+                &[&iso.title(), &iso.minor_units, &iso.symbol.unwrap_or(iso.code)]
+            )
+        );
+    }
+
+    let name = match iso {
+        Some(iso) => prompt_defaulted(messages.currency_name, Some(iso.name), Vec::new(), |input| {
+            Ok::<_, &str>(input.trim().to_string())
+        })?,
+        None => prompt_cancellable(messages.currency_name, Vec::new())?,
+    };
+    let rate = prompt_amount(messages.exchange_rate, messages.invalid_amount)?;
+
+    outln!();
+
+    match with_bank(bank, |bank| {
+        bank.add_currency(&code, &name, rate)
+            .map(|()| bank.currencies().last().map(|c| c.title()))
+    }) {
+        Ok(title) => outln!("{}", fill(messages.added_currency, &[&title?, &rate])),
+        Err(err) => print_error(err),
+    }
+
+    Some(())
+}
+
+/// Closes a user's account.
+///
+/// An account with a remaining balance must be confirmed to be withdrawn from before it is closed. Nothing is returned
+/// if the user cancels the transaction.
+fn close_account(bank: &Mutex<Bank>, name: &str) -> Option<()> {
+    let messages = messages();
+
+    let account = with_bank(bank, |bank| bank.account(name).cloned())?;
+
+    if account.balance.amount() > Decimal::ZERO {
+        let is_confirmed = prompt_validated(
+            &fill(
+                messages.confirm_close_remainder,
+                &[&money(account.balance.amount(), &account.currency)],
+            ),
+            Vec::new(),
+            |input| match input.to_uppercase().as_str() {
+                "Y" => Ok(true),
+                "N" => Ok(false),
+                _ => Err(messages.invalid_yes_no),
+            },
+        )?;
+
+        if !is_confirmed {
+            return None;
+        }
+    }
+
+    let currency = account.currency.clone();
+
+    match with_bank(bank, |bank| bank.close(name, true)) {
+        Ok(withdrawn_amount) => {
+            if withdrawn_amount > Decimal::ZERO {
+                outln!(
+                    "{}",
+                    fill(
+                        messages.withdrew_remainder,
+                        &[&style::decrease(money(withdrawn_amount, &currency))]
+                    )
+                );
+            }
+
+            outln!("{}", fill(messages.closed_account, &[&name]));
+        }
+        Err(err) => print_error(err),
+    }
+
+    Some(())
+}
+
+/// Calculates and prints the daily increase to an account's balance from interest by its terms, starting from a date.
+///
+/// The user is prompted to input the number of days to calculate for, whether the interest compounds, and how often,
+/// defaulting to the terms' mode and compounding frequency. The user can then export the schedule to a CSV file instead
+/// of printing it, which only prints the totals. Nothing is returned if the user cancels the transaction.
+fn calculate_interest(account: &Account, terms: InterestTerms, start: NaiveDate) -> Option<()> {
+    let messages = messages();
+
+    outln!("{}", fill(messages.owners_label, &[&account.owners.join(", ")]));
+    outln!(
+        "{}",
+        fill(
+            messages.current_balance,
+            &[&money(account.balance.amount(), &account.currency)]
+        )
+    );
+    outln!(
+        "{}",
+        fill(messages.account_currency, &[&style::code(&account.currency)])
+    );
+    outln!(
+        "{}",
+        fill(
+            messages.account_type_label,
+            &[&messages.account_types[account.account_type as usize]]
+        )
+    );
+    outln!(
+        "{}",
+        fill(
+            messages.interest_rate,
+            &[&format_rate(account.interest_rate * Decimal::ONE_HUNDRED)]
+        )
+    );
+
+    if !terms.tiers.is_empty() {
+        outln!("{}", messages.interest_tiers);
+
+        for tier in terms.tiers {
+            let percentage = format_rate(tier.percentage);
+
+            match tier.below {
+                Some(below) => outln!(
+                    "{}",
+                    fill(messages.interest_tier, &[&percentage, &money(below, &account.currency)])
+                ),
+                None => outln!("{}", fill(messages.interest_tier_rest, &[&percentage])),
+            }
+        }
+    }
+
+    outln!();
+
+    let day_cnt = prompt_validated(messages.day_count, Vec::new(), |input| {
+        input.parse::<u32>().map_err(|_| messages.invalid_number)
+    })?;
+    let mode = prompt_defaulted(
+        messages.interest_mode,
+        Some(InterestMode::TAGS[terms.mode as usize]),
+        InterestMode::TAGS.map(String::from).to_vec(),
+        |input| {
+            input
+                .parse::<InterestMode>()
+                .map_err(|_| messages.invalid_interest_mode)
+        },
+    )?;
+    let compounding = prompt_compounding(terms.compounding)?;
+    let deposit = prompt_validated(messages.planned_deposit, Vec::new(), |input| match input {
+        "" => Ok(None),
+        _ => input.parse::<Decimal>().map(Some).map_err(|_| messages.invalid_amount),
+    })?;
+    let contribution = match deposit {
+        Some(amount) => Some(Contribution {
+            amount,
+            interval_days: prompt_interval()?,
+        }),
+        None => None,
+    };
+    let terms = InterestTerms {
+        mode,
+        compounding,
+        ..terms
+    };
+    let schedule = interest::schedule(account.balance.amount(), &terms, start, day_cnt, contribution);
+    let is_exported = prompt_validated(messages.export_interest, Vec::new(), |input| {
+        match input.to_uppercase().as_str() {
+            "Y" => Ok(true),
+            "N" => Ok(false),
+            _ => Err(messages.invalid_yes_no),
+        }
+    })?;
+    let exported_to = if is_exported {
+        Some(prompt_validated(messages.csv_path, Vec::new(), |input| {
+            fs::write(input, interest::to_csv(&schedule))
+                .map(|_| input.to_string())
+                .map_err(|err| fill(messages.failed_csv_write, &[&err]))
+        })?)
+    } else {
+        None
+    };
+
+    outln!();
+
+    outln!(
+        "{}",
+        fill(
+            messages.effective_rate,
+            &[&format_rate(terms.effective_rate() * Decimal::ONE_HUNDRED)]
+        )
+    );
+
+    outln!();
+
+    print_interest_schedule(
+        &schedule,
+        &account.currency,
+        Some(INTEREST_PAGE_SIZE),
+        exported_to.as_deref(),
+    );
+
+    Some(())
+}
+
+/// Prints a table of accounts, or a notice if there are none.
+fn print_accounts(accounts: &[AccountInfo]) {
+    let messages = messages();
+
+    if accounts.is_empty() {
+        outln!("{}", messages.no_accounts);
+
+        return;
+    }
+
+    let mut table = Table::new(messages.account_headers).align(4, Align::Right);
+
+    for account in accounts {
+        table.push_row([
+            account.number.clone(),
+            account.name.clone(),
+            messages.account_types[account.account_type as usize].to_string(),
+            account.currency.clone(),
+            money(account.balance, &account.currency),
+            messages.account_statuses[account.status as usize].to_string(),
+        ]);
+    }
+
+    outln!("{table}");
+}
+
+/// Prints every detail of an account.
+fn print_account_details(account: &AccountInfo) {
+    let messages = messages();
+
+    let opened_on = DateTime::from_timestamp(account.opened_at as i64, 0)
+        .filter(|_| account.opened_at != 0)
+        .map(|d| d.with_timezone(&Local).format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| String::from(messages.unknown_date));
+
+    outln!("{}", style::header(messages.account_details));
+    outln!("{}", fill(messages.account_number, &[&style::code(&account.number)]));
+    outln!("{}", fill(messages.name_label, &[&account.name]));
+    outln!("{}", fill(messages.owners_label, &[&account.owners.join(", ")]));
+    outln!(
+        "{}",
+        fill(
+            messages.account_type_label,
+            &[&messages.account_types[account.account_type as usize]]
+        )
+    );
+    outln!(
+        "{}",
+        fill(messages.balance_label, &[&money(account.balance, &account.currency)])
+    );
+    outln!(
+        "{}",
+        fill(
+            messages.overdraft_limit_label,
+            &[&money(account.overdraft_limit, &account.currency)]
+        )
+    );
+    outln!(
+        "{}",
+        fill(
+            messages.withdrawal_limit_label,
+            &[&account
+                .daily_withdrawal_limit
+                .map_or_else(|| messages.default_limit.to_string(), |l| money(l, &account.currency))]
+        )
+    );
+    outln!(
+        "{}",
+        fill(
+            messages.interest_rate,
+            &[&format_rate(account.interest_rate * Decimal::ONE_HUNDRED)]
+        )
+    );
+    outln!(
+        "{}",
+        fill(messages.compounding_label, &[&account.compounding.to_string()])
+    );
+    outln!(
+        "{}",
+        fill(
+            messages.status_label,
+            &[&messages.account_statuses[account.status as usize]]
+        )
+    );
+    outln!("{}", fill(messages.opened_on, &[&opened_on]));
+    outln!(
+        "{}",
+        fill(
+            messages.email_label,
+            &[&account.email.as_deref().unwrap_or(messages.not_given)]
+        )
+    );
+    outln!(
+        "{}",
+        fill(
+            messages.phone_label,
+            &[&account.phone.as_deref().unwrap_or(messages.not_given)]
+        )
+    );
+}
+
+/// Prompts a CLI user to choose how to sort and filter the registered accounts, then lists them.
+///
+/// Nothing is returned if the user cancels the transaction.
+fn list_accounts(bank: &Mutex<Bank>) -> Option<()> {
+    let messages = messages();
+
+    let order = prompt_defaulted(
+        messages.sort_order,
+        Some(AccountOrder::TAGS[0]),
+        AccountOrder::TAGS.map(String::from).to_vec(),
+        |input| input.parse::<AccountOrder>().map_err(|_| messages.invalid_sort_order),
+    )?;
+    let min_balance = prompt_validated(messages.min_balance, Vec::new(), |input| match input {
+        "" => Ok(None),
+        _ => input.parse::<Decimal>().map(Some).map_err(|_| messages.invalid_amount),
+    })?;
+    let account_type = prompt_validated(
+        messages.type_filter,
+        AccountType::TAGS.map(String::from).to_vec(),
+        |input| match input {
+            "" => Ok(None),
+            _ => input
+                .parse::<AccountType>()
+                .map(Some)
+                .map_err(|_| messages.invalid_account_type),
+        },
+    )?;
+
+    outln!();
+
+    let filter = AccountFilter {
+        min_balance,
+        account_type,
+    };
+
+    print_accounts(&with_bank(bank, |bank| {
+        bank.list_accounts(&filter, order)
+            .into_iter()
+            .map(AccountInfo::from)
+            .collect::<Vec<_>>()
+    }));
+
+    Some(())
+}
+
+/// Prints a table's header, followed by its rows.
+///
+/// If a page size is given, the user is prompted whether to print more after each page, stopping if they don't.
+fn print_table_paged(table: &Table, page_size: Option<usize>) {
+    let lines = table.lines();
+    let (header, rows) = lines.split_at(2);
+
+    outln!("{}", header.join("\n"));
+
+    let page_size = page_size.unwrap_or(rows.len()).max(1);
+
+    for (i, page) in rows.chunks(page_size).enumerate() {
+        if i > 0 && !prompt_more() {
+            break;
+        }
+
+        outln!("{}", page.join("\n"));
+    }
+}
+
+/// The number of transactions of an account's history printed per page in the interactive menu.
+const HISTORY_PAGE_SIZE: usize = 10;
+
+/// Prints the transactions against an account, in the order given, with its balance in its currency.
+///
+/// If a page size is given, the user is prompted whether to print more after each page.
+fn print_history(records: &[Record], currency: &str, page_size: Option<usize>) {
+    let messages = messages();
+
+    if records.is_empty() {
+        outln!("{}", messages.no_history);
+
+        return;
+    }
+
+    let mut table = Table::new(messages.history_headers)
+        .align(3, Align::Right)
+        .align(4, Align::Right);
+
+    for record in records {
+        let date = DateTime::from_timestamp(record.timestamp as i64, 0)
+            .map(|d| d.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default();
+
+        table.push_row([
+            date,
+            record.reference.clone(),
+            messages.record_kinds[record.kind as usize].to_string(),
+            money(record.amount, &record.currency),
+            money(record.balance, currency),
+            history_note(record),
+        ]);
+    }
+
+    print_table_paged(&table, page_size);
+}
+
+/// Returns a record's memo, followed by its links to the transaction it reversed or that reversed it.
+fn history_note(record: &Record) -> String {
+    let messages = messages();
+
+    let links = [
+        record.reversal_of.as_ref().map(|r| fill(messages.reversal_of, &[r])),
+        record.reversed_by.as_ref().map(|r| fill(messages.reversed_by, &[r])),
+    ];
+
+    record
+        .memo
+        .iter()
+        .cloned()
+        .chain(links.into_iter().flatten())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// The number of days of an interest schedule printed per page in the interactive menu.
+const INTEREST_PAGE_SIZE: usize = 30;
+
+/// Prints the daily increase to an account's balance from interest, followed by its total.
+///
+/// If a page size is given, the user is prompted whether to print more after each page, skipping to the total if they
+/// don't. If the schedule was exported to a CSV file, the path is printed in place of the daily increases.
+fn print_interest_schedule(
+    schedule: &[InterestRow],
+    currency: &str,
+    page_size: Option<usize>,
+    exported_to: Option<&str>,
+) {
+    let messages = messages();
+
+    // The overdraft charges, taxes, and deposits are only shown when any were made, keeping the usual schedule narrow.
+    let is_overdrawn = schedule.iter().any(|r| r.overdraft_charge > Decimal::ZERO);
+    let is_taxed = schedule.iter().any(|r| r.tax > Decimal::ZERO);
+    let is_contributed = schedule.iter().any(|r| r.deposit != Decimal::ZERO);
+
+    if let Some(path) = exported_to {
+        outln!("{}", fill(messages.exported_interest, &[&schedule.len(), &path]));
+    } else {
+        let mut headers = messages.interest_headers.to_vec();
+
+        if is_overdrawn {
+            headers.insert(2, messages.overdraft_charge_header);
+        }
+
+        if is_taxed {
+            headers.splice(2..2, [messages.tax_header, messages.net_interest_header]);
+        }
+
+        if is_contributed {
+            headers.insert(headers.len() - 1, messages.deposit_header);
+        }
+
+        let mut table = (0..headers.len()).fold(Table::new(&headers), |table, i| table.align(i, Align::Right));
+
+        for row in schedule {
+            let mut cells = vec![row.day.to_string(), money(row.interest, currency)];
+
+            if is_taxed {
+                cells.push(money(row.tax, currency));
+                cells.push(money(row.interest - row.tax, currency));
+            }
+
+            if is_overdrawn {
+                cells.push(money(row.overdraft_charge, currency));
+            }
+
+            if is_contributed {
+                cells.push(money(row.deposit, currency));
+            }
+
+            cells.push(money(row.balance, currency));
+
+            table.push_row(cells);
+        }
+
+        print_table_paged(&table, page_size);
+    }
+
+    outln!();
+
+    outln!(
+        "{}",
+        fill(
+            messages.total_interest,
+            &[&money(schedule.iter().map(|r| r.interest).sum(), currency)]
+        )
+    );
+
+    if is_taxed {
+        let total_tax = schedule.iter().map(|r| r.tax).sum::<Decimal>();
+
+        outln!("{}", fill(messages.total_tax, &[&money(total_tax, currency)]));
+        outln!(
+            "{}",
+            fill(
+                messages.net_interest,
+                &[&money(
+                    schedule.iter().map(|r| r.interest).sum::<Decimal>() - total_tax,
+                    currency
+                )]
+            )
+        );
+    }
+
+    if is_overdrawn {
+        outln!(
+            "{}",
+            fill(
+                messages.total_overdraft_charge,
+                &[&money(schedule.iter().map(|r| r.overdraft_charge).sum(), currency)]
+            )
+        );
+    }
+
+    if is_contributed {
+        outln!(
+            "{}",
+            fill(
+                messages.total_deposits,
+                &[&money(schedule.iter().map(|r| r.deposit).sum(), currency)]
+            )
+        );
+    }
+
+    outln!(
+        "{}",
+        fill(
+            messages.final_balance,
+            &[&money(schedule.last().map(|r| r.balance).unwrap_or_default(), currency)]
+        )
+    );
+}
+
+/// Prints the interest posted to an account's balance, with the account's updated balance.
+fn print_interest_posting(account: &str, posting: &InterestPosting, currency: &str) {
+    let messages = messages();
+
+    outln!(
+        "{}",
+        fill(
+            messages.posted_interest,
+            &[&money(posting.interest, currency), &posting.day_cnt, &account]
+        )
+    );
+
+    if posting.tax > Decimal::ZERO {
+        outln!(
+            "{}",
+            fill(
+                messages.withheld_tax,
+                &[
+                    &style::decrease(money(posting.tax, currency)),
+                    &money(posting.interest - posting.tax, currency)
+                ]
+            )
+        );
+    }
+
+    if posting.overdraft_charge > Decimal::ZERO {
+        outln!(
+            "{}",
+            fill(
+                messages.posted_overdraft_charge,
+                &[&style::decrease(money(posting.overdraft_charge, currency))]
+            )
+        );
+    }
+
+    outln!(
+        "{}",
+        fill(messages.updated_balance, &[&money(posting.balance, currency)])
+    );
+    print_reference(&posting.reference);
+}
+
+/// Prints an annual percentage rate and the yield it compounds to at a frequency.
+fn print_yield(compounding: Compounding, apr: Decimal, apy: Decimal) {
+    let messages = messages();
+
+    outln!("{}", fill(messages.compounding_label, &[&compounding]));
+    outln!("{}", fill(messages.apr_label, &[&format_rate(apr)]));
+    outln!("{}", fill(messages.apy_label, &[&format_rate(apy)]));
+}
+
+/// Converts between an annual percentage rate and the yield it compounds to, then prints both.
+///
+/// The user is prompted to input which of the two they have, its percentage, and how often it compounds. Nothing is
+/// returned if the user cancels the transaction.
+fn calculate_yield() -> Option<()> {
+    let messages = messages();
+
+    let is_apr = prompt_validated(
+        messages.rate_kind,
+        vec![String::from("apr"), String::from("apy")],
+        |input| match input.to_lowercase().as_str() {
+            "apr" => Ok(true),
+            "apy" => Ok(false),
+            _ => Err(messages.invalid_rate_kind),
+        },
+    )?;
+    let rate = prompt_validated(messages.yield_rate, Vec::new(), |input| {
+        match input.parse::<Decimal>() {
+            Ok(rate) if rate > -Decimal::ONE_HUNDRED => Ok(rate / Decimal::ONE_HUNDRED),
+            _ => Err(messages.invalid_yield_rate),
+        }
+    })?;
+    let compounding = prompt_compounding(Compounding::default())?;
+
+    outln!();
+
+    if is_apr {
+        print_yield(
+            compounding,
+            rate * Decimal::ONE_HUNDRED,
+            compounding.effective_rate(rate) * Decimal::ONE_HUNDRED,
+        );
+    } else {
+        print_yield(
+            compounding,
+            compounding.nominal_rate(rate) * Decimal::ONE_HUNDRED,
+            rate * Decimal::ONE_HUNDRED,
+        );
+    }
+
+    Some(())
+}
+
+/// Ends a period, charging the monthly fee to every account below its minimum balance, returning the charges.
+fn end_period(bank: &mut Bank) -> Result<Vec<FeeCharge>, BankError> {
+    Ok(bank
+        .end_period()?
+        .into_iter()
+        .map(|(account, amount)| FeeCharge {
+            currency: account_currency(bank, &account),
+            account,
+            amount,
+        })
+        .collect())
+}
+
+/// Sorts amounts collected by the bank, like its fees, by currency.
+fn by_currency(amounts: &HashMap<String, Decimal>) -> BTreeMap<String, Decimal> {
+    amounts.iter().map(|(code, amount)| (code.clone(), *amount)).collect()
+}
+
+/// Prints the fees charged at the end of a period.
+fn print_fee_charges(charges: &[FeeCharge]) {
+    let messages = messages();
+
+    if charges.is_empty() {
+        outln!("{}", messages.no_fees_charged);
+    }
+
+    for charge in charges {
+        outln!(
+            "{}",
+            fill(
+                messages.charged_fee,
+                &[
+                    &charge.account,
+                    &style::decrease(money(charge.amount, &charge.currency))
+                ]
+            )
+        );
+    }
+}
+
+/// Opens the journal file with a passphrase given beforehand, never prompting for one.
+///
+/// A new journal is only encrypted if a passphrase is given. Opening an encrypted journal without one fails.
+fn open_journal_with(path: &Path, passphrase: Option<&str>) -> io::Result<Journal> {
+    match passphrase {
+        Some(passphrase) if Journal::is_new(path) || Journal::is_encrypted(path)? => {
+            Journal::open_encrypted(path, passphrase)
+        }
+        _ => Journal::open(path),
+    }
+}
+
+/// Opens the journal file, prompting for its passphrase if it is or will be encrypted.
+///
+/// A new journal is only encrypted if the user sets a passphrase for it. An encrypted journal keeps prompting until the
+/// correct passphrase is inputted. Nothing is prompted if the passphrase was given beforehand.
+fn open_journal(path: &Path, passphrase: Option<&str>) -> Journal {
+    let messages = messages();
+
+    if passphrase.is_some() {
+        return open_journal_with(path, passphrase).unwrap_or_else(|err| {
+            eprint_error(fill(messages.failed_journal_open, &[&err]));
+
+            process::exit(1);
+        });
+    }
+
+    if Journal::is_new(path) {
+        let passphrase = prompt_private(messages.set_passphrase);
+
+        outln!();
+
+        return if passphrase.is_empty() {
+            Journal::open(path)
+        } else {
+            Journal::open_encrypted(path, &passphrase)
+        }
+        .expect("Failed to open the journal file...");
+    }
+
+    if !Journal::is_encrypted(path).expect("Failed to read the journal file...") {
+        return Journal::open(path).expect("Failed to open the journal file...");
+    }
+
+    loop {
+        match Journal::open_encrypted(path, &prompt_private(messages.passphrase)) {
+            Ok(journal) => {
+                outln!();
+
+                return journal;
+            }
+            Err(err) if err.kind() == io::ErrorKind::InvalidData => {
+                print_error(messages.incorrect_passphrase);
+
+                outln!();
+            }
+            Err(err) => panic!("Failed to open the journal file: {err}"),
+        }
+    }
+}
+
+/// Rolls the bank's state back to a backup.
+///
+/// The user is prompted to select from the list of available backups, from oldest to newest. Nothing is returned if
+/// the user cancels the transaction.
+fn restore_backup(bank: &Mutex<Bank>, profile: &Profile) -> Option<()> {
+    let messages = messages();
+
+    let backups = match Backup::list(profile.backup_dir()) {
+        Ok(backups) => backups,
+        Err(err) => {
+            print_error(fill(messages.failed_backup_list, &[&err]));
+
+            return Some(());
+        }
+    };
+
+    if backups.is_empty() {
+        print_error(messages.no_backups);
+
+        return Some(());
+    }
+
+    outln!("{}", style::header(messages.backup_options));
+    print_choices(&backups);
+
+    outln!();
+
+    let backup = &backups[select_from(messages.select_backup, &backups, messages.unknown_backup)?];
+
+    match with_bank(bank, |bank| bank.restore(backup)) {
+        Ok(()) => outln!("{}", fill(messages.restored_backup, &[backup])),
+        Err(err) => print_error(err),
+    }
+
+    Some(())
+}
+
+/// Rebuilds a bank by replaying its journal, or only up to a point in it, noticing the user that nothing done to such a
+/// bank is saved.
+fn replay_bank(journal: Journal, replay: Option<ReplayPoint>) -> io::Result<Bank> {
+    let messages = messages();
+
+    let Some(point) = replay else {
+        return Bank::replay(journal);
+    };
+    let bank = Bank::replay_to(&journal, point)?;
+    let notice = match point {
+        ReplayPoint::Entries(entry_cnt) => fill(messages.replayed_entries, &[&entry_cnt]),
+        ReplayPoint::Time(timestamp) => {
+            let time = DateTime::from_timestamp(timestamp as i64, 0)
+                .map(|d| d.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string())
+                .unwrap_or_default();
+
+            fill(messages.replayed_time, &[&time])
+        }
+    };
+
+    eprintln!("{}", style::header(notice));
+
+    Ok(bank)
+}
+
+/// Opens a profile's bank by replaying its journal, or only up to a point in it.
+///
+/// The profile's webhooks are subscribed to the bank, unless it is replayed up to a point.
+fn open_bank(profile: &Profile, passphrase: Option<&str>, replay: Option<ReplayPoint>) -> Bank {
+    profile.create_dir().expect("Failed to create the profile directory...");
+
+    let config = Config::load(profile.config_path()).expect("Failed to read the configuration file...");
+    let mut bank = replay_bank(open_journal(&profile.journal_path(), passphrase), replay)
+        .expect("Failed to replay the journal file...");
+
+    bank.configure(&config);
+    // Conversions at out-of-date rates are confirmed by the user instead.
+    bank.allow_stale_rates(true);
+
+    if replay.is_none() {
+        subscribe_webhooks(&mut bank, profile, config.webhooks);
+    }
+
+    bank
+}
+
+/// Populates a profile's empty bank with the demo data generated from a seed, exiting if it fails.
+///
+/// The notice of the populated bank is left out of JSON results.
+fn populate_demo(profile: &Profile, passphrase: Option<&str>, seed: u64, format: Format) {
+    let messages = messages();
+    let mut bank = open_bank(profile, passphrase, None);
+
+    if !bank.accounts().is_empty() {
+        eprint_error(messages.demo_needs_empty_bank);
+
+        process::exit(1);
+    }
+
+    if let Err(err) = demo::populate(&mut bank, seed) {
+        eprint_error(err);
+
+        process::exit(1);
+    }
+
+    if format == Format::Text {
+        outln!("{}", fill(messages.populated_demo, &[&seed, &demo::DEMO_PIN]));
+    }
+}
+
+/// Switches to another profile, opening its bank in place of the current one.
+///
+/// The user is prompted to input the name of an existing profile or a new one to create. Nothing is returned if the
+/// user cancels the transaction.
+fn switch_profile(bank: &Mutex<Bank>, profile: &mut Profile) -> Option<()> {
+    let messages = messages();
+
+    match Profile::list() {
+        Ok(profiles) => {
+            outln!("{}", style::header(messages.profiles));
+            print_choices(&profiles);
+        }
+        Err(err) => print_error(fill(messages.failed_profile_list, &[&err])),
+    }
+
+    outln!();
+
+    let new_profile = prompt_validated(messages.profile_name, Vec::new(), |input| {
+        Profile::new(input).ok_or(messages.invalid_profile_name)
+    })?;
+
+    outln!();
+
+    let new_bank = open_bank(&new_profile, None, None);
+
+    // The old bank is dropped after it is swapped out, so that closing it doesn't hold up the other sessions.
+    drop(with_bank(bank, |bank| mem::replace(bank, new_bank)));
+    *profile = new_profile;
+
+    outln!("{}", fill(messages.switched_profile, &[profile]));
+
+    Some(())
+}
+
+/// Opens a profile's bank non-interactively, with the passphrase given beforehand, if any, whether conversions can use
+/// out-of-date exchange rates, and the point in its journal to replay it up to, if any.
+///
+/// Due scheduled transactions are run, unless the bank is replayed up to a point.
+fn open_bank_with(
+    profile: &Profile,
+    passphrase: Option<&str>,
+    allow_stale_rates: bool,
+    replay: Option<ReplayPoint>,
+) -> Result<Bank, Box<dyn Error>> {
+    profile.create_dir()?;
+
+    let config = Config::load(profile.config_path())?;
+    let mut bank = replay_bank(open_journal_with(&profile.journal_path(), passphrase)?, replay)?;
+
+    bank.configure(&config);
+    bank.allow_stale_rates(allow_stale_rates);
+
+    if replay.is_none() {
+        subscribe_webhooks(&mut bank, profile, config.webhooks);
+        bank.run_schedules(bank.now())?;
+    }
+
+    Ok(bank)
+}
+
+/// Subscribes a profile's webhooks to its bank's events, if it has any.
+#[cfg(feature = "webhooks")]
+fn subscribe_webhooks(bank: &mut Bank, profile: &Profile, urls: Vec<String>) {
+    if !urls.is_empty() {
+        bank.subscribe(Webhooks::new(urls, profile.delivery_log_path()));
+    }
+}
+
+/// Warns that a profile's webhooks are ignored, since the app was built without them.
+#[cfg(not(feature = "webhooks"))]
+fn subscribe_webhooks(_: &mut Bank, _: &Profile, urls: Vec<String>) {
+    if !urls.is_empty() {
+        tracing::warn!("ignoring the configured webhooks, since the app was built without the webhooks feature");
+    }
+}
+
+/// Resolves an account's number to its name, leaving anything else as is.
+fn account_name(bank: &Bank, name_or_number: String) -> String {
+    bank.find_account(&name_or_number)
+        .map(|a| a.name.clone())
+        .unwrap_or(name_or_number)
+}
+
+/// Returns the currency of an account's balance.
+fn account_currency(bank: &Bank, name: &str) -> String {
+    bank.account(name).map(|a| a.currency.clone()).unwrap_or_default()
+}
+
+/// Stress-tests a scratch bank recording to a temporary journal, which is removed afterwards.
+fn run_stress(account_cnt: usize, transaction_cnt: usize, seed: u64) -> Result<Outcome, BankError> {
+    let path = env::temp_dir().join(format!("mcos-stress-{}.jsonl", process::id()));
+    let report = stress::run(&path, account_cnt, transaction_cnt, seed);
+
+    let _ = fs::remove_file(&path);
+
+    Ok(Outcome::Stressed { report: report? })
+}
+
+/// Prints the throughput of a stress test and whether the bank held up, in the error style if it didn't.
+fn print_stress_report(report: &StressReport) {
+    let messages = messages();
+
+    outln!(
+        "{}",
+        fill(
+            messages.stress_transactions,
+            &[
+                &report.transaction_cnt,
+                &report.account_cnt,
+                &format!("{:.3}", report.transaction_secs),
+                &format!("{:.0}", report.transactions_per_sec),
+                &report.rejected_cnt,
+            ]
+        )
+    );
+    outln!(
+        "{}",
+        fill(
+            messages.stress_timings,
+            &[
+                &format!("{:.3}", report.register_secs),
+                &format!("{:.3}", report.replay_secs)
+            ]
+        )
+    );
+
+    if !report.is_replay_consistent {
+        outln!("{}", style::error(messages.inconsistent_replay));
+    }
+
+    if report.violations.is_empty() {
+        outln!("{}", messages.no_violations);
+    }
+
+    for violation in &report.violations {
+        outln!("{}", style::error(violation));
+    }
+}
+
+/// Performs a transaction non-interactively, printing its results in a format.
+fn run_command(
+    command: Command,
+    idempotency_key: Option<String>,
+    format: Format,
+    profile: &Profile,
+    passphrase: Option<&str>,
+    allow_stale_rates: bool,
+    replay: Option<ReplayPoint>,
+) -> Result<(), Box<dyn Error>> {
+    let outcome = match command {
+        Command::Profiles => Outcome::Profiles {
+            profiles: Profile::list()?.into_iter().map(|p| p.name).collect(),
+        },
+        Command::Stress {
+            accounts,
+            transactions,
+            seed,
+        } => run_stress(accounts, transactions, seed)?,
+        #[cfg(feature = "serve")]
+        Command::Serve { addr } => {
+            let bank = open_bank_with(profile, passphrase, allow_stale_rates, replay)?;
+
+            return server::run(bank, profile.clone(), addr).map_err(Box::from);
+        }
+        #[cfg(feature = "grpc")]
+        Command::Grpc { addr } => {
+            let bank = open_bank_with(profile, passphrase, allow_stale_rates, replay)?;
+
+            return grpc::run(bank, profile.clone(), addr).map_err(Box::from);
+        }
+        Command::Completions { shell } => {
+            clap_complete::generate(shell, &mut Cli::command(), "mcos", &mut io::stdout());
+
+            return Ok(());
+        }
+        _ => {
+            let mut bank = open_bank_with(profile, passphrase, allow_stale_rates, replay)?;
+
+            execute_once(command, idempotency_key, &mut bank, profile)?.print(format);
+            notify_alerts(bank.take_notifications(), format);
+
+            return Ok(());
+        }
+    };
+
+    outcome.print(format);
+
+    Ok(())
+}
+
+/// Performs the transactions in a script file, one command per line, printing their results in a format.
+///
+/// Blank lines and lines starting with `#` are skipped. The script stops at the first failed command, unless it is
+/// told to keep going, in which case every failure is reported at the end. Commands are only echoed in the text
+/// format, so that the JSON format prints exactly one result per line.
+fn run_script(
+    path: &Path,
+    keep_going: bool,
+    format: Format,
+    profile: &Profile,
+    passphrase: Option<&str>,
+    allow_stale_rates: bool,
+    replay: Option<ReplayPoint>,
+) -> Result<(), Box<dyn Error>> {
+    let messages = messages();
+
+    let script = fs::read_to_string(path)?;
+    let mut bank = open_bank_with(profile, passphrase, allow_stale_rates, replay)?;
+    let mut failure_cnt = 0;
+
+    for (i, line) in script.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if format == Format::Text {
+            outln!("> {line}");
+        }
+
+        let result = shlex::split(line)
+            .ok_or_else(|| Box::<dyn Error>::from(messages.unterminated_quotes))
+            .and_then(|args| Ok(ScriptLine::try_parse_from(args)?))
+            .and_then(|script_line| execute_once(script_line.command, script_line.idempotency_key, &mut bank, profile));
+
+        match result {
+            Ok(outcome) => {
+                outcome.print(format);
+                notify_alerts(bank.take_notifications(), format);
+            }
+            Err(err) => {
+                eprint_error(fill(
+                    messages.script_line_error,
+                    &[&(i + 1), &i18n::describe(&err).trim_end()],
+                ));
+
+                if !keep_going {
+                    return Err(fill(messages.script_stopped, &[&(i + 1)]).into());
+                }
+
+                failure_cnt += 1;
+            }
+        }
+
+        if format == Format::Text {
+            outln!();
+        }
+    }
+
+    if failure_cnt > 0 {
+        return Err(fill(messages.script_failures, &[&failure_cnt]).into());
+    }
+
+    Ok(())
+}
+
+/// Performs a transaction against an opened bank unless one with the same idempotency key already was, returning its
+/// results.
+fn execute_once(
+    command: Command,
+    idempotency_key: Option<String>,
+    bank: &mut Bank,
+    profile: &Profile,
+) -> Result<Outcome, Box<dyn Error>> {
+    let Some(key) = idempotency_key else {
+        return execute(command, bank, profile);
+    };
+
+    if let Some(reference) = bank.processed_key(&key) {
+        return Ok(Outcome::Skipped {
+            reference: reference.to_string(),
+            key,
+        });
+    }
+
+    bank.set_idempotency_key(Some(key));
+
+    let result = execute(command, bank, profile);
+
+    bank.set_idempotency_key(None);
+
+    result
+}
+
+/// Performs a transaction against an opened bank, returning its results.
+fn execute(command: Command, bank: &mut Bank, profile: &Profile) -> Result<Outcome, Box<dyn Error>> {
+    let messages = messages();
+
+    Ok(match command {
+        Command::Register {
+            account,
+            joint_owners,
+            account_type,
+            interest_rate,
+            email,
+            phone,
+            pin,
+        } => {
+            bank.register(
+                account.clone(),
+                joint_owners,
+                account_type,
+                interest_rate.map(|rate| rate / Decimal::ONE_HUNDRED),
+                Contact { email, phone },
+                &pin,
+            )?;
+
+            let registered_account = bank.account(&account).ok_or(BankError::AccountNotFound)?;
+
+            Outcome::Registered {
+                number: registered_account.number.clone(),
+                owners: registered_account.owners.clone(),
+                account,
+                account_type,
+            }
+        }
+        Command::Deposit {
+            account,
+            pin,
+            amount,
+            currency,
+            memo,
+        } => {
+            let account = account_name(bank, account);
+
+            bank.verify_pin(&account, pin.as_deref().unwrap_or_default())?;
+
+            let receipt = bank.deposit(&account, &currency.to_uppercase(), amount, memo)?;
+
+            Outcome::Deposited {
+                reference: receipt.reference,
+                balance: receipt.balance,
+                currency: account_currency(bank, &account),
+                account,
+            }
+        }
+        Command::Withdraw {
+            account,
+            pin,
+            amount,
+            currency,
+            memo,
+        } => {
+            let account = account_name(bank, account);
+
+            bank.verify_pin(&account, pin.as_deref().unwrap_or_default())?;
+
+            let transaction = HeldTransaction::Withdrawal {
+                account: account.clone(),
+                amount,
+                currency: currency.to_uppercase(),
+            };
+
+            if bank.requires_approval(&transaction)? {
+                return Ok(Outcome::Held {
+                    id: bank.hold(transaction, memo)?,
+                    account,
+                });
+            }
+
+            let receipt = bank.withdraw(&account, &currency.to_uppercase(), amount, memo)?;
+
+            Outcome::Withdrawn {
+                reference: receipt.reference,
+                balance: receipt.balance,
+                charges: receipt.charges,
+                currency: account_currency(bank, &account),
+                account,
+            }
+        }
+        Command::Transfer {
+            account,
+            pin,
+            to,
+            amount,
+            memo,
+        } => {
+            let account = account_name(bank, account);
+            let recipient = account_name(bank, to);
+
+            bank.verify_pin(&account, pin.as_deref().unwrap_or_default())?;
+
+            let transaction = HeldTransaction::Transfer {
+                account: account.clone(),
+                recipient: recipient.clone(),
+                amount,
+            };
+
+            if bank.requires_approval(&transaction)? {
+                return Ok(Outcome::Held {
+                    id: bank.hold(transaction, memo)?,
+                    account,
+                });
+            }
+
+            let receipt = bank.transfer(&account, &recipient, amount, memo)?;
+
+            Outcome::Transferred {
+                reference: receipt.reference,
+                balance: receipt.balance,
+                charges: receipt.charges,
+                currency: account_currency(bank, &account),
+                account,
+                recipient,
+                amount,
+            }
+        }
+        Command::Pending => Outcome::Pending { pending: pending(bank) },
+        Command::Approve { id } => {
+            let receipt = bank.approve(id)?;
+            let account = bank
+                .pending()
+                .iter()
+                .find(|p| p.id == id)
+                .map(|p| p.transaction.account().to_string())
+                .unwrap_or_default();
+
+            Outcome::Approved {
+                id,
+                reference: receipt.reference,
+                balance: receipt.balance,
+                charges: receipt.charges,
+                currency: account_currency(bank, &account),
+                account,
+            }
+        }
+        Command::Reject { id } => {
+            bank.reject(id)?;
+
+            Outcome::Rejected { id }
+        }
+        Command::RecurringDeposit {
+            account,
+            pin,
+            amount,
+            currency,
+            every,
+            memo,
+        } => {
+            let account = account_name(bank, account);
+
+            bank.verify_pin(&account, pin.as_deref().unwrap_or_default())?;
+
+            let transaction = ScheduledTransaction::Deposit {
+                account,
+                amount,
+                currency: currency.to_uppercase(),
+            };
+            let id = bank.schedule(transaction, every, memo)?;
+
+            Outcome::Scheduled {
+                id,
+                next_run_at: bank.schedules()[id as usize - 1].next_run_at,
+            }
+        }
+        Command::StandingOrder {
+            account,
+            pin,
+            to,
+            amount,
+            every,
+            memo,
+        } => {
+            let account = account_name(bank, account);
+            let recipient = account_name(bank, to);
+
+            bank.verify_pin(&account, pin.as_deref().unwrap_or_default())?;
+
+            let transaction = ScheduledTransaction::Transfer {
+                account,
+                recipient,
+                amount,
+            };
+            let id = bank.schedule(transaction, every, memo)?;
+
+            Outcome::Scheduled {
+                id,
+                next_run_at: bank.schedules()[id as usize - 1].next_run_at,
+            }
+        }
+        Command::Schedules => Outcome::Schedules {
+            schedules: schedules(bank),
+        },
+        Command::CancelSchedule { id } => {
+            bank.cancel_schedule(id)?;
+
+            Outcome::ScheduleCancelled { id }
+        }
+        Command::Advance { days } => Outcome::SchedulesRun {
+            runs: bank
+                .run_schedules(bank.now() + days * clock::SECONDS_PER_DAY)?
+                .into_iter()
+                .map(RunInfo::from)
+                .collect(),
+        },
+        Command::BulkDeposit { file, dry_run } => Outcome::BulkDeposited {
+            rows: bulk_rows(bank, &fs::read_to_string(file)?, dry_run),
+            is_dry_run: dry_run,
+        },
+        Command::Close {
+            account,
+            pin,
+            withdraw_remainder,
+        } => {
+            let account = account_name(bank, account);
+
+            bank.verify_pin(&account, pin.as_deref().unwrap_or_default())?;
+
+            Outcome::Closed {
+                withdrawn_amount: bank.close(&account, withdraw_remainder)?,
+                currency: account_currency(bank, &account),
+                account,
+            }
+        }
+        Command::Freeze { account } => {
+            let account = account_name(bank, account);
+
+            bank.set_frozen(&account, true)?;
+
+            Outcome::FreezeUpdated {
+                account,
+                is_frozen: true,
+            }
+        }
+        Command::Unfreeze { account } => {
+            let account = account_name(bank, account);
+
+            bank.set_frozen(&account, false)?;
+
+            Outcome::FreezeUpdated {
+                account,
+                is_frozen: false,
+            }
+        }
+        Command::Overdraft { account, limit } => {
+            let account = account_name(bank, account);
+
+            bank.set_overdraft_limit(&account, limit)?;
+
+            Outcome::OverdraftSet {
+                currency: account_currency(bank, &account),
+                account,
+                limit,
+            }
+        }
+        Command::InterestRate { account, percent } => {
+            let account = account_name(bank, account);
+
+            bank.set_interest_rate(&account, percent / Decimal::ONE_HUNDRED)?;
+
+            Outcome::InterestRateSet {
+                account,
+                rate: percent / Decimal::ONE_HUNDRED,
+            }
+        }
+        Command::Compounding { account, frequency } => {
+            let account = account_name(bank, account);
+
+            bank.set_compounding(&account, frequency)?;
+
+            Outcome::CompoundingSet {
+                account,
+                compounding: frequency,
+            }
+        }
+        Command::MinimumBalance {
+            account_type,
+            amount,
+            breach_fee,
+            monthly_fee,
+        } => {
+            let minimum = MinimumBalance {
+                amount,
+                breach_fee,
+                monthly_fee,
+            };
+
+            bank.set_minimum_balance(account_type, minimum)?;
+
+            Outcome::MinimumBalanceSet { account_type, minimum }
+        }
+        Command::WithdrawalLimit { account, limit } => {
+            let account = account_name(bank, account);
+
+            bank.set_withdrawal_limit(&account, limit)?;
+
+            Outcome::WithdrawalLimitSet {
+                currency: account_currency(bank, &account),
+                account,
+                limit,
+            }
+        }
+        Command::EndPeriod => Outcome::PeriodEnded {
+            charges: end_period(bank)?,
+        },
+        Command::Revenue => Outcome::Revenue {
+            revenue: by_currency(bank.revenue()),
+            spread_income: by_currency(bank.spread_income()),
+        },
+        Command::Owners { account, pin, owners } => {
+            let account = account_name(bank, account);
+
+            bank.verify_pin(&account, pin.as_deref().unwrap_or_default())?;
+            bank.set_owners(&account, owners)?;
+
+            Outcome::OwnersUpdated {
+                owners: bank.account(&account).map(|a| a.owners.clone()).unwrap_or_default(),
+                account,
+            }
+        }
+        Command::Rename { account, pin, new_name } => {
+            let account = account_name(bank, account);
+
+            bank.verify_pin(&account, pin.as_deref().unwrap_or_default())?;
+            bank.rename(&account, new_name.clone())?;
+
+            Outcome::Renamed { account, new_name }
+        }
+        Command::Details { account, pin } => {
+            let account = bank.find_account(&account).ok_or(BankError::AccountNotFound)?;
+
+            bank.verify_pin(&account.name, pin.as_deref().unwrap_or_default())?;
+
+            Outcome::Details {
+                account: AccountInfo::from(account),
+            }
+        }
+        Command::History {
+            account,
+            pin,
+            limit,
+            search,
+        } => {
+            let account = bank.find_account(&account).ok_or(BankError::AccountNotFound)?;
+
+            bank.verify_pin(&account.name, pin.as_deref().unwrap_or_default())?;
+
+            Outcome::History {
+                account: account.name.clone(),
+                currency: account.currency.clone(),
+                records: account
+                    .history
+                    .iter()
+                    .rev()
+                    .filter(|r| search.as_deref().is_none_or(|q| r.matches(q)))
+                    .take(limit.unwrap_or(usize::MAX))
+                    .cloned()
+                    .collect(),
+            }
+        }
+        Command::Accounts {
+            sort,
+            min_balance,
+            account_type,
+        } => Outcome::Accounts {
+            accounts: bank
+                .list_accounts(
+                    &AccountFilter {
+                        min_balance,
+                        account_type,
+                    },
+                    sort,
+                )
+                .into_iter()
+                .map(AccountInfo::from)
+                .collect(),
+        },
+        Command::Exchange {
+            from,
+            to,
+            amount,
+            dry_run,
+        } => {
+            let quote = bank.quote_exchange(&from, &to, amount)?;
+
+            if dry_run {
+                Outcome::ExchangeQuoted { quote }
+            } else {
+                bank.exchange(&from, &to, amount)?;
+
+                Outcome::Exchanged { quote }
+            }
+        }
+        Command::Convert {
+            from,
+            to,
+            amount,
+            as_of,
+        } => Outcome::Converted {
+            exchange_amount: match as_of {
+                Some(date) => bank.convert_at(&from, &to, amount, end_of_day(date))?,
+                None => bank.convert(&from, &to, amount)?,
+            },
+            amount,
+            currency: from,
+            exchange_currency: to,
+            as_of: as_of.map(|d| d.to_string()),
+        },
+        Command::SetRate { currency, rate } => {
+            let change = bank.rate_change_warning(&currency, rate);
+
+            bank.set_exchange_rate(&currency, rate)?;
+
+            Outcome::RateRecorded { currency, rate, change }
+        }
+        Command::SetSpread { currency, percent } => {
+            bank.set_spread(&currency, percent)?;
+
+            Outcome::SpreadSet {
+                currency,
+                spread: percent,
+            }
+        }
+        Command::Rates => Outcome::Rates {
+            rates: current_rates(bank),
+        },
+        Command::RateHistory { currency, days } => {
+            if !bank.currency_codes().contains(&currency) {
+                return Err(BankError::UnknownCurrency.into());
+            }
+
+            let rates = recent_rates(bank, &currency, days);
+
+            Outcome::RateHistory {
+                stats: RateStats::of(&rates),
+                currency,
+                days,
+                rates,
+            }
+        }
+        Command::Alerts => Outcome::Alerts {
+            alerts: bank.alerts().to_vec(),
+        },
+        Command::AddAlert {
+            currency,
+            at_least,
+            at_most,
+        } => {
+            let (condition, threshold) = match (at_least, at_most) {
+                (Some(threshold), _) => (AlertCondition::AtLeast, threshold),
+                (None, threshold) => (AlertCondition::AtMost, threshold.unwrap_or_default()),
+            };
+
+            Outcome::AlertAdded {
+                id: bank.add_alert(&currency, condition, threshold)?,
+            }
+        }
+        Command::RemoveAlert { id } => {
+            bank.remove_alert(id)?;
+
+            Outcome::AlertRemoved { id }
+        }
+        Command::ExportRates { file } => {
+            let rates = foreign_rates(bank);
+
+            fs::write(&file, rates::to_csv(&rates))?;
+
+            Outcome::RatesExported {
+                path: file.display().to_string(),
+                count: rates.len(),
+            }
+        }
+        Command::ImportRates { file } => Outcome::RatesRefreshed {
+            rates: bank
+                .refresh_rates(&mut CsvRates {
+                    csv: fs::read_to_string(file)?,
+                })?
+                .into_iter()
+                .map(|(currency, rate)| RateInfo { currency, rate })
+                .collect(),
+        },
+        #[cfg(feature = "live-rates")]
+        Command::RefreshRates { url } => Outcome::RatesRefreshed {
+            rates: bank
+                .refresh_rates(&mut EcbRates { url })?
+                .into_iter()
+                .map(|(currency, rate)| RateInfo { currency, rate })
+                .collect(),
+        },
+        Command::AddCurrency { code, name, rate } => {
+            bank.add_currency(&code, name.as_deref().unwrap_or_default(), rate)?;
+
+            let currency = bank.currencies().last().ok_or(BankError::UnknownCurrency)?;
+
+            Outcome::CurrencyAdded {
+                code: currency.code.clone(),
+                name: currency.name.clone(),
+                rate,
+            }
+        }
+        Command::Undo => {
+            let transaction = bank.undo()?;
+
+            Outcome::Undone {
+                currency: undone_currency(bank, &transaction),
+                transaction,
+            }
+        }
+        Command::Reverse { reference } => Outcome::Reversed {
+            reversal: bank.reverse_transaction(&reference)?,
+            reference: reference.trim().to_uppercase(),
+        },
+        Command::Yield { apr, apy, compounding } => {
+            let (apr, apy) = match (apr, apy) {
+                (Some(apr), _) => (
+                    apr,
+                    compounding.effective_rate(apr / Decimal::ONE_HUNDRED) * Decimal::ONE_HUNDRED,
+                ),
+                (None, apy) => {
+                    let apy = apy.unwrap_or_default();
+
+                    (
+                        compounding.nominal_rate(apy / Decimal::ONE_HUNDRED) * Decimal::ONE_HUNDRED,
+                        apy,
+                    )
+                }
+            };
+
+            Outcome::Yield { compounding, apr, apy }
+        }
+        Command::PostInterest { account, days } => {
+            let account = account_name(bank, account);
+            let posting = bank.post_interest(&account, days)?;
+
+            Outcome::InterestPosted {
+                currency: account_currency(bank, &account),
+                account,
+                posting,
+            }
+        }
+        Command::Interest {
+            account,
+            pin,
+            days,
+            mode,
+            compounding,
+            deposit,
+            every,
+            csv,
+        } => {
+            let account = bank.open_account(&account_name(bank, account))?;
+
+            bank.verify_pin(&account.name, pin.as_deref().unwrap_or_default())?;
+
+            let terms = bank.interest_terms(account);
+            let terms = InterestTerms {
+                mode: mode.unwrap_or(terms.mode),
+                compounding: compounding.unwrap_or(terms.compounding),
+                ..terms
+            };
+            let contribution = deposit
+                .zip(every)
+                .map(|(amount, interval_days)| Contribution { amount, interval_days });
+            let schedule = interest::schedule(
+                account.balance.amount(),
+                &terms,
+                clock::date(bank.now()),
+                days,
+                contribution,
+            );
+
+            if let Some(path) = &csv {
+                fs::write(path, interest::to_csv(&schedule))?;
+            }
+
+            Outcome::Interest {
+                account: account.name.clone(),
+                owners: account.owners.clone(),
+                balance: account.balance.amount(),
+                currency: account.currency.clone(),
+                account_type: account.account_type,
+                interest_rate: account.interest_rate,
+                mode: terms.mode,
+                compounding: terms.compounding,
+                effective_rate: terms.effective_rate(),
+                tiers: terms.tiers.to_vec(),
+                day_count: terms.day_count,
+                contribution,
+                schedule,
+                exported_to: csv.map(|path| path.display().to_string()),
+            }
+        }
+        Command::Backup => Outcome::BackedUp {
+            backup: bank.backup(profile.backup_dir())?.into(),
+        },
+        Command::Backups => Outcome::Backups {
+            backups: Backup::list(profile.backup_dir())?
+                .into_iter()
+                .map(BackupInfo::from)
+                .collect(),
+        },
+        Command::Restore { backup } => {
+            let backups = Backup::list(profile.backup_dir())?;
+            let backup = backup
+                .checked_sub(1)
+                .and_then(|idx| backups.get(idx))
+                .ok_or(messages.unknown_backup)?;
+
+            bank.restore(backup)?;
+
+            Outcome::Restored {
+                backup: backup.clone().into(),
+            }
+        }
+        Command::Verify => Outcome::Verified {
+            violations: bank.verify(),
+        },
+        Command::Profiles => Outcome::Profiles {
+            profiles: Profile::list()?.into_iter().map(|p| p.name).collect(),
+        },
+        Command::Stress {
+            accounts,
+            transactions,
+            seed,
+        } => run_stress(accounts, transactions, seed)?,
+        #[cfg(feature = "serve")]
+        Command::Serve { .. } => return Err(messages.serve_in_script.into()),
+        #[cfg(feature = "grpc")]
+        Command::Grpc { .. } => return Err(messages.grpc_in_script.into()),
+        Command::Telnet { .. } => return Err(messages.telnet_in_script.into()),
+        Command::Completions { .. } => return Err(messages.completions_in_script.into()),
+    })
+}
+
+/// Performs a transaction chosen from the interactive menu.
+///
+/// Nothing is returned if the user cancels the transaction.
+fn perform_transaction(
+    kind: TransactionKind,
+    bank: &Mutex<Bank>,
+    profile: &mut Profile,
+    large_withdrawal: Decimal,
+) -> Option<()> {
+    let messages = messages();
+
+    match kind {
+        TransactionKind::Register => {
+            let name = prompt_cancellable(messages.account_name, Vec::new())?;
+
+            outln!();
+
+            let joint_owners = parse_owners(&prompt_cancellable(messages.joint_owners, Vec::new())?);
+
+            outln!();
+
+            let account_type = prompt_account_type()?;
+            let interest_rate = prompt_interest_rate(account_type.interest_rate())?;
+
+            outln!();
+
+            let contact = prompt_contact()?;
+
+            outln!();
+
+            let pin = prompt_private_validated(messages.new_pin, |input| {
+                if account::is_valid_pin(input) {
+                    Ok(input.to_string())
+                } else {
+                    Err(messages.invalid_pin)
+                }
+            })?;
+
+            match with_bank(bank, |bank| {
+                bank.register(
+                    name.clone(),
+                    joint_owners,
+                    account_type,
+                    Some(interest_rate),
+                    contact,
+                    &pin,
+                )
+                .map(|()| bank.account(&name).cloned())
+            }) {
+                Ok(account) => {
+                    if let Some(account) = account {
+                        outln!("{}", fill(messages.account_number, &[&style::code(&account.number)]));
+                        outln!("{}", fill(messages.owners_label, &[&account.owners.join(", ")]));
+                    }
+                }
+                Err(err) => print_error(err),
+            }
+        }
+        TransactionKind::Deposit | TransactionKind::Withdraw => {
+            let name = prompt_account_name(bank)?;
+
+            if let Err(err) = with_bank(bank, |bank| bank.active_account(&name).map(|_| ())) {
+                print_error(err);
+            } else if kind == TransactionKind::Deposit {
+                verify_pin(bank, &name)?;
+                deposit_balance(bank, &name)?;
+            } else {
+                verify_pin(bank, &name)?;
+                withdraw_balance(bank, &name, large_withdrawal)?;
+            }
+        }
+        TransactionKind::Transfer => {
+            let name = prompt_account_name(bank)?;
+
+            if let Err(err) = with_bank(bank, |bank| bank.active_account(&name).map(|_| ())) {
+                print_error(err);
+            } else {
+                verify_pin(bank, &name)?;
+                transfer_balance(bank, &name)?;
+            }
+        }
+        TransactionKind::RecurringDeposit => {
+            let name = prompt_account_name(bank)?;
+
+            if let Err(err) = with_bank(bank, |bank| bank.active_account(&name).map(|_| ())) {
+                print_error(err);
+            } else {
+                verify_pin(bank, &name)?;
+                schedule_deposit(bank, &name)?;
+            }
+        }
+        TransactionKind::StandingOrder => {
+            let name = prompt_account_name(bank)?;
+
+            if let Err(err) = with_bank(bank, |bank| bank.active_account(&name).map(|_| ())) {
+                print_error(err);
+            } else {
+                verify_pin(bank, &name)?;
+                schedule_transfer(bank, &name)?;
+            }
+        }
+        TransactionKind::Schedules => manage_schedules(bank)?,
+        TransactionKind::BulkDeposit => bulk_deposit(bank)?,
+        TransactionKind::Approvals => manage_pending(bank)?,
+        TransactionKind::Exchange => 'currency_exchange: loop {
+            exchange_currencies(bank)?;
+
+            outln!();
+
+            'repeat_prompt: loop {
+                let is_repeating = prompt_cancellable(messages.convert_another, Vec::new())?.to_uppercase();
+
+                if is_repeating == "Y" {
+                    outln!();
+
+                    break 'repeat_prompt;
+                } else if is_repeating == "N" {
+                    break 'currency_exchange;
+                } else {
+                    print_error(messages.invalid_yes_no);
+
+                    outln!();
+                }
+            }
+        },
+        TransactionKind::ShowRates => {
+            outln!();
+
+            print_current_rates(&with_bank(bank, |bank| current_rates(bank)));
+        }
+        TransactionKind::SetRate => {
+            outln!();
+
+            set_exchange_rate(bank)?;
+        }
+        TransactionKind::SetSpread => {
+            outln!();
+
+            set_spread(bank)?;
+        }
+        TransactionKind::RefreshRates => {
+            outln!();
+
+            refresh_rates(bank)?;
+        }
+        TransactionKind::RateHistory => {
+            outln!();
+
+            show_rate_history(bank)?;
+        }
+        TransactionKind::RateAlerts => manage_alerts(bank)?,
+        TransactionKind::ExportRates => {
+            outln!();
+
+            export_rates(bank)?;
+        }
+        TransactionKind::AddCurrency => {
+            outln!();
+
+            add_currency(bank)?;
+        }
+        TransactionKind::Undo => match with_bank(bank, |bank| {
+            bank.undo().map(|transaction| {
+                let currency = undone_currency(bank, &transaction);
+
+                (transaction, currency)
+            })
+        }) {
+            Ok((transaction, currency)) => print_undone(&transaction, &currency),
+            Err(err) => print_error(err),
+        },
+        TransactionKind::Reverse => {
+            let reference = prompt_cancellable(messages.reference_to_reverse, Vec::new())?;
+
+            match with_bank(bank, |bank| bank.reverse_transaction(&reference)) {
+                Ok(reversal) => outln!(
+                    "{}",
+                    fill(
+                        messages.reversed_transaction,
+                        &[&style::code(reference.trim().to_uppercase()), &style::code(reversal)]
+                    )
+                ),
+                Err(err) => print_error(err),
+            }
+        }
+        TransactionKind::Interest => {
+            let name = prompt_account_name(bank)?;
+
+            match with_bank(bank, |bank| {
+                bank.open_account(&name)
+                    .map(|a| (a.clone(), detached_terms(bank, a), clock::date(bank.now())))
+            }) {
+                Ok((account, (terms, tiers), start)) => {
+                    verify_pin(bank, &account.name)?;
+                    calculate_interest(&account, InterestTerms { tiers: &tiers, ..terms }, start)?;
+                }
+                Err(err) => print_error(err),
+            }
+        }
+        TransactionKind::Yield => {
+            outln!();
+
+            calculate_yield()?;
+        }
+        TransactionKind::PostInterest => {
+            let name = prompt_account_name(bank)?;
+
+            match with_bank(bank, |bank| bank.open_account(&name).map(|a| a.currency.clone())) {
+                Ok(currency) => {
+                    let day_cnt = prompt_validated(messages.posting_days, Vec::new(), |input| match input {
+                        "" => Ok(None),
+                        _ => input.parse::<u32>().map(Some).map_err(|_| messages.invalid_number),
+                    })?;
+
+                    outln!();
+
+                    match with_bank(bank, |bank| bank.post_interest(&name, day_cnt)) {
+                        Ok(posting) => print_interest_posting(&name, &posting, &currency),
+                        Err(err) => print_error(err),
+                    }
+                }
+                Err(err) => print_error(err),
+            }
+        }
+        TransactionKind::Close => {
+            let name = prompt_account_name(bank)?;
+
+            if let Err(err) = with_bank(bank, |bank| bank.open_account(&name).map(|_| ())) {
+                print_error(err);
+            } else {
+                verify_pin(bank, &name)?;
+                close_account(bank, &name)?;
+            }
+        }
+        TransactionKind::EditOwners => {
+            let name = prompt_account_name(bank)?;
+
+            if let Err(err) = with_bank(bank, |bank| bank.open_account(&name).map(|_| ())) {
+                print_error(err);
+            } else {
+                verify_pin(bank, &name)?;
+
+                let owners = with_bank(bank, |bank| bank.account(&name).map(|a| a.owners.clone()))?;
+
+                outln!("{}", fill(messages.owners_label, &[&owners.join(", ")]));
+
+                let owners = parse_owners(&prompt_cancellable(messages.new_owners, owners)?);
+
+                match with_bank(bank, |bank| {
+                    bank.set_owners(&name, owners)
+                        .map(|()| bank.account(&name).map(|a| a.owners.join(", ")))
+                }) {
+                    Ok(owners) => {
+                        let owners = owners?;
+
+                        outln!("{}", fill(messages.updated_owners, &[&name, &owners]));
+                    }
+                    Err(err) => print_error(err),
+                }
+            }
+        }
+        TransactionKind::Details => {
+            let name = prompt_account_name(bank)?;
+
+            match with_bank(bank, |bank| bank.account(&name).map(AccountInfo::from)) {
+                Some(account) => {
+                    verify_pin(bank, &name)?;
+
+                    outln!();
+
+                    print_account_details(&account);
+                }
+                None => print_error(messages.unknown_account),
+            }
+        }
+        TransactionKind::History => {
+            let name = prompt_account_name(bank)?;
+
+            match with_bank(bank, |bank| bank.account(&name).cloned()) {
+                Some(account) => {
+                    verify_pin(bank, &name)?;
+
+                    let search = prompt_cancellable(messages.history_search, Vec::new())?;
+
+                    outln!();
+
+                    print_history(
+                        &account
+                            .history
+                            .iter()
+                            .rev()
+                            .filter(|r| search.is_empty() || r.matches(&search))
+                            .cloned()
+                            .collect::<Vec<_>>(),
+                        &account.currency,
+                        Some(HISTORY_PAGE_SIZE),
+                    );
+                }
+                None => print_error(messages.unknown_account),
+            }
+        }
+        TransactionKind::ListAccounts => {
+            outln!();
+
+            list_accounts(bank)?;
+        }
+        TransactionKind::Freeze => {
+            let name = prompt_account_name(bank)?;
+
+            match with_bank(bank, |bank| {
+                let is_frozen = !bank.open_account(&name)?.is_frozen;
+
+                bank.set_frozen(&name, is_frozen).map(|()| is_frozen)
+            }) {
+                Ok(true) => outln!("{}", fill(messages.froze_account, &[&name])),
+                Ok(false) => outln!("{}", fill(messages.unfroze_account, &[&name])),
+                Err(err) => print_error(err),
+            }
+        }
+        TransactionKind::Overdraft => {
+            let name = prompt_account_name(bank)?;
+
+            match with_bank(bank, |bank| bank.open_account(&name).cloned()) {
+                Ok(account) => {
+                    let currency = account.currency.clone();
+
+                    outln!(
+                        "{}",
+                        fill(
+                            messages.overdraft_limit_label,
+                            &[&money(account.overdraft_limit, &currency)]
+                        )
+                    );
+
+                    let limit = prompt_amount(messages.overdraft_limit, messages.invalid_overdraft_limit)?;
+
+                    match with_bank(bank, |bank| bank.set_overdraft_limit(&name, limit)) {
+                        Ok(()) => outln!("{}", fill(messages.set_overdraft, &[&name, &money(limit, &currency)])),
+                        Err(err) => print_error(err),
+                    }
+                }
+                Err(err) => print_error(err),
+            }
+        }
+        TransactionKind::InterestRate => {
+            let name = prompt_account_name(bank)?;
+
+            match with_bank(bank, |bank| bank.open_account(&name).cloned()) {
+                Ok(account) => {
+                    outln!(
+                        "{}",
+                        fill(
+                            messages.interest_rate,
+                            &[&format_rate(account.interest_rate * Decimal::ONE_HUNDRED)]
+                        )
+                    );
+
+                    let rate = prompt_interest_rate(account.interest_rate)?;
+
+                    match with_bank(bank, |bank| bank.set_interest_rate(&name, rate)) {
+                        Ok(()) => outln!(
+                            "{}",
+                            fill(
+                                messages.set_interest_rate,
+                                &[&name, &format_rate(rate * Decimal::ONE_HUNDRED)]
+                            )
+                        ),
+                        Err(err) => print_error(err),
+                    }
+                }
+                Err(err) => print_error(err),
+            }
+        }
+        TransactionKind::Compounding => {
+            let name = prompt_account_name(bank)?;
+
+            match with_bank(bank, |bank| bank.open_account(&name).cloned()) {
+                Ok(account) => {
+                    outln!(
+                        "{}",
+                        fill(messages.compounding_label, &[&account.compounding.to_string()])
+                    );
+
+                    let compounding = prompt_compounding(account.compounding)?;
+
+                    match with_bank(bank, |bank| bank.set_compounding(&name, compounding)) {
+                        Ok(()) => outln!("{}", fill(messages.set_compounding, &[&name, &compounding.to_string()])),
+                        Err(err) => print_error(err),
+                    }
+                }
+                Err(err) => print_error(err),
+            }
+        }
+        TransactionKind::WithdrawalLimit => {
+            let name = prompt_account_name(bank)?;
+
+            match with_bank(bank, |bank| bank.open_account(&name).cloned()) {
+                Ok(account) => {
+                    let currency = account.currency.clone();
+
+                    if let Some(limit) = account.daily_withdrawal_limit {
+                        outln!("{}", fill(messages.withdrawal_limit_label, &[&money(limit, &currency)]));
+                    }
+
+                    let limit = prompt_validated(messages.withdrawal_limit, Vec::new(), |input| match input {
+                        "" => Ok(None),
+                        _ => input
+                            .parse::<Decimal>()
+                            .map(Some)
+                            .map_err(|_| messages.invalid_withdrawal_limit),
+                    })?;
+
+                    match with_bank(bank, |bank| bank.set_withdrawal_limit(&name, limit)) {
+                        Ok(()) => print_withdrawal_limit_set(&name, limit, &currency),
+                        Err(err) => print_error(err),
+                    }
+                }
+                Err(err) => print_error(err),
+            }
+        }
+        TransactionKind::MinimumBalance => {
+            let account_type = prompt_account_type()?;
+
+            outln!();
+
+            let amount = prompt_amount(messages.minimum_amount, messages.invalid_minimum_balance)?;
+            let breach_fee = prompt_validated(messages.breach_fee, Vec::new(), |input| match input {
+                "" => Ok(None),
+                _ => input
+                    .parse::<Decimal>()
+                    .map(Some)
+                    .map_err(|_| messages.invalid_minimum_balance),
+            })?;
+            let monthly_fee = prompt_amount(messages.monthly_fee, messages.invalid_minimum_balance)?;
+
+            outln!();
+
+            let minimum = MinimumBalance {
+                amount,
+                breach_fee,
+                monthly_fee,
+            };
+
+            match with_bank(bank, |bank| bank.set_minimum_balance(account_type, minimum)) {
+                Ok(()) => outln!(
+                    "{}",
+                    fill(
+                        messages.set_minimum_balance,
+                        &[
+                            &messages.account_types[account_type as usize],
+                            &money(amount, BASE_CURRENCY)
+                        ]
+                    )
+                ),
+                Err(err) => print_error(err),
+            }
+        }
+        TransactionKind::EndPeriod => match with_bank(bank, end_period) {
+            Ok(charges) => print_fee_charges(&charges),
+            Err(err) => print_error(err),
+        },
+        TransactionKind::Revenue => {
+            let (revenue, spread_income) = with_bank(bank, |bank| {
+                (by_currency(bank.revenue()), by_currency(bank.spread_income()))
+            });
+
+            print_revenue(&revenue, &spread_income);
+        }
+        TransactionKind::Rename => {
+            let name = prompt_account_name(bank)?;
+
+            if let Err(err) = with_bank(bank, |bank| bank.open_account(&name).map(|_| ())) {
+                print_error(err);
+            } else {
+                verify_pin(bank, &name)?;
+
+                let new_name = prompt_cancellable(messages.new_account_name, Vec::new())?;
+
+                match with_bank(bank, |bank| bank.rename(&name, new_name.clone())) {
+                    Ok(()) => outln!("{}", fill(messages.renamed_account, &[&name, &new_name])),
+                    Err(err) => print_error(err),
+                }
+            }
+        }
+        TransactionKind::Backup => match with_bank(bank, |bank| bank.backup(profile.backup_dir())) {
+            Ok(backup) => outln!("{}", fill(messages.saved_backup, &[&backup.path.display()])),
+            Err(err) => print_error(err),
+        },
+        TransactionKind::Restore => {
+            outln!();
+
+            restore_backup(bank, profile)?;
+        }
+        TransactionKind::SwitchProfile => {
+            outln!();
+
+            switch_profile(bank, profile)?;
+        }
+        TransactionKind::Help => {
+            outln!();
+
+            menu::print_help(&with_bank(bank, |bank| bank.currency_titles()), profile);
+        }
+    }
+
+    Some(())
+}
+
+/// Performs transactions chosen from the interactive menu until the user exits.
+fn run_menu(profile: Profile, passphrase: Option<&str>, replay: Option<ReplayPoint>, large_withdrawal: Decimal) {
+    let bank = Mutex::new(open_bank(&profile, passphrase, replay));
+
+    run_session(&bank, profile, large_withdrawal, false);
+}
+
+/// Performs transactions chosen from the interactive menu on a bank until the user exits.
+///
+/// A transaction is chosen by its ID, its alias, or a prefix of its alias. A cancelled transaction returns to the main
+/// menu immediately. The bank is only locked around each of its calls, never while the user is prompted, so that it can
+/// be shared with other sessions, in which case the profile can't be switched.
+fn run_session(bank: &Mutex<Bank>, mut profile: Profile, large_withdrawal: Decimal, is_shared: bool) {
+    let messages = messages();
+
+    'main_menu: loop {
+        run_due_schedules(bank);
+
+        outln!("{}", style::header(messages.select_transaction));
+        menu::print_transactions();
+
+        outln!();
+
+        let chosen = menu::find_transaction(&prompt_completed("> ", menu::aliases()));
+
+        outln!();
+
+        match chosen {
+            Ok(transaction) if is_shared && transaction.kind == TransactionKind::SwitchProfile => {
+                print_error(messages.shared_profile)
+            }
+            Ok(transaction) => {
+                outln!("{}", style::header(transaction.text().title));
+
+                if perform_transaction(transaction.kind, bank, &mut profile, large_withdrawal).is_none() {
+                    outln!();
+
+                    outln!("{}", messages.cancelled);
+
+                    outln!();
+
+                    continue 'main_menu;
+                }
+
+                notify_alerts(with_bank(bank, Bank::take_notifications), Format::Text);
+            }
+            Err(err) => print_error(err),
+        }
+
+        outln!();
+
+        'exit_prompt: loop {
+            let is_continuing = prompt(messages.back_to_menu).to_uppercase();
+
+            if is_continuing == "Y" {
+                outln!();
+
+                break 'exit_prompt;
+            } else if is_continuing == "N" {
+                break 'main_menu;
+            } else {
+                print_error(messages.invalid_yes_no);
+
+                outln!();
+            }
+        }
+    }
+}
+
+/// Logs to the log file, or the standard error, at the level chosen by the verbosity flags.
+///
+/// Only errors are logged to the standard error by default, while every transaction is logged to a log file by
+/// default. Each `-v` raises the level by one, and each `-q` lowers it by one.
+fn init_logging(cli: &Cli) {
+    const LEVELS: [LevelFilter; 6] = [
+        LevelFilter::OFF,
+        LevelFilter::ERROR,
+        LevelFilter::WARN,
+        LevelFilter::INFO,
+        LevelFilter::DEBUG,
+        LevelFilter::TRACE,
+    ];
+
+    let default_idx = if cli.log_file.is_some() { 3 } else { 1 };
+    let level_idx = (default_idx + usize::from(cli.verbose))
+        .saturating_sub(usize::from(cli.quiet))
+        .min(LEVELS.len() - 1);
+    let subscriber = tracing_subscriber::fmt().with_max_level(LEVELS[level_idx]);
+
+    if let Some(path) = &cli.log_file {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .expect("Failed to open the log file...");
+
+        subscriber.with_ansi(false).with_writer(Mutex::new(file)).init();
+    } else {
+        subscriber
+            .with_ansi(!cli.no_color && io::stderr().is_terminal())
+            .with_writer(io::stderr)
+            .init();
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    style::init(cli.no_color);
+    i18n::init(cli.lang);
+    output::init_locale(cli.locale);
+    prompt::set_retry_limit(cli.retry_limit);
+    prompt::set_pin_attempts(cli.pin_attempts);
+    init_logging(&cli);
+
+    if cli.command.is_some() && cli.script.is_some() {
+        Cli::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "a subcommand can't be used with '--script <SCRIPT>'",
+            )
+            .exit();
+    }
+
+    if cli.idempotency_key.is_some() && cli.script.is_some() {
+        Cli::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "'--idempotency-key <IDEMPOTENCY_KEY>' can only be used with a subcommand or on a script's lines",
+            )
+            .exit();
+    }
+
+    let passphrase = cli.passphrase.as_deref();
+
+    if let Some(seed) = cli.demo {
+        populate_demo(&cli.profile, passphrase, seed, cli.format);
+    }
+
+    let result = match (cli.command, cli.script) {
+        (Some(Command::Telnet { addr }), _) => open_bank_with(&cli.profile, passphrase, true, cli.replay)
+            .and_then(|bank| telnet::run(bank, cli.profile, addr, cli.large_withdrawal).map_err(Box::from)),
+        (Some(command), _) => run_command(
+            command,
+            cli.idempotency_key,
+            cli.format,
+            &cli.profile,
+            passphrase,
+            cli.allow_stale_rates,
+            cli.replay,
+        ),
+        (None, Some(script)) => run_script(
+            &script,
+            cli.keep_going,
+            cli.format,
+            &cli.profile,
+            passphrase,
+            cli.allow_stale_rates,
+            cli.replay,
+        ),
+        #[cfg(feature = "tui")]
+        (None, None) if cli.tui => tui::run(open_bank(&cli.profile, passphrase, cli.replay)).map_err(Box::from),
+        (None, None) => {
+            run_menu(cli.profile, passphrase, cli.replay, cli.large_withdrawal);
+
+            Ok(())
+        }
+    };
+
+    if let Err(err) = result {
+        eprint_error(err);
+
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+        sync::mpsc::{self, Receiver, Sender},
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::console::{self, Console, ScriptedConsole};
+
+    /// Creates a bank without a journal, with a savings account named Ana holding ₱1,000.00 and one United States
+    /// Dollar worth ₱50.00.
+    fn bank() -> Bank {
+        let mut bank = Bank::new();
+
+        bank.register(
+            String::from("Ana"),
+            Vec::new(),
+            AccountType::Savings,
+            None,
+            Contact::default(),
+            "1234",
+        )
+        .unwrap();
+        bank.deposit("Ana", BASE_CURRENCY, dec!(1000), None).unwrap();
+        bank.set_exchange_rate("USD", dec!(50)).unwrap();
+
+        bank
+    }
+
+    /// Answers the prompts with scripted responses, returning a handle to the output.
+    fn answer(responses: &[&str]) -> Rc<RefCell<String>> {
+        let (scripted, output) = ScriptedConsole::new(responses);
+
+        console::set_console(scripted);
+
+        output
+    }
+
+    /// A console relaying each prompt's message to the test and answering with the response the test sends back, so
+    /// that a session can be left waiting on a prompt.
+    struct RelayedConsole {
+        /// Sends the messages of the prompts.
+        prompted: Sender<String>,
+        /// Receives the responses to the prompts.
+        responses: Receiver<String>,
+    }
+    impl Console for RelayedConsole {
+        fn read_line(&mut self, msg: &str, _: bool, _: bool, _: Vec<String>) -> String {
+            self.prompted.send(msg.to_string()).unwrap();
+            self.responses.recv().unwrap()
+        }
+
+        fn write(&mut self, _: &str) {}
+    }
+
+    /// Returns the balance of Ana's account.
+    fn balance(bank: &Mutex<Bank>) -> Decimal {
+        bank.lock().unwrap().account("Ana").unwrap().balance.amount()
+    }
+
+    #[test]
+    fn deposit_adds_to_balance() {
+        let bank = Mutex::new(bank());
+        let output = answer(&["", "500", "Salary"]);
+
+        assert_eq!(deposit_balance(&bank, "Ana"), Some(()));
+        assert_eq!(balance(&bank), dec!(1500));
+        assert!(output.borrow().contains("Updated Balance: ₱1,500.00"));
+    }
+
+    #[test]
+    fn deposit_converts_foreign_currency() {
+        let bank = Mutex::new(bank());
+        let output = answer(&["USD", "10", ""]);
+
+        assert_eq!(deposit_balance(&bank, "Ana"), Some(()));
+        assert_eq!(balance(&bank), dec!(1500));
+        assert!(output.borrow().contains("Updated Balance: ₱1,500.00"));
+    }
+
+    #[test]
+    fn deposit_retries_invalid_amount() {
+        let bank = Mutex::new(bank());
+        let output = answer(&["", "lots", "250", ""]);
+
+        assert_eq!(deposit_balance(&bank, "Ana"), Some(()));
+        assert_eq!(balance(&bank), dec!(1250));
+        assert!(output.borrow().contains(messages().invalid_deposit_amount));
+    }
+
+    #[test]
+    fn deposit_can_be_cancelled() {
+        let bank = Mutex::new(bank());
+
+        answer(&["", "cancel"]);
+
+        assert_eq!(deposit_balance(&bank, "Ana"), None);
+        assert_eq!(balance(&bank), dec!(1000));
+    }
+
+    #[test]
+    fn withdraw_deducts_from_balance() {
+        let bank = Mutex::new(bank());
+        let output = answer(&["", "300", ""]);
+
+        assert_eq!(withdraw_balance(&bank, "Ana", dec!(10000)), Some(()));
+        assert_eq!(balance(&bank), dec!(700));
+        assert!(output.borrow().contains("Updated Balance: ₱700.00"));
+    }
+
+    #[test]
+    fn withdraw_rejects_more_than_balance() {
+        let bank = Mutex::new(bank());
+        let output = answer(&["", "5000", ""]);
+
+        assert_eq!(withdraw_balance(&bank, "Ana", dec!(10000)), Some(()));
+        assert_eq!(balance(&bank), dec!(1000));
+        assert!(output.borrow().contains(messages().insufficient_balance));
+    }
+
+    #[test]
+    fn large_withdrawal_must_be_confirmed() {
+        let bank = Mutex::new(bank());
+        let output = answer(&["", "900", "N"]);
+
+        assert_eq!(withdraw_balance(&bank, "Ana", dec!(500)), None);
+        assert_eq!(balance(&bank), dec!(1000));
+        assert!(output.borrow().contains("Resulting Balance: ₱100.00"));
+
+        answer(&["", "900", "Y", ""]);
+
+        assert_eq!(withdraw_balance(&bank, "Ana", dec!(500)), Some(()));
+        assert_eq!(balance(&bank), dec!(100));
+    }
+
+    #[test]
+    fn exchange_converts_at_rate() {
+        let bank = Mutex::new(bank());
+        let output = answer(&["1", "100", "2", "", "Y"]);
+
+        assert_eq!(exchange_currencies(&bank), Some(()));
+        assert!(output.borrow().contains("Exchange Amount: $2.00"));
+    }
+
+    #[test]
+    fn exchange_can_be_declined() {
+        let bank = Mutex::new(bank());
+        let output = answer(&["2", "3", "1", "", "N"]);
+
+        assert_eq!(exchange_currencies(&bank), None);
+        assert!(!output.borrow().contains("Exchange Amount:"));
+    }
+
+    #[test]
+    fn exchange_rejects_unknown_currency_id() {
+        let bank = Mutex::new(bank());
+        let output = answer(&["9", "2", "100", "1", "", "Y"]);
+
+        assert_eq!(exchange_currencies(&bank), Some(()));
+        assert!(output.borrow().contains(messages().unknown_currency_id));
+        assert!(output.borrow().contains("Exchange Amount: ₱5,000.00"));
+    }
+
+    #[test]
+    fn interest_schedule_ends_at_final_balance() {
+        let bank = bank();
+        let account = bank.account("Ana").unwrap();
+        let terms = bank.interest_terms(account);
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let expected = interest::schedule(dec!(1000), &terms, start, 10, None);
+        let output = answer(&["10", "", "", "", "N"]);
+
+        assert_eq!(calculate_interest(account, terms, start), Some(()));
+        assert!(output.borrow().contains(&fill(
+            messages().final_balance,
+            &[&money(expected.last().unwrap().balance, BASE_CURRENCY)]
+        )));
+    }
+
+    #[test]
+    fn interest_schedule_adds_planned_deposits() {
+        let bank = bank();
+        let account = bank.account("Ana").unwrap();
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let output = answer(&["10", "", "", "100", "5", "N"]);
+
+        assert_eq!(
+            calculate_interest(account, bank.interest_terms(account), start),
+            Some(())
+        );
+        assert!(output.borrow().contains("Total Deposits: ₱200.00"));
+    }
+
+    #[test]
+    fn session_waiting_on_prompt_doesnt_block_another() {
+        let bank = Mutex::new(bank());
+        let (prompted, prompts) = mpsc::channel();
+        let (respond, responses) = mpsc::channel();
+
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                console::set_console(RelayedConsole { prompted, responses });
+                run_session(&bank, Profile::new("default").unwrap(), dec!(10000), true);
+            });
+
+            // The first session is left waiting on the amount to deposit, after it has already read the account.
+            for response in ["deposit", "Ana", "1234", ""] {
+                prompts.recv().unwrap();
+                respond.send(response.to_string()).unwrap();
+            }
+
+            prompts.recv().unwrap();
+
+            let other = scope.spawn(|| {
+                answer(&["deposit", "Ana", "1234", "", "250", "", "N"]);
+                run_session(&bank, Profile::new("default").unwrap(), dec!(10000), true);
+            });
+            let deadline = Instant::now() + Duration::from_secs(10);
+
+            while !other.is_finished() {
+                assert!(
+                    Instant::now() < deadline,
+                    "The other session is blocked by the waiting one..."
+                );
+
+                thread::sleep(Duration::from_millis(10));
+            }
+
+            assert_eq!(balance(&bank), dec!(1250));
+
+            for response in ["500", "", "N"] {
+                respond.send(response.to_string()).unwrap();
+            }
+        });
+
+        assert_eq!(balance(&bank), dec!(1750));
+        assert!(!bank.is_poisoned());
+    }
+
+    /// Returns the command depositing an amount in Philippine Pesos to Ana's account, with a PIN.
+    fn deposit_command(amount: Decimal, pin: &str) -> Command {
+        Command::Deposit {
+            account: String::from("Ana"),
+            pin: Some(pin.to_string()),
+            amount,
+            currency: BASE_CURRENCY.to_string(),
+            memo: None,
+        }
+    }
+
+    #[test]
+    fn execute_once_skips_repeated_idempotency_key() {
+        let mut bank = bank();
+        let profile = Profile::new("default").unwrap();
+        let key = Some(String::from("retried"));
+
+        let Outcome::Deposited { reference, .. } =
+            execute_once(deposit_command(dec!(500), "1234"), key.clone(), &mut bank, &profile).unwrap()
+        else {
+            panic!("the deposit wasn't committed");
+        };
+        let retried = execute_once(deposit_command(dec!(500), "1234"), key, &mut bank, &profile).unwrap();
+
+        assert!(
+            matches!(retried, Outcome::Skipped { key, reference: skipped } if key == "retried" && skipped == reference)
+        );
+        assert_eq!(bank.account("Ana").unwrap().balance.amount(), dec!(1500));
+        assert_eq!(bank.processed_key("retried"), Some(reference.as_str()));
+    }
+
+    #[test]
+    fn execute_once_keeps_idempotency_key_of_failed_transaction() {
+        let mut bank = bank();
+        let profile = Profile::new("default").unwrap();
+        let key = Some(String::from("retried"));
+
+        assert!(execute_once(deposit_command(dec!(500), "0000"), key.clone(), &mut bank, &profile).is_err());
+        assert_eq!(bank.processed_key("retried"), None);
+
+        let outcome = execute_once(deposit_command(dec!(500), "1234"), key, &mut bank, &profile).unwrap();
+
+        assert!(matches!(outcome, Outcome::Deposited { .. }));
+        assert_eq!(bank.account("Ana").unwrap().balance.amount(), dec!(1500));
     }
 }
@@ -0,0 +1,334 @@
+//! The registry of the transactions available from the interactive menu.
+
+use banking_and_currency_app::{account::AccountType, currency::BASE_CURRENCY, profile::Profile};
+use rust_decimal::Decimal;
+
+use crate::{
+    i18n::{TransactionText, fill, messages},
+    prompt::{CANCEL_KEYWORDS, parse_choice, print_choices},
+    style,
+};
+
+/// The kinds of transactions available from the interactive menu, in the order they are listed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransactionKind {
+    Register,
+    Deposit,
+    Withdraw,
+    Transfer,
+    RecurringDeposit,
+    StandingOrder,
+    Schedules,
+    BulkDeposit,
+    Approvals,
+    Exchange,
+    ShowRates,
+    SetRate,
+    SetSpread,
+    RefreshRates,
+    RateHistory,
+    RateAlerts,
+    ExportRates,
+    AddCurrency,
+    Undo,
+    Reverse,
+    Interest,
+    PostInterest,
+    Yield,
+    Close,
+    Rename,
+    Freeze,
+    Overdraft,
+    WithdrawalLimit,
+    InterestRate,
+    Compounding,
+    MinimumBalance,
+    EndPeriod,
+    Revenue,
+    EditOwners,
+    ListAccounts,
+    Details,
+    History,
+    Backup,
+    Restore,
+    SwitchProfile,
+    Help,
+}
+
+/// A transaction available from the interactive menu.
+pub struct Transaction {
+    /// The kind of the transaction.
+    pub kind: TransactionKind,
+    /// The names the transaction can be chosen by in the menu, besides its ID.
+    pub aliases: &'static [&'static str],
+}
+impl Transaction {
+    /// Returns the text describing the transaction, in the chosen language.
+    pub fn text(&self) -> &'static TransactionText {
+        &messages().transactions[self.kind as usize]
+    }
+}
+
+/// The transactions available from the interactive menu, in the order they are listed.
+pub const TRANSACTIONS: [Transaction; 41] = [
+    Transaction {
+        kind: TransactionKind::Register,
+        aliases: &["register"],
+    },
+    Transaction {
+        kind: TransactionKind::Deposit,
+        aliases: &["deposit"],
+    },
+    Transaction {
+        kind: TransactionKind::Withdraw,
+        aliases: &["withdraw"],
+    },
+    Transaction {
+        kind: TransactionKind::Transfer,
+        aliases: &["transfer"],
+    },
+    Transaction {
+        kind: TransactionKind::RecurringDeposit,
+        aliases: &["recurring"],
+    },
+    Transaction {
+        kind: TransactionKind::StandingOrder,
+        aliases: &["standing-order"],
+    },
+    Transaction {
+        kind: TransactionKind::Schedules,
+        aliases: &["schedules"],
+    },
+    Transaction {
+        kind: TransactionKind::BulkDeposit,
+        aliases: &["bulk-deposit"],
+    },
+    Transaction {
+        kind: TransactionKind::Approvals,
+        aliases: &["approvals"],
+    },
+    Transaction {
+        kind: TransactionKind::Exchange,
+        aliases: &["exchange", "convert"],
+    },
+    Transaction {
+        kind: TransactionKind::ShowRates,
+        aliases: &["rates", "show-rates"],
+    },
+    Transaction {
+        kind: TransactionKind::SetRate,
+        aliases: &["rate", "set-rate"],
+    },
+    Transaction {
+        kind: TransactionKind::SetSpread,
+        aliases: &["spread", "set-spread"],
+    },
+    Transaction {
+        kind: TransactionKind::RefreshRates,
+        aliases: &["refresh-rates"],
+    },
+    Transaction {
+        kind: TransactionKind::RateHistory,
+        aliases: &["rate-history"],
+    },
+    Transaction {
+        kind: TransactionKind::RateAlerts,
+        aliases: &["alerts"],
+    },
+    Transaction {
+        kind: TransactionKind::ExportRates,
+        aliases: &["export-rates"],
+    },
+    Transaction {
+        kind: TransactionKind::AddCurrency,
+        aliases: &["add-currency"],
+    },
+    Transaction {
+        kind: TransactionKind::Undo,
+        aliases: &["undo"],
+    },
+    Transaction {
+        kind: TransactionKind::Reverse,
+        aliases: &["reverse"],
+    },
+    Transaction {
+        kind: TransactionKind::Interest,
+        aliases: &["interest"],
+    },
+    Transaction {
+        kind: TransactionKind::PostInterest,
+        aliases: &["post-interest"],
+    },
+    Transaction {
+        kind: TransactionKind::Yield,
+        aliases: &["yield", "apy"],
+    },
+    Transaction {
+        kind: TransactionKind::Close,
+        aliases: &["close"],
+    },
+    Transaction {
+        kind: TransactionKind::Rename,
+        aliases: &["rename"],
+    },
+    Transaction {
+        kind: TransactionKind::Freeze,
+        aliases: &["freeze", "unfreeze"],
+    },
+    Transaction {
+        kind: TransactionKind::Overdraft,
+        aliases: &["overdraft"],
+    },
+    Transaction {
+        kind: TransactionKind::WithdrawalLimit,
+        aliases: &["limit", "withdrawal-limit"],
+    },
+    Transaction {
+        kind: TransactionKind::InterestRate,
+        aliases: &["interest-rate"],
+    },
+    Transaction {
+        kind: TransactionKind::Compounding,
+        aliases: &["compounding"],
+    },
+    Transaction {
+        kind: TransactionKind::MinimumBalance,
+        aliases: &["minimum"],
+    },
+    Transaction {
+        kind: TransactionKind::EndPeriod,
+        aliases: &["end-period"],
+    },
+    Transaction {
+        kind: TransactionKind::Revenue,
+        aliases: &["revenue"],
+    },
+    Transaction {
+        kind: TransactionKind::EditOwners,
+        aliases: &["owners"],
+    },
+    Transaction {
+        kind: TransactionKind::ListAccounts,
+        aliases: &["accounts", "list"],
+    },
+    Transaction {
+        kind: TransactionKind::Details,
+        aliases: &["details", "info"],
+    },
+    Transaction {
+        kind: TransactionKind::History,
+        aliases: &["history"],
+    },
+    Transaction {
+        kind: TransactionKind::Backup,
+        aliases: &["backup"],
+    },
+    Transaction {
+        kind: TransactionKind::Restore,
+        aliases: &["restore"],
+    },
+    Transaction {
+        kind: TransactionKind::SwitchProfile,
+        aliases: &["profile", "switch"],
+    },
+    Transaction {
+        kind: TransactionKind::Help,
+        aliases: &["help", "?"],
+    },
+];
+
+/// Finds the transaction chosen by its ID, its alias, or a prefix of its alias.
+///
+/// A prefix must only match the aliases of a single transaction.
+pub fn find_transaction(input: &str) -> Result<&'static Transaction, &'static str> {
+    if input.parse::<usize>().is_ok() {
+        return parse_choice(input, TRANSACTIONS.len(), messages().unknown_transaction_id)
+            .map(|idx| &TRANSACTIONS[idx]);
+    }
+
+    let input = input.to_lowercase();
+
+    if let Some(transaction) = TRANSACTIONS.iter().find(|t| t.aliases.contains(&input.as_str())) {
+        return Ok(transaction);
+    }
+
+    let mut matches = TRANSACTIONS
+        .iter()
+        .filter(|t| !input.is_empty() && t.aliases.iter().any(|a| a.starts_with(&input)));
+
+    match (matches.next(), matches.next()) {
+        (Some(transaction), None) => Ok(transaction),
+        (Some(_), Some(_)) => Err(messages().ambiguous_transaction),
+        (None, _) => Err(messages().unknown_transaction_name),
+    }
+}
+
+/// Returns the aliases of every transaction, for completing the menu's prompt.
+pub fn aliases() -> Vec<String> {
+    TRANSACTIONS
+        .iter()
+        .flat_map(|t| t.aliases.iter().map(|a| a.to_string()))
+        .collect()
+}
+
+/// Prints the titles of the transactions as CLI prompt choices.
+pub fn print_transactions() {
+    print_choices(&TRANSACTIONS.iter().map(|t| t.text().title).collect::<Vec<_>>());
+}
+
+/// Prints a description of every transaction, the exchangeable currencies, and the current configuration.
+pub fn print_help(currency_titles: &[String], profile: &Profile) {
+    let messages = messages();
+
+    outln!("{}", style::header(messages.transactions_header));
+
+    for (i, transaction) in TRANSACTIONS.iter().enumerate() {
+        let text = transaction.text();
+
+        outln!("[{}] {}", i + 1, style::header(text.title));
+        outln!("    {}", text.description);
+        outln!("    {}", fill(messages.aliases, &[&transaction.aliases.join(", ")]));
+
+        if !text.inputs.is_empty() {
+            outln!("    {}", fill(messages.inputs, &[&text.inputs.join(", ")]));
+        }
+    }
+
+    outln!();
+
+    outln!(
+        "{}",
+        fill(
+            messages.cancel_hint,
+            &[&CANCEL_KEYWORDS.map(style::code).map(|k| k.to_string()).join(", ")]
+        )
+    );
+
+    outln!();
+
+    outln!("{}", style::header(messages.currencies));
+    print_choices(currency_titles);
+
+    outln!();
+
+    outln!("{}", style::header(messages.account_type_options));
+
+    for account_type in AccountType::ALL {
+        outln!(
+            "{}",
+            fill(
+                messages.account_type_rate,
+                &[
+                    &messages.account_types[account_type as usize],
+                    &(account_type.interest_rate() * Decimal::ONE_HUNDRED).normalize()
+                ]
+            )
+        );
+    }
+
+    outln!();
+
+    outln!("{}", style::header(messages.configuration));
+    outln!("{}", fill(messages.base_currency, &[&style::code(BASE_CURRENCY)]));
+    outln!("{}", fill(messages.profile, &[profile]));
+}
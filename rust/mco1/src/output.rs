@@ -0,0 +1,972 @@
+//! The results of the non-interactive transactions and the formats they are printed in.
+
+use std::{collections::BTreeMap, fmt, sync::OnceLock};
+
+use banking_and_currency_app::{
+    account::{Account, AccountStatus, AccountType, MinimumBalance},
+    alert::{RateAlert, RateNotification},
+    approval::PendingTransaction,
+    backup::Backup,
+    bank::{Charge, ExchangeQuote, InterestPosting, ScheduledRun, Violation},
+    batch::RowResult,
+    currency::BASE_CURRENCY,
+    history::Record,
+    interest::{Compounding, Contribution, DayCount, InterestMode, InterestRow, RateTier},
+    journal::EntryKind,
+    locale::Locale,
+    rates::{RatePoint, RateStats},
+    schedule::Schedule,
+    stress::StressReport,
+};
+use clap::ValueEnum;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use tracing::warn;
+
+use crate::{
+    format_rate,
+    i18n::{self, fill, messages},
+    print_account_details, print_accounts, print_alerts, print_bulk_deposit, print_charges, print_conversion,
+    print_current_rates, print_exchange, print_exchange_quote, print_fee_charges, print_history,
+    print_interest_posting, print_interest_schedule, print_pending, print_rate_history, print_refreshed_rates,
+    print_revenue, print_scheduled, print_scheduled_runs, print_schedules, print_stress_report, print_transfer,
+    print_triggered_alerts, print_undone, print_withdrawal_limit_set, print_yield,
+    prompt::print_choices,
+    style,
+};
+
+/// The locale that amounts of money are formatted for.
+static LOCALE: OnceLock<Locale> = OnceLock::new();
+
+/// Sets the locale that amounts of money are formatted for.
+pub fn init_locale(locale: Locale) {
+    let _ = LOCALE.set(locale);
+}
+
+/// Formats an amount of money in a currency for the chosen locale.
+pub fn money(amount: Decimal, currency: &str) -> String {
+    LOCALE.get().copied().unwrap_or_default().format_money(amount, currency)
+}
+
+/// Describes the warning that a currency's new exchange rate differs sharply from the previous one by a percentage.
+pub fn sharp_rate_change<T: fmt::Display>(currency: T, change: Decimal) -> String {
+    fill(
+        messages().sharp_rate_change,
+        &[&currency, &change.round_dp(2).normalize()],
+    )
+}
+
+/// Prints an error message for the user in the chosen language, logging it as well.
+pub fn print_error<T: fmt::Display + 'static>(err: T) {
+    warn!(%err, "reported an error");
+
+    outln!("{}", style::error(i18n::describe(&err)));
+}
+
+/// Prints an error message for the user to the standard error in the chosen language, logging it as well.
+pub fn eprint_error<T: fmt::Display + 'static>(err: T) {
+    warn!(%err, "reported an error");
+
+    eprintln!("{}", style::error(i18n::describe(&err)));
+}
+
+/// Prints the reference number of a committed transaction.
+pub fn print_reference(reference: &str) {
+    outln!("{}", fill(messages().reference_number, &[&style::code(reference)]));
+}
+
+/// The formats the results can be printed in.
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum Format {
+    /// Human-readable text, like the interactive menu.
+    Text,
+    /// One JSON object per result, for other tools to consume.
+    Json,
+}
+
+/// The details of a backup.
+#[derive(Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct BackupInfo {
+    /// The path of the backup file.
+    path: String,
+    /// When the backup was created.
+    created_at: String,
+}
+impl From<Backup> for BackupInfo {
+    fn from(backup: Backup) -> Self {
+        BackupInfo {
+            path: backup.path.display().to_string(),
+            created_at: backup.to_string(),
+        }
+    }
+}
+
+/// The details of an account.
+#[derive(Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AccountInfo {
+    /// The number of the account.
+    pub number: String,
+    /// The name of the account.
+    pub name: String,
+    /// The names of the account's owners.
+    pub owners: Vec<String>,
+    /// The type of the account.
+    pub account_type: AccountType,
+    /// The currency of the account's balance.
+    pub currency: String,
+    /// The current balance of the account.
+    pub balance: Decimal,
+    /// The status of the account.
+    pub status: AccountStatus,
+    /// How far below zero withdrawals can push the account's balance.
+    pub overdraft_limit: Decimal,
+    /// The most that can be withdrawn from the account each day, or none to use the bank's default.
+    pub daily_withdrawal_limit: Option<Decimal>,
+    /// The annual interest rate earned by the account, as a fraction.
+    pub interest_rate: Decimal,
+    /// How often the interest earned by the account is added to its balance.
+    pub compounding: Compounding,
+    /// The time the account was opened, in seconds since the Unix epoch, or zero if it wasn't recorded.
+    pub opened_at: u64,
+    /// The email address of the account's owners.
+    pub email: Option<String>,
+    /// The phone number of the account's owners.
+    pub phone: Option<String>,
+}
+impl From<&Account> for AccountInfo {
+    fn from(account: &Account) -> Self {
+        AccountInfo {
+            number: account.number.clone(),
+            name: account.name.clone(),
+            owners: account.owners.clone(),
+            account_type: account.account_type,
+            currency: account.currency.clone(),
+            balance: account.balance.amount(),
+            status: account.status(),
+            overdraft_limit: account.overdraft_limit,
+            daily_withdrawal_limit: account.daily_withdrawal_limit,
+            interest_rate: account.interest_rate,
+            compounding: account.compounding,
+            opened_at: account.opened_at,
+            email: account.contact.email.clone(),
+            phone: account.contact.phone.clone(),
+        }
+    }
+}
+
+/// A fee charged to an account.
+#[derive(Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FeeCharge {
+    /// The name of the account.
+    pub account: String,
+    /// The charged amount.
+    pub amount: Decimal,
+    /// The currency of the account's balance.
+    pub currency: String,
+}
+
+/// A scheduled transaction, along with the currency of its amount.
+#[derive(Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ScheduleInfo {
+    /// The schedule.
+    #[serde(flatten)]
+    pub schedule: Schedule,
+    /// The currency of the transaction's amount.
+    pub currency: String,
+}
+
+/// A transaction held for approval, along with the currency of its amount.
+#[derive(Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PendingInfo {
+    /// The held transaction.
+    #[serde(flatten)]
+    pub pending: PendingTransaction,
+    /// The currency of the transaction's amount.
+    pub currency: String,
+}
+
+/// A run of a scheduled transaction.
+#[derive(Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RunInfo {
+    /// The ID of the schedule.
+    pub schedule: u64,
+    /// The time the run was due, in seconds since the Unix epoch.
+    pub due_at: u64,
+    /// The reference number of the transaction, if it succeeded.
+    pub reference: Option<String>,
+    /// Why the transaction failed, if it did.
+    pub error: Option<String>,
+}
+impl From<ScheduledRun> for RunInfo {
+    fn from(run: ScheduledRun) -> Self {
+        let (reference, error) = match run.result {
+            Ok(receipt) => (Some(receipt.reference), None),
+            Err(err) => (None, Some(i18n::bank_error(&err))),
+        };
+
+        RunInfo {
+            schedule: run.schedule,
+            due_at: run.due_at,
+            reference,
+            error,
+        }
+    }
+}
+
+/// The result of a row of deposits read from a CSV file.
+#[derive(Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RowInfo {
+    /// The line the row is on, starting from one.
+    pub line: usize,
+    /// The name or number of the account, as written.
+    pub account: String,
+    /// The amount, as written.
+    pub amount: String,
+    /// The currency of the amount.
+    pub currency: String,
+    /// The reference number of the deposit, if it was made.
+    pub reference: Option<String>,
+    /// Why the row was rejected, if it was.
+    pub error: Option<String>,
+}
+impl From<RowResult> for RowInfo {
+    fn from(result: RowResult) -> Self {
+        let (reference, error) = match result.result {
+            Ok(receipt) => (receipt.map(|r| r.reference), None),
+            Err(err) => (None, Some(i18n::row_error(&err))),
+        };
+
+        RowInfo {
+            line: result.row.line,
+            account: result.row.account,
+            amount: result.row.amount,
+            currency: result.row.currency,
+            reference,
+            error,
+        }
+    }
+}
+
+/// The refreshed exchange rate of a currency.
+#[derive(Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RateInfo {
+    /// The currency whose rate was refreshed.
+    pub currency: String,
+    /// The new value of one unit of the currency in Philippine Pesos.
+    pub rate: Decimal,
+}
+
+/// The current exchange rate of a foreign currency.
+#[derive(Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CurrentRate {
+    /// The code of the currency.
+    pub currency: String,
+    /// The value of one unit of the currency in Philippine Pesos.
+    pub rate: Decimal,
+    /// The number of units of the currency that one Philippine Peso is worth.
+    pub inverse: Decimal,
+    /// When the rate was last updated, in seconds since the Unix epoch, if it ever was.
+    pub updated_at: Option<u64>,
+}
+
+/// The result of a non-interactive transaction.
+#[derive(Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum Outcome {
+    /// A new account was registered.
+    Registered {
+        /// The name of the registered account.
+        account: String,
+        /// The number given to the registered account.
+        number: String,
+        /// The names of the registered account's owners.
+        owners: Vec<String>,
+        /// The type of the registered account.
+        account_type: AccountType,
+    },
+    /// An amount was deposited to an account.
+    Deposited {
+        /// The name of the account.
+        account: String,
+        /// The reference number of the deposit.
+        reference: String,
+        /// The updated balance of the account.
+        balance: Decimal,
+        /// The currency of the account's balance.
+        currency: String,
+    },
+    /// An amount was withdrawn from an account.
+    Withdrawn {
+        /// The name of the account.
+        account: String,
+        /// The reference number of the withdrawal.
+        reference: String,
+        /// The updated balance of the account.
+        balance: Decimal,
+        /// The fees charged along with the withdrawal.
+        charges: Vec<Charge>,
+        /// The currency of the account's balance.
+        currency: String,
+    },
+    /// An amount was transferred from one account to another.
+    Transferred {
+        /// The name of the account transferred from.
+        account: String,
+        /// The name of the account transferred to.
+        recipient: String,
+        /// The reference number of the transfer.
+        reference: String,
+        /// The transferred amount.
+        amount: Decimal,
+        /// The updated balance of the account transferred from.
+        balance: Decimal,
+        /// The fees charged to the account transferred from along with the transfer.
+        charges: Vec<Charge>,
+        /// The currency of the balance of the account transferred from.
+        currency: String,
+    },
+    /// A withdrawal or transfer was held for approval instead of being committed.
+    Held {
+        /// The name of the account the transaction is against.
+        account: String,
+        /// The ID of the held transaction.
+        id: u64,
+    },
+    /// The transactions held for approval were listed.
+    Pending {
+        /// The held transactions, including the decided ones, in the order they were held.
+        pending: Vec<PendingInfo>,
+    },
+    /// A held transaction was approved, committing it.
+    Approved {
+        /// The ID of the held transaction.
+        id: u64,
+        /// The name of the account the transaction is against.
+        account: String,
+        /// The reference number of the transaction.
+        reference: String,
+        /// The updated balance of the account.
+        balance: Decimal,
+        /// The fees charged along with the transaction.
+        charges: Vec<Charge>,
+        /// The currency of the account's balance.
+        currency: String,
+    },
+    /// A held transaction was rejected.
+    Rejected {
+        /// The ID of the held transaction.
+        id: u64,
+    },
+    /// A transaction was scheduled to repeat.
+    Scheduled {
+        /// The ID of the schedule.
+        id: u64,
+        /// The time of the transaction's first run, in seconds since the Unix epoch.
+        next_run_at: u64,
+    },
+    /// The scheduled transactions were listed.
+    Schedules {
+        /// The scheduled transactions, including the cancelled ones, in the order they were created.
+        schedules: Vec<ScheduleInfo>,
+    },
+    /// A schedule was cancelled.
+    ScheduleCancelled {
+        /// The ID of the schedule.
+        id: u64,
+    },
+    /// The scheduled transactions due were run.
+    SchedulesRun {
+        /// The runs, from the earliest due.
+        runs: Vec<RunInfo>,
+    },
+    /// The rows of a CSV file were deposited, or only validated in a dry run.
+    BulkDeposited {
+        /// Whether the rows were only validated.
+        is_dry_run: bool,
+        /// The result of each row, in the order they were read.
+        rows: Vec<RowInfo>,
+    },
+    /// An account was closed.
+    Closed {
+        /// The name of the account.
+        account: String,
+        /// The remaining balance withdrawn before closing the account.
+        withdrawn_amount: Decimal,
+        /// The currency of the account's balance.
+        currency: String,
+    },
+    /// An account was renamed.
+    Renamed {
+        /// The previous name of the account.
+        account: String,
+        /// The new name of the account.
+        new_name: String,
+    },
+    /// An account was frozen or unfrozen.
+    FreezeUpdated {
+        /// The name of the account.
+        account: String,
+        /// Whether the account is now frozen.
+        is_frozen: bool,
+    },
+    /// The overdraft limit of an account was set.
+    OverdraftSet {
+        /// The name of the account.
+        account: String,
+        /// The new overdraft limit.
+        limit: Decimal,
+        /// The currency of the account's balance.
+        currency: String,
+    },
+    /// The daily withdrawal limit of an account was set.
+    WithdrawalLimitSet {
+        /// The name of the account.
+        account: String,
+        /// The new daily withdrawal limit, or none to use the bank's default.
+        limit: Option<Decimal>,
+        /// The currency of the account's balance.
+        currency: String,
+    },
+    /// The annual interest rate earned by an account was set.
+    InterestRateSet {
+        /// The name of the account.
+        account: String,
+        /// The new annual interest rate, as a fraction.
+        rate: Decimal,
+    },
+    /// How often the interest earned by an account compounds was set.
+    CompoundingSet {
+        /// The name of the account.
+        account: String,
+        /// The new compounding frequency.
+        compounding: Compounding,
+    },
+    /// The minimum balance of a type of account was set.
+    MinimumBalanceSet {
+        /// The type of account.
+        account_type: AccountType,
+        /// The new minimum balance and its fees.
+        minimum: MinimumBalance,
+    },
+    /// A period was ended, charging the monthly fees.
+    PeriodEnded {
+        /// The accounts charged a fee.
+        charges: Vec<FeeCharge>,
+    },
+    /// The fees and spread income collected by the bank were shown.
+    Revenue {
+        /// The collected fees, per currency.
+        revenue: BTreeMap<String, Decimal>,
+        /// The income from the exchange spreads, per currency.
+        spread_income: BTreeMap<String, Decimal>,
+    },
+    /// The owners of an account were replaced.
+    OwnersUpdated {
+        /// The name of the account.
+        account: String,
+        /// The names of the account's new owners.
+        owners: Vec<String>,
+    },
+    /// The registered accounts were listed.
+    Accounts {
+        /// The listed accounts, in the order they were sorted in.
+        accounts: Vec<AccountInfo>,
+    },
+    /// The details of an account were shown.
+    Details {
+        /// The account's details.
+        account: AccountInfo,
+    },
+    /// The history of an account was listed.
+    History {
+        /// The name of the account.
+        account: String,
+        /// The currency of the account's balance.
+        currency: String,
+        /// The listed transactions, from newest to oldest.
+        records: Vec<Record>,
+    },
+    /// An amount was exchanged from one currency to another.
+    Exchanged {
+        /// The breakdown of the exchange.
+        #[serde(flatten)]
+        quote: ExchangeQuote,
+    },
+    /// An amount was converted from one currency to another, without exchanging it.
+    Converted {
+        /// The converted amount, in the source currency.
+        amount: Decimal,
+        /// The source currency.
+        currency: String,
+        /// The currency converted to.
+        exchange_currency: String,
+        /// The converted amount, in the currency converted to.
+        exchange_amount: Decimal,
+        /// The date whose exchange rates were converted at, written as YYYY-MM-DD, or none for the current rates.
+        as_of: Option<String>,
+    },
+    /// An exchange of an amount from one currency to another was broken down, without making it.
+    ExchangeQuoted {
+        /// The breakdown of the exchange.
+        #[serde(flatten)]
+        quote: ExchangeQuote,
+    },
+    /// The exchange rate of a currency was recorded.
+    RateRecorded {
+        /// The currency whose rate was recorded.
+        currency: String,
+        /// The new exchange rate.
+        rate: Decimal,
+        /// The percentage by which the new rate differs from the previous one, if it differs enough to be warned about.
+        change: Option<Decimal>,
+    },
+    /// The spread of a foreign currency was set.
+    SpreadSet {
+        /// The currency whose spread was set.
+        currency: String,
+        /// The new spread, as a percentage of the currency's exchange rate.
+        spread: Decimal,
+    },
+    /// The current exchange rates of the foreign currencies were listed.
+    Rates {
+        /// The rates, in the order the currencies are listed.
+        rates: Vec<CurrentRate>,
+    },
+    /// The recent exchange rates of a foreign currency were listed.
+    RateHistory {
+        /// The foreign currency.
+        currency: String,
+        /// The number of most recent days the rates were listed from.
+        days: u64,
+        /// The rates recorded within the days, from newest to oldest.
+        rates: Vec<RatePoint>,
+        /// The lowest, highest, and average of the rates, if there are any.
+        stats: Option<RateStats>,
+    },
+    /// The alerts on the exchange rates were listed.
+    Alerts {
+        /// The alerts, including the removed ones, in the order they were added.
+        alerts: Vec<RateAlert>,
+    },
+    /// An alert on the exchange rate of a foreign currency was added.
+    AlertAdded {
+        /// The ID of the alert.
+        id: u64,
+    },
+    /// A rate alert was removed.
+    AlertRemoved {
+        /// The ID of the alert.
+        id: u64,
+    },
+    /// Changes in the exchange rates triggered alerts.
+    AlertsTriggered {
+        /// The notifications of the triggered alerts, from oldest to newest.
+        notifications: Vec<RateNotification>,
+    },
+    /// The exchange rates of the foreign currencies were refreshed from a provider.
+    RatesRefreshed {
+        /// The refreshed rates, in the order the currencies are listed.
+        rates: Vec<RateInfo>,
+    },
+    /// The exchange rates of the foreign currencies were written to a CSV file.
+    RatesExported {
+        /// The path of the CSV file.
+        path: String,
+        /// The number of rates written.
+        count: usize,
+    },
+    /// A currency was registered along with its exchange rate.
+    CurrencyAdded {
+        /// The code of the registered currency.
+        code: String,
+        /// The display name of the registered currency.
+        name: String,
+        /// The value of one unit of the currency in Philippine Pesos.
+        rate: Decimal,
+    },
+    /// The most recent transaction that can be undone was undone.
+    Undone {
+        /// The undone transaction.
+        #[cfg_attr(feature = "openapi", schema(value_type = Object))]
+        transaction: EntryKind,
+        /// The currency of the balance of the account the transaction was against, if any.
+        currency: String,
+    },
+    /// A transaction was reversed by its reference number.
+    Reversed {
+        /// The reference number of the reversed transaction.
+        reference: String,
+        /// The reference number of the reversal.
+        reversal: String,
+    },
+    /// A transaction was skipped, since one with the same idempotency key was already performed.
+    Skipped {
+        /// The idempotency key of the transaction.
+        key: String,
+        /// The reference number of the first transaction performed with the key.
+        reference: String,
+    },
+    /// The daily interest earned by an account was calculated.
+    Interest {
+        /// The name of the account.
+        account: String,
+        /// The names of the account's owners.
+        owners: Vec<String>,
+        /// The current balance of the account.
+        balance: Decimal,
+        /// The currency of the account's balance.
+        currency: String,
+        /// The type of the account.
+        account_type: AccountType,
+        /// The annual interest rate of the account.
+        interest_rate: Decimal,
+        /// The way the interest accrued.
+        mode: InterestMode,
+        /// How often the interest was added to the balance.
+        compounding: Compounding,
+        /// The effective annual rate of the account's interest rate, as it compounded.
+        effective_rate: Decimal,
+        /// The bands of balances that earned their own interest rates instead of the account's.
+        tiers: Vec<RateTier>,
+        /// How the days that the interest accrued over were counted.
+        day_count: DayCount,
+        /// The deposit planned to be made regularly over the schedule, if any.
+        contribution: Option<Contribution>,
+        /// The daily interest and balance.
+        schedule: Vec<InterestRow>,
+        /// The path of the CSV file the schedule was written to, if it was exported.
+        exported_to: Option<String>,
+    },
+    /// An annual percentage rate was converted to or from its yield.
+    Yield {
+        /// How often the rate compounds.
+        compounding: Compounding,
+        /// The nominal annual rate percentage.
+        apr: Decimal,
+        /// The effective annual yield percentage.
+        apy: Decimal,
+    },
+    /// The interest earned by an account was posted to its balance.
+    InterestPosted {
+        /// The name of the account.
+        account: String,
+        /// The currency of the account's balance.
+        currency: String,
+        /// The posted interest.
+        #[serde(flatten)]
+        posting: InterestPosting,
+    },
+    /// The bank's state was snapshot to a new backup.
+    BackedUp {
+        /// The new backup.
+        backup: BackupInfo,
+    },
+    /// The available backups were listed.
+    Backups {
+        /// The available backups, from oldest to newest.
+        backups: Vec<BackupInfo>,
+    },
+    /// The bank's state was rolled back to a backup.
+    Restored {
+        /// The backup rolled back to.
+        backup: BackupInfo,
+    },
+    /// The available profiles were listed.
+    Profiles {
+        /// The names of the available profiles.
+        profiles: Vec<String>,
+    },
+    /// The bank's state was checked for broken invariants.
+    Verified {
+        /// The broken invariants found.
+        violations: Vec<Violation>,
+    },
+    /// A scratch bank was stress-tested.
+    Stressed {
+        /// The results of the stress test.
+        report: StressReport,
+    },
+}
+impl Outcome {
+    /// Prints the result in a format.
+    pub fn print(&self, format: Format) {
+        let messages = messages();
+
+        if format == Format::Json {
+            outln!(
+                "{}",
+                serde_json::to_string(self).expect("Failed to serialize the result...")
+            );
+
+            return;
+        }
+
+        match self {
+            Outcome::Registered {
+                account,
+                number,
+                owners,
+                account_type,
+            } => {
+                outln!("{}", fill(messages.registered_account, &[account]));
+                outln!("{}", fill(messages.account_number, &[number]));
+                outln!("{}", fill(messages.owners_label, &[&owners.join(", ")]));
+                outln!(
+                    "{}",
+                    fill(
+                        messages.account_type_label,
+                        &[&messages.account_types[*account_type as usize]]
+                    )
+                );
+            }
+            Outcome::Deposited {
+                reference,
+                balance,
+                currency,
+                ..
+            } => {
+                outln!(
+                    "{}",
+                    fill(messages.updated_balance, &[&style::increase(money(*balance, currency))])
+                );
+                print_reference(reference);
+            }
+            Outcome::Withdrawn {
+                reference,
+                balance,
+                charges,
+                currency,
+                ..
+            } => {
+                outln!(
+                    "{}",
+                    fill(messages.updated_balance, &[&style::decrease(money(*balance, currency))])
+                );
+                print_charges(charges, currency);
+                print_reference(reference);
+            }
+            Outcome::Transferred {
+                recipient,
+                reference,
+                amount,
+                balance,
+                charges,
+                currency,
+                ..
+            } => {
+                print_transfer(recipient, *amount, *balance, currency);
+                print_charges(charges, currency);
+                print_reference(reference);
+            }
+            Outcome::Held { id, .. } => outln!("{}", fill(messages.held_for_approval, &[id])),
+            Outcome::Pending { pending } => print_pending(pending),
+            Outcome::Approved {
+                id,
+                reference,
+                balance,
+                charges,
+                currency,
+                ..
+            } => {
+                outln!("{}", fill(messages.approved_pending, &[id]));
+                outln!(
+                    "{}",
+                    fill(messages.updated_balance, &[&style::decrease(money(*balance, currency))])
+                );
+                print_charges(charges, currency);
+                print_reference(reference);
+            }
+            Outcome::Rejected { id } => outln!("{}", fill(messages.rejected_pending, &[id])),
+            Outcome::Scheduled { id, next_run_at } => print_scheduled(*id, *next_run_at),
+            Outcome::Schedules { schedules } => print_schedules(schedules),
+            Outcome::ScheduleCancelled { id } => outln!("{}", fill(messages.cancelled_schedule, &[id])),
+            Outcome::SchedulesRun { runs } => {
+                if runs.is_empty() {
+                    outln!("{}", messages.no_runs_due);
+                }
+
+                print_scheduled_runs(runs);
+            }
+            Outcome::BulkDeposited { is_dry_run, rows } => print_bulk_deposit(rows, *is_dry_run),
+            Outcome::Closed {
+                account,
+                withdrawn_amount,
+                currency,
+            } => {
+                if *withdrawn_amount > Decimal::ZERO {
+                    outln!(
+                        "{}",
+                        fill(
+                            messages.withdrew_remainder,
+                            &[&style::decrease(money(*withdrawn_amount, currency))]
+                        )
+                    );
+                }
+
+                outln!("{}", fill(messages.closed_account, &[account]));
+            }
+            Outcome::Renamed { account, new_name } => {
+                outln!("{}", fill(messages.renamed_account, &[account, new_name]))
+            }
+            Outcome::FreezeUpdated { account, is_frozen } => outln!(
+                "{}",
+                fill(
+                    if *is_frozen {
+                        messages.froze_account
+                    } else {
+                        messages.unfroze_account
+                    },
+                    &[account]
+                )
+            ),
+            Outcome::OverdraftSet {
+                account,
+                limit,
+                currency,
+            } => outln!("{}", fill(messages.set_overdraft, &[account, &money(*limit, currency)])),
+            Outcome::WithdrawalLimitSet {
+                account,
+                limit,
+                currency,
+            } => print_withdrawal_limit_set(account, *limit, currency),
+            Outcome::InterestRateSet { account, rate } => {
+                outln!(
+                    "{}",
+                    fill(
+                        messages.set_interest_rate,
+                        &[account, &format_rate(rate * Decimal::ONE_HUNDRED)]
+                    )
+                )
+            }
+            Outcome::CompoundingSet { account, compounding } => outln!(
+                "{}",
+                fill(messages.set_compounding, &[account, &compounding.to_string()])
+            ),
+            Outcome::MinimumBalanceSet { account_type, minimum } => outln!(
+                "{}",
+                fill(
+                    messages.set_minimum_balance,
+                    &[
+                        &messages.account_types[*account_type as usize],
+                        &money(minimum.amount, BASE_CURRENCY)
+                    ]
+                )
+            ),
+            Outcome::PeriodEnded { charges } => print_fee_charges(charges),
+            Outcome::Revenue { revenue, spread_income } => print_revenue(revenue, spread_income),
+            Outcome::OwnersUpdated { account, owners } => {
+                outln!("{}", fill(messages.updated_owners, &[account, &owners.join(", ")]))
+            }
+            Outcome::Accounts { accounts } => print_accounts(accounts),
+            Outcome::Details { account } => print_account_details(account),
+            Outcome::History { records, currency, .. } => print_history(records, currency, None),
+            Outcome::Exchanged { quote } => print_exchange(
+                quote.exchange_amount,
+                &quote.exchange_currency,
+                quote.fee,
+                &quote.currency,
+            ),
+            Outcome::ExchangeQuoted { quote } => print_exchange_quote(quote),
+            Outcome::Converted {
+                amount,
+                currency,
+                exchange_currency,
+                exchange_amount,
+                as_of,
+            } => print_conversion(*amount, currency, *exchange_amount, exchange_currency, as_of.as_deref()),
+            Outcome::RateRecorded { currency, change, .. } => {
+                outln!("{}", fill(messages.recorded_rate, &[&style::code(currency)]));
+
+                if let Some(change) = change {
+                    outln!("{}", sharp_rate_change(style::code(currency), *change));
+                }
+            }
+            Outcome::SpreadSet { currency, spread } => {
+                outln!("{}", fill(messages.set_spread, &[&style::code(currency), spread]))
+            }
+            Outcome::Rates { rates } => print_current_rates(rates),
+            Outcome::RateHistory {
+                currency,
+                days,
+                rates,
+                stats,
+            } => print_rate_history(currency, *days, rates, stats.as_ref()),
+            Outcome::Alerts { alerts } => print_alerts(alerts),
+            Outcome::AlertAdded { id } => outln!("{}", fill(messages.added_alert, &[id])),
+            Outcome::AlertRemoved { id } => outln!("{}", fill(messages.removed_alert, &[id])),
+            Outcome::AlertsTriggered { notifications } => print_triggered_alerts(notifications),
+            Outcome::RatesRefreshed { rates } => {
+                print_refreshed_rates(&rates.iter().map(|r| (r.currency.clone(), r.rate)).collect::<Vec<_>>())
+            }
+            Outcome::RatesExported { path, count } => {
+                outln!("{}", fill(messages.exported_rates, &[count, path]))
+            }
+            Outcome::CurrencyAdded { code, name, rate } => outln!(
+                "{}",
+                fill(
+                    messages.added_currency,
+                    &[&format!("{name} ({})", style::code(code)), rate]
+                )
+            ),
+            Outcome::Undone { transaction, currency } => print_undone(transaction, currency),
+            Outcome::Skipped { key, reference } => outln!(
+                "{}",
+                fill(
+                    messages.skipped_transaction,
+                    &[&style::code(key), &style::code(reference)]
+                )
+            ),
+            Outcome::Reversed { reference, reversal } => outln!(
+                "{}",
+                fill(
+                    messages.reversed_transaction,
+                    &[&style::code(reference), &style::code(reversal)]
+                )
+            ),
+            Outcome::Interest {
+                schedule,
+                currency,
+                effective_rate,
+                exported_to,
+                ..
+            } => {
+                outln!(
+                    "{}",
+                    fill(
+                        messages.effective_rate,
+                        &[&format_rate(effective_rate * Decimal::ONE_HUNDRED)]
+                    )
+                );
+                outln!();
+                print_interest_schedule(schedule, currency, None, exported_to.as_deref())
+            }
+            Outcome::Yield { compounding, apr, apy } => print_yield(*compounding, *apr, *apy),
+            Outcome::InterestPosted {
+                account,
+                currency,
+                posting,
+            } => print_interest_posting(account, posting, currency),
+            Outcome::BackedUp { backup } => outln!("{}", fill(messages.saved_backup, &[&backup.path])),
+            Outcome::Backups { backups } => print_choices(&backups.iter().map(|b| &b.created_at).collect::<Vec<_>>()),
+            Outcome::Restored { backup } => outln!("{}", fill(messages.restored_backup, &[&backup.created_at])),
+            Outcome::Profiles { profiles } => print_choices(profiles),
+            Outcome::Verified { violations } if violations.is_empty() => outln!("{}", messages.no_violations),
+            Outcome::Verified { violations } => {
+                for violation in violations {
+                    outln!("{}", style::error(violation));
+                }
+            }
+            Outcome::Stressed { report } => print_stress_report(report),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! The profiles isolating separate banks on the same machine.
+
+use std::{
+    fmt, fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+#[cfg(feature = "webhooks")]
+use crate::webhook::DELIVERY_LOG_PATH;
+use crate::{backup::BACKUP_DIR, config::CONFIG_PATH, journal::JOURNAL_PATH};
+
+/// The directory where the non-default profiles are stored.
+pub const PROFILES_DIR: &str = "profiles";
+/// The name of the profile used when none is chosen.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// A profile with its own accounts, exchange rates, journal, backups, and configuration.
+///
+/// The default profile stores its data in the working directory, while the others each get their own directory in
+/// [`PROFILES_DIR`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Profile {
+    /// The name of the profile.
+    pub name: String,
+    /// The directory where the profile's data is stored.
+    dir: PathBuf,
+}
+impl Profile {
+    /// Creates a profile from its name.
+    ///
+    /// Returns `None` if the name is empty or contains anything other than letters, numbers, dashes, and underscores.
+    pub fn new(name: &str) -> Option<Profile> {
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return None;
+        }
+
+        let dir = if name == DEFAULT_PROFILE {
+            PathBuf::from(".")
+        } else {
+            Path::new(PROFILES_DIR).join(name)
+        };
+
+        Some(Profile {
+            name: name.to_string(),
+            dir,
+        })
+    }
+
+    /// Lists the default profile and every profile with a directory, sorted by name.
+    pub fn list() -> io::Result<Vec<Profile>> {
+        let mut profiles = vec![Profile::default()];
+
+        let entries = match fs::read_dir(PROFILES_DIR) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(profiles),
+            Err(err) => return Err(err),
+        };
+
+        for entry in entries {
+            let entry = entry?;
+
+            if !entry.file_type()?.is_dir() {
+                continue;
+            }
+
+            if let Some(profile) = entry.file_name().to_str().and_then(Profile::new)
+                && profile.name != DEFAULT_PROFILE
+            {
+                profiles.push(profile);
+            }
+        }
+
+        profiles[1..].sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(profiles)
+    }
+
+    /// Creates the profile's directory if it doesn't exist yet.
+    pub fn create_dir(&self) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)
+    }
+
+    /// Returns the path of the profile's journal file.
+    pub fn journal_path(&self) -> PathBuf {
+        self.dir.join(JOURNAL_PATH)
+    }
+
+    /// Returns the path of the profile's configuration file.
+    pub fn config_path(&self) -> PathBuf {
+        self.dir.join(CONFIG_PATH)
+    }
+
+    /// Returns the path of the profile's backup directory.
+    pub fn backup_dir(&self) -> PathBuf {
+        self.dir.join(BACKUP_DIR)
+    }
+
+    /// Returns the path of the profile's webhook delivery log.
+    #[cfg(feature = "webhooks")]
+    pub fn delivery_log_path(&self) -> PathBuf {
+        self.dir.join(DELIVERY_LOG_PATH)
+    }
+}
+impl Default for Profile {
+    fn default() -> Self {
+        Profile {
+            name: String::from(DEFAULT_PROFILE),
+            dir: PathBuf::from("."),
+        }
+    }
+}
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
@@ -0,0 +1,193 @@
+//! The CLI prompts reading the user's responses.
+
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::{console::read_line, i18n::messages, output::print_error};
+
+/// Prints an array's contents as CLI prompt choices.
+///
+/// The array's elements are stringified and printed along with their index incremented by one (`i + 1`), serving as the
+/// choice's identifier.
+pub fn print_choices<T: fmt::Display>(choices: &[T]) {
+    for (i, val) in choices.iter().enumerate() {
+        outln!("[{}] {val}", i + 1)
+    }
+}
+
+/// Finds the index of the choice identified by a response, given as the one-based identifier printed by
+/// [`print_choices`], among a number of choices.
+///
+/// The error is returned if the identifier isn't any choice's, and a generic one if the response isn't a number.
+pub fn parse_choice(input: &str, choice_cnt: usize, unknown_err: &'static str) -> Result<usize, &'static str> {
+    match input.parse::<usize>() {
+        Ok(id) => id.checked_sub(1).filter(|idx| *idx < choice_cnt).ok_or(unknown_err),
+        Err(_) => Err(messages().invalid_id),
+    }
+}
+
+/// Prompts a CLI user to choose one of the choices printed by [`print_choices`] until they input a valid identifier,
+/// returning the chosen choice's index.
+///
+/// Nothing is returned if the user inputs a cancel keyword or the retry limit is reached.
+pub fn select_from<T>(msg: &str, choices: &[T], unknown_err: &'static str) -> Option<usize> {
+    prompt_validated(msg, Vec::new(), |input| parse_choice(input, choices.len(), unknown_err))
+}
+
+/// Prompts a CLI user to input a response.
+///
+/// A message is printed before awaiting the user's response, which is inputted on the same line in the console. The
+/// response is recorded in the prompt history.
+pub fn prompt(msg: &str) -> String {
+    read_line(msg, true, false, Vec::new())
+}
+
+/// Prompts a CLI user to input a response, which can be tab-completed to one of the candidates.
+pub fn prompt_completed<I: IntoIterator<Item = String>>(msg: &str, candidates: I) -> String {
+    read_line(msg, true, false, candidates.into_iter().collect())
+}
+
+/// Prompts a CLI user to input a response to a transaction, which can be tab-completed to one of the candidates.
+///
+/// Nothing is returned if the user inputs a cancel keyword.
+pub fn prompt_cancellable<I: IntoIterator<Item = String>>(msg: &str, candidates: I) -> Option<String> {
+    Some(read_line(msg, true, false, candidates.into_iter().collect())).filter(|input| !is_cancelling(input))
+}
+
+/// Prompts a CLI user whether to print more of a long output, returning whether they pressed Enter rather than `q`.
+pub fn prompt_more() -> bool {
+    !read_line(messages().more, false, false, Vec::new()).eq_ignore_ascii_case("q")
+}
+
+/// Prompts a CLI user to input a secret response, like a passphrase or PIN, which is masked and never recorded.
+pub fn prompt_private(msg: &str) -> String {
+    read_line(msg, false, true, Vec::new())
+}
+
+/// The keywords that cancel a transaction's prompt, returning to the main menu.
+pub const CANCEL_KEYWORDS: [&str; 3] = ["back", "cancel", "q"];
+
+/// Checks whether a response is one of the cancel keywords.
+fn is_cancelling(input: &str) -> bool {
+    CANCEL_KEYWORDS.iter().any(|k| input.eq_ignore_ascii_case(k))
+}
+
+/// The number of invalid responses a validated prompt accepts before giving up, with `0` meaning no limit.
+static RETRY_LIMIT: AtomicUsize = AtomicUsize::new(3);
+
+/// Sets the number of invalid responses a validated prompt accepts before giving up, with `0` meaning no limit.
+pub fn set_retry_limit(limit: usize) {
+    RETRY_LIMIT.store(limit, Ordering::Relaxed);
+}
+
+/// The number of incorrect PINs accepted before the transaction is denied, with `0` meaning no limit.
+static PIN_ATTEMPTS: AtomicUsize = AtomicUsize::new(3);
+
+/// Sets the number of incorrect PINs accepted before the transaction is denied, with `0` meaning no limit.
+pub fn set_pin_attempts(limit: usize) {
+    PIN_ATTEMPTS.store(limit, Ordering::Relaxed);
+}
+
+/// Prompts a CLI user to input a response until it is valid, completing it from the candidates.
+///
+/// The response is validated and converted by the closure, whose error is printed before prompting again. Nothing is
+/// returned if the user inputs a cancel keyword or the retry limit is reached.
+pub fn prompt_validated<T, E: fmt::Display + 'static>(
+    msg: &str,
+    candidates: Vec<String>,
+    validate: impl Fn(&str) -> Result<T, E>,
+) -> Option<T> {
+    prompt_limited(msg, false, candidates, RETRY_LIMIT.load(Ordering::Relaxed), validate)
+}
+
+/// Prompts a CLI user to input a secret response until it is valid, which is masked and never recorded.
+///
+/// Nothing is returned if the user inputs a cancel keyword or the retry limit is reached.
+pub fn prompt_private_validated<T, E: fmt::Display + 'static>(
+    msg: &str,
+    validate: impl Fn(&str) -> Result<T, E>,
+) -> Option<T> {
+    prompt_limited(msg, true, Vec::new(), RETRY_LIMIT.load(Ordering::Relaxed), validate)
+}
+
+/// Prompts a CLI user to input a PIN until the closure verifies it, which is masked and never recorded.
+///
+/// Nothing is returned if the user inputs a cancel keyword or the PIN attempt limit is reached.
+pub fn prompt_pin<E: fmt::Display + 'static>(msg: &str, verify: impl Fn(&str) -> Result<(), E>) -> Option<()> {
+    prompt_limited(msg, true, Vec::new(), PIN_ATTEMPTS.load(Ordering::Relaxed), verify)
+}
+
+/// Prompts a CLI user to input a response until it is valid or the limit of invalid responses is reached.
+fn prompt_limited<T, E: fmt::Display + 'static>(
+    msg: &str,
+    is_masked: bool,
+    candidates: Vec<String>,
+    retry_limit: usize,
+    validate: impl Fn(&str) -> Result<T, E>,
+) -> Option<T> {
+    let mut retry_cnt = 0;
+
+    loop {
+        let input = read_line(msg, !is_masked, is_masked, candidates.clone());
+
+        if is_cancelling(&input) {
+            return None;
+        }
+
+        match validate(&input) {
+            Ok(val) => return Some(val),
+            Err(err) => print_error(err),
+        }
+
+        retry_cnt += 1;
+
+        if retry_limit != 0 && retry_cnt >= retry_limit {
+            print_error(messages().too_many_retries);
+
+            return None;
+        }
+    }
+}
+
+thread_local! {
+    /// The last valid response to each defaulted prompt, by the prompt's message.
+    static LAST_RESPONSES: RefCell<HashMap<String, String>> = RefCell::default();
+}
+
+/// Prompts a CLI user to input a response until it is valid, accepting a default response by inputting nothing.
+///
+/// The default response is the given one, or else the last valid response to the same prompt, and is shown in brackets
+/// before the colon (`Currency [PHP]: `). Nothing is returned if the user inputs a cancel keyword or the retry limit is
+/// reached.
+pub fn prompt_defaulted<T, E: fmt::Display + 'static>(
+    msg: &str,
+    default: Option<&str>,
+    candidates: Vec<String>,
+    validate: impl Fn(&str) -> Result<T, E>,
+) -> Option<T> {
+    let default = default
+        .map(String::from)
+        .or_else(|| LAST_RESPONSES.with_borrow(|responses| responses.get(msg).cloned()));
+    let shown_msg = match &default {
+        Some(default) => format!("{} [{default}]: ", msg.trim_end().trim_end_matches(':')),
+        None => msg.to_string(),
+    };
+
+    prompt_validated(&shown_msg, candidates, |input| {
+        let input = match &default {
+            Some(default) if input.is_empty() => default,
+            _ => input,
+        };
+        let result = validate(input);
+
+        if result.is_ok() {
+            LAST_RESPONSES.with_borrow_mut(|responses| responses.insert(msg.to_string(), input.to_string()));
+        }
+
+        result
+    })
+}
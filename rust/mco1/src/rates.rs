@@ -0,0 +1,378 @@
+//! The providers that the exchange rates can be refreshed from.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    batch,
+    currency::{self, BASE_CURRENCY, RoundingPolicy},
+};
+
+/// The URL of the European Central Bank's daily reference rates, which are based on the Euro.
+#[cfg(feature = "live-rates")]
+pub const ECB_URL: &str = "https://www.ecb.europa.eu/stats/eurofxref/eurofxref-daily.xml";
+
+/// The header of a CSV file of exchange rates.
+const CSV_HEADER: [&str; 2] = ["currency", "rate"];
+
+/// The number of decimal places that rates derived from another currency's rates are rounded to.
+const RATE_DECIMALS: u32 = 6;
+
+/// The reasons a provider can fail to give the current exchange rates.
+#[derive(Debug)]
+pub enum RateError {
+    /// The provider couldn't be reached.
+    Request(String),
+    /// The provider's response couldn't be read.
+    Malformed,
+    /// The user cancelled entering the rates.
+    Cancelled,
+    /// A row of a CSV file of rates lacks a currency or a rate, or has more than two fields.
+    MalformedRow {
+        /// The line the row is on, starting from one.
+        line: usize,
+    },
+    /// A row of a CSV file of rates is of a currency that isn't a foreign exchangeable one.
+    UnknownCurrency {
+        /// The line the row is on, starting from one.
+        line: usize,
+        /// The code of the currency, as written.
+        code: String,
+    },
+    /// A row of a CSV file of rates has a rate that isn't a number from [`currency::MIN_RATE`] to
+    /// [`currency::MAX_RATE`].
+    InvalidRate {
+        /// The line the row is on, starting from one.
+        line: usize,
+    },
+}
+impl fmt::Display for RateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RateError::Request(err) => write!(f, "Failed to reach the rate provider: {err}"),
+            RateError::Malformed => write!(f, "The rate provider's response couldn't be read!"),
+            RateError::Cancelled => write!(f, "Entering the exchange rates was cancelled!"),
+            RateError::MalformedRow { line } => write!(f, "Line {line} must have a currency and a rate!"),
+            RateError::UnknownCurrency { line, code } => {
+                write!(
+                    f,
+                    "Line {line} has {code}, which isn't an exchangeable foreign currency!"
+                )
+            }
+            RateError::InvalidRate { line } => write!(
+                f,
+                "Line {line} must have a rate from {} to {}!",
+                currency::MIN_RATE,
+                currency::MAX_RATE
+            ),
+        }
+    }
+}
+impl std::error::Error for RateError {}
+
+/// An exchange rate of a currency as it was recorded at some point in time.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RatePoint {
+    /// The value of one unit of the currency in Philippine Pesos.
+    pub rate: Decimal,
+    /// When the rate was recorded, in seconds since the Unix epoch.
+    pub recorded_at: u64,
+}
+
+/// The lowest, highest, and average of a currency's exchange rates over some window of time.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RateStats {
+    /// The lowest rate.
+    pub min: Decimal,
+    /// The highest rate.
+    pub max: Decimal,
+    /// The mean of the rates.
+    pub average: Decimal,
+}
+impl RateStats {
+    /// Summarizes recorded rates, unless there are none.
+    pub fn of(points: &[RatePoint]) -> Option<RateStats> {
+        if points.is_empty() {
+            return None;
+        }
+
+        let rates = points.iter().map(|p| p.rate);
+
+        Some(RateStats {
+            min: rates.clone().min().unwrap_or_default(),
+            max: rates.clone().max().unwrap_or_default(),
+            average: rates.sum::<Decimal>() / Decimal::from(points.len()),
+        })
+    }
+}
+
+/// A source of the current exchange rates.
+pub trait RateProvider {
+    /// Fetches the current value of foreign currencies in Philippine Pesos, by their codes.
+    ///
+    /// Currencies the provider doesn't quote are left out.
+    fn fetch(&mut self, codes: &[String]) -> Result<HashMap<String, Decimal>, RateError>;
+}
+
+/// The exchange rates read from a CSV file, as a rate provider.
+///
+/// Each row is written as `currency,rate`, under an optional header naming the fields. Every row must be of a foreign
+/// exchangeable currency with a positive rate, or none of the rates are given.
+pub struct CsvRates {
+    /// The contents of the CSV file.
+    pub csv: String,
+}
+impl RateProvider for CsvRates {
+    fn fetch(&mut self, codes: &[String]) -> Result<HashMap<String, Decimal>, RateError> {
+        let mut rates = HashMap::new();
+
+        for (idx, line) in self.csv.lines().enumerate() {
+            let fields = batch::split_fields(line);
+
+            if line.trim().is_empty() || idx == 0 && fields.iter().map(|f| f.to_lowercase()).eq(CSV_HEADER) {
+                continue;
+            }
+
+            let line = idx + 1;
+
+            let (code, rate) = match fields.as_slice() {
+                [code, rate] if !code.is_empty() && !rate.is_empty() => (code.to_uppercase(), rate),
+                _ => return Err(RateError::MalformedRow { line }),
+            };
+
+            if !codes.contains(&code) {
+                return Err(RateError::UnknownCurrency { line, code });
+            }
+
+            let rate = rate
+                .parse::<Decimal>()
+                .ok()
+                .filter(|r| currency::is_valid_rate(*r))
+                .ok_or(RateError::InvalidRate { line })?;
+
+            rates.insert(code, rate);
+        }
+
+        Ok(rates)
+    }
+}
+
+/// A fixed set of exchange rates, as a rate provider, for tests and offline use.
+#[derive(Clone, Debug, Default)]
+pub struct StaticRateProvider {
+    /// The value of each quoted foreign currency in Philippine Pesos, by its code.
+    pub rates: HashMap<String, Decimal>,
+}
+impl RateProvider for StaticRateProvider {
+    fn fetch(&mut self, codes: &[String]) -> Result<HashMap<String, Decimal>, RateError> {
+        Ok(codes
+            .iter()
+            .filter_map(|code| Some((code.clone(), *self.rates.get(code)?)))
+            .collect())
+    }
+}
+
+/// The responses of the European Central Bank's rate feed recorded to files, as a rate provider that replays them in
+/// order, one per fetch, so that reading the feed can be tested and used without reaching it.
+#[derive(Clone, Debug, Default)]
+pub struct RecordedRateProvider {
+    /// The paths of the recorded responses that are left to replay, the next first.
+    pub responses: VecDeque<PathBuf>,
+}
+impl RecordedRateProvider {
+    /// Replays the responses recorded to the files in a directory, in the order of their names.
+    pub fn from_dir<P: AsRef<Path>>(dir: P) -> io::Result<RecordedRateProvider> {
+        let mut responses = fs::read_dir(dir)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        responses.sort();
+
+        Ok(RecordedRateProvider {
+            responses: responses.into(),
+        })
+    }
+}
+impl RateProvider for RecordedRateProvider {
+    fn fetch(&mut self, codes: &[String]) -> Result<HashMap<String, Decimal>, RateError> {
+        let path = self
+            .responses
+            .pop_front()
+            .ok_or_else(|| RateError::Request(String::from("no recorded responses are left")))?;
+        let xml = fs::read_to_string(&path).map_err(|err| RateError::Request(format!("{}: {err}", path.display())))?;
+
+        ecb_rates(&xml, codes)
+    }
+}
+
+/// Writes exchange rates as CSV, under a header naming the fields, so that they can be read back by [`CsvRates`].
+pub fn to_csv(rates: &[(String, Decimal)]) -> String {
+    let mut csv = CSV_HEADER.join(",");
+
+    for (code, rate) in rates {
+        csv.push_str(&format!("\n{code},{rate}"));
+    }
+
+    csv.push('\n');
+
+    csv
+}
+
+/// The exchange rates published daily by the European Central Bank.
+#[cfg(feature = "live-rates")]
+pub struct EcbRates {
+    /// The URL of the rates' XML feed.
+    pub url: String,
+}
+#[cfg(feature = "live-rates")]
+impl Default for EcbRates {
+    fn default() -> Self {
+        EcbRates {
+            url: String::from(ECB_URL),
+        }
+    }
+}
+#[cfg(feature = "live-rates")]
+impl RateProvider for EcbRates {
+    fn fetch(&mut self, codes: &[String]) -> Result<HashMap<String, Decimal>, RateError> {
+        let xml = ureq::get(&self.url)
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|err| RateError::Request(err.to_string()))?;
+
+        ecb_rates(&xml, codes)
+    }
+}
+
+/// Reads the Euro-based rates from the European Central Bank's XML, adding the Euro itself.
+fn parse_ecb_xml(xml: &str) -> HashMap<String, Decimal> {
+    let mut rates = HashMap::from([(String::from("EUR"), Decimal::ONE)]);
+
+    for cube in xml.split("<Cube").skip(1) {
+        let attribute = |name: &str| {
+            let (_, rest) = cube.split_once(&format!("{name}='"))?;
+
+            rest.split_once('\'').map(|(value, _)| value)
+        };
+
+        if let (Some(code), Some(Ok(rate))) = (attribute("currency"), attribute("rate").map(str::parse::<Decimal>)) {
+            rates.insert(code.to_string(), rate);
+        }
+    }
+
+    rates
+}
+
+/// Derives the values of foreign currencies in Philippine Pesos from the European Central Bank's XML, by their codes.
+///
+/// Currencies the bank doesn't quote are left out.
+fn ecb_rates(xml: &str, codes: &[String]) -> Result<HashMap<String, Decimal>, RateError> {
+    let euro_rates = parse_ecb_xml(xml);
+    let base_rate = euro_rates.get(BASE_CURRENCY).ok_or(RateError::Malformed)?;
+
+    Ok(codes
+        .iter()
+        .filter_map(|code| Some((code.clone(), round_rate(base_rate.checked_div(*euro_rates.get(code)?)?))))
+        .collect())
+}
+
+/// Rounds a rate derived from another currency's rates, dropping the noise of the division.
+fn round_rate(rate: Decimal) -> Decimal {
+    RoundingPolicy::HalfUp.round(rate, RATE_DECIMALS)
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::bank::{Bank, BankError};
+
+    /// The directory of the recorded responses of the European Central Bank's rate feed.
+    const ECB_FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/ecb");
+
+    /// Returns the codes of currencies.
+    fn codes(codes: &[&str]) -> Vec<String> {
+        codes.iter().map(|c| c.to_string()).collect()
+    }
+
+    #[test]
+    fn static_provider_leaves_out_unquoted_currencies() {
+        let mut provider = StaticRateProvider {
+            rates: HashMap::from([(String::from("USD"), dec!(56.25)), (String::from("KRW"), dec!(0.042))]),
+        };
+
+        let rates = provider.fetch(&codes(&["USD", "JPY"])).unwrap();
+
+        assert_eq!(rates, HashMap::from([(String::from("USD"), dec!(56.25))]));
+    }
+
+    #[test]
+    fn static_provider_refreshes_bank() {
+        let mut bank = Bank::new();
+        let mut provider = StaticRateProvider {
+            rates: HashMap::from([(String::from("USD"), dec!(56.25)), (String::from("JPY"), dec!(0.3775))]),
+        };
+
+        bank.refresh_rates(&mut provider).unwrap();
+
+        assert_eq!(bank.exchange_rates()["USD"], dec!(56.25));
+        assert_eq!(bank.exchange_rates()["JPY"], dec!(0.3775));
+    }
+
+    #[test]
+    fn recorded_provider_replays_responses_in_order() {
+        let mut provider = RecordedRateProvider::from_dir(ECB_FIXTURES).unwrap();
+        let codes = codes(&["USD", "JPY", "GBP", "EUR", "CNY"]);
+
+        assert_eq!(
+            provider.fetch(&codes).unwrap(),
+            HashMap::from([
+                (String::from("USD"), dec!(55.543994)),
+                (String::from("JPY"), dec!(0.390942)),
+                (String::from("GBP"), dec!(70.336809)),
+                (String::from("EUR"), dec!(60.854)),
+                (String::from("CNY"), dec!(7.775478)),
+            ])
+        );
+        assert_eq!(provider.fetch(&codes).unwrap()["USD"], dec!(55.788076));
+        assert!(matches!(provider.fetch(&codes), Err(RateError::Request(_))));
+    }
+
+    #[test]
+    fn recorded_provider_rejects_response_without_peso() {
+        let path = std::env::temp_dir().join(format!("mcos-ecb-{}.xml", std::process::id()));
+
+        fs::write(&path, "<Cube currency='USD' rate='1.0956'/>").unwrap();
+
+        let mut provider = RecordedRateProvider {
+            responses: VecDeque::from([path.clone()]),
+        };
+        let result = provider.fetch(&codes(&["USD"]));
+
+        fs::remove_file(path).unwrap();
+
+        assert!(matches!(result, Err(RateError::Malformed)));
+    }
+
+    #[test]
+    fn recorded_provider_reports_missing_response() {
+        let mut bank = Bank::new();
+        let mut provider = RecordedRateProvider {
+            responses: VecDeque::from([PathBuf::from(ECB_FIXTURES).join("missing.xml")]),
+        };
+
+        assert!(matches!(
+            bank.refresh_rates(&mut provider),
+            Err(BankError::Rates(RateError::Request(_)))
+        ));
+    }
+}
@@ -0,0 +1,80 @@
+//! The transactions repeated automatically on a schedule.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::clock::SECONDS_PER_DAY;
+
+/// The transactions that can be repeated on a schedule.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScheduledTransaction {
+    /// Deposits an amount in any currency to an account.
+    Deposit {
+        /// The name of the account deposited to.
+        account: String,
+        /// The deposited amount, in the deposited currency.
+        amount: Decimal,
+        /// The currency of the deposited amount.
+        currency: String,
+    },
+    /// Transfers an amount in the sending account's currency to another account, like a standing order.
+    Transfer {
+        /// The name of the account transferred from.
+        account: String,
+        /// The name of the account transferred to.
+        recipient: String,
+        /// The transferred amount, in the currency of the account transferred from.
+        amount: Decimal,
+    },
+}
+impl ScheduledTransaction {
+    /// Returns the name of the account the transaction is against, which a transfer is from.
+    pub fn account(&self) -> &str {
+        match self {
+            ScheduledTransaction::Deposit { account, .. } | ScheduledTransaction::Transfer { account, .. } => account,
+        }
+    }
+
+    /// Follows an account involved in the transaction to its new name.
+    pub fn rename_account(&mut self, name: &str, new_name: &str) {
+        let accounts = match self {
+            ScheduledTransaction::Deposit { account, .. } => vec![account],
+            ScheduledTransaction::Transfer { account, recipient, .. } => vec![account, recipient],
+        };
+
+        for account in accounts.into_iter().filter(|a| *a == name) {
+            *account = new_name.to_string();
+        }
+    }
+}
+
+/// A transaction repeated every number of days.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Schedule {
+    /// The ID of the schedule, numbered in the order the schedules were created, starting from one.
+    pub id: u64,
+    /// The repeated transaction.
+    pub transaction: ScheduledTransaction,
+    /// The number of days between the transaction's runs.
+    pub interval_days: u64,
+    /// The time of the transaction's next run, in seconds since the Unix epoch.
+    pub next_run_at: u64,
+    /// The note attached to every run of the transaction, if any.
+    pub memo: Option<String>,
+    /// Whether the schedule was cancelled, which keeps its record but stops its runs.
+    pub is_cancelled: bool,
+}
+impl Schedule {
+    /// Checks whether the transaction's next run is due by a time, in seconds since the Unix epoch.
+    pub fn is_due_by(&self, timestamp: u64) -> bool {
+        !self.is_cancelled && self.next_run_at <= timestamp
+    }
+
+    /// Moves the transaction's next run an interval later.
+    pub fn advance(&mut self) {
+        self.next_run_at += self.interval_days * SECONDS_PER_DAY;
+    }
+}
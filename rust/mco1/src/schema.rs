@@ -0,0 +1,563 @@
+//! The versioning of the persisted data's format and the migrations between versions.
+//!
+//! Every persisted journal entry and snapshot is stamped with the [`SCHEMA_VERSION`] it was saved with. Older data is
+//! migrated step by step to the current version before it is deserialized, so that it keeps loading as the bank's
+//! types grow new fields.
+
+use std::io;
+
+use rust_decimal::Decimal;
+use serde_json::{Map, Value};
+
+use crate::{
+    account::{self, AccountType},
+    currency::{self, MinorAmount, RoundingPolicy},
+    iso4217,
+};
+
+/// The current version of the persisted data's format.
+pub const SCHEMA_VERSION: u32 = 29;
+
+/// A step migrating persisted data from one version to the next.
+type Migration = fn(&mut Map<String, Value>);
+
+/// The steps migrating a snapshot, each from the version of its index to the next.
+const SNAPSHOT_MIGRATIONS: [Migration; SCHEMA_VERSION as usize] = [
+    // Version 0 is the unversioned format, which only lacks the version stamp.
+    |_| {},
+    // Version 1 lacks the accounts' numbers, which are given in the order the accounts were registered.
+    |snapshot| {
+        if let Some(Value::Array(accounts)) = snapshot.get_mut("accounts") {
+            for (i, account) in accounts.iter_mut().enumerate() {
+                if let Some(fields) = account.as_object_mut() {
+                    fields.insert(
+                        String::from("number"),
+                        Value::from(account::generate_number(i as u64 + 1)),
+                    );
+                }
+            }
+        }
+    },
+    // Version 2 lacks the accounts' types and registration times, so they are treated as long-opened savings.
+    |snapshot| {
+        if let Some(Value::Array(accounts)) = snapshot.get_mut("accounts") {
+            for fields in accounts.iter_mut().filter_map(Value::as_object_mut) {
+                fields.insert(String::from("account_type"), Value::from("savings"));
+                fields.insert(String::from("opened_at"), Value::from(0));
+            }
+        }
+    },
+    // Version 3 lacks the accounts' PINs, which are left unset.
+    |snapshot| {
+        if let Some(Value::Array(accounts)) = snapshot.get_mut("accounts") {
+            for fields in accounts.iter_mut().filter_map(Value::as_object_mut) {
+                fields.insert(String::from("pin_hash"), Value::from(""));
+            }
+        }
+    },
+    // Version 4 lacks the accounts' closure, which didn't exist yet.
+    |snapshot| {
+        if let Some(Value::Array(accounts)) = snapshot.get_mut("accounts") {
+            for fields in accounts.iter_mut().filter_map(Value::as_object_mut) {
+                fields.insert(String::from("is_closed"), Value::from(false));
+            }
+        }
+    },
+    // Version 5 lacks the accounts' owners, who were only the accounts' namesakes.
+    |snapshot| {
+        if let Some(Value::Array(accounts)) = snapshot.get_mut("accounts") {
+            for fields in accounts.iter_mut().filter_map(Value::as_object_mut) {
+                let name = fields.get("name").cloned().unwrap_or_default();
+
+                fields.insert(String::from("owners"), Value::Array(vec![name]));
+            }
+        }
+    },
+    // Version 6 lacks the accounts' contact details, which are left empty.
+    |snapshot| {
+        if let Some(Value::Array(accounts)) = snapshot.get_mut("accounts") {
+            for fields in accounts.iter_mut().filter_map(Value::as_object_mut) {
+                fields.insert(String::from("contact"), empty_contact());
+            }
+        }
+    },
+    // Version 7 lacks the accounts' freezing, which didn't exist yet.
+    |snapshot| {
+        if let Some(Value::Array(accounts)) = snapshot.get_mut("accounts") {
+            for fields in accounts.iter_mut().filter_map(Value::as_object_mut) {
+                fields.insert(String::from("is_frozen"), Value::from(false));
+            }
+        }
+    },
+    // Version 8 lacks the accounts' overdraft limits, which didn't exist yet.
+    |snapshot| {
+        if let Some(Value::Array(accounts)) = snapshot.get_mut("accounts") {
+            for fields in accounts.iter_mut().filter_map(Value::as_object_mut) {
+                fields.insert(String::from("overdraft_limit"), Value::from(0.0));
+            }
+        }
+    },
+    // Version 9 lacks the minimum balances of the types of accounts, which weren't enforced yet.
+    |snapshot| {
+        let minimum = serde_json::json!({ "amount": 0.0, "breach_fee": null, "monthly_fee": 0.0 });
+
+        snapshot.insert(
+            String::from("minimum_balances"),
+            Value::Array(vec![minimum; account::AccountType::ALL.len()]),
+        );
+    },
+    // Version 10 lacks the accounts' histories, which are left empty.
+    |snapshot| {
+        if let Some(Value::Array(accounts)) = snapshot.get_mut("accounts") {
+            for fields in accounts.iter_mut().filter_map(Value::as_object_mut) {
+                fields.insert(String::from("history"), Value::Array(Vec::new()));
+            }
+        }
+    },
+    // Version 11 lacks the reference numbers and memos of the accounts' histories, which are left empty.
+    |snapshot| {
+        if let Some(Value::Array(accounts)) = snapshot.get_mut("accounts") {
+            for account in accounts.iter_mut() {
+                if let Some(Value::Array(history)) = account.get_mut("history") {
+                    for fields in history.iter_mut().filter_map(Value::as_object_mut) {
+                        fields.insert(String::from("reference"), Value::from(""));
+                        fields.insert(String::from("memo"), Value::Null);
+                    }
+                }
+            }
+        }
+    },
+    // Version 12 lacks the bank's revenue from fees, which weren't tracked yet.
+    |snapshot| {
+        snapshot.insert(String::from("revenue"), Value::Object(Map::new()));
+    },
+    // Version 13 lacks the accounts' daily withdrawal limits and totals, which weren't tracked yet.
+    |snapshot| {
+        if let Some(Value::Array(accounts)) = snapshot.get_mut("accounts") {
+            for fields in accounts.iter_mut().filter_map(Value::as_object_mut) {
+                fields.insert(String::from("daily_withdrawal_limit"), Value::Null);
+                fields.insert(
+                    String::from("daily_withdrawals"),
+                    serde_json::json!({ "day": 0, "amount": 0.0 }),
+                );
+            }
+        }
+    },
+    // Version 14 lacks the scheduled transactions, which didn't exist yet.
+    |snapshot| {
+        snapshot.insert(String::from("schedules"), Value::Array(Vec::new()));
+    },
+    // Version 15 lacks the transactions held for approval, which couldn't be held yet.
+    |snapshot| {
+        snapshot.insert(String::from("pending"), Value::Array(Vec::new()));
+    },
+    // Version 16 lacks the links between the transactions in the accounts' histories and their reversals.
+    |snapshot| {
+        if let Some(Value::Array(accounts)) = snapshot.get_mut("accounts") {
+            for account in accounts.iter_mut() {
+                if let Some(Value::Array(history)) = account.get_mut("history") {
+                    for fields in history.iter_mut().filter_map(Value::as_object_mut) {
+                        fields.insert(String::from("reversal_of"), Value::Null);
+                        fields.insert(String::from("reversed_by"), Value::Null);
+                    }
+                }
+            }
+        }
+    },
+    // Version 17 lacks the idempotency keys of the operations performed, which couldn't be given yet.
+    |snapshot| {
+        snapshot.insert(String::from("idempotency_keys"), Value::Object(Map::new()));
+    },
+    // Version 18 lacks the currencies registered at runtime, which couldn't be registered yet.
+    |snapshot| {
+        snapshot.insert(String::from("currencies"), Value::Array(Vec::new()));
+    },
+    // Version 19 lacks the history of the exchange rates, of which only the current ones were kept.
+    |snapshot| {
+        snapshot.insert(String::from("rate_history"), Value::Object(Map::new()));
+    },
+    // Version 20 lacks the spreads of the exchange rates and their income, which weren't charged yet.
+    |snapshot| {
+        snapshot.insert(String::from("spreads"), Value::Object(Map::new()));
+        snapshot.insert(String::from("spread_income"), Value::Object(Map::new()));
+    },
+    // Version 21 lacks the registered currencies' minor units, which were always those listed in ISO 4217, if any.
+    |snapshot| {
+        if let Some(Value::Array(currencies)) = snapshot.get_mut("currencies") {
+            for fields in currencies.iter_mut().filter_map(Value::as_object_mut) {
+                add_minor_units(fields);
+            }
+        }
+    },
+    // Version 22 lacks the alerts on the exchange rates, which couldn't be added yet.
+    |snapshot| {
+        snapshot.insert(String::from("alerts"), Value::Array(Vec::new()));
+    },
+    // Version 23 lacks the accounts' interest rates, which were always those of their types.
+    |snapshot| {
+        if let Some(Value::Array(accounts)) = snapshot.get_mut("accounts") {
+            for fields in accounts.iter_mut().filter_map(Value::as_object_mut) {
+                add_interest_rate(fields);
+            }
+        }
+    },
+    // Version 24 lacks how often the accounts' interest compounds, which was always daily.
+    |snapshot| {
+        if let Some(Value::Array(accounts)) = snapshot.get_mut("accounts") {
+            for fields in accounts.iter_mut().filter_map(Value::as_object_mut) {
+                fields.insert(String::from("compounding"), Value::from("daily"));
+            }
+        }
+    },
+    // Version 25 lacks when the accounts' interest was last posted, which never happened before they were opened.
+    |snapshot| {
+        if let Some(Value::Array(accounts)) = snapshot.get_mut("accounts") {
+            for fields in accounts.iter_mut().filter_map(Value::as_object_mut) {
+                let opened_at = fields.get("opened_at").cloned().unwrap_or(Value::from(0));
+
+                fields.insert(String::from("interest_posted_at"), opened_at);
+            }
+        }
+    },
+    // Version 26 only differs in the journal entries, whose interest postings' taxes are given by their own migration.
+    |_| {},
+    // Version 27 keeps the accounts' balances as decimal amounts, which are turned into their currencies' minor units.
+    |snapshot| {
+        if let Some(Value::Array(accounts)) = snapshot.get_mut("accounts") {
+            for fields in accounts.iter_mut().filter_map(Value::as_object_mut) {
+                to_minor_amount(fields);
+            }
+        }
+    },
+    // Version 28 lacks the balances that the accounts' histories start from, which are the whole balances of the
+    // accounts whose histories were left empty by version 10 and zero for the others.
+    |snapshot| {
+        if let Some(Value::Array(accounts)) = snapshot.get_mut("accounts") {
+            for fields in accounts.iter_mut().filter_map(Value::as_object_mut) {
+                add_opening_balance(fields);
+            }
+        }
+    },
+];
+/// The steps migrating a journal entry, each from the version of its index to the next.
+const ENTRY_MIGRATIONS: [Migration; SCHEMA_VERSION as usize] = [
+    // Version 0 is the unversioned format, which only lacks the version stamp.
+    |_| {},
+    // Version 1 only differs in the snapshots within, whose accounts' numbers are given by their own migration.
+    |_| {},
+    // Version 2 lacks the registered accounts' types, which were all savings, and the withdrawals' penalties.
+    |entry| match entry.get("type").and_then(Value::as_str) {
+        Some("register") => {
+            entry.insert(String::from("account_type"), Value::from("savings"));
+        }
+        Some("withdrawal") => {
+            entry.insert(String::from("penalty"), Value::from(0.0));
+        }
+        _ => {}
+    },
+    // Version 3 lacks the registered accounts' PINs, which are left unset.
+    |entry| {
+        if entry.get("type").and_then(Value::as_str) == Some("register") {
+            entry.insert(String::from("pin_hash"), Value::from(""));
+        }
+    },
+    // Version 4 only differs in the snapshots within, whose accounts' closure is given by its own migration.
+    |_| {},
+    // Version 5 lacks the registered accounts' owners, who were only the accounts' namesakes.
+    |entry| {
+        if entry.get("type").and_then(Value::as_str) == Some("register") {
+            let name = entry.get("account").cloned().unwrap_or_default();
+
+            entry.insert(String::from("owners"), Value::Array(vec![name]));
+        }
+    },
+    // Version 6 lacks the registered accounts' contact details, which are left empty.
+    |entry| {
+        if entry.get("type").and_then(Value::as_str) == Some("register") {
+            entry.insert(String::from("contact"), empty_contact());
+        }
+    },
+    // Version 7 only differs in the snapshots within, whose accounts' freezing is given by its own migration.
+    |_| {},
+    // Version 8 only differs in the snapshots within, whose accounts' overdraft limits are given by their own
+    // migration.
+    |_| {},
+    // Version 9 only differs in the snapshots within, whose minimum balances are given by their own migration.
+    |_| {},
+    // Version 10 only differs in the snapshots within, whose accounts' histories are given by their own migration.
+    |_| {},
+    // Version 11 lacks the memos of the deposits, withdrawals, and transfers, including the undone ones.
+    |entry| {
+        add_empty_memo(entry);
+
+        if let Some(Value::Object(transaction)) = entry.get_mut("transaction") {
+            add_empty_memo(transaction);
+        }
+    },
+    // Version 12 lacks the exchanges' fees, which weren't charged yet.
+    |entry| {
+        if entry.get("type").and_then(Value::as_str) == Some("exchange") {
+            entry.insert(String::from("fee"), Value::from(0.0));
+        }
+    },
+    // Version 13 only differs in the snapshots within, whose accounts' daily withdrawals are given by their own
+    // migration.
+    |_| {},
+    // Version 14 only differs in the snapshots within, whose scheduled transactions are given by their own migration.
+    |_| {},
+    // Version 15 only differs in the snapshots within, whose held transactions are given by their own migration.
+    |_| {},
+    // Version 16 lacks the reference numbers of the undone transactions, which weren't recorded.
+    |entry| {
+        if entry.get("type").and_then(Value::as_str) == Some("undo") {
+            entry.insert(String::from("reference"), Value::Null);
+        }
+    },
+    // Version 17 lacks the idempotency keys of the transactions, which couldn't be given yet.
+    |entry| {
+        entry.insert(String::from("idempotency_key"), Value::Null);
+    },
+    // Version 18 only differs in the snapshots within, whose registered currencies are given by their own migration.
+    |_| {},
+    // Version 19 only differs in the snapshots within, whose rate history is given by its own migration.
+    |_| {},
+    // Version 20 lacks the exchanges' income from the spreads, which weren't charged yet.
+    |entry| {
+        if entry.get("type").and_then(Value::as_str) == Some("exchange") {
+            entry.insert(String::from("spread"), Value::from(0.0));
+        }
+    },
+    // Version 21 lacks the registered currencies' minor units, which were always those listed in ISO 4217, if any.
+    |entry| {
+        if entry.get("type").and_then(Value::as_str) == Some("add_currency")
+            && let Some(Value::Object(fields)) = entry.get_mut("currency")
+        {
+            add_minor_units(fields);
+        }
+    },
+    // Version 22 only differs in the snapshots within, whose rate alerts are given by their own migration.
+    |_| {},
+    // Version 23 lacks the registered accounts' interest rates, which were always those of their types.
+    |entry| {
+        if entry.get("type").and_then(Value::as_str) == Some("register") {
+            add_interest_rate(entry);
+        }
+    },
+    // Version 24 only differs in the snapshots within, whose accounts' compounding is given by its own migration.
+    |_| {},
+    // Version 25 only differs in the snapshots within, whose accounts' interest postings are given by their own
+    // migration.
+    |_| {},
+    // Version 26 lacks the taxes withheld from the interest postings, which weren't withheld yet.
+    |entry| {
+        if entry.get("type").and_then(Value::as_str) == Some("post_interest") {
+            entry.insert(String::from("tax"), Value::from(0.0));
+        }
+    },
+    // Version 27 only differs in the snapshots within, whose accounts' balances are turned into minor units by their
+    // own migration.
+    |_| {},
+    // Version 28 only differs in the snapshots within, whose accounts' opening balances are given by their own
+    // migration.
+    |_| {},
+];
+
+/// Returns the persisted form of empty contact details.
+fn empty_contact() -> Value {
+    serde_json::json!({ "email": null, "phone": null })
+}
+
+/// Adds an empty memo to a persisted deposit, withdrawal, or transfer.
+fn add_empty_memo(transaction: &mut Map<String, Value>) {
+    if let Some("deposit" | "withdrawal" | "transfer") = transaction.get("type").and_then(Value::as_str) {
+        transaction.insert(String::from("memo"), Value::Null);
+    }
+}
+
+/// Adds the interest rate of a persisted account's type to the account or its registration.
+fn add_interest_rate(account: &mut Map<String, Value>) {
+    let interest_rate = account
+        .get("account_type")
+        .and_then(|t| serde_json::from_value::<AccountType>(t.clone()).ok())
+        .unwrap_or_default()
+        .interest_rate();
+
+    account.insert(
+        String::from("interest_rate"),
+        serde_json::to_value(interest_rate).unwrap_or_default(),
+    );
+}
+
+/// Adds the minor units listed in ISO 4217 for a persisted registered currency, or the default ones.
+fn add_minor_units(currency: &mut Map<String, Value>) {
+    let minor_units = currency
+        .get("code")
+        .and_then(Value::as_str)
+        .and_then(iso4217::find_by_code)
+        .map_or(currency::DEFAULT_MINOR_UNITS, |c| c.minor_units);
+
+    currency.insert(String::from("minor_units"), Value::from(minor_units));
+}
+
+/// Turns a persisted account's decimal balance into the minor units of its currency, rounding any finer digits half up.
+fn to_minor_amount(account: &mut Map<String, Value>) {
+    let balance = account
+        .get("balance")
+        .cloned()
+        .and_then(|b| serde_json::from_value::<Decimal>(b).ok())
+        .unwrap_or_default();
+    let scale = account
+        .get("currency")
+        .and_then(Value::as_str)
+        .and_then(currency::minor_units)
+        .unwrap_or(currency::DEFAULT_MINOR_UNITS);
+
+    account.insert(
+        String::from("balance"),
+        serde_json::to_value(MinorAmount::from_decimal(balance, scale, RoundingPolicy::HalfUp)).unwrap_or_default(),
+    );
+}
+
+/// Adds the balance that a persisted account's history starts from, which is its whole balance if it has no history.
+fn add_opening_balance(account: &mut Map<String, Value>) {
+    let is_unrecorded = account
+        .get("history")
+        .and_then(Value::as_array)
+        .is_none_or(Vec::is_empty);
+    let opening_balance = account
+        .get("balance")
+        .cloned()
+        .and_then(|b| serde_json::from_value::<MinorAmount>(b).ok())
+        .filter(|_| is_unrecorded)
+        .map_or(Decimal::ZERO, MinorAmount::amount);
+
+    account.insert(
+        String::from("opening_balance"),
+        serde_json::to_value(opening_balance).unwrap_or_default(),
+    );
+}
+
+/// Migrates a persisted snapshot to the current version.
+pub fn migrate_snapshot(value: &mut Value) -> io::Result<()> {
+    upgrade(value, &SNAPSHOT_MIGRATIONS)
+}
+
+/// Migrates a persisted journal entry, including any snapshot within it, to the current version.
+pub fn migrate_entry(value: &mut Value) -> io::Result<()> {
+    upgrade(value, &ENTRY_MIGRATIONS)?;
+
+    if let Some(snapshot) = value.get_mut("snapshot") {
+        migrate_snapshot(snapshot)?;
+    }
+
+    Ok(())
+}
+
+/// Applies every migration step from the persisted data's version, then stamps it with the current version.
+///
+/// Data without a version stamp is treated as version 0. Data from a newer version of the app is rejected.
+fn upgrade(value: &mut Value, migrations: &[Migration]) -> io::Result<()> {
+    let object = value
+        .as_object_mut()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "The saved data must be a JSON object..."))?;
+    let version = object.get("version").and_then(Value::as_u64).unwrap_or(0) as usize;
+
+    if version > migrations.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("The saved data is from a newer version ({version}) of the app..."),
+        ));
+    }
+
+    for migrate in &migrations[version..] {
+        migrate(object);
+    }
+
+    object.insert(String::from("version"), Value::from(SCHEMA_VERSION));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::{
+        bank::{Bank, Snapshot},
+        history::RecordKind,
+    };
+
+    /// A journal saved with version 1 of the format, which rolls the bank back to a snapshot from the same version
+    /// before registering an account, transacting with it, and updating an exchange rate.
+    const V1_JOURNAL: &str = r#"{"version":1,"timestamp":100,"type":"restore","snapshot":{"version":1,"accounts":[{"name":"Ana","balance":250.75,"currency":"PHP"},{"name":"Ben","balance":1000.6,"currency":"JPY"}],"exchange_rates":{"USD":56.25,"JPY":0.38}}}
+{"version":1,"timestamp":200,"type":"register","account":"Cy"}
+{"version":1,"timestamp":300,"type":"deposit","account":"Cy","amount":100.5,"currency":"PHP","converted_amount":100.5}
+{"version":1,"timestamp":400,"type":"withdrawal","account":"Cy","amount":40.25,"currency":"PHP","converted_amount":40.25}
+{"version":1,"timestamp":500,"type":"rate_update","currency":"USD","rate":57.5}
+"#;
+
+    #[test]
+    fn migrates_v1_journal_and_snapshot() {
+        let path = env::temp_dir().join(format!("mcos-schema-v1-{}.jsonl", process::id()));
+
+        fs::write(&path, V1_JOURNAL).unwrap();
+
+        let bank = Bank::from_journal(&path).unwrap();
+
+        fs::remove_file(&path).unwrap();
+
+        let ana = bank.account("Ana").unwrap();
+        let ben = bank.account("Ben").unwrap();
+        let cy = bank.account("Cy").unwrap();
+
+        assert_eq!(ana.number, account::generate_number(1));
+        assert_eq!(ben.number, account::generate_number(2));
+        assert_eq!(ana.balance.amount(), dec!(250.75));
+        assert_eq!(ben.balance.amount(), dec!(1001));
+        assert_eq!(cy.balance.amount(), dec!(60.25));
+
+        assert!(ana.history.is_empty());
+        assert_eq!(ana.opening_balance, dec!(250.75));
+        assert_eq!(ben.opening_balance, dec!(1001));
+        assert_eq!(cy.opening_balance, Decimal::ZERO);
+        assert_eq!(
+            cy.history
+                .iter()
+                .map(|r| (r.kind, r.amount, r.balance))
+                .collect::<Vec<_>>(),
+            [
+                (RecordKind::Deposit, dec!(100.5), dec!(100.5)),
+                (RecordKind::Withdrawal, dec!(40.25), dec!(60.25)),
+            ]
+        );
+
+        assert_eq!(bank.exchange_rates().get("USD"), Some(&dec!(57.5)));
+        assert_eq!(bank.exchange_rates().get("JPY"), Some(&dec!(0.38)));
+        assert_eq!(bank.verify(), Vec::new());
+    }
+
+    #[test]
+    fn migrates_v1_snapshot() {
+        let mut value = serde_json::json!({
+            "version": 1,
+            "accounts": [{ "name": "Ana", "balance": 250.75, "currency": "PHP" }],
+            "exchange_rates": { "USD": 56.25 },
+        });
+
+        migrate_snapshot(&mut value).unwrap();
+
+        let snapshot = serde_json::from_value::<Snapshot>(value).unwrap();
+        let ana = &snapshot.accounts[0];
+
+        assert_eq!(snapshot.version, SCHEMA_VERSION);
+        assert_eq!(ana.balance.amount(), dec!(250.75));
+        assert_eq!(ana.opening_balance, dec!(250.75));
+        assert_eq!(ana.owners, [String::from("Ana")]);
+        assert_eq!(ana.account_type, AccountType::Savings);
+        assert_eq!(ana.interest_rate, AccountType::Savings.interest_rate());
+        assert_eq!(snapshot.exchange_rates.get("USD"), Some(&dec!(56.25)));
+    }
+}
@@ -0,0 +1,585 @@
+//! The HTTP server exposing the bank as a JSON API, which performs the same transactions as the non-interactive
+//! commands and responds with the same results as their JSON format.
+//!
+//! The PIN of the account a request is made on is sent in the `X-PIN` header, except when registering the account,
+//! and an idempotency key can be sent in the `Idempotency-Key` header so that retrying a request doesn't repeat its
+//! transaction. With GraphQL, the bank can also be queried at `/graphql`, which serves an explorer of the schema when
+//! opened in a browser. With OpenAPI, the API describes itself at `/openapi.json`, which can be browsed and tried at
+//! `/swagger-ui`.
+
+use std::{
+    error::Error,
+    io,
+    net::SocketAddr,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use banking_and_currency_app::{
+    account::{AccountOrder, AccountType},
+    bank::{Bank, BankError},
+    currency::BASE_CURRENCY,
+    interest::{Compounding, InterestMode},
+    profile::Profile,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+#[cfg(feature = "openapi")]
+use utoipa::OpenApi;
+#[cfg(feature = "openapi")]
+use utoipa_swagger_ui::SwaggerUi;
+
+#[cfg(feature = "graphql")]
+use crate::graphql;
+use crate::{
+    cli::{Command, parse_currency},
+    execute_once,
+    i18n::{self, fill, messages},
+    output::Outcome,
+    style,
+};
+
+/// The header carrying the PIN of the account a request is made on.
+const PIN_HEADER: &str = "x-pin";
+
+/// The header carrying the key that identifies a request's transaction, so that retrying it doesn't repeat it.
+const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// The state shared by the requests.
+pub struct Server {
+    /// The bank that the transactions are performed on, one at a time.
+    bank: Mutex<Bank>,
+    /// The profile whose bank is served.
+    profile: Profile,
+}
+impl Server {
+    /// Performs a transaction, only once for an idempotency key if one is given, returning its results.
+    ///
+    /// The transaction waits for the bank and writes its journal on a thread meant for blocking, instead of holding up
+    /// the other requests handled by the same worker.
+    pub async fn perform(
+        self: &Arc<Self>,
+        command: Command,
+        idempotency_key: Option<String>,
+    ) -> Result<Outcome, ApiError> {
+        let server = Arc::clone(self);
+
+        tokio::task::spawn_blocking(move || {
+            let mut bank = server.bank.lock().unwrap_or_else(PoisonError::into_inner);
+
+            execute_once(command, idempotency_key, &mut bank, &server.profile).map_err(ApiError::from)
+        })
+        .await
+        .map_err(|err| ApiError {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: err.to_string(),
+        })?
+    }
+
+    /// Performs a request's transaction, returning its results.
+    async fn execute(self: &Arc<Self>, command: Command, headers: &HeaderMap) -> Result<Json<Outcome>, ApiError> {
+        Ok(Json(
+            self.perform(command, header(headers, IDEMPOTENCY_KEY_HEADER)).await?,
+        ))
+    }
+}
+
+/// The state of the server, shared by the requests.
+type Shared = State<Arc<Server>>;
+
+/// The OpenAPI document describing the JSON API.
+#[cfg(feature = "openapi")]
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "MCOS",
+        description = "The JSON API of the banking and currency exchange app, which responds with the same results as \
+                       the JSON format of its non-interactive commands."
+    ),
+    paths(register, accounts, details, deposit, withdraw, transfer, interest, exchange, rates)
+)]
+struct ApiDoc;
+
+/// A failed request, responded to with a status fitting its error and the error's message.
+pub struct ApiError {
+    /// The status of the response.
+    status: StatusCode,
+    /// The message of the error, in the chosen language.
+    pub message: String,
+}
+impl From<Box<dyn Error>> for ApiError {
+    fn from(err: Box<dyn Error>) -> Self {
+        let status = match err.downcast_ref::<BankError>() {
+            Some(
+                BankError::AccountNotFound
+                | BankError::ScheduleNotFound
+                | BankError::AlertNotFound
+                | BankError::PendingNotFound
+                | BankError::TransactionNotFound,
+            ) => StatusCode::NOT_FOUND,
+            Some(BankError::IncorrectPin) => StatusCode::UNAUTHORIZED,
+            Some(BankError::DuplicateAccount | BankError::DuplicateCurrency) => StatusCode::CONFLICT,
+            Some(BankError::Journal(_) | BankError::Backup(_)) => StatusCode::INTERNAL_SERVER_ERROR,
+            _ if err.is::<io::Error>() => StatusCode::INTERNAL_SERVER_ERROR,
+            _ => StatusCode::UNPROCESSABLE_ENTITY,
+        };
+
+        ApiError {
+            status,
+            message: i18n::describe(&err),
+        }
+    }
+}
+impl From<&str> for ApiError {
+    fn from(message: &str) -> Self {
+        ApiError {
+            status: StatusCode::UNPROCESSABLE_ENTITY,
+            message: message.to_string(),
+        }
+    }
+}
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status, Json(ErrorBody { error: self.message })).into_response()
+    }
+}
+
+/// The body of a response to a failed request.
+#[derive(Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+struct ErrorBody {
+    /// The message of the error, in the chosen language.
+    error: String,
+}
+
+/// The body of a request to register an account.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+struct NewAccount {
+    /// The name of the account.
+    account: String,
+    /// The names of the joint owners of the account, besides its namesake.
+    #[serde(default)]
+    joint_owners: Vec<String>,
+    /// The type of the account, savings if not given.
+    #[serde(default)]
+    account_type: AccountType,
+    /// The annual interest rate percentage earned by the account, instead of its type's.
+    interest_rate: Option<Decimal>,
+    /// The email address of the account's owners.
+    email: Option<String>,
+    /// The phone number of the account's owners.
+    phone: Option<String>,
+    /// The PIN protecting the account, made of 4 to 6 digits.
+    pin: String,
+}
+
+/// The query of a request to list the accounts.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::IntoParams), into_params(parameter_in = Query))]
+struct AccountQuery {
+    /// The order to list the accounts in, either `name` or `balance`.
+    sort: Option<String>,
+    /// Only lists the accounts with at least this balance.
+    min_balance: Option<Decimal>,
+    /// Only lists the accounts of this type.
+    #[serde(rename = "type")]
+    account_type: Option<AccountType>,
+}
+
+/// The body of a request to deposit to or withdraw from an account.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+struct Movement {
+    /// The amount to deposit or withdraw.
+    amount: Decimal,
+    /// The code or name of the currency of the amount, Philippine Pesos if not given.
+    currency: Option<String>,
+    /// A note to attach to the transaction.
+    memo: Option<String>,
+}
+
+/// The body of a request to transfer from an account.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+struct Transfer {
+    /// The name or number of the account to transfer to.
+    to: String,
+    /// The amount to transfer, in the currency of the account to transfer from.
+    amount: Decimal,
+    /// A note to attach to the transfer.
+    memo: Option<String>,
+}
+
+/// The body of a request to exchange an amount between currencies.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+struct Exchange {
+    /// The code or name of the currency to exchange from.
+    from: String,
+    /// The code or name of the currency to exchange to.
+    to: String,
+    /// The amount to exchange.
+    amount: Decimal,
+    /// Only shows the breakdown of the exchange, without making it.
+    #[serde(default)]
+    dry_run: bool,
+}
+
+/// The query of a request to calculate the interest earned by an account.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::IntoParams), into_params(parameter_in = Query))]
+struct InterestQuery {
+    /// The total number of days to calculate for.
+    days: u32,
+    /// The way interest accrues, instead of the configured one.
+    mode: Option<InterestMode>,
+    /// How often the interest is added to the balance, instead of the account's compounding frequency.
+    compounding: Option<Compounding>,
+    /// An amount planned to be deposited every interval.
+    deposit: Option<Decimal>,
+    /// The number of days between the planned deposits.
+    every: Option<u64>,
+}
+
+/// Serves a profile's bank over HTTP at an address, until the process is interrupted.
+pub fn run(bank: Bank, profile: Profile, addr: SocketAddr) -> io::Result<()> {
+    let server = Arc::new(Server {
+        bank: Mutex::new(bank),
+        profile,
+    });
+    let app = Router::new()
+        .route("/accounts", get(accounts).post(register))
+        .route("/accounts/{account}", get(details))
+        .route("/accounts/{account}/deposits", post(deposit))
+        .route("/accounts/{account}/withdrawals", post(withdraw))
+        .route("/accounts/{account}/transfers", post(transfer))
+        .route("/accounts/{account}/interest", get(interest))
+        .route("/exchanges", post(exchange))
+        .route("/rates", get(rates))
+        .with_state(server.clone());
+    #[cfg(feature = "openapi")]
+    let app = app.merge(SwaggerUi::new("/swagger-ui").url("/openapi.json", ApiDoc::openapi()));
+    #[cfg(feature = "graphql")]
+    let app = app.route(
+        "/graphql",
+        get(graphql::graphiql).post_service(async_graphql_axum::GraphQL::new(graphql::schema(server))),
+    );
+
+    tokio::runtime::Runtime::new()?.block_on(async {
+        let listener = tokio::net::TcpListener::bind(addr).await?;
+        let addr = listener.local_addr()?;
+
+        info!(%addr, "serving the bank");
+        eprintln!("{}", style::header(fill(messages().serving, &[&addr])));
+
+        axum::serve(listener, app).await
+    })
+}
+
+/// Returns the value of a request's header, if it was sent.
+fn header(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Parses the code or name of a currency, Philippine Pesos if not given.
+fn currency(query: Option<String>) -> Result<String, ApiError> {
+    match query {
+        Some(query) => parse_currency(&query).map_err(|err| ApiError::from(err.as_str())),
+        None => Ok(BASE_CURRENCY.to_string()),
+    }
+}
+
+/// Registers an account.
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/accounts",
+    tag = "accounts",
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Keeps a retry from repeating the transaction."),
+    ),
+    request_body = NewAccount,
+    responses(
+        (status = 201, description = "The account was registered.", body = Outcome),
+        (status = "4XX", description = "The transaction was rejected.", body = ErrorBody),
+        (status = 500, description = "The bank's journal couldn't be read or written.", body = ErrorBody),
+    ),
+))]
+async fn register(
+    State(server): Shared,
+    headers: HeaderMap,
+    Json(body): Json<NewAccount>,
+) -> Result<(StatusCode, Json<Outcome>), ApiError> {
+    let command = Command::Register {
+        account: body.account,
+        joint_owners: body.joint_owners,
+        account_type: body.account_type,
+        interest_rate: body.interest_rate,
+        email: body.email,
+        phone: body.phone,
+        pin: body.pin,
+    };
+
+    Ok((StatusCode::CREATED, server.execute(command, &headers).await?))
+}
+
+/// Lists the registered accounts.
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/accounts",
+    tag = "accounts",
+    params(
+        AccountQuery,
+    ),
+    responses(
+        (status = 200, description = "The accounts, in the requested order.", body = Outcome),
+        (status = "4XX", description = "The transaction was rejected.", body = ErrorBody),
+        (status = 500, description = "The bank's journal couldn't be read or written.", body = ErrorBody),
+    ),
+))]
+async fn accounts(
+    State(server): Shared,
+    headers: HeaderMap,
+    Query(query): Query<AccountQuery>,
+) -> Result<Json<Outcome>, ApiError> {
+    let sort = match query.sort {
+        Some(sort) => sort.parse::<AccountOrder>().map_err(|err| ApiError {
+            status: StatusCode::UNPROCESSABLE_ENTITY,
+            message: err,
+        })?,
+        None => AccountOrder::Name,
+    };
+
+    server
+        .execute(
+            Command::Accounts {
+                sort,
+                min_balance: query.min_balance,
+                account_type: query.account_type,
+            },
+            &headers,
+        )
+        .await
+}
+
+/// Shows the details of an account.
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/accounts/{account}",
+    tag = "accounts",
+    params(
+        ("account" = String, Path, description = "The name or number of the account."),
+        ("X-PIN" = Option<String>, Header, description = "The PIN of the account."),
+    ),
+    responses(
+        (status = 200, description = "The account's details.", body = Outcome),
+        (status = "4XX", description = "The transaction was rejected.", body = ErrorBody),
+        (status = 500, description = "The bank's journal couldn't be read or written.", body = ErrorBody),
+    ),
+))]
+async fn details(
+    State(server): Shared,
+    Path(account): Path<String>,
+    headers: HeaderMap,
+) -> Result<Json<Outcome>, ApiError> {
+    let pin = header(&headers, PIN_HEADER);
+
+    server.execute(Command::Details { account, pin }, &headers).await
+}
+
+/// Deposits an amount to an account.
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/accounts/{account}/deposits",
+    tag = "transactions",
+    params(
+        ("account" = String, Path, description = "The name or number of the account."),
+        ("X-PIN" = Option<String>, Header, description = "The PIN of the account."),
+        ("Idempotency-Key" = Option<String>, Header, description = "Keeps a retry from repeating the transaction."),
+    ),
+    request_body = Movement,
+    responses(
+        (status = 200, description = "The deposit was committed.", body = Outcome),
+        (status = "4XX", description = "The transaction was rejected.", body = ErrorBody),
+        (status = 500, description = "The bank's journal couldn't be read or written.", body = ErrorBody),
+    ),
+))]
+async fn deposit(
+    State(server): Shared,
+    Path(account): Path<String>,
+    headers: HeaderMap,
+    Json(body): Json<Movement>,
+) -> Result<Json<Outcome>, ApiError> {
+    let command = Command::Deposit {
+        account,
+        pin: header(&headers, PIN_HEADER),
+        amount: body.amount,
+        currency: currency(body.currency)?,
+        memo: body.memo,
+    };
+
+    server.execute(command, &headers).await
+}
+
+/// Withdraws an amount from an account, holding it for approval instead if it is large enough.
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/accounts/{account}/withdrawals",
+    tag = "transactions",
+    params(
+        ("account" = String, Path, description = "The name or number of the account."),
+        ("X-PIN" = Option<String>, Header, description = "The PIN of the account."),
+        ("Idempotency-Key" = Option<String>, Header, description = "Keeps a retry from repeating the transaction."),
+    ),
+    request_body = Movement,
+    responses(
+        (status = 200, description = "The withdrawal was committed or held for approval.", body = Outcome),
+        (status = "4XX", description = "The transaction was rejected.", body = ErrorBody),
+        (status = 500, description = "The bank's journal couldn't be read or written.", body = ErrorBody),
+    ),
+))]
+async fn withdraw(
+    State(server): Shared,
+    Path(account): Path<String>,
+    headers: HeaderMap,
+    Json(body): Json<Movement>,
+) -> Result<Json<Outcome>, ApiError> {
+    let command = Command::Withdraw {
+        account,
+        pin: header(&headers, PIN_HEADER),
+        amount: body.amount,
+        currency: currency(body.currency)?,
+        memo: body.memo,
+    };
+
+    server.execute(command, &headers).await
+}
+
+/// Transfers an amount from one account to another, holding it for approval instead if it is large enough.
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/accounts/{account}/transfers",
+    tag = "transactions",
+    params(
+        ("account" = String, Path, description = "The name or number of the account."),
+        ("X-PIN" = Option<String>, Header, description = "The PIN of the account."),
+        ("Idempotency-Key" = Option<String>, Header, description = "Keeps a retry from repeating the transaction."),
+    ),
+    request_body = Transfer,
+    responses(
+        (status = 200, description = "The transfer was committed or held for approval.", body = Outcome),
+        (status = "4XX", description = "The transaction was rejected.", body = ErrorBody),
+        (status = 500, description = "The bank's journal couldn't be read or written.", body = ErrorBody),
+    ),
+))]
+async fn transfer(
+    State(server): Shared,
+    Path(account): Path<String>,
+    headers: HeaderMap,
+    Json(body): Json<Transfer>,
+) -> Result<Json<Outcome>, ApiError> {
+    let command = Command::Transfer {
+        account,
+        pin: header(&headers, PIN_HEADER),
+        to: body.to,
+        amount: body.amount,
+        memo: body.memo,
+    };
+
+    server.execute(command, &headers).await
+}
+
+/// Calculates the daily interest earned by an account.
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/accounts/{account}/interest",
+    tag = "accounts",
+    params(
+        ("account" = String, Path, description = "The name or number of the account."),
+        ("X-PIN" = Option<String>, Header, description = "The PIN of the account."),
+        InterestQuery,
+    ),
+    responses(
+        (status = 200, description = "The account's daily interest.", body = Outcome),
+        (status = "4XX", description = "The transaction was rejected.", body = ErrorBody),
+        (status = 500, description = "The bank's journal couldn't be read or written.", body = ErrorBody),
+    ),
+))]
+async fn interest(
+    State(server): Shared,
+    Path(account): Path<String>,
+    headers: HeaderMap,
+    Query(query): Query<InterestQuery>,
+) -> Result<Json<Outcome>, ApiError> {
+    if query.deposit.is_some() != query.every.is_some() || query.every == Some(0) {
+        return Err(messages().invalid_contribution.into());
+    }
+
+    let command = Command::Interest {
+        account,
+        pin: header(&headers, PIN_HEADER),
+        days: query.days,
+        mode: query.mode,
+        compounding: query.compounding,
+        deposit: query.deposit,
+        every: query.every,
+        csv: None,
+    };
+
+    server.execute(command, &headers).await
+}
+
+/// Exchanges an amount from one currency to another.
+#[cfg_attr(feature = "openapi", utoipa::path(
+    post,
+    path = "/exchanges",
+    tag = "currencies",
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Keeps a retry from repeating the transaction."),
+    ),
+    request_body = Exchange,
+    responses(
+        (status = 200, description = "The exchange was made, or only broken down if it is a dry run.", body = Outcome),
+        (status = "4XX", description = "The transaction was rejected.", body = ErrorBody),
+        (status = 500, description = "The bank's journal couldn't be read or written.", body = ErrorBody),
+    ),
+))]
+async fn exchange(
+    State(server): Shared,
+    headers: HeaderMap,
+    Json(body): Json<Exchange>,
+) -> Result<Json<Outcome>, ApiError> {
+    let command = Command::Exchange {
+        from: currency(Some(body.from))?,
+        to: currency(Some(body.to))?,
+        amount: body.amount,
+        dry_run: body.dry_run,
+    };
+
+    server.execute(command, &headers).await
+}
+
+/// Lists the current exchange rates.
+#[cfg_attr(feature = "openapi", utoipa::path(
+    get,
+    path = "/rates",
+    tag = "currencies",
+    responses(
+        (status = 200, description = "The current exchange rates.", body = Outcome),
+        (status = "4XX", description = "The transaction was rejected.", body = ErrorBody),
+        (status = 500, description = "The bank's journal couldn't be read or written.", body = ErrorBody),
+    ),
+))]
+async fn rates(State(server): Shared, headers: HeaderMap) -> Result<Json<Outcome>, ApiError> {
+    server.execute(Command::Rates, &headers).await
+}
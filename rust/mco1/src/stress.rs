@@ -0,0 +1,140 @@
+//! The stress test of the bank, which performs many random transactions on many accounts to check that its state and
+//! journal hold up under load.
+
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use rust_decimal::Decimal;
+use serde::Serialize;
+
+use crate::{
+    account::{AccountType, Contact},
+    bank::{Bank, BankError, Violation},
+    currency::CURRENCIES_CODES,
+    demo::SplitMix64,
+    journal::Journal,
+};
+
+/// The PIN of every stress-tested account.
+const STRESS_PIN: &str = "1234";
+
+/// The results of a stress test.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct StressReport {
+    /// The number of accounts registered.
+    pub account_cnt: usize,
+    /// The number of transactions attempted.
+    pub transaction_cnt: usize,
+    /// The number of transactions that the bank rejected, like withdrawals from accounts without enough balance.
+    pub rejected_cnt: usize,
+    /// How long registering the accounts took, in seconds.
+    pub register_secs: f64,
+    /// How long attempting the transactions took, in seconds.
+    pub transaction_secs: f64,
+    /// The number of transactions attempted per second.
+    pub transactions_per_sec: f64,
+    /// How long replaying the journal took, in seconds.
+    pub replay_secs: f64,
+    /// Whether replaying the journal rebuilt the same state that the transactions left.
+    pub is_replay_consistent: bool,
+    /// The broken invariants found in the state that the transactions left.
+    pub violations: Vec<Violation>,
+}
+
+/// Stress-tests a new bank recording to a new journal file at a path, registering a number of accounts and attempting a
+/// number of random deposits, withdrawals, transfers, and exchange rate updates, generated from a seed.
+///
+/// Any existing file at the path is replaced. No transactions are attempted without accounts to make them on.
+/// Afterwards, the bank's state is checked for broken invariants, and the journal is replayed to check that it rebuilds
+/// the same state.
+pub fn run(path: &Path, account_cnt: usize, transaction_cnt: usize, seed: u64) -> Result<StressReport, BankError> {
+    let mut rng = SplitMix64(seed);
+
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+
+    let mut bank = Bank::replay(Journal::open(path)?)?;
+    let started_at = Instant::now();
+
+    for i in 0..account_cnt {
+        let account_type = if i % 2 == 0 {
+            AccountType::Savings
+        } else {
+            AccountType::Checking
+        };
+
+        bank.register(
+            account_name(i),
+            Vec::new(),
+            account_type,
+            None,
+            Contact::default(),
+            STRESS_PIN,
+        )?;
+    }
+
+    let register_time = started_at.elapsed();
+    let started_at = Instant::now();
+    let transaction_cnt = if account_cnt == 0 { 0 } else { transaction_cnt };
+    let mut rejected_cnt = 0;
+
+    for _ in 0..transaction_cnt {
+        let name = account_name(rng.below(account_cnt as u64) as usize);
+
+        let result = match rng.below(10) {
+            0..=3 => bank.deposit(&name, "PHP", rng.amount(100, 10_000), None).map(drop),
+            4..=6 => bank.withdraw(&name, "PHP", rng.amount(100, 5_000), None).map(drop),
+            7 | 8 => {
+                let recipient = account_name(rng.below(account_cnt as u64) as usize);
+
+                bank.transfer(&name, &recipient, rng.amount(100, 2_000), None).map(drop)
+            }
+            _ => {
+                let code = CURRENCIES_CODES[1 + rng.below(CURRENCIES_CODES.len() as u64 - 1) as usize];
+
+                bank.set_exchange_rate(code, Decimal::new(rng.below(10_000) as i64 + 1, 2))
+            }
+        };
+
+        if result.is_err() {
+            rejected_cnt += 1;
+        }
+    }
+
+    let transaction_time = started_at.elapsed();
+    let violations = bank.verify();
+    let started_at = Instant::now();
+    let replayed = Bank::replay(Journal::open(path)?)?;
+    let replay_time = started_at.elapsed();
+
+    Ok(StressReport {
+        account_cnt,
+        transaction_cnt,
+        rejected_cnt,
+        register_secs: register_time.as_secs_f64(),
+        transaction_secs: transaction_time.as_secs_f64(),
+        transactions_per_sec: per_sec(transaction_cnt, transaction_time),
+        replay_secs: replay_time.as_secs_f64(),
+        is_replay_consistent: replayed.snapshot() == bank.snapshot(),
+        violations,
+    })
+}
+
+/// Returns the name of a stress-tested account by its index.
+fn account_name(idx: usize) -> String {
+    format!("Stress {idx}")
+}
+
+/// Returns how many of something were done per second over a duration.
+fn per_sec(cnt: usize, duration: Duration) -> f64 {
+    if duration.is_zero() {
+        0.0
+    } else {
+        cnt as f64 / duration.as_secs_f64()
+    }
+}
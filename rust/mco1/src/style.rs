@@ -0,0 +1,103 @@
+//! The styling of the CLI's output with colors and emphasis.
+//!
+//! Styling is only applied when the output is a terminal, and can be disabled with the `--no-color` flag or the
+//! [`NO_COLOR`](https://no-color.org) environment variable.
+
+use std::{
+    env, fmt,
+    io::{self, IsTerminal},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Whether the output is styled.
+static IS_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enables styling if the output is a terminal, unless it was disabled by the user.
+pub fn init(no_color: bool) {
+    let is_disabled = no_color || env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+
+    IS_ENABLED.store(!is_disabled && io::stdout().is_terminal(), Ordering::Relaxed);
+}
+
+/// The styles that can be applied to the output.
+#[derive(Clone, Copy)]
+enum Style {
+    /// Bold, for menu headers.
+    Header,
+    /// Red, for errors.
+    Error,
+    /// Green, for increased balances.
+    Increase,
+    /// Red, for decreased balances.
+    Decrease,
+    /// Bold cyan, for currency codes.
+    Code,
+}
+impl Style {
+    /// Returns the style's [SGR](https://en.wikipedia.org/wiki/ANSI_escape_code#SGR) parameters.
+    fn sgr(self) -> &'static str {
+        match self {
+            Style::Header => "1",
+            Style::Error | Style::Decrease => "31",
+            Style::Increase => "32",
+            Style::Code => "1;36",
+        }
+    }
+}
+
+/// A value printed with a style, if styling is enabled.
+pub struct Styled<T> {
+    /// The value to print.
+    value: T,
+    /// The style to print the value with.
+    style: Style,
+}
+impl<T: fmt::Display> fmt::Display for Styled<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if IS_ENABLED.load(Ordering::Relaxed) {
+            write!(f, "\x1b[{}m{}\x1b[0m", self.style.sgr(), self.value)
+        } else {
+            write!(f, "{}", self.value)
+        }
+    }
+}
+
+/// Styles a menu header.
+pub fn header<T: fmt::Display>(value: T) -> Styled<T> {
+    Styled {
+        value,
+        style: Style::Header,
+    }
+}
+
+/// Styles an error message.
+pub fn error<T: fmt::Display>(value: T) -> Styled<T> {
+    Styled {
+        value,
+        style: Style::Error,
+    }
+}
+
+/// Styles a balance that was increased.
+pub fn increase<T: fmt::Display>(value: T) -> Styled<T> {
+    Styled {
+        value,
+        style: Style::Increase,
+    }
+}
+
+/// Styles a balance that was decreased.
+pub fn decrease<T: fmt::Display>(value: T) -> Styled<T> {
+    Styled {
+        value,
+        style: Style::Decrease,
+    }
+}
+
+/// Styles a currency code.
+pub fn code<T: fmt::Display>(value: T) -> Styled<T> {
+    Styled {
+        value,
+        style: Style::Code,
+    }
+}
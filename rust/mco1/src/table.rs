@@ -0,0 +1,87 @@
+//! The rendering of tabular output with automatically sized columns.
+
+use std::fmt;
+
+use crate::style;
+
+/// How a column's cells are aligned.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+/// A table of rows under a header, with each column as wide as its widest cell.
+pub struct Table {
+    /// The titles of the columns.
+    headers: Vec<String>,
+    /// How each column's cells are aligned.
+    alignments: Vec<Align>,
+    /// The cells of each row.
+    rows: Vec<Vec<String>>,
+}
+impl Table {
+    /// Creates a new table with the titles of its columns, which are all left-aligned.
+    pub fn new<I: IntoIterator<Item = S>, S: ToString>(headers: I) -> Table {
+        let headers: Vec<_> = headers.into_iter().map(|h| h.to_string()).collect();
+
+        Table {
+            alignments: vec![Align::Left; headers.len()],
+            headers,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Aligns the cells of a column.
+    pub fn align(mut self, column: usize, alignment: Align) -> Table {
+        self.alignments[column] = alignment;
+
+        self
+    }
+
+    /// Adds a row of cells to the bottom of the table.
+    pub fn push_row<I: IntoIterator<Item = S>, S: ToString>(&mut self, cells: I) {
+        self.rows.push(cells.into_iter().map(|c| c.to_string()).collect());
+    }
+
+    /// Renders the table's lines: the header, a separator, then each row.
+    pub fn lines(&self) -> Vec<String> {
+        let widths: Vec<_> = (0..self.headers.len())
+            .map(|i| {
+                self.rows
+                    .iter()
+                    .filter_map(|r| r.get(i))
+                    .chain([&self.headers[i]])
+                    .map(|c| c.chars().count())
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect();
+        let render = |cells: &[String]| {
+            cells
+                .iter()
+                .zip(&widths)
+                .zip(&self.alignments)
+                .map(|((cell, width), alignment)| match alignment {
+                    Align::Left => format!("{cell:<width$}"),
+                    Align::Right => format!("{cell:>width$}"),
+                })
+                .collect::<Vec<_>>()
+                .join(" | ")
+        };
+
+        let mut lines = vec![
+            style::header(render(&self.headers)).to_string(),
+            widths.iter().map(|w| "-".repeat(*w)).collect::<Vec<_>>().join("-+-"),
+        ];
+
+        lines.extend(self.rows.iter().map(|r| render(r)));
+
+        lines
+    }
+}
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.lines().join("\n"))
+    }
+}
@@ -0,0 +1,123 @@
+//! The TCP server serving the interactive menu, one session per connection, like a minimal online bank without HTTP.
+//!
+//! Each session runs on its own thread with its own console, and every session shares the profile's bank, which is
+//! locked only around each of its calls, never while a session waits on its user. A session ends when its user exits
+//! the menu, disconnects, or leaves a prompt unanswered for too long.
+
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    panic::{self, AssertUnwindSafe},
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use banking_and_currency_app::{bank::Bank, profile::Profile};
+use rust_decimal::Decimal;
+use tracing::{info, warn};
+
+use crate::{
+    console::{self, Console},
+    i18n::{fill, messages},
+    run_session, style,
+};
+
+/// How long a session waits for its user's response before disconnecting them.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// The payload unwinding a session whose user disconnected, which ends it without reporting a panic.
+struct Disconnected;
+
+/// A console reading a session's responses from its connection and writing the output to it.
+struct TcpConsole {
+    /// The connection's buffered incoming half.
+    reader: BufReader<TcpStream>,
+    /// The connection's outgoing half.
+    writer: TcpStream,
+}
+impl TcpConsole {
+    /// Creates a console over a connection, which disconnects its user after they stay idle for too long.
+    fn new(stream: TcpStream) -> io::Result<TcpConsole> {
+        stream.set_read_timeout(Some(IDLE_TIMEOUT))?;
+
+        Ok(TcpConsole {
+            writer: stream.try_clone()?,
+            reader: BufReader::new(stream),
+        })
+    }
+}
+impl Console for TcpConsole {
+    /// Reads a line from the connection, ending the session if the user disconnected or stayed idle for too long.
+    ///
+    /// Responses are neither masked nor completed, since the connection only sends whole lines.
+    fn read_line(&mut self, msg: &str, _: bool, _: bool, _: Vec<String>) -> String {
+        self.write(msg);
+
+        let mut input = String::new();
+
+        match self.reader.read_line(&mut input) {
+            Ok(0) | Err(_) => panic::resume_unwind(Box::new(Disconnected)),
+            Ok(_) => input.trim().to_string(),
+        }
+    }
+
+    /// Writes output to the connection with the CRLF line endings of telnet, ending the session if the user
+    /// disconnected.
+    fn write(&mut self, output: &str) {
+        if self.writer.write_all(output.replace('\n', "\r\n").as_bytes()).is_err() {
+            panic::resume_unwind(Box::new(Disconnected));
+        }
+    }
+}
+
+/// Serves the interactive menu on a profile's bank over TCP at an address, until the process is interrupted.
+///
+/// Withdrawals of at least the large withdrawal amount must be confirmed, like in the local menu.
+pub fn run(bank: Bank, profile: Profile, addr: SocketAddr, large_withdrawal: Decimal) -> io::Result<()> {
+    let listener = TcpListener::bind(addr)?;
+    let bank = Arc::new(Mutex::new(bank));
+
+    info!(%addr, "serving the interactive menu over TCP");
+    eprintln!("{}", style::header(fill(messages().serving_telnet, &[&addr])));
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                let bank = Arc::clone(&bank);
+                let profile = profile.clone();
+
+                thread::spawn(move || serve(stream, &bank, profile, large_withdrawal));
+            }
+            Err(err) => warn!(%err, "failed to accept a connection"),
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs a session of the interactive menu over a connection until its user exits or disconnects.
+fn serve(stream: TcpStream, bank: &Mutex<Bank>, profile: Profile, large_withdrawal: Decimal) {
+    let started = stream
+        .peer_addr()
+        .and_then(|peer| TcpConsole::new(stream).map(|console| (peer, console)));
+    let (peer, console) = match started {
+        Ok(console) => console,
+        Err(err) => {
+            warn!(%err, "failed to start a session");
+
+            return;
+        }
+    };
+
+    info!(%peer, "started a session");
+    console::set_console(console);
+
+    if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| run_session(bank, profile, large_withdrawal, true)))
+        && !payload.is::<Disconnected>()
+    {
+        panic::resume_unwind(payload);
+    }
+
+    info!(%peer, "ended a session");
+}
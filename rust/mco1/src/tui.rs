@@ -0,0 +1,336 @@
+//! The full-screen terminal user interface, an alternative to the scrolling prompt loop.
+
+use std::io;
+
+use banking_and_currency_app::{
+    account::{AccountType, Contact},
+    bank::Bank,
+};
+use ratatui::{
+    DefaultTerminal, Frame,
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, List, ListState, Paragraph, Row, Table},
+};
+use rust_decimal::Decimal;
+use tracing::warn;
+
+use crate::{
+    i18n::{fill, messages},
+    output::{money, sharp_rate_change},
+};
+
+/// The number of transactions that can be performed from the form.
+const FORM_CNT: usize = 4;
+
+/// The pane with the keyboard focus.
+#[derive(Clone, Copy, PartialEq)]
+enum Focus {
+    /// The list of accounts.
+    Accounts,
+    /// The transaction form.
+    Form,
+}
+
+/// The state of the terminal user interface.
+struct App {
+    /// The bank the transactions are performed on.
+    bank: Bank,
+    /// The pane with the keyboard focus.
+    focus: Focus,
+    /// The selection in the list of accounts.
+    accounts: ListState,
+    /// The index of the transaction chosen in the form.
+    form_idx: usize,
+    /// The values inputted into the form's fields.
+    fields: [String; 3],
+    /// The index of the form's field being inputted.
+    field_idx: usize,
+    /// The result of the last transaction, and whether it failed.
+    status: Option<(String, bool)>,
+    /// Whether the user asked to quit.
+    is_quitting: bool,
+}
+impl App {
+    /// Returns the labels of the chosen transaction's fields.
+    fn field_labels(&self) -> Vec<&'static str> {
+        let messages = messages();
+
+        match self.form_idx {
+            0 => vec![messages.tui_account_name, messages.tui_account_type, messages.tui_pin],
+            1 | 2 => vec![messages.tui_currency, messages.tui_amount, messages.tui_pin],
+            _ => vec![messages.tui_currency, messages.tui_exchange_rate],
+        }
+    }
+
+    /// Returns the name of the selected account.
+    fn selected_account(&self) -> Option<String> {
+        let idx = self.accounts.selected()?;
+
+        self.bank.accounts().get(idx).map(|a| a.name.clone())
+    }
+
+    /// Performs the chosen transaction with the inputted values, returning its result.
+    fn submit(&mut self) -> Result<String, String> {
+        let messages = messages();
+
+        let [first, second, pin] = &self.fields;
+        let currency = first.trim().to_uppercase();
+
+        if self.form_idx == 0 {
+            let account_type = match second.trim() {
+                "" => AccountType::default(),
+                tag => tag.parse().map_err(|_| String::from(messages.invalid_account_type))?,
+            };
+
+            self.bank
+                .register(
+                    first.trim().to_string(),
+                    Vec::new(),
+                    account_type,
+                    None,
+                    Contact::default(),
+                    pin.trim(),
+                )
+                .map_err(|e| e.to_string())?;
+
+            return Ok(fill(messages.registered_account, &[&first.trim()]));
+        }
+
+        let value = second
+            .trim()
+            .parse::<Decimal>()
+            .map_err(|_| String::from(messages.invalid_amount))?;
+
+        if self.form_idx == 3 {
+            let change = self.bank.rate_change_warning(&currency, value);
+
+            self.bank
+                .set_exchange_rate(&currency, value)
+                .map_err(|e| e.to_string())?;
+
+            return Ok(match change {
+                Some(change) => format!(
+                    "{} {}",
+                    fill(messages.recorded_rate, &[&currency]),
+                    sharp_rate_change(&currency, change)
+                ),
+                None => fill(messages.recorded_rate, &[&currency]),
+            });
+        }
+
+        let name = self
+            .selected_account()
+            .ok_or_else(|| String::from(messages.tui_select_account))?;
+
+        self.bank.verify_pin(&name, pin.trim()).map_err(|e| e.to_string())?;
+        let receipt = if self.form_idx == 1 {
+            self.bank.deposit(&name, &currency, value, None)
+        } else {
+            self.bank.withdraw(&name, &currency, value, None)
+        }
+        .map_err(|e| e.to_string())?;
+
+        let currency = self
+            .bank
+            .account(&name)
+            .map(|a| a.currency.as_str())
+            .unwrap_or_default();
+
+        Ok(fill(messages.updated_balance, &[&money(receipt.balance, currency)]))
+    }
+
+    /// Updates the state from a pressed key.
+    fn handle_key(&mut self, code: KeyCode) {
+        match (self.focus, code) {
+            (_, KeyCode::Esc) => self.is_quitting = true,
+            (_, KeyCode::Tab) => {
+                self.focus = if self.focus == Focus::Accounts {
+                    Focus::Form
+                } else {
+                    Focus::Accounts
+                };
+            }
+            (Focus::Accounts, KeyCode::Char('q')) => self.is_quitting = true,
+            (Focus::Accounts, KeyCode::Up) => self.accounts.select_previous(),
+            (Focus::Accounts, KeyCode::Down) => self.accounts.select_next(),
+            (Focus::Form, KeyCode::Left) => {
+                self.form_idx = (self.form_idx + FORM_CNT - 1) % FORM_CNT;
+                self.field_idx = 0;
+            }
+            (Focus::Form, KeyCode::Right) => {
+                self.form_idx = (self.form_idx + 1) % FORM_CNT;
+                self.field_idx = 0;
+            }
+            (Focus::Form, KeyCode::Up) => self.field_idx = self.field_idx.saturating_sub(1),
+            (Focus::Form, KeyCode::Down) => {
+                self.field_idx = (self.field_idx + 1).min(self.field_labels().len() - 1);
+            }
+            (Focus::Form, KeyCode::Char(c)) => self.fields[self.field_idx].push(c),
+            (Focus::Form, KeyCode::Backspace) => {
+                self.fields[self.field_idx].pop();
+            }
+            (Focus::Form, KeyCode::Enter) => {
+                self.status = Some(match self.submit() {
+                    Ok(msg) => {
+                        self.fields = Default::default();
+                        self.field_idx = 0;
+
+                        (msg, false)
+                    }
+                    Err(msg) => {
+                        warn!(err = %msg, "reported an error");
+
+                        (msg, true)
+                    }
+                });
+            }
+            _ => {}
+        }
+    }
+
+    /// Draws the panes of the user interface.
+    fn draw(&mut self, frame: &mut Frame) {
+        let messages = messages();
+
+        let [main_area, help_area] = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [accounts_area, side_area] =
+            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(main_area);
+        let [rates_area, form_area] = Layout::vertical([
+            Constraint::Length(self.bank.currency_codes().len() as u16 + 2),
+            Constraint::Min(0),
+        ])
+        .areas(side_area);
+        let focused = |focus| {
+            if self.focus == focus {
+                Style::new().fg(Color::Cyan)
+            } else {
+                Style::new()
+            }
+        };
+
+        let accounts = List::new(self.bank.accounts().iter().map(|a| {
+            format!(
+                "{} {:<20} {:>16}",
+                a.number,
+                a.name,
+                money(a.balance.amount(), &a.currency)
+            )
+        }))
+        .block(
+            Block::bordered()
+                .title(messages.tui_accounts)
+                .border_style(focused(Focus::Accounts)),
+        )
+        .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+
+        frame.render_stateful_widget(accounts, accounts_area, &mut self.accounts);
+
+        let rates = Table::new(
+            self.bank
+                .currency_codes()
+                .iter()
+                .zip(self.bank.currency_titles())
+                .skip(1)
+                .map(|(code, title)| {
+                    Row::new([
+                        title,
+                        self.bank
+                            .exchange_rates()
+                            .get(code)
+                            .copied()
+                            .unwrap_or_default()
+                            .to_string(),
+                    ])
+                }),
+            [Constraint::Min(0), Constraint::Length(12)],
+        )
+        .header(
+            Row::new([messages.tui_currency, messages.rate_headers[2]])
+                .style(Style::new().add_modifier(Modifier::BOLD)),
+        )
+        .block(Block::bordered().title(messages.tui_exchange_rates));
+
+        frame.render_widget(rates, rates_area);
+
+        let mut lines = vec![
+            Line::from(format!("< {} >", messages.tui_forms[self.form_idx]))
+                .style(Style::new().add_modifier(Modifier::BOLD)),
+            Line::from(""),
+        ];
+
+        if self.form_idx == 1 || self.form_idx == 2 {
+            lines.push(Line::from(fill(
+                messages.tui_account,
+                &[&self
+                    .selected_account()
+                    .unwrap_or_else(|| String::from(messages.tui_none_selected))],
+            )));
+        }
+
+        for (i, label) in self.field_labels().iter().enumerate() {
+            let value = if *label == messages.tui_pin {
+                "*".repeat(self.fields[i].chars().count())
+            } else {
+                self.fields[i].clone()
+            };
+            let line = Line::from(format!("{label}: {value}"));
+
+            lines.push(if self.focus == Focus::Form && i == self.field_idx {
+                line.style(Style::new().add_modifier(Modifier::REVERSED))
+            } else {
+                line
+            });
+        }
+
+        if let Some((msg, is_error)) = &self.status {
+            lines.push(Line::from(""));
+            lines.push(Line::from(msg.as_str()).style(Style::new().fg(if *is_error {
+                Color::Red
+            } else {
+                Color::Green
+            })));
+        }
+
+        frame.render_widget(
+            Paragraph::new(lines).block(
+                Block::bordered()
+                    .title(messages.tui_transaction)
+                    .border_style(focused(Focus::Form)),
+            ),
+            form_area,
+        );
+
+        frame.render_widget(Paragraph::new(messages.tui_keys), help_area);
+    }
+}
+
+/// Runs the terminal user interface over a bank until the user quits.
+pub fn run(bank: Bank) -> io::Result<()> {
+    let mut app = App {
+        bank,
+        focus: Focus::Accounts,
+        accounts: ListState::default().with_selected(Some(0)),
+        form_idx: 1,
+        fields: Default::default(),
+        field_idx: 0,
+        status: None,
+        is_quitting: false,
+    };
+
+    ratatui::run(|terminal: &mut DefaultTerminal| {
+        while !app.is_quitting {
+            terminal.draw(|frame| app.draw(frame))?;
+
+            if let Event::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+            {
+                app.handle_key(key.code);
+            }
+        }
+
+        Ok(())
+    })
+}
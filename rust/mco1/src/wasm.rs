@@ -0,0 +1,120 @@
+//! The WebAssembly bindings of the core, so that a browser demo page can be driven by the same logic as the CLI.
+//!
+//! Build them with `cargo rustc --lib --target wasm32-unknown-unknown --no-default-features --features wasm
+//! --crate-type cdylib`, then generate their JavaScript with `wasm-bindgen --target web`. Amounts and rates are passed
+//! as decimal strings so that no precision is lost, and the results are returned as JSON strings. Nothing is saved,
+//! since a browser has no file system to keep a journal in.
+
+use rust_decimal::Decimal;
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    account::{AccountType, Contact},
+    bank::{Bank, BankError},
+    clock, interest,
+};
+
+/// A bank kept in the browser's memory, with the transactions of the demo page.
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct WasmBank {
+    /// The bank that the transactions are performed on.
+    bank: Bank,
+}
+#[wasm_bindgen]
+impl WasmBank {
+    /// Creates an empty bank, with every exchange rate at one.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmBank {
+        WasmBank::default()
+    }
+
+    /// Registers a new savings account, protected by a PIN.
+    pub fn register(&mut self, name: String, pin: &str) -> Result<(), JsError> {
+        self.bank
+            .register(name, Vec::new(), AccountType::Savings, None, Contact::default(), pin)
+            .map_err(js_error)
+    }
+
+    /// Returns an account's balance, in its currency, as a decimal string.
+    pub fn balance(&self, account: &str) -> Result<String, JsError> {
+        let account = self
+            .bank
+            .account(account)
+            .ok_or_else(|| js_error(BankError::AccountNotFound))?;
+
+        Ok(account.balance.amount().to_string())
+    }
+
+    /// Deposits an amount in a currency to an account, returning the JSON of the receipt.
+    pub fn deposit(&mut self, account: &str, amount: &str, currency: &str) -> Result<String, JsError> {
+        let receipt = self
+            .bank
+            .deposit(account, currency, decimal(amount)?, None)
+            .map_err(js_error)?;
+
+        to_json(&receipt)
+    }
+
+    /// Withdraws an amount in a currency from an account, returning the JSON of the receipt.
+    pub fn withdraw(&mut self, account: &str, amount: &str, currency: &str) -> Result<String, JsError> {
+        let receipt = self
+            .bank
+            .withdraw(account, currency, decimal(amount)?, None)
+            .map_err(js_error)?;
+
+        to_json(&receipt)
+    }
+
+    /// Sets the value of one unit of a foreign currency in Philippine Pesos.
+    #[wasm_bindgen(js_name = setExchangeRate)]
+    pub fn set_exchange_rate(&mut self, currency: &str, rate: &str) -> Result<(), JsError> {
+        self.bank.set_exchange_rate(currency, decimal(rate)?).map_err(js_error)
+    }
+
+    /// Exchanges an amount from one currency to another, returning the JSON of its breakdown.
+    pub fn exchange(&mut self, from: &str, to: &str, amount: &str) -> Result<String, JsError> {
+        let amount = decimal(amount)?;
+        let quote = self.bank.quote_exchange(from, to, amount).map_err(js_error)?;
+
+        self.bank.exchange(from, to, amount).map_err(js_error)?;
+
+        to_json(&quote)
+    }
+
+    /// Calculates the interest an account would earn over a number of days from today, returning the JSON of each
+    /// day's row.
+    pub fn interest(&self, account: &str, days: u32) -> Result<String, JsError> {
+        let account = self
+            .bank
+            .account(account)
+            .ok_or_else(|| js_error(BankError::AccountNotFound))?;
+        let rows = interest::schedule(
+            account.balance.amount(),
+            &self.bank.interest_terms(account),
+            clock::date(self.bank.now()),
+            days,
+            None,
+        );
+
+        to_json(&rows)
+    }
+}
+
+/// Converts a rejected transaction's error into a JavaScript error with its message.
+fn js_error(err: BankError) -> JsError {
+    JsError::new(&err.to_string())
+}
+
+/// Parses a decimal string.
+fn decimal(amount: &str) -> Result<Decimal, JsError> {
+    amount
+        .trim()
+        .parse::<Decimal>()
+        .map_err(|_| js_error(BankError::InvalidAmount { max: None }))
+}
+
+/// Serializes a result into JSON.
+fn to_json<T: serde::Serialize>(result: &T) -> Result<String, JsError> {
+    serde_json::to_string(result).map_err(|err| JsError::new(&err.to_string()))
+}
@@ -0,0 +1,372 @@
+//! The webhooks that the JSON of each deposit, withdrawal, transfer, reversal, and rate change is posted to as it is
+//! committed.
+//!
+//! Events are delivered in the order they were published by a worker thread, so that a slow or unreachable webhook
+//! doesn't hold up the transactions, nor the bank's other users while it is locked. Each delivery is retried a few
+//! times if the webhook can't be reached or fails, then recorded in the profile's delivery log, whether it succeeded or
+//! not. The pending deliveries are flushed when the webhooks are dropped along with their bank, or by [`flush`] before
+//! the process exits without dropping it, so that none are lost.
+
+use std::{
+    fs::OpenOptions,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::{
+        Condvar, Mutex, PoisonError,
+        mpsc::{self, Sender},
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use serde::Serialize;
+use tracing::{debug, warn};
+
+use crate::{
+    clock::{Clock, SystemClock},
+    event::{Event, EventKind, EventSink},
+};
+
+/// The default path of the webhooks' delivery log.
+pub const DELIVERY_LOG_PATH: &str = "webhooks.jsonl";
+
+/// The most times an event is posted to a webhook before its delivery is given up on.
+const MAX_ATTEMPTS: u32 = 3;
+
+/// How long to wait before retrying the first failed attempt, which doubles after each one.
+const RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// How long a single attempt can take before it fails.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The number of events published to any webhooks that are still being delivered, with a signal for when it drops.
+static PENDING: (Mutex<usize>, Condvar) = (Mutex::new(0), Condvar::new());
+
+/// A delivery of an event to a webhook, as recorded in the delivery log.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Delivery {
+    /// The URL of the webhook.
+    pub url: String,
+    /// The kind of the delivered event.
+    pub event: EventKind,
+    /// The reference number of the event's transaction.
+    pub reference: String,
+    /// The time the delivery ended at, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The number of times the event was posted.
+    pub attempts: u32,
+    /// The HTTP status of the last response, if the webhook responded.
+    pub status: Option<u16>,
+    /// Whether the webhook accepted the event with a successful status.
+    pub is_delivered: bool,
+    /// The reason the last attempt failed, if it did.
+    pub error: Option<String>,
+}
+
+/// The webhooks of a bank, subscribed to its events, which are handed to their worker to be delivered.
+pub struct Webhooks {
+    /// Sends the published events to the worker, until the webhooks are dropped.
+    events: Option<Sender<Event>>,
+    /// The worker delivering the events.
+    worker: Option<JoinHandle<()>>,
+}
+impl Webhooks {
+    /// Creates the webhooks at some URLs, recording their deliveries in the log at a path, and starts their worker.
+    pub fn new<P: AsRef<Path>>(urls: Vec<String>, log_path: P) -> Webhooks {
+        let deliverer = Deliverer::new(urls, log_path.as_ref().to_path_buf());
+        let (events, published) = mpsc::channel::<Event>();
+        let worker = thread::spawn(move || {
+            for event in published {
+                deliverer.publish(&event);
+                settle();
+            }
+        });
+
+        Webhooks {
+            events: Some(events),
+            worker: Some(worker),
+        }
+    }
+}
+impl EventSink for Webhooks {
+    fn publish(&mut self, event: &Event) {
+        *PENDING.0.lock().unwrap_or_else(PoisonError::into_inner) += 1;
+
+        if self.events.as_ref().is_none_or(|e| e.send(event.clone()).is_err()) {
+            warn!(
+                reference = event.reference,
+                "the webhooks' worker stopped, so an event wasn't delivered"
+            );
+            settle();
+        }
+    }
+}
+impl Drop for Webhooks {
+    /// Waits for the worker to deliver the events already published, which it stops after.
+    fn drop(&mut self) {
+        self.events = None;
+
+        if let Some(worker) = self.worker.take()
+            && worker.join().is_err()
+        {
+            warn!("the webhooks' worker panicked");
+        }
+    }
+}
+
+/// Marks an event published to webhooks as delivered or given up on.
+fn settle() {
+    let (pending, settled) = &PENDING;
+
+    *pending.lock().unwrap_or_else(PoisonError::into_inner) -= 1;
+    settled.notify_all();
+}
+
+/// Waits for every event published to any webhooks to be delivered or given up on, so that none are lost when the
+/// process exits without dropping their banks.
+pub fn flush() {
+    let (pending, settled) = &PENDING;
+    let pending = pending.lock().unwrap_or_else(PoisonError::into_inner);
+
+    drop(
+        settled
+            .wait_while(pending, |p| *p > 0)
+            .unwrap_or_else(PoisonError::into_inner),
+    );
+}
+
+/// The worker of some webhooks, posting each event to every webhook in turn.
+struct Deliverer {
+    /// The URLs of the webhooks.
+    urls: Vec<String>,
+    /// The path of the delivery log.
+    log_path: PathBuf,
+    /// The HTTP client posting the events.
+    agent: ureq::Agent,
+}
+impl Deliverer {
+    /// Creates the worker of the webhooks at some URLs, recording their deliveries in the log at a path.
+    fn new(urls: Vec<String>, log_path: PathBuf) -> Deliverer {
+        let agent = ureq::Agent::config_builder()
+            .timeout_global(Some(TIMEOUT))
+            .http_status_as_error(false)
+            .build()
+            .into();
+
+        Deliverer { urls, log_path, agent }
+    }
+
+    /// Posts an event's JSON to a webhook, retrying if it can't be reached or fails on its end.
+    fn deliver(&self, url: &str, event: &Event, payload: &str) -> Delivery {
+        let mut delivery = Delivery {
+            url: url.to_string(),
+            event: event.event,
+            reference: event.reference.clone(),
+            timestamp: 0,
+            attempts: 0,
+            status: None,
+            is_delivered: false,
+            error: None,
+        };
+        let mut delay = RETRY_DELAY;
+
+        while delivery.attempts < MAX_ATTEMPTS {
+            if delivery.attempts > 0 {
+                thread::sleep(delay);
+
+                delay *= 2;
+            }
+
+            delivery.attempts += 1;
+
+            let is_retryable = match self
+                .agent
+                .post(url)
+                .header("Content-Type", "application/json")
+                .send(payload)
+            {
+                Ok(response) => {
+                    let status = response.status();
+
+                    delivery.status = Some(status.as_u16());
+                    delivery.is_delivered = status.is_success();
+                    delivery.error = (!status.is_success()).then(|| status.to_string());
+
+                    status.is_server_error() || status.as_u16() == 429
+                }
+                Err(err) => {
+                    delivery.status = None;
+                    delivery.error = Some(err.to_string());
+
+                    true
+                }
+            };
+
+            if delivery.is_delivered || !is_retryable {
+                break;
+            }
+        }
+
+        delivery.timestamp = SystemClock.now();
+
+        delivery
+    }
+
+    /// Appends a delivery to the delivery log.
+    fn record(&self, delivery: &Delivery) -> io::Result<()> {
+        let mut log = OpenOptions::new().create(true).append(true).open(&self.log_path)?;
+        let line = serde_json::to_string(delivery)?;
+
+        writeln!(log, "{line}")
+    }
+
+    /// Delivers an event to every webhook, recording each delivery.
+    fn publish(&self, event: &Event) {
+        let payload = match serde_json::to_string(event) {
+            Ok(payload) => payload,
+            Err(err) => {
+                warn!(%err, "failed to serialize an event for the webhooks");
+
+                return;
+            }
+        };
+
+        for url in &self.urls {
+            let delivery = self.deliver(url, event, &payload);
+
+            if delivery.is_delivered {
+                debug!(url, attempts = delivery.attempts, "delivered an event to a webhook");
+            } else {
+                warn!(url, error = ?delivery.error, "failed to deliver an event to a webhook");
+            }
+
+            if let Err(err) = self.record(&delivery) {
+                warn!(%err, path = %self.log_path.display(), "failed to record a webhook delivery");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        io::{BufRead, BufReader, Read},
+        net::TcpListener,
+        process,
+    };
+
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::journal::EntryKind;
+
+    /// Starts a webhook answering each request with the next of some statuses, returning its URL and a handle to the
+    /// bodies of the requests it answered.
+    fn stub(statuses: &'static [u16]) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            statuses
+                .iter()
+                .map(|status| {
+                    let (stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(&stream);
+                    let mut content_len = 0;
+
+                    loop {
+                        let mut line = String::new();
+
+                        reader.read_line(&mut line).unwrap();
+
+                        if let Some((name, value)) = line.split_once(':')
+                            && name.eq_ignore_ascii_case("content-length")
+                        {
+                            content_len = value.trim().parse().unwrap();
+                        }
+
+                        if line.trim().is_empty() {
+                            break;
+                        }
+                    }
+
+                    let mut body = vec![0; content_len];
+
+                    reader.read_exact(&mut body).unwrap();
+                    write!(
+                        &stream,
+                        "HTTP/1.1 {status} Stub\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    )
+                    .unwrap();
+
+                    String::from_utf8(body).unwrap()
+                })
+                .collect()
+        });
+
+        (url, handle)
+    }
+
+    /// Returns the event of a deposit of ₱100.00 to an account named Ana.
+    fn deposit() -> Event {
+        Event {
+            event: EventKind::Deposit,
+            reference: String::from("TX00000001"),
+            timestamp: 42,
+            transaction: EntryKind::Deposit {
+                account: String::from("Ana"),
+                amount: dec!(100),
+                currency: String::from("PHP"),
+                converted_amount: dec!(100),
+                memo: None,
+            },
+        }
+    }
+
+    /// Publishes an event to a webhook at a URL, returning the deliveries recorded in the delivery log once the
+    /// webhooks are dropped.
+    fn publish(url: &str, event: &Event, test: &str) -> Vec<serde_json::Value> {
+        let log_path = env::temp_dir().join(format!("mcos-{test}-{}.jsonl", process::id()));
+        let mut webhooks = Webhooks::new(vec![url.to_string()], &log_path);
+
+        webhooks.publish(event);
+        drop(webhooks);
+
+        let log = fs::read_to_string(&log_path).unwrap();
+
+        fs::remove_file(&log_path).unwrap();
+
+        log.lines().map(|l| serde_json::from_str(l).unwrap()).collect()
+    }
+
+    #[test]
+    fn failed_delivery_is_retried_until_accepted() {
+        let (url, requests) = stub(&[500, 200]);
+        let event = deposit();
+        let deliveries = publish(&url, &event, "webhook-retried");
+
+        assert_eq!(
+            requests.join().unwrap(),
+            vec![serde_json::to_string(&event).unwrap(); 2]
+        );
+        assert_eq!(deliveries.len(), 1);
+        assert_eq!(deliveries[0]["url"], url.as_str());
+        assert_eq!(deliveries[0]["event"], "deposit");
+        assert_eq!(deliveries[0]["reference"], "TX00000001");
+        assert_eq!(deliveries[0]["attempts"], 2);
+        assert_eq!(deliveries[0]["status"], 200);
+        assert_eq!(deliveries[0]["is_delivered"], true);
+        assert_eq!(deliveries[0]["error"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn rejected_delivery_isnt_retried() {
+        let (url, requests) = stub(&[400]);
+        let deliveries = publish(&url, &deposit(), "webhook-rejected");
+
+        assert_eq!(requests.join().unwrap().len(), 1);
+        assert_eq!(deliveries[0]["attempts"], 1);
+        assert_eq!(deliveries[0]["status"], 400);
+        assert_eq!(deliveries[0]["is_delivered"], false);
+    }
+}
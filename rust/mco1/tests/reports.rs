@@ -0,0 +1,127 @@
+//! The snapshots of the reports and tables that the non-interactive commands print, which catch changes to their
+//! formatting.
+//!
+//! Each test runs commands against a bank of the same few accounts, transactions, and exchange rates, then compares the
+//! printed report against its snapshot in `tests/snapshots`. Review changed snapshots with `cargo insta review`, or
+//! run the tests with the `INSTA_UPDATE=always` environment variable set to accept them all.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// The configuration of the snapshotted banks, whose interest doesn't depend on whether the current year is a leap
+/// year.
+const CONFIG: &str = r#"{ "day_count": "act/360" }"#;
+
+/// The commands that populate the snapshotted banks.
+const SETUP: &[&str] = &[
+    "register --account Ana --pin 1234",
+    "register --account Ben --pin 1234 --type checking --owner Cy",
+    "deposit --account Ana --pin 1234 --amount 50000 --memo Salary",
+    "withdraw --account Ana --pin 1234 --amount 1250.50",
+    "transfer --account Ana --pin 1234 --to Ben --amount 30000",
+    "set-rate --currency USD --rate 56.25",
+    "set-rate --currency JPY --rate 0.3775",
+    "deposit --account Ben --pin 1234 --amount 125 --currency USD",
+];
+
+/// Runs the app in a working directory with space-separated arguments, returning what it printed.
+///
+/// The environment is cleared so that the user's own settings can't leak into the output, and color is disabled.
+fn mcos(dir: &Path, args: &str) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_mcos"))
+        .args(args.split_whitespace())
+        .current_dir(dir)
+        .env_clear()
+        .env("NO_COLOR", "1")
+        .output()
+        .expect("Failed to run the app...");
+
+    assert!(
+        output.status.success(),
+        "The app exited with {} on `mcos {args}`:\n{}",
+        output.status,
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    String::from_utf8(output.stdout).expect("The app's output must be UTF-8...")
+}
+
+/// Creates a populated bank in a fresh working directory for a test.
+fn bank(name: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("reports").join(name);
+
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("Failed to create the bank's working directory...");
+    fs::write(dir.join("config.json"), CONFIG).expect("Failed to write the bank's configuration...");
+
+    for args in SETUP {
+        mcos(&dir, args);
+    }
+
+    dir
+}
+
+/// Asserts that a report matches its snapshot, with the times that transactions and exchange rates were recorded at
+/// masked without changing the widths of the tables' columns.
+macro_rules! assert_report {
+    ($report:expr) => {
+        insta::with_settings!({ filters => vec![(r"\d{4}-\d{2}-\d{2} \d{2}:\d{2}", "YYYY-MM-DD HH:MM")] }, {
+            insta::assert_snapshot!($report);
+        });
+    };
+}
+
+#[test]
+fn interest_table() {
+    let dir = bank("interest_table");
+
+    assert_report!(mcos(&dir, "interest --account Ana --pin 1234 --days 10"));
+}
+
+#[test]
+fn compound_interest_table_with_deposits() {
+    let dir = bank("compound_interest_table_with_deposits");
+
+    assert_report!(mcos(
+        &dir,
+        "interest --account Ana --pin 1234 --days 12 --mode compound --deposit 1000 --every 5"
+    ));
+}
+
+#[test]
+fn rate_table() {
+    let dir = bank("rate_table");
+
+    assert_report!(mcos(&dir, "rates"));
+}
+
+#[test]
+fn account_list() {
+    let dir = bank("account_list");
+
+    assert_report!(mcos(&dir, "accounts"));
+}
+
+#[test]
+fn account_list_by_balance() {
+    let dir = bank("account_list_by_balance");
+
+    assert_report!(mcos(&dir, "accounts --sort balance"));
+}
+
+#[test]
+fn statement() {
+    let dir = bank("statement");
+
+    assert_report!(mcos(&dir, "history --account Ana --pin 1234"));
+}
+
+#[test]
+fn foreign_currency_statement() {
+    let dir = bank("foreign_currency_statement");
+
+    assert_report!(mcos(&dir, "history --account Ben --pin 1234"));
+}
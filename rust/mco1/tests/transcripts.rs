@@ -0,0 +1,107 @@
+//! The golden transcripts of the interactive menu, which feed canned responses to the app and compare everything it
+//! prints against the checked-in output.
+//!
+//! Each transcript is a pair of files in `tests/transcripts`: `<name>.in` holding the responses, one per line, and
+//! `<name>.out` holding the expected standard output. Neither depends on the Rust app, so other implementations of the
+//! app can be run against the same transcripts to check that they print the same. Run the tests with the
+//! `UPDATE_TRANSCRIPTS` environment variable set to write the current output as the expected output instead.
+
+use std::{
+    env, fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+/// The directory holding the transcripts' responses and expected output.
+const TRANSCRIPTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/transcripts");
+
+/// Runs the app in a fresh working directory, answering its prompts with the responses of a transcript.
+///
+/// The environment is cleared so that the user's own settings can't leak into the output, and color is disabled.
+fn run(name: &str, responses: &[u8]) -> String {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
+        .join("transcripts")
+        .join(name);
+
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("Failed to create the transcript's working directory...");
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_mcos"))
+        .current_dir(&dir)
+        .env_clear()
+        .env("NO_COLOR", "1")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to start the app...");
+
+    child
+        .stdin
+        .take()
+        .expect("Failed to open the app's standard input...")
+        .write_all(responses)
+        .expect("Failed to write the transcript's responses...");
+
+    let output = child.wait_with_output().expect("Failed to wait for the app...");
+
+    assert!(
+        output.status.success(),
+        "The app exited with {} on the {name} transcript:\n{}",
+        output.status,
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    String::from_utf8(output.stdout).expect("The app's output must be UTF-8...")
+}
+
+/// Checks that the app prints a transcript's expected output, or writes it if `UPDATE_TRANSCRIPTS` is set.
+fn check(name: &str) {
+    let path = |extension: &str| Path::new(TRANSCRIPTS_DIR).join(format!("{name}.{extension}"));
+    let responses = fs::read(path("in")).expect("Failed to read the transcript's responses...");
+    let actual = run(name, &responses);
+
+    if env::var_os("UPDATE_TRANSCRIPTS").is_some() {
+        fs::write(path("out"), actual).expect("Failed to write the transcript's output...");
+
+        return;
+    }
+
+    let expected = fs::read_to_string(path("out")).expect("Failed to read the transcript's output...");
+
+    if let Some((idx, (expected_line, actual_line))) = expected
+        .lines()
+        .chain(["<end of output>"])
+        .zip(actual.lines().chain(["<end of output>"]))
+        .enumerate()
+        .find(|(_, (e, a))| e != a)
+    {
+        panic!(
+            "The {name} transcript differs on line {}:\nexpected: {expected_line}\n  actual: {actual_line}\n\nRun with \
+             UPDATE_TRANSCRIPTS=1 if the change is intended.",
+            idx + 1
+        );
+    }
+
+    assert_eq!(expected, actual, "The {name} transcript differs in its line endings!");
+}
+
+/// Defines a test for each transcript, named after its files.
+macro_rules! transcripts {
+    ($($name:ident),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                check(stringify!($name));
+            }
+        )*
+    };
+}
+
+transcripts! {
+    register_and_deposit,
+    withdraw_and_transfer,
+    currency_exchange,
+    invalid_input,
+}